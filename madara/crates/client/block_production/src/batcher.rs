@@ -1,5 +1,6 @@
 use crate::util::{AdditionalTxInfo, BatchToExecute};
 use anyhow::Context;
+use blockifier::transaction::transaction_execution::Transaction;
 use futures::{
     stream::{self, BoxStream, PollNext},
     StreamExt, TryStreamExt,
@@ -7,11 +8,12 @@ use futures::{
 use mc_db::MadaraBackend;
 use mc_mempool::Mempool;
 use mc_settlement_client::SettlementClient;
+use mc_submit_tx::TransactionPolicies;
 use mp_convert::ToFelt;
 use mp_transactions::{validated::ValidatedMempoolTx, L1HandlerTransactionWithFee};
 use mp_utils::service::ServiceContext;
 use std::sync::Arc;
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, OwnedMutexGuard};
 
 pub struct Batcher {
     backend: Arc<MadaraBackend>,
@@ -19,8 +21,48 @@ pub struct Batcher {
     l1_message_stream: BoxStream<'static, anyhow::Result<L1HandlerTransactionWithFee>>,
     ctx: ServiceContext,
     out: mpsc::Sender<BatchToExecute>,
-    bypass_in: mpsc::Receiver<ValidatedMempoolTx>,
+    bypass_in: OwnedMutexGuard<mpsc::Receiver<ValidatedMempoolTx>>,
     batch_size: usize,
+    policies: TransactionPolicies,
+}
+
+/// Converts a mempool transaction for execution. The policies or the L2 gas price may have changed since the transaction
+/// was accepted into the mempool, in which case it is dropped and removed from the mempool.
+/// Bypass and L1 handler transactions are not filtered.
+fn convert_mempool_tx(
+    policies: &TransactionPolicies,
+    mempool: &Mempool,
+    l2_gas_price: u128,
+    tx: ValidatedMempoolTx,
+) -> Option<anyhow::Result<(Transaction, AdditionalTxInfo)>> {
+    let tx_hash = tx.tx_hash;
+    // L2 gas bounds are not kept in the blockifier transaction, so they are checked before the conversion.
+    if let Some(l2_gas) = tx.tx.resource_bounds().map(|bounds| &bounds.l2_gas).filter(|l2_gas| l2_gas.max_amount != 0) {
+        if l2_gas.max_price_per_unit < l2_gas_price {
+            tracing::info!(
+                "Dropping mempool transaction {tx_hash:#x}: L2 gas max price per unit {} is lower than the current L2 gas price {l2_gas_price}",
+                l2_gas.max_price_per_unit
+            );
+            mempool.on_txs_removed(&[tx]);
+            return None;
+        }
+    }
+
+    let (btx, arrived_at, declared_class) = match tx.into_blockifier_for_sequencing() {
+        Ok(res) => res,
+        Err(err) => return Some(Err(err.into())),
+    };
+    let rejection = match &btx {
+        Transaction::Account(account_tx) => policies.check(&account_tx.tx).err(),
+        Transaction::L1Handler(_) => None,
+    };
+    let Some(err) = rejection else { return Some(Ok((btx, AdditionalTxInfo { declared_class }))) };
+
+    tracing::info!("Dropping mempool transaction {tx_hash:#x}: {err}");
+    if let Transaction::Account(account_tx) = btx {
+        mempool.on_txs_removed(&[ValidatedMempoolTx::from_starknet_api(account_tx.tx, arrived_at, declared_class)]);
+    }
+    None
 }
 
 impl Batcher {
@@ -30,7 +72,8 @@ impl Batcher {
         l1_client: Arc<dyn SettlementClient>,
         ctx: ServiceContext,
         out: mpsc::Sender<BatchToExecute>,
-        bypass_in: mpsc::Receiver<ValidatedMempoolTx>,
+        bypass_in: OwnedMutexGuard<mpsc::Receiver<ValidatedMempoolTx>>,
+        policies: TransactionPolicies,
     ) -> Self {
         Self {
             mempool,
@@ -39,6 +82,7 @@ impl Batcher {
             out,
             bypass_in,
             batch_size: backend.chain_config().block_production_concurrency.batch_size,
+            policies,
             backend,
         }
     }
@@ -79,16 +123,14 @@ impl Batcher {
             });
 
             // Note: this is not hoisted out of the loop, because we don't want to keep the lock around when waiting on the output channel reserve().
+            let (policies, mempool, backend) = (&self.policies, &self.mempool, &self.backend);
             let mempool_txs_stream = stream::unfold(self.mempool.clone(), |mempool| async move {
                 let consumer = mempool.get_consumer().await;
                 Some((consumer, mempool))
             })
-            .map(|c| {
-                stream::iter(c.map(|tx| {
-                    tx.into_blockifier_for_sequencing()
-                        .map(|(btx, _ts, declared_class)| (btx, AdditionalTxInfo { declared_class }))
-                        .map_err(anyhow::Error::from)
-                }))
+            .map(move |c| {
+                let l2_gas_price = backend.latest_pending_block().header.l1_gas_price.strk_l2_gas_price;
+                stream::iter(c.filter_map(move |tx| convert_mempool_tx(policies, mempool, l2_gas_price, tx)))
             })
             .flatten();
 
@@ -137,3 +179,41 @@ impl Batcher {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{devnet_setup, make_invoke_tx, DevnetSetup};
+    use assert_matches::assert_matches;
+    use mc_devnet::Multicall;
+    use mc_submit_tx::SubmitTransaction;
+    use mp_chain_config::TransactionPolicyConfig;
+    use starknet_types_core::felt::Felt;
+    use std::time::Duration;
+
+    // The L2 gas price may have gone up since the transactions were accepted into the mempool.
+    #[rstest::rstest]
+    #[timeout(Duration::from_secs(30))]
+    #[tokio::test]
+    async fn test_convert_mempool_tx_l2_gas_price(#[future] devnet_setup: DevnetSetup) {
+        let setup = devnet_setup.await;
+        let policies = TransactionPolicies::new(TransactionPolicyConfig::default());
+        for sender in &setup.contracts.0[..2] {
+            // The max L2 gas price per unit of these transactions is 10000.
+            let tx = make_invoke_tx(sender, Multicall::default(), &setup.backend, Felt::ZERO);
+            setup.tx_validator.submit_invoke_transaction(tx).await.unwrap();
+        }
+
+        let mut consumer = setup.mempool.get_consumer().await;
+        let tx = consumer.next().unwrap();
+        assert_matches!(convert_mempool_tx(&policies, &setup.mempool, 10_000, tx), Some(Ok(_)));
+        let tx = consumer.next().unwrap();
+        let dropped_tx_hash = tx.tx_hash;
+        assert_matches!(convert_mempool_tx(&policies, &setup.mempool, 10_001, tx), None);
+        drop(consumer);
+
+        assert!(setup.mempool.is_empty().await);
+        // The dropped transaction is forgotten by the mempool.
+        assert_eq!(setup.tx_validator.received_transaction(dropped_tx_hash).await, Some(false));
+    }
+}
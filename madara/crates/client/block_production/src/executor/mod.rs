@@ -6,11 +6,13 @@ use blockifier::{
 };
 use mc_db::MadaraBackend;
 use mc_mempool::L1DataProvider;
+use mc_submit_tx::ImpersonatedAccounts;
+use mp_block::header::GasPrices;
 use mp_convert::Felt;
 use std::{any::Any, collections::HashMap, panic::AssertUnwindSafe, sync::Arc};
 use tokio::sync::{
     mpsc::{self, UnboundedReceiver},
-    oneshot,
+    oneshot, OwnedMutexGuard,
 };
 
 mod tests;
@@ -31,12 +33,33 @@ pub struct ExecutorThreadHandle {
 pub enum ExecutorCommandError {
     #[error("Executor not running")]
     ChannelClosed,
+    #[error("Block timestamp out of range")]
+    BlockTimestampOutOfRange,
+    #[error("Storage value overflow")]
+    StorageOverflow,
 }
 
 #[derive(Debug)]
 pub enum ExecutorCommand {
     /// Force close the current block.
     CloseBlock(oneshot::Sender<Result<(), ExecutorCommandError>>),
+    /// Add amounts to u256 storage values, held as a low limb at the key and a high limb at the next key. The current
+    /// block is closed, and the writes are made at the start of a new block, which is closed right away. Replies with
+    /// the new `(low, high)` values once they have been written, or with an error and no write if one of them
+    /// overflows. Devnet only.
+    IncreaseStorage(Vec<(StorageEntry, u128)>, oneshot::Sender<Result<Vec<(u128, u128)>, ExecutorCommandError>>),
+    /// Change the timestamp of the next blocks. Devnet only.
+    SetBlockTimestamp(BlockTimestampUpdate, oneshot::Sender<Result<(), ExecutorCommandError>>),
+    /// Override the gas prices of the next blocks, or go back to the L1 data provider prices with `None`. Devnet only.
+    SetGasPrices(Option<GasPrices>, oneshot::Sender<Result<(), ExecutorCommandError>>),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum BlockTimestampUpdate {
+    /// Unix timestamp of the next block, in seconds. The following blocks keep advancing from there.
+    Set(u64),
+    /// Move the clock forward by this many seconds.
+    Increase(u64),
 }
 
 #[derive(Debug)]
@@ -71,17 +94,28 @@ impl StopErrorReceiver {
     }
 }
 /// Create the executor thread and returns a handle to it.
+///
+/// The commands receiver is borrowed through a mutex guard, so that it is handed back once the thread stops and the
+/// block production task can be restarted.
 pub fn start_executor_thread(
     backend: Arc<MadaraBackend>,
     l1_data_provider: Arc<dyn L1DataProvider>,
-    commands: UnboundedReceiver<ExecutorCommand>,
+    commands: OwnedMutexGuard<UnboundedReceiver<ExecutorCommand>>,
+    impersonated_accounts: ImpersonatedAccounts,
 ) -> anyhow::Result<ExecutorThreadHandle> {
     // buffer is 1.
     let (send_batch, incoming_batches) = mpsc::channel(1);
     let (replies_sender, replies_recv) = mpsc::channel(100);
     let (stop_sender, stop_recv) = oneshot::channel();
 
-    let executor = thread::ExecutorThread::new(backend, l1_data_provider, incoming_batches, replies_sender, commands)?;
+    let executor = thread::ExecutorThread::new(
+        backend,
+        l1_data_provider,
+        incoming_batches,
+        replies_sender,
+        commands,
+        impersonated_accounts,
+    )?;
     std::thread::Builder::new()
         .name("executor".into())
         .spawn(move || stop_sender.send(std::panic::catch_unwind(AssertUnwindSafe(move || executor.run()))))
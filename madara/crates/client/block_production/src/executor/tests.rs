@@ -1,20 +1,22 @@
 #![cfg(test)]
 use super::*;
-use crate::tests::{make_declare_tx, make_udc_call, DevnetSetup};
+use crate::tests::{make_declare_tx, make_invoke_tx, make_udc_call, DevnetSetup};
 use crate::{tests::devnet_setup, util::AdditionalTxInfo};
 use assert_matches::assert_matches;
 use blockifier::transaction::transaction_execution::Transaction;
 use mc_db::MadaraBackend;
+use mc_devnet::Multicall;
 use mc_exec::execution::TxInfo;
-use mp_chain_config::StarknetVersion;
+use mp_chain_config::{L2GasPriceConfig, StarknetVersion};
 use mp_convert::ToFelt;
-use mp_rpc::BroadcastedTxn;
+use mp_rpc::{BroadcastedInvokeTxn, BroadcastedTxn};
 use mp_transactions::IntoStarknetApiExt;
 use mp_transactions::{L1HandlerTransaction, L1HandlerTransactionWithFee};
 use rstest::fixture;
+use starknet_api::state::StorageKey;
 use starknet_core::utils::get_selector_from_name;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, SystemTime};
 use tokio::sync::mpsc::UnboundedSender;
 
 fn make_tx(backend: &MadaraBackend, tx: impl IntoStarknetApiExt) -> (Transaction, AdditionalTxInfo) {
@@ -70,7 +72,14 @@ async fn l1_handler_setup(
     let setup = devnet_setup.await;
 
     let (commands_sender, commands) = mpsc::unbounded_channel();
-    let mut handle = start_executor_thread(setup.backend.clone(), setup.l1_data_provider.clone(), commands).unwrap();
+    let commands = Arc::new(tokio::sync::Mutex::new(commands)).lock_owned().await;
+    let mut handle = start_executor_thread(
+        setup.backend.clone(),
+        setup.l1_data_provider.clone(),
+        commands,
+        ImpersonatedAccounts::default(),
+    )
+    .unwrap();
 
     let (tx, additional_info) = make_tx(
         &setup.backend,
@@ -307,3 +316,200 @@ async fn test_duplicate_l1_handler_in_db(#[future] l1_handler_setup: L1HandlerSe
     });
     assert_matches!(setup.handle.replies.recv().await, Some(ExecutorMessage::EndBlock));
 }
+
+#[rstest::rstest]
+#[tokio::test]
+async fn test_devnet_block_overrides_and_storage_increase(
+    #[with(Duration::from_secs(30000), None)]
+    #[future]
+    devnet_setup: DevnetSetup,
+) {
+    let setup = devnet_setup.await;
+    let (commands_sender, commands) = mpsc::unbounded_channel();
+    let commands = Arc::new(tokio::sync::Mutex::new(commands)).lock_owned().await;
+    let mut handle = start_executor_thread(
+        setup.backend.clone(),
+        setup.l1_data_provider.clone(),
+        commands,
+        ImpersonatedAccounts::default(),
+    )
+    .unwrap();
+
+    // The timestamp update applies to the block started right away.
+    let (sender, recv) = oneshot::channel();
+    commands_sender.send(ExecutorCommand::SetBlockTimestamp(BlockTimestampUpdate::Increase(3600), sender)).unwrap();
+    recv.await.unwrap().unwrap();
+    assert_matches!(handle.replies.recv().await, Some(ExecutorMessage::StartNewBlock { exec_ctx, .. }) => {
+        assert!(exec_ctx.block_timestamp >= SystemTime::now() + Duration::from_secs(3590));
+    });
+    assert_matches!(handle.replies.recv().await, Some(ExecutorMessage::BatchExecuted(res)) => {
+        assert_eq!(res.executed_txs.len(), 0);
+    });
+
+    // Timestamps out of range are rejected, and leave the offset untouched.
+    for update in [BlockTimestampUpdate::Increase(u64::MAX), BlockTimestampUpdate::Set(u64::MAX)] {
+        let (sender, recv) = oneshot::channel();
+        commands_sender.send(ExecutorCommand::SetBlockTimestamp(update, sender)).unwrap();
+        assert_matches!(recv.await.unwrap(), Err(ExecutorCommandError::BlockTimestampOutOfRange));
+        assert_matches!(handle.replies.recv().await, Some(ExecutorMessage::BatchExecuted(_)));
+    }
+
+    // The gas prices apply from the next block.
+    let gas_prices = GasPrices {
+        eth_l1_gas_price: 1,
+        strk_l1_gas_price: 2,
+        eth_l1_data_gas_price: 3,
+        strk_l1_data_gas_price: 4,
+        // Set from the chain config L2 gas price, regardless of the override.
+        eth_l2_gas_price: 1,
+        strk_l2_gas_price: 1,
+    };
+    let (sender, recv) = oneshot::channel();
+    commands_sender.send(ExecutorCommand::SetGasPrices(Some(gas_prices.clone()), sender)).unwrap();
+    recv.await.unwrap().unwrap();
+    assert_matches!(handle.replies.recv().await, Some(ExecutorMessage::BatchExecuted(res)) => {
+        assert_eq!(res.executed_txs.len(), 0);
+    });
+
+    // The storage increase closes the current block, and is made in a new block that is closed right away.
+    let entry: StorageEntry = (0x1234u64.into(), 0x10u64.into());
+    let (sender, recv) = oneshot::channel();
+    commands_sender.send(ExecutorCommand::IncreaseStorage(vec![(entry, 5)], sender)).unwrap();
+    assert_matches!(handle.replies.recv().await, Some(ExecutorMessage::BatchExecuted(res)) => {
+        assert_eq!(res.executed_txs.len(), 0);
+    });
+    assert_matches!(handle.replies.recv().await, Some(ExecutorMessage::EndBlock));
+    assert_matches!(handle.replies.recv().await, Some(ExecutorMessage::StartNewBlock { initial_state_diffs_storage, exec_ctx }) => {
+        assert_eq!(initial_state_diffs_storage.get(&entry), Some(&Felt::from(5)));
+        assert_eq!(exec_ctx.l1_gas_price, gas_prices);
+        assert!(exec_ctx.block_timestamp >= SystemTime::now() + Duration::from_secs(3590));
+        assert!(exec_ctx.block_timestamp <= SystemTime::now() + Duration::from_secs(3610));
+    });
+    assert_eq!(recv.await.unwrap().unwrap(), vec![(5, 0)]);
+    assert_matches!(handle.replies.recv().await, Some(ExecutorMessage::BatchExecuted(res)) => {
+        assert_eq!(res.executed_txs.len(), 0);
+    });
+    assert_matches!(handle.replies.recv().await, Some(ExecutorMessage::EndBlock));
+
+    // Increases add up on top of the previous blocks.
+    let (sender, recv) = oneshot::channel();
+    commands_sender.send(ExecutorCommand::IncreaseStorage(vec![(entry, 3)], sender)).unwrap();
+    assert_eq!(recv.await.unwrap().unwrap(), vec![(8, 0)]);
+    assert_matches!(handle.replies.recv().await, Some(ExecutorMessage::StartNewBlock { initial_state_diffs_storage, .. }) => {
+        assert_eq!(initial_state_diffs_storage.get(&entry), Some(&Felt::from(8)));
+    });
+    assert_matches!(handle.replies.recv().await, Some(ExecutorMessage::BatchExecuted(_)));
+    assert_matches!(handle.replies.recv().await, Some(ExecutorMessage::EndBlock));
+
+    // The low limb carries into the high limb, stored at the next key.
+    let high_entry: StorageEntry = (entry.0, entry.1.next_storage_key().unwrap());
+    let (sender, recv) = oneshot::channel();
+    commands_sender.send(ExecutorCommand::IncreaseStorage(vec![(entry, u128::MAX)], sender)).unwrap();
+    assert_eq!(recv.await.unwrap().unwrap(), vec![(7, 1)]);
+    assert_matches!(handle.replies.recv().await, Some(ExecutorMessage::StartNewBlock { initial_state_diffs_storage, .. }) => {
+        assert_eq!(initial_state_diffs_storage.get(&entry), Some(&Felt::from(7)));
+        assert_eq!(initial_state_diffs_storage.get(&high_entry), Some(&Felt::ONE));
+    });
+    assert_matches!(handle.replies.recv().await, Some(ExecutorMessage::BatchExecuted(_)));
+    assert_matches!(handle.replies.recv().await, Some(ExecutorMessage::EndBlock));
+
+    // Nothing is written when one of the values overflows: the last storage key has no room for a high limb.
+    let last_key = StorageKey::try_from(Felt::from_hex_unchecked(&format!("0x7{}", "f".repeat(62)))).unwrap();
+    let (sender, recv) = oneshot::channel();
+    commands_sender.send(ExecutorCommand::IncreaseStorage(vec![(entry, 1), ((entry.0, last_key), 1)], sender)).unwrap();
+    assert_matches!(recv.await.unwrap(), Err(ExecutorCommandError::StorageOverflow));
+    assert_matches!(handle.replies.recv().await, Some(ExecutorMessage::StartNewBlock { initial_state_diffs_storage, .. }) => {
+        assert_eq!(initial_state_diffs_storage.get(&entry), None);
+    });
+}
+
+#[rstest::rstest]
+#[tokio::test]
+async fn test_eip1559_l2_gas_price(
+    #[with(
+        Duration::from_secs(30000),
+        None,
+        false,
+        L2GasPriceConfig::Eip1559 {
+            initial_price: 1000,
+            min_price: 1,
+            target_fullness_percent: 50,
+            max_change_denominator: 8
+        }
+    )]
+    #[future]
+    devnet_setup: DevnetSetup,
+) {
+    let setup = devnet_setup.await;
+    let (commands_sender, commands) = mpsc::unbounded_channel();
+    let commands = Arc::new(tokio::sync::Mutex::new(commands)).lock_owned().await;
+    let mut handle = start_executor_thread(
+        setup.backend.clone(),
+        setup.l1_data_provider.clone(),
+        commands,
+        ImpersonatedAccounts::default(),
+    )
+    .unwrap();
+
+    let entry: StorageEntry = (0x1234u64.into(), 0x10u64.into());
+    let (sender, recv) = oneshot::channel();
+    commands_sender.send(ExecutorCommand::IncreaseStorage(vec![(entry, 5)], sender)).unwrap();
+    assert_matches!(handle.replies.recv().await, Some(ExecutorMessage::StartNewBlock { exec_ctx, .. }) => {
+        assert_eq!(exec_ctx.l1_gas_price.strk_l2_gas_price, 1000);
+        // The L1 gas prices from the data provider are 128 wei and 128 fri.
+        assert_eq!(exec_ctx.l1_gas_price.eth_l2_gas_price, 1000);
+    });
+    assert_eq!(recv.await.unwrap().unwrap(), vec![(5, 0)]);
+    assert_matches!(handle.replies.recv().await, Some(ExecutorMessage::BatchExecuted(_)));
+    assert_matches!(handle.replies.recv().await, Some(ExecutorMessage::EndBlock));
+
+    // The previous block was empty: the price goes down by 1/8.
+    let (sender, recv) = oneshot::channel();
+    commands_sender.send(ExecutorCommand::IncreaseStorage(vec![(entry, 3)], sender)).unwrap();
+    assert_matches!(handle.replies.recv().await, Some(ExecutorMessage::StartNewBlock { exec_ctx, .. }) => {
+        assert_eq!(exec_ctx.l1_gas_price.strk_l2_gas_price, 875);
+    });
+    assert_eq!(recv.await.unwrap().unwrap(), vec![(8, 0)]);
+}
+
+#[rstest::rstest]
+#[tokio::test]
+async fn test_impersonated_account_skips_validation(
+    #[with(Duration::from_secs(30000), None)]
+    #[future]
+    devnet_setup: DevnetSetup,
+) {
+    let setup = devnet_setup.await;
+    let impersonated_accounts = ImpersonatedAccounts::default();
+    let (_commands_sender, commands) = mpsc::unbounded_channel();
+    let commands = Arc::new(tokio::sync::Mutex::new(commands)).lock_owned().await;
+    let mut handle = start_executor_thread(
+        setup.backend.clone(),
+        setup.l1_data_provider.clone(),
+        commands,
+        impersonated_accounts.clone(),
+    )
+    .unwrap();
+
+    let account = &setup.contracts.0[0];
+    let make_unsigned_tx = || {
+        let mut tx = make_invoke_tx(account, Multicall::default(), &setup.backend, Felt::ZERO);
+        let BroadcastedInvokeTxn::V3(inner) = &mut tx else { unreachable!() };
+        inner.signature = vec![Felt::ONE, Felt::TWO].into();
+        make_tx(&setup.backend, BroadcastedTxn::Invoke(tx))
+    };
+
+    // Invalid signature.
+    handle.send_batch.as_mut().unwrap().send([make_unsigned_tx()].into_iter().collect()).await.unwrap();
+    assert_matches!(handle.replies.recv().await, Some(ExecutorMessage::StartNewBlock { .. }));
+    assert_matches!(handle.replies.recv().await, Some(ExecutorMessage::BatchExecuted(res)) => {
+        assert!(res.blockifier_results[0].is_err());
+    });
+
+    // The same transaction is accepted once the account is impersonated.
+    impersonated_accounts.insert(account.address);
+    handle.send_batch.as_mut().unwrap().send([make_unsigned_tx()].into_iter().collect()).await.unwrap();
+    assert_matches!(handle.replies.recv().await, Some(ExecutorMessage::BatchExecuted(res)) => {
+        assert!(!res.blockifier_results[0].as_ref().unwrap().0.is_reverted());
+    });
+}
@@ -3,28 +3,39 @@
 use anyhow::Context;
 use blockifier::{
     blockifier::transaction_executor::TransactionExecutor,
-    state::{cached_state::StorageEntry, state_api::State},
+    state::{
+        cached_state::StorageEntry,
+        state_api::{State, StateReader},
+    },
+    transaction::transaction_execution::Transaction,
 };
 use futures::future::OptionFuture;
 use starknet_api::contract_class::ContractClass;
 use starknet_api::core::ClassHash;
+use starknet_api::state::StorageKey;
 use std::{
     collections::{HashMap, HashSet},
     mem,
     sync::Arc,
+    time::{Duration, SystemTime},
 };
 use tokio::{
-    sync::{broadcast, mpsc},
+    sync::{broadcast, mpsc, oneshot, OwnedMutexGuard},
     time::Instant,
 };
 
 use mc_db::{db_block_id::DbBlockId, MadaraBackend};
 use mc_exec::{execution::TxInfo, LayeredStateAdapter, MadaraBackendExecutionExt};
 use mc_mempool::L1DataProvider;
+use mc_submit_tx::ImpersonatedAccounts;
+use mp_block::{header::GasPrices, BlockId, BlockTag};
 use mp_convert::{Felt, ToFelt};
 
+use super::{BlockTimestampUpdate, ExecutorCommandError};
 use crate::util::{create_execution_context, BatchToExecute, BlockExecutionContext, ExecutionStats};
 
+type StorageIncrease = (Vec<(StorageEntry, u128)>, oneshot::Sender<Result<Vec<(u128, u128)>, ExecutorCommandError>>);
+
 struct ExecutorStateExecuting {
     exec_ctx: BlockExecutionContext,
     /// Note: We have a special StateAdaptor here. This is because saving the block to the database can actually lag a
@@ -83,7 +94,18 @@ pub struct ExecutorThread {
 
     incoming_batches: mpsc::Receiver<super::BatchToExecute>,
     replies_sender: mpsc::Sender<super::ExecutorMessage>,
-    commands: mpsc::UnboundedReceiver<super::ExecutorCommand>,
+    commands: OwnedMutexGuard<mpsc::UnboundedReceiver<super::ExecutorCommand>>,
+
+    /// Transactions from these accounts are executed without running `__validate__`.
+    impersonated_accounts: ImpersonatedAccounts,
+    /// Devnet storage increases waiting for the next block to be started.
+    pending_storage_increases: Vec<StorageIncrease>,
+    /// Offset in seconds applied to the timestamp of new blocks.
+    block_timestamp_offset: i64,
+    /// When set, used instead of the gas prices given by the L1 data provider.
+    gas_prices_override: Option<GasPrices>,
+    /// L2 gas price in fri of the next block, updated every time a block is closed.
+    l2_gas_price: u128,
 
     /// See `take_tx_batch`. When the mempool is empty, we will not be getting transactions.
     /// We still potentially want to emit empty blocks based on the block_time deadline.
@@ -99,20 +121,69 @@ enum WaitTxBatchOutcome {
     Batch(BatchToExecute),
 }
 
+/// Moves `timestamp` by `offset` seconds, or returns `None` if the result is before the unix epoch or out of range.
+fn offset_timestamp(timestamp: SystemTime, offset: i64) -> Option<SystemTime> {
+    let duration = Duration::from_secs(offset.unsigned_abs());
+    let timestamp = if offset >= 0 { timestamp.checked_add(duration) } else { timestamp.checked_sub(duration) }?;
+    timestamp.duration_since(SystemTime::UNIX_EPOCH).is_ok().then_some(timestamp)
+}
+
+/// Adds the amounts to the u256 storage values, held as a low limb at the key and a high limb at the next key. Returns
+/// the new `(low, high)` values and the storage writes, or `None` if one of the values overflows.
+fn sum_u256_storage_increases(
+    state: &impl StateReader,
+    increases: Vec<(StorageEntry, u128)>,
+) -> anyhow::Result<Option<(Vec<(u128, u128)>, HashMap<StorageEntry, Felt>)>> {
+    let mut writes: HashMap<StorageEntry, Felt> = HashMap::new();
+    let mut new_values = Vec::with_capacity(increases.len());
+    for ((contract_address, low_key), amount) in increases {
+        let Ok(high_key) = low_key.next_storage_key() else { return Ok(None) };
+        // An entry can be increased more than once.
+        let read = |key: StorageKey| match writes.get(&(contract_address, key)) {
+            Some(value) => Ok(*value),
+            None => state.get_storage_at(contract_address, key).context("Cannot get storage value"),
+        };
+        let (Ok(low), Ok(high)) = (u128::try_from(read(low_key)?), u128::try_from(read(high_key)?)) else {
+            return Ok(None);
+        };
+        let (low, carry) = low.overflowing_add(amount);
+        let Some(high) = high.checked_add(carry.into()) else { return Ok(None) };
+        writes.insert((contract_address, low_key), low.into());
+        if carry {
+            writes.insert((contract_address, high_key), high.into());
+        }
+        new_values.push((low, high));
+    }
+    Ok(Some((new_values, writes)))
+}
+
 impl ExecutorThread {
     pub fn new(
         backend: Arc<MadaraBackend>,
         l1_data_provider: Arc<dyn L1DataProvider>,
         incoming_batches: mpsc::Receiver<super::BatchToExecute>,
         replies_sender: mpsc::Sender<super::ExecutorMessage>,
-        commands: mpsc::UnboundedReceiver<super::ExecutorCommand>,
+        commands: OwnedMutexGuard<mpsc::UnboundedReceiver<super::ExecutorCommand>>,
+        impersonated_accounts: ImpersonatedAccounts,
     ) -> anyhow::Result<Self> {
+        let latest_l2_gas_price = backend
+            .get_block_info(&BlockId::Tag(BlockTag::Latest))
+            .context("Getting latest block info")?
+            .and_then(|info| info.into_closed())
+            .map(|info| info.header.l1_gas_price.strk_l2_gas_price);
+        let l2_gas_price = backend.chain_config().l2_gas_price.initial_price(latest_l2_gas_price);
+
         Ok(Self {
             backend,
             l1_data_provider,
             incoming_batches,
             replies_sender,
             commands,
+            impersonated_accounts,
+            pending_storage_increases: Vec::new(),
+            block_timestamp_offset: 0,
+            gas_prices_override: None,
+            l2_gas_price,
             wait_rt: tokio::runtime::Builder::new_current_thread()
                 .enable_time()
                 .build()
@@ -201,8 +272,41 @@ impl ExecutorThread {
         }
     }
 
+    fn set_block_timestamp(&mut self, update: BlockTimestampUpdate) -> Result<(), ExecutorCommandError> {
+        let offset = match update {
+            BlockTimestampUpdate::Set(timestamp) => {
+                let now = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH).unwrap_or_default().as_secs();
+                i64::try_from(timestamp).ok().zip(i64::try_from(now).ok()).and_then(|(t, now)| t.checked_sub(now))
+            }
+            BlockTimestampUpdate::Increase(secs) => {
+                i64::try_from(secs).ok().and_then(|secs| self.block_timestamp_offset.checked_add(secs))
+            }
+        };
+        self.block_timestamp_offset = offset
+            .filter(|offset| offset_timestamp(SystemTime::now(), *offset).is_some())
+            .ok_or(ExecutorCommandError::BlockTimestampOutOfRange)?;
+        tracing::debug!("Block timestamp offset is now {}s", self.block_timestamp_offset);
+        Ok(())
+    }
+
+    /// Impersonated accounts skip `__validate__`, which is how they can send transactions without a valid signature.
+    fn skip_validation_if_impersonated(&self, tx: &mut Transaction) {
+        if let Transaction::Account(account_tx) = tx {
+            if self.impersonated_accounts.contains(&account_tx.tx.contract_address().to_felt()) {
+                account_tx.execution_flags.validate = false;
+            }
+        }
+    }
+
     /// End the current block.
     fn end_block(&mut self, state: &mut ExecutorStateExecuting) -> anyhow::Result<ExecutorThreadState> {
+        let chain_config = self.backend.chain_config();
+        self.l2_gas_price = chain_config.l2_gas_price.next_price(
+            self.l2_gas_price,
+            state.executor.bouncer.lock().expect("Bouncer lock poisoned").get_accumulated_weights(),
+            &chain_config.bouncer_config.block_max_capacity,
+        );
+
         let mut cached_state = state.executor.block_state.take().expect("Executor block state already taken");
 
         let state_diff = cached_state.to_state_diff().context("Cannot make state diff")?.state_maps;
@@ -224,7 +328,22 @@ impl ExecutorThread {
         &mut self,
         state: ExecutorStateNewBlock,
     ) -> anyhow::Result<(ExecutorStateExecuting, HashMap<StorageEntry, Felt>)> {
-        let exec_ctx = create_execution_context(&self.l1_data_provider, &self.backend, state.state_adaptor.block_n());
+        let mut exec_ctx =
+            create_execution_context(&self.l1_data_provider, &self.backend, state.state_adaptor.block_n());
+        exec_ctx.block_timestamp = offset_timestamp(exec_ctx.block_timestamp, self.block_timestamp_offset)
+            .context("Block timestamp out of range")?;
+        if let Some(gas_prices) = &self.gas_prices_override {
+            exec_ctx.l1_gas_price = gas_prices.clone();
+        }
+        let gas_prices = &mut exec_ctx.l1_gas_price;
+        gas_prices.strk_l2_gas_price = self.l2_gas_price;
+        // The wei price follows the STRK/ETH rate of the L1 gas prices.
+        gas_prices.eth_l2_gas_price = match gas_prices.strk_l1_gas_price {
+            0 => self.l2_gas_price,
+            strk_l1_gas_price => {
+                (self.l2_gas_price.saturating_mul(gas_prices.eth_l1_gas_price) / strk_l1_gas_price).max(1)
+            }
+        };
 
         // Create the TransactionExecution, but reuse the layered_state_adapter.
         let mut executor =
@@ -250,6 +369,22 @@ impl ExecutorThread {
                 key.to_felt()
             );
         }
+
+        for (increases, callback) in mem::take(&mut self.pending_storage_increases) {
+            let block_state = executor.block_state.as_mut().expect("Blockifier block context has been taken");
+            let Some((new_values, writes)) = sum_u256_storage_increases(&*block_state, increases)? else {
+                let _ = callback.send(Err(ExecutorCommandError::StorageOverflow));
+                continue;
+            };
+            for ((contract_address, key), value) in writes {
+                block_state
+                    .set_storage_at(contract_address, key, value)
+                    .context("Cannot set storage value in cache")?;
+                state_maps_storages.insert((contract_address, key), value);
+            }
+            let _ = callback.send(Ok(new_values));
+        }
+
         Ok((
             ExecutorStateExecuting {
                 exec_ctx,
@@ -295,7 +430,8 @@ impl ExecutorThread {
                 let wait_deadline = if block_empty && no_empty_blocks { None } else { Some(next_block_deadline) };
                 // should_wait: We don't want to wait if we already have transactions to process - but we would still like to fill up our batch if possible.
 
-                let taken = match self.wait_take_tx_batch(wait_deadline, /* should_wait */ to_exec.is_empty()) {
+                let should_wait = to_exec.is_empty() && self.pending_storage_increases.is_empty();
+                let taken = match self.wait_take_tx_batch(wait_deadline, should_wait) {
                     // Got a batch
                     WaitTxBatchOutcome::Batch(batch_to_execute) => batch_to_execute,
                     // Got a command
@@ -305,12 +441,27 @@ impl ExecutorThread {
                             let _ = callback.send(Ok(()));
                             Default::default()
                         }
+                        super::ExecutorCommand::IncreaseStorage(increases, callback) => {
+                            // Close the current block, the increases are made at the start of the next one.
+                            self.pending_storage_increases.push((increases, callback));
+                            force_close = true;
+                            Default::default()
+                        }
+                        super::ExecutorCommand::SetBlockTimestamp(update, callback) => {
+                            let _ = callback.send(self.set_block_timestamp(update));
+                            Default::default()
+                        }
+                        super::ExecutorCommand::SetGasPrices(gas_prices, callback) => {
+                            self.gas_prices_override = gas_prices;
+                            let _ = callback.send(Ok(()));
+                            Default::default()
+                        }
                     },
                     // Channel closed. Exit gracefully.
                     WaitTxBatchOutcome::Exit => return Ok(()),
                 };
 
-                for (tx, additional_info) in taken {
+                for (mut tx, additional_info) in taken {
                     // Remove duplicate l1handlertxs. We want to be absolutely sure we're not duplicating them.
                     if let Some(nonce) = tx.l1_handler_tx_nonce() {
                         let nonce: u64 = nonce.to_felt().try_into().context("Converting nonce from felt to u64")?;
@@ -326,6 +477,7 @@ impl ExecutorThread {
                             continue;
                         }
                     }
+                    self.skip_validation_if_impersonated(&mut tx);
                     to_exec.push(tx, additional_info)
                 }
             }
@@ -336,6 +488,10 @@ impl ExecutorThread {
             let execution_state = match state {
                 ExecutorThreadState::Executing(ref mut executor_state_executing) => executor_state_executing,
                 ExecutorThreadState::NewBlock(state_new_block) => {
+                    // A block holding storage increases is closed right away, so that they are visible in a closed block.
+                    if !self.pending_storage_increases.is_empty() {
+                        force_close = true;
+                    }
                     // Create new execution state.
                     let (execution_state, initial_state_diffs_storage) =
                         self.create_execution_state(state_new_block).context("Creating execution state")?;
@@ -1,10 +1,12 @@
-use crate::executor::{self, ExecutorCommand, ExecutorCommandError};
+use crate::executor::{self, BlockTimestampUpdate, ExecutorCommand, ExecutorCommandError};
 use async_trait::async_trait;
+use blockifier::state::cached_state::StorageEntry;
 use mc_db::MadaraBackend;
 use mc_submit_tx::{
-    SubmitTransaction, SubmitTransactionError, SubmitValidatedTransaction, TransactionValidator,
-    TransactionValidatorConfig,
+    ImpersonatedAccounts, SubmitTransaction, SubmitTransactionError, SubmitValidatedTransaction, TransactionPolicies,
+    TransactionValidator, TransactionValidatorConfig,
 };
+use mp_block::header::GasPrices;
 use mp_rpc::{
     admin::BroadcastedDeclareTxnV0, AddInvokeTransactionResult, BroadcastedDeclareTxn, BroadcastedDeployAccountTxn,
     BroadcastedInvokeTxn, ClassAndTxnHash, ContractAndTxnHash,
@@ -38,6 +40,8 @@ pub struct BlockProductionHandle {
     bypass_input: mpsc::Sender<ValidatedMempoolTx>,
     /// We use TransactionValidator to handle conversion to blockifier, class compilation etc. Mostly for convenience.
     tx_converter: Arc<TransactionValidator>,
+    impersonated_accounts: ImpersonatedAccounts,
+    transaction_policies: TransactionPolicies,
 }
 
 impl BlockProductionHandle {
@@ -45,6 +49,8 @@ impl BlockProductionHandle {
         backend: Arc<MadaraBackend>,
         executor_commands: mpsc::UnboundedSender<executor::ExecutorCommand>,
         bypass_input: mpsc::Sender<ValidatedMempoolTx>,
+        impersonated_accounts: ImpersonatedAccounts,
+        transaction_policies: TransactionPolicies,
     ) -> Self {
         Self {
             executor_commands,
@@ -55,18 +61,57 @@ impl BlockProductionHandle {
                 TransactionValidatorConfig::default().with_disable_validation(true),
             )
             .into(),
+            impersonated_accounts,
+            transaction_policies,
         }
     }
 
-    /// Force the current block to close without waiting for block time.
-    pub async fn close_block(&self) -> Result<(), ExecutorCommandError> {
+    async fn send_command<T>(
+        &self,
+        command: impl FnOnce(oneshot::Sender<Result<T, ExecutorCommandError>>) -> ExecutorCommand,
+    ) -> Result<T, ExecutorCommandError> {
         let (sender, recv) = oneshot::channel();
-        self.executor_commands
-            .send(ExecutorCommand::CloseBlock(sender))
-            .map_err(|_| ExecutorCommandError::ChannelClosed)?;
+        self.executor_commands.send(command(sender)).map_err(|_| ExecutorCommandError::ChannelClosed)?;
         recv.await.map_err(|_| ExecutorCommandError::ChannelClosed)?
     }
 
+    /// Force the current block to close without waiting for block time.
+    pub async fn close_block(&self) -> Result<(), ExecutorCommandError> {
+        self.send_command(ExecutorCommand::CloseBlock).await
+    }
+
+    /// Add amounts to u256 storage values, in a new block that is closed right away. Returns the new `(low, high)`
+    /// values. Devnet only.
+    pub async fn increase_storage(
+        &self,
+        increases: Vec<(StorageEntry, u128)>,
+    ) -> Result<Vec<(u128, u128)>, ExecutorCommandError> {
+        self.send_command(|callback| ExecutorCommand::IncreaseStorage(increases, callback)).await
+    }
+
+    /// Change the timestamp of the blocks started from now on. Devnet only.
+    pub async fn set_block_timestamp(&self, update: BlockTimestampUpdate) -> Result<(), ExecutorCommandError> {
+        self.send_command(|callback| ExecutorCommand::SetBlockTimestamp(update, callback)).await
+    }
+
+    /// Override the gas prices of the blocks started from now on. `None` removes the override. Devnet only.
+    pub async fn set_gas_prices(&self, gas_prices: Option<GasPrices>) -> Result<(), ExecutorCommandError> {
+        self.send_command(|callback| ExecutorCommand::SetGasPrices(gas_prices, callback)).await
+    }
+
+    /// Accounts whose transactions skip `__validate__`. This needs to be shared with the transaction validator in
+    /// front of the mempool. Devnet only.
+    pub fn impersonated_accounts(&self) -> &ImpersonatedAccounts {
+        &self.impersonated_accounts
+    }
+
+    /// Transaction filtering policies, loaded from the chain config. This needs to be shared with the transaction
+    /// validator in front of the mempool; block production also applies them to the transactions it takes from the
+    /// mempool.
+    pub fn transaction_policies(&self) -> &TransactionPolicies {
+        &self.transaction_policies
+    }
+
     /// Send a transaction through the bypass channel to bypass mempool and validation.
     pub async fn send_tx_raw(&self, tx: ValidatedMempoolTx) -> Result<(), ExecutorCommandError> {
         self.bypass_input.send(tx).await.map_err(|_| ExecutorCommandError::ChannelClosed)
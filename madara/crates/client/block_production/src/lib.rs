@@ -11,6 +11,7 @@ use mc_db::MadaraBackend;
 use mc_exec::execution::TxInfo;
 use mc_mempool::{L1DataProvider, Mempool};
 use mc_settlement_client::SettlementClient;
+use mc_submit_tx::{ImpersonatedAccounts, TransactionPolicies};
 use mp_block::header::PendingHeader;
 use mp_block::{BlockId, BlockTag, PendingFullBlock, TransactionWithReceipt};
 use mp_class::ConvertedClass;
@@ -27,7 +28,7 @@ use std::collections::{HashMap, HashSet};
 use std::mem;
 use std::sync::Arc;
 use std::time::Instant;
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, Mutex};
 use util::{BlockExecutionContext, ExecutionStats};
 
 mod batcher;
@@ -36,6 +37,7 @@ mod handle;
 pub mod metrics;
 mod util;
 
+pub use executor::{BlockTimestampUpdate, ExecutorCommandError};
 pub use handle::BlockProductionHandle;
 
 #[derive(Debug, Clone)]
@@ -158,6 +160,7 @@ impl CurrentPendingState {
             if let Ok((execution_info, state_diff)) = blockifier_exec_result {
                 if let Some(class) = additional_info.declared_class.take() {
                     if !execution_info.is_reverted() {
+                        mc_exec::on_class_declared(&class);
                         self.block.declared_classes.push(class);
                     }
                 }
@@ -215,9 +218,13 @@ pub struct BlockProductionTask {
     metrics: Arc<BlockProductionMetrics>,
     state_notifications: Option<mpsc::UnboundedSender<BlockProductionStateNotification>>,
     handle: BlockProductionHandle,
-    executor_commands_recv: Option<mpsc::UnboundedReceiver<executor::ExecutorCommand>>,
+    /// Receiving end of the [`BlockProductionHandle`] channels. They outlive a single [`BlockProductionTask::run`], so
+    /// that the task can be stopped and restarted without invalidating the handles given out.
+    executor_commands_recv: Arc<Mutex<mpsc::UnboundedReceiver<executor::ExecutorCommand>>>,
     l1_client: Arc<dyn SettlementClient>,
-    bypass_tx_input: Option<mpsc::Receiver<ValidatedMempoolTx>>,
+    bypass_tx_input: Arc<Mutex<mpsc::Receiver<ValidatedMempoolTx>>>,
+    impersonated_accounts: ImpersonatedAccounts,
+    transaction_policies: TransactionPolicies,
 }
 
 impl BlockProductionTask {
@@ -230,17 +237,27 @@ impl BlockProductionTask {
     ) -> Self {
         let (sender, recv) = mpsc::unbounded_channel();
         let (bypass_input_sender, bypass_tx_input) = mpsc::channel(16);
+        let impersonated_accounts = ImpersonatedAccounts::default();
+        let transaction_policies = TransactionPolicies::new(backend.chain_config().transaction_policies.clone());
         Self {
             backend: backend.clone(),
             l1_data_provider,
             mempool,
             current_state: None,
             metrics,
-            handle: BlockProductionHandle::new(backend, sender, bypass_input_sender),
+            handle: BlockProductionHandle::new(
+                backend,
+                sender,
+                bypass_input_sender,
+                impersonated_accounts.clone(),
+                transaction_policies.clone(),
+            ),
             state_notifications: None,
-            executor_commands_recv: Some(recv),
+            executor_commands_recv: Arc::new(Mutex::new(recv)),
             l1_client,
-            bypass_tx_input: Some(bypass_tx_input),
+            bypass_tx_input: Arc::new(Mutex::new(bypass_tx_input)),
+            impersonated_accounts,
+            transaction_policies,
         }
     }
 
@@ -466,13 +483,18 @@ impl BlockProductionTask {
     }
 
     #[tracing::instrument(skip(self, ctx), fields(module = "BlockProductionTask"))]
-    pub async fn run(mut self, ctx: ServiceContext) -> Result<(), anyhow::Error> {
+    pub async fn run(&mut self, ctx: ServiceContext) -> Result<(), anyhow::Error> {
         self.setup_initial_state().await?;
 
+        // The receivers are released when the executor thread and batcher task of a previous run have stopped.
+        let executor_commands_recv = Arc::clone(&self.executor_commands_recv).lock_owned().await;
+        let bypass_tx_input = Arc::clone(&self.bypass_tx_input).lock_owned().await;
+
         let mut executor = executor::start_executor_thread(
             Arc::clone(&self.backend),
             Arc::clone(&self.l1_data_provider),
-            self.executor_commands_recv.take().context("Task already started")?,
+            executor_commands_recv,
+            self.impersonated_accounts.clone(),
         )
         .context("Starting executor thread")?;
 
@@ -485,7 +507,6 @@ impl BlockProductionTask {
 
         // Batcher task is handled in a separate tokio task.
         let batch_sender = executor.send_batch.take().context("Channel sender already taken")?;
-        let bypass_tx_input = self.bypass_tx_input.take().context("Bypass tx channel already taken")?;
         let mut batcher_task = AbortOnDrop::spawn(
             Batcher::new(
                 self.backend.clone(),
@@ -494,6 +515,7 @@ impl BlockProductionTask {
                 ctx,
                 batch_sender,
                 bypass_tx_input,
+                self.transaction_policies.clone(),
             )
             .run(),
         );
@@ -534,6 +556,7 @@ impl BlockProductionTask {
 pub(crate) mod tests {
     use crate::BlockProductionStateNotification;
     use crate::{metrics::BlockProductionMetrics, BlockProductionTask};
+    use assert_matches::assert_matches;
     use blockifier::{
         bouncer::{BouncerConfig, BouncerWeights},
         state::cached_state::StateMaps,
@@ -544,10 +567,13 @@ pub(crate) mod tests {
     };
     use mc_mempool::{Mempool, MempoolConfig, MockL1DataProvider};
     use mc_settlement_client::L1ClientMock;
-    use mc_submit_tx::{SubmitTransaction, TransactionValidator, TransactionValidatorConfig};
+    use mc_submit_tx::{
+        RejectedTransactionError, RejectedTransactionErrorKind, SubmitTransaction, SubmitTransactionError,
+        TransactionPolicies, TransactionValidator, TransactionValidatorConfig,
+    };
     use mp_block::header::GasPrices;
     use mp_block::{BlockId, BlockTag};
-    use mp_chain_config::ChainConfig;
+    use mp_chain_config::{ChainConfig, L2GasPriceConfig, RateLimitConfig, TransactionPolicyConfig};
     use mp_convert::ToFelt;
     use mp_receipt::{Event, ExecutionResult};
     use mp_rpc::{
@@ -616,6 +642,7 @@ pub(crate) mod tests {
         #[default(Duration::from_secs(30))] block_time: Duration,
         #[default(Some(Duration::from_secs(2)))] pending_block_update_time: Option<Duration>,
         #[default(false)] use_bouncer_weights: bool,
+        #[default(L2GasPriceConfig::default())] l2_gas_price: L2GasPriceConfig,
     ) -> DevnetSetup {
         let _ = tracing_subscriber::fmt()
             .with_env_filter(tracing_subscriber::EnvFilter::from_default_env())
@@ -631,10 +658,16 @@ pub(crate) mod tests {
                 block_time,
                 pending_block_update_time,
                 bouncer_config: BouncerConfig { block_max_capacity: bouncer_weights },
+                l2_gas_price,
                 ..ChainConfig::madara_devnet()
             })
         } else {
-            Arc::new(ChainConfig { block_time, pending_block_update_time, ..ChainConfig::madara_devnet() })
+            Arc::new(ChainConfig {
+                block_time,
+                pending_block_update_time,
+                l2_gas_price,
+                ..ChainConfig::madara_devnet()
+            })
         };
 
         let backend = MadaraBackend::open_for_testing(Arc::clone(&chain_config));
@@ -646,6 +679,7 @@ pub(crate) mod tests {
             strk_l1_gas_price: 128,
             eth_l1_data_gas_price: 128,
             strk_l1_data_gas_price: 128,
+            ..Default::default()
         });
         let l1_data_provider = Arc::new(l1_data_provider);
 
@@ -2218,4 +2252,92 @@ pub(crate) mod tests {
             }
         );
     }
+
+    // Replacing the policies applies to the transactions which were already accepted into the mempool.
+    #[rstest::rstest]
+    #[timeout(Duration::from_secs(30))]
+    #[tokio::test]
+    async fn test_transaction_policies_filter_mempool(#[future] devnet_setup: DevnetSetup) {
+        let mut devnet_setup = devnet_setup.await;
+
+        let denied_tx =
+            make_invoke_tx(&devnet_setup.contracts.0[0], Multicall::default(), &devnet_setup.backend, Felt::ZERO);
+        let denied_tx_hash =
+            devnet_setup.tx_validator.submit_invoke_transaction(denied_tx).await.unwrap().transaction_hash;
+        sign_and_add_invoke_tx(
+            &devnet_setup.contracts.0[1],
+            &devnet_setup.contracts.0[2],
+            &devnet_setup.backend,
+            &devnet_setup.tx_validator,
+            Felt::ZERO,
+        )
+        .await;
+
+        let mut block_production_task = devnet_setup.block_prod_task();
+        block_production_task.handle().transaction_policies().replace(TransactionPolicyConfig {
+            denied_senders: vec![devnet_setup.contracts.0[0].address],
+            ..Default::default()
+        });
+
+        let mut notifications = block_production_task.subscribe_state_notifications();
+        let _task =
+            AbortOnDrop::spawn(
+                async move { block_production_task.run(ServiceContext::new_for_testing()).await.unwrap() },
+            );
+        assert_eq!(notifications.recv().await.unwrap(), BlockProductionStateNotification::UpdatedPendingBlock);
+
+        let pending_block = devnet_setup.backend.get_block(&DbBlockId::Pending).unwrap().unwrap();
+        assert!(devnet_setup.mempool.is_empty().await);
+        assert_eq!(pending_block.inner.transactions.len(), 1);
+        assert_eq!(
+            pending_block.inner.transactions[0].as_invoke().unwrap().sender_address(),
+            &devnet_setup.contracts.0[1].address
+        );
+        // The dropped transaction is forgotten by the mempool.
+        assert_eq!(devnet_setup.tx_validator.received_transaction(denied_tx_hash).await, Some(false));
+    }
+
+    // Transactions rejected by `__validate__` do not count towards the rate limit of their sender.
+    #[rstest::rstest]
+    #[timeout(Duration::from_secs(30))]
+    #[tokio::test]
+    async fn test_rate_limit_counts_validated_transactions(#[future] devnet_setup: DevnetSetup) {
+        let devnet_setup = devnet_setup.await;
+        let policies = TransactionPolicies::new(TransactionPolicyConfig {
+            rate_limit: Some(RateLimitConfig { max_transactions: 1, period: Duration::from_secs(60) }),
+            ..Default::default()
+        });
+        let tx_validator = Arc::new(
+            TransactionValidator::new(
+                Arc::clone(&devnet_setup.mempool) as _,
+                Arc::clone(&devnet_setup.backend),
+                TransactionValidatorConfig::default(),
+            )
+            .with_transaction_policies(policies),
+        );
+        let sender = &devnet_setup.contracts.0[0];
+
+        let mut invalid_tx = make_invoke_tx(sender, Multicall::default(), &devnet_setup.backend, Felt::ZERO);
+        let BroadcastedInvokeTxn::V3(tx) = &mut invalid_tx else { unreachable!() };
+        tx.signature = vec![Felt::ONE, Felt::TWO].into();
+        assert_matches!(
+            tx_validator.submit_invoke_transaction(invalid_tx).await,
+            Err(SubmitTransactionError::Rejected(RejectedTransactionError {
+                kind: RejectedTransactionErrorKind::ValidateFailure,
+                ..
+            }))
+        );
+
+        sign_and_add_invoke_tx(sender, &devnet_setup.contracts.0[1], &devnet_setup.backend, &tx_validator, Felt::ZERO)
+            .await;
+
+        let tx = make_invoke_tx(sender, Multicall::default(), &devnet_setup.backend, Felt::ONE);
+        assert_matches!(
+            tx_validator.submit_invoke_transaction(tx).await,
+            Err(SubmitTransactionError::Rejected(RejectedTransactionError {
+                kind: RejectedTransactionErrorKind::RateLimited,
+                ..
+            }))
+        );
+    }
 }
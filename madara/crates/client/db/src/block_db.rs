@@ -25,7 +25,7 @@ struct ChainInfo {
 }
 
 const ROW_CHAIN_INFO: &[u8] = b"chain_info";
-const ROW_PENDING_INFO: &[u8] = b"pending_info";
+pub(crate) const ROW_PENDING_INFO: &[u8] = b"pending_info";
 const ROW_PENDING_STATE_UPDATE: &[u8] = b"pending_state_update";
 const ROW_PENDING_INNER: &[u8] = b"pending";
 const ROW_L1_LAST_CONFIRMED_BLOCK: &[u8] = b"l1_last";
@@ -94,7 +94,7 @@ impl MadaraBackend {
     }
 
     #[tracing::instrument(skip(self), fields(module = "BlockDB"))]
-    fn get_state_update(&self, block_n: u64) -> Result<Option<StateDiff>> {
+    pub(crate) fn get_state_update(&self, block_n: u64) -> Result<Option<StateDiff>> {
         let col = self.db.get_column(Column::BlockNToStateDiff);
         let res = self.db.get_cf(&col, bincode::serialize(&block_n)?)?;
         let Some(res) = res else { return Ok(None) };
@@ -103,7 +103,7 @@ impl MadaraBackend {
     }
 
     #[tracing::instrument(skip(self), fields(module = "BlockDB"))]
-    fn get_block_info_from_block_n(&self, block_n: u64) -> Result<Option<MadaraBlockInfo>> {
+    pub(crate) fn get_block_info_from_block_n(&self, block_n: u64) -> Result<Option<MadaraBlockInfo>> {
         let col = self.db.get_column(Column::BlockNToBlockInfo);
         let res = self.db.get_cf(&col, block_n.to_be_bytes())?;
         let Some(res) = res else { return Ok(None) };
@@ -112,7 +112,7 @@ impl MadaraBackend {
     }
 
     #[tracing::instrument(skip(self), fields(module = "BlockDB"))]
-    fn get_block_inner_from_block_n(&self, block_n: u64) -> Result<Option<MadaraBlockInner>> {
+    pub(crate) fn get_block_inner_from_block_n(&self, block_n: u64) -> Result<Option<MadaraBlockInner>> {
         let col = self.db.get_column(Column::BlockNToBlockInner);
         let res = self.db.get_cf(&col, bincode::serialize(&block_n)?)?;
         let Some(res) = res else { return Ok(None) };
@@ -149,6 +149,8 @@ impl MadaraBackend {
                             strk_l1_gas_price: 1,
                             eth_l1_data_gas_price: 1,
                             strk_l1_data_gas_price: 1,
+                            eth_l2_gas_price: 1,
+                            strk_l2_gas_price: 1,
                         },
                         l1_da_mode: self.chain_config.l1_da_mode,
                     },
@@ -314,6 +316,37 @@ impl MadaraBackend {
         Ok(())
     }
 
+    /// Removes every row of a closed block from the block columns. This does not touch the contract, class or
+    /// global trie columns, see [`MadaraBackend::revert_to`].
+    #[tracing::instrument(skip(self), fields(module = "BlockDB"))]
+    pub(crate) fn block_db_revert_block(&self, block_n: u64) -> Result<()> {
+        let mut tx = WriteBatchWithTransaction::default();
+
+        let tx_hash_to_block_n = self.db.get_column(Column::TxHashToBlockN);
+        let block_hash_to_block_n = self.db.get_column(Column::BlockHashToBlockN);
+        let block_n_to_block = self.db.get_column(Column::BlockNToBlockInfo);
+        let block_n_to_block_inner = self.db.get_column(Column::BlockNToBlockInner);
+        let block_n_to_state_diff = self.db.get_column(Column::BlockNToStateDiff);
+        let block_n_to_bloom = self.db.get_column(Column::EventBloom);
+
+        let block_n_encoded = bincode::serialize(&block_n)?;
+
+        if let Some(info) = self.get_block_info_from_block_n(block_n)? {
+            for hash in &info.tx_hashes {
+                tx.delete_cf(&tx_hash_to_block_n, bincode::serialize(hash)?);
+            }
+            tx.delete_cf(&block_hash_to_block_n, bincode::serialize(&info.block_hash)?);
+        }
+
+        tx.delete_cf(&block_n_to_block, block_n.to_be_bytes());
+        tx.delete_cf(&block_n_to_block_inner, &block_n_encoded);
+        tx.delete_cf(&block_n_to_state_diff, &block_n_encoded);
+        tx.delete_cf(&block_n_to_bloom, &block_n_encoded);
+
+        self.db.write_opt(tx, &self.writeopts_no_wal)?;
+        Ok(())
+    }
+
     // Convenience functions
 
     fn storage_to_info(&self, id: &RawDbBlockId) -> Result<Option<MadaraMaybePendingBlockInfo>> {
@@ -51,7 +51,18 @@ impl MadaraBackend {
         class_hash: &Felt,
     ) -> Result<Option<ClassInfo>, MadaraStorageError> {
         let Some(requested_id) = id.resolve_db_block_id(self)? else { return Ok(None) };
+        if let Some(info) = self.get_local_class_info(requested_id, class_hash)? {
+            return Ok(Some(info));
+        }
+        Ok(self.get_forked_class(class_hash)?.map(|class| class.info()))
+    }
 
+    /// Class info declared in this database, without falling back to the forked chain.
+    fn get_local_class_info(
+        &self,
+        requested_id: RawDbBlockId,
+        class_hash: &Felt,
+    ) -> Result<Option<ClassInfo>, MadaraStorageError> {
         tracing::debug!("get class info {requested_id:?} {class_hash:#x}");
 
         let Some(info) = self.class_db_get_encoded_kv::<ClassInfoWithBlockNumber>(
@@ -125,9 +136,9 @@ impl MadaraBackend {
             return Ok(None);
         };
 
-        let Some(class_info) = self.get_class_info(&id, class_hash)? else {
-            // No class found.
-            return Ok(None);
+        let Some(class_info) = self.get_local_class_info(id, class_hash)? else {
+            // No class found, the forked chain may have it with its compiled class.
+            return self.get_forked_class(class_hash);
         };
 
         match class_info {
@@ -233,6 +244,37 @@ impl MadaraBackend {
         )
     }
 
+    /// Removes the classes that were first declared in block `block_n`. Classes that were re-declared in that
+    /// block but already existed before are kept.
+    #[tracing::instrument(skip(self, class_hashes), fields(module = "ClassDB"))]
+    pub(crate) fn class_db_revert_block(
+        &self,
+        block_n: u64,
+        class_hashes: impl IntoIterator<Item = Felt>,
+    ) -> Result<(), MadaraStorageError> {
+        let col_info = self.db.get_column(Column::ClassInfo);
+        let col_compiled = self.db.get_column(Column::ClassCompiled);
+        let mut batch = WriteBatchWithTransaction::default();
+
+        for class_hash in class_hashes {
+            let key_bin = bincode::serialize(&class_hash)?;
+            let Some(info) = self.db.get_pinned_cf(&col_info, &key_bin)? else { continue };
+            let info: ClassInfoWithBlockNumber = bincode::deserialize(&info)?;
+            if info.block_id != RawDbBlockId::Number(block_n) {
+                continue;
+            }
+
+            tracing::trace!("Class revert key={class_hash:#x}");
+            batch.delete_cf(&col_info, &key_bin);
+            if let ClassInfo::Sierra(sierra) = info.class_info {
+                batch.delete_cf(&col_compiled, bincode::serialize(&sierra.compiled_class_hash)?);
+            }
+        }
+
+        self.db.write_opt(batch, &self.writeopts_no_wal)?;
+        Ok(())
+    }
+
     /// NB: This functions needs to run on the rayon thread pool
     #[tracing::instrument(skip(self, converted_classes), fields(module = "ClassDB"))]
     pub fn class_db_store_pending(&self, converted_classes: &[ConvertedClass]) -> Result<(), MadaraStorageError> {
@@ -140,13 +140,18 @@ impl MadaraBackend {
         id: &impl DbBlockIdResolvable,
         contract_addr: &Felt,
     ) -> Result<Option<Felt>, MadaraStorageError> {
-        self.resolve_history_kv(
-            id,
+        let Some(id) = id.resolve_db_block_id(self)? else { return Ok(None) };
+        if let Some(class_hash) = self.resolve_history_kv(
+            &id,
             Column::PendingContractToClassHashes,
             Column::ContractToClassHashes,
             contract_addr,
             |k| k.to_bytes_be(),
-        )
+        )? {
+            return Ok(Some(class_hash));
+        }
+        // The forked chain returns zero for contracts that are not deployed there either.
+        Ok(self.get_forked_class_hash_at(contract_addr)?.filter(|class_hash| *class_hash != Felt::ZERO))
     }
 
     #[tracing::instrument(skip(self, id), fields(module = "ContractDB"))]
@@ -155,9 +160,17 @@ impl MadaraBackend {
         id: &impl DbBlockIdResolvable,
         contract_addr: &Felt,
     ) -> Result<Option<Felt>, MadaraStorageError> {
-        self.resolve_history_kv(id, Column::PendingContractToNonces, Column::ContractToNonces, contract_addr, |k| {
-            k.to_bytes_be()
-        })
+        let Some(id) = id.resolve_db_block_id(self)? else { return Ok(None) };
+        if let Some(nonce) = self.resolve_history_kv(
+            &id,
+            Column::PendingContractToNonces,
+            Column::ContractToNonces,
+            contract_addr,
+            |k| k.to_bytes_be(),
+        )? {
+            return Ok(Some(nonce));
+        }
+        self.get_forked_nonce_at(contract_addr)
     }
 
     #[tracing::instrument(skip(self, id, key), fields(module = "ContractDB"))]
@@ -167,13 +180,17 @@ impl MadaraBackend {
         contract_addr: &Felt,
         key: &Felt,
     ) -> Result<Option<Felt>, MadaraStorageError> {
-        self.resolve_history_kv(
-            id,
+        let Some(id) = id.resolve_db_block_id(self)? else { return Ok(None) };
+        if let Some(value) = self.resolve_history_kv(
+            &id,
             Column::PendingContractStorage,
             Column::ContractStorage,
             &(*contract_addr, *key),
             |(k1, k2)| make_storage_key_prefix(*k1, *k2),
-        )
+        )? {
+            return Ok(Some(value));
+        }
+        self.get_forked_storage_at(contract_addr, key)
     }
 
     fn contract_db_store_chunk(
@@ -251,6 +268,35 @@ impl MadaraBackend {
         Ok(())
     }
 
+    /// Removes the history entries written at `block_number` by [`Self::contract_db_store_block`]. The previous
+    /// values are left untouched, which means reads will resolve to them again.
+    #[tracing::instrument(skip(self, value), fields(module = "ContractDB"))]
+    pub(crate) fn contract_db_revert_block(
+        &self,
+        block_number: u64,
+        value: ContractDbBlockUpdate,
+    ) -> Result<(), MadaraStorageError> {
+        let block_number = u32::try_from(block_number).map_err(|_| MadaraStorageError::InvalidBlockNumber)?;
+        let make_key = |prefix: &[u8]| [prefix, &block_number.to_be_bytes() as &[u8]].concat();
+
+        let mut batch = WriteBatchWithTransaction::default();
+        let col = self.db.get_column(Column::ContractToClassHashes);
+        for (contract_address, _) in &value.contract_class_updates {
+            batch.delete_cf(&col, make_key(&contract_address.to_bytes_be()));
+        }
+        let col = self.db.get_column(Column::ContractToNonces);
+        for (contract_address, _) in &value.contract_nonces_updates {
+            batch.delete_cf(&col, make_key(&contract_address.to_bytes_be()));
+        }
+        let col = self.db.get_column(Column::ContractStorage);
+        for ((contract_address, key), _) in &value.contract_kv_updates {
+            batch.delete_cf(&col, make_key(&make_storage_key_prefix(*contract_address, *key)));
+        }
+        self.db.write_opt(batch, &self.writeopts_no_wal)?;
+
+        Ok(())
+    }
+
     /// NB: This functions needs to run on the rayon thread pool
     #[tracing::instrument(skip(self, value), fields(module = "ContractDB"))]
     pub(crate) fn contract_db_store_pending(&self, value: ContractDbBlockUpdate) -> Result<(), MadaraStorageError> {
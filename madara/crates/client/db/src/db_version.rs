@@ -2,7 +2,8 @@
 //!
 //! This module ensures database version compatibility with the current binary.
 //! The version check prevents data corruption from version mismatches between
-//! database files and binary versions.
+//! database files and binary versions. Databases older than the binary are
+//! upgraded at startup by the [migrations](crate::migration).
 //!
 //! # Version File
 //! The version is stored in a `.db-version` file in the database directory.
@@ -22,7 +23,7 @@ const DB_VERSION_FILE: &str = ".db-version";
 /// Errors that can occur during version checking
 #[derive(Debug, thiserror::Error)]
 pub enum DbVersionError {
-    /// The database version doesn't match the binary version
+    /// The database version is newer than the binary version, or no migration path exists
     #[error(
         "Database version {db_version} is not compatible with current binary. Expected version {required_version}"
     )]
@@ -45,8 +46,8 @@ pub enum DbVersionError {
 ///
 /// # Returns
 /// * `Ok(None)` - New database created with current version
-/// * `Ok(Some(version))` - Existing database with compatible version
-/// * `Err(DbVersionError)` - Version mismatch or IO error
+/// * `Ok(Some(version))` - Existing database, with a version equal to or older than the current one
+/// * `Err(DbVersionError)` - Database newer than the binary or IO error
 ///
 /// # Examples
 /// ```ignore
@@ -56,14 +57,13 @@ pub enum DbVersionError {
 /// let db_path = Path::new("test_db");
 /// match check_db_version(db_path) {
 ///     Ok(None) => println!("Created new database"),
-///     Ok(Some(v)) => println!("Database version {} is compatible", v),
+///     Ok(Some(v)) => println!("Database version {} is compatible or can be migrated", v),
 ///     Err(e) => eprintln!("Error: {}", e),
 /// }
 /// ```
 ///
 pub fn check_db_version(path: &Path) -> Result<Option<u32>, DbVersionError> {
-    let required_db_version =
-        REQUIRED_DB_VERSION.parse::<u32>().expect("REQUIRED_DB_VERSION is checked at compile time");
+    let required_db_version = required_db_version();
 
     // Create directory if it doesn't exist
     if !path.exists() {
@@ -80,7 +80,8 @@ pub fn check_db_version(path: &Path) -> Result<Option<u32>, DbVersionError> {
         let version = fs::read_to_string(&file_path).map_err(|e| DbVersionError::VersionReadError(e.to_string()))?;
         let version = version.trim().parse::<u32>().map_err(|_| DbVersionError::VersionReadError(version))?;
 
-        if version != required_db_version {
+        // Older databases are upgraded by the migrations once opened.
+        if version > required_db_version {
             return Err(DbVersionError::IncompatibleVersion {
                 db_version: version,
                 required_version: required_db_version,
@@ -90,6 +91,17 @@ pub fn check_db_version(path: &Path) -> Result<Option<u32>, DbVersionError> {
     }
 }
 
+/// Database version expected by the current binary.
+pub(crate) fn required_db_version() -> u32 {
+    REQUIRED_DB_VERSION.parse::<u32>().expect("REQUIRED_DB_VERSION is checked at compile time")
+}
+
+/// Overwrites the database version file, once the database has been migrated to `version`.
+pub(crate) fn write_db_version(path: &Path, version: u32) -> Result<(), DbVersionError> {
+    fs::write(path.join(DB_VERSION_FILE), version.to_string())
+        .map_err(|e| DbVersionError::VersionReadError(e.to_string()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -140,6 +152,19 @@ mod tests {
         assert!(matches!(err, DbVersionError::IncompatibleVersion { .. }));
     }
 
+    #[test]
+    fn test_older_version() {
+        let temp_dir = setup_test_db();
+        let version_file = temp_dir.path().join(DB_VERSION_FILE);
+
+        // Older databases are returned as is, they are migrated later on
+        let older_version = REQUIRED_DB_VERSION.parse::<u32>().unwrap().checked_sub(1).unwrap();
+        fs::write(version_file, older_version.to_string()).unwrap();
+
+        let result = check_db_version(temp_dir.path()).unwrap();
+        assert_eq!(result, Some(older_version));
+    }
+
     #[test]
     fn test_invalid_version_format() {
         let temp_dir = setup_test_db();
@@ -27,6 +27,18 @@ pub enum MadaraStorageError {
     MissingCompiledClass { class_hash: Felt, compiled_class_hash: Felt },
     #[error("Batch is empty")]
     EmptyBatch,
+    #[error("Cannot revert to block #{target}: the latest block in database is {latest:?}")]
+    InvalidRevertTarget { target: u64, latest: Option<u64> },
+    #[error(
+        "Cannot revert the global tries {depth} blocks back: only {max_saved_trie_logs} trie logs are kept (see --db-max-saved-trie-logs)"
+    )]
+    RevertTooDeep { depth: u64, max_saved_trie_logs: usize },
+    #[error("Reading the forked chain state: {0:#}")]
+    ForkedStateProvider(anyhow::Error),
+    #[error("The database was forked from block #{saved}, cannot fork it from block #{requested}")]
+    ForkBlockMismatch { saved: u64, requested: u64 },
+    #[error("The database is already forked")]
+    ForkAlreadySet,
 }
 
 pub type BonsaiStorageError = bonsai_trie::BonsaiStorageError<DbError>;
@@ -0,0 +1,272 @@
+//! State of the chain a devnet was forked from.
+//!
+//! A forked devnet starts from its own genesis block, but any storage, nonce, class hash or class read that is not
+//! found in the local database falls through to a [`ForkedStateProvider`], which reads it from the remote chain at the
+//! fork block. This happens in the backend state reads ([`MadaraBackend::get_contract_storage_at`],
+//! [`MadaraBackend::get_class_info`]...), so that execution, the mempool and the rpc all see the forked state. Remote
+//! values are cached in the [`Column::Fork`] column, so that every value is only fetched once.
+//!
+//! The fork is not part of the global tries: the state root of a forked devnet only commits to the local state.
+
+use crate::DatabaseExt;
+use crate::{Column, MadaraBackend, MadaraStorageError};
+use mp_class::ConvertedClass;
+use serde::{de::DeserializeOwned, Serialize};
+use starknet_types_core::felt::Felt;
+use std::{fmt, sync::Arc};
+
+pub const FORK_BLOCK_N: &[u8] = b"FORK_BLOCK_N";
+
+const STORAGE_PREFIX: u8 = 0;
+const NONCE_PREFIX: u8 = 1;
+const CLASS_HASH_PREFIX: u8 = 2;
+const CLASS_PREFIX: u8 = 3;
+
+type Result<T, E = MadaraStorageError> = std::result::Result<T, E>;
+
+/// Reads state from the remote chain at the fork block. Implementations are blocking, as they are called from the
+/// blockifier state reader.
+pub trait ForkedStateProvider: fmt::Debug + Send + Sync {
+    /// The block on the remote chain the devnet was forked from.
+    fn fork_block_n(&self) -> u64;
+    fn get_storage_at(&self, contract_address: &Felt, key: &Felt) -> anyhow::Result<Felt>;
+    fn get_nonce_at(&self, contract_address: &Felt) -> anyhow::Result<Felt>;
+    /// Returns zero if no contract is deployed at this address.
+    fn get_class_hash_at(&self, contract_address: &Felt) -> anyhow::Result<Felt>;
+    fn get_class(&self, class_hash: &Felt) -> anyhow::Result<Option<ConvertedClass>>;
+}
+
+impl MadaraBackend {
+    /// Makes this database a fork of a remote chain. A database can only ever be forked from a single block, as the
+    /// remote values it caches are only valid for that block.
+    #[tracing::instrument(skip(self, provider), fields(module = "ForkDB"))]
+    pub fn set_forked_state_provider(&self, provider: Arc<dyn ForkedStateProvider>) -> Result<()> {
+        let col = self.db.get_column(Column::Fork);
+        let fork_block_n = provider.fork_block_n();
+        match self.db.get_cf(&col, FORK_BLOCK_N)? {
+            Some(saved) => {
+                let saved: u64 = bincode::deserialize(&saved)?;
+                if saved != fork_block_n {
+                    return Err(MadaraStorageError::ForkBlockMismatch { saved, requested: fork_block_n });
+                }
+            }
+            None => {
+                self.db.put_cf_opt(&col, FORK_BLOCK_N, bincode::serialize(&fork_block_n)?, &self.writeopts_no_wal)?
+            }
+        }
+
+        self.fork.set(provider).map_err(|_| MadaraStorageError::ForkAlreadySet)
+    }
+
+    /// The fork block saved by [`MadaraBackend::set_forked_state_provider`] when this database was first forked.
+    pub fn saved_fork_block_n(&self) -> Result<Option<u64>> {
+        let col = self.db.get_column(Column::Fork);
+        let Some(saved) = self.db.get_pinned_cf(&col, FORK_BLOCK_N)? else { return Ok(None) };
+        Ok(Some(bincode::deserialize(&saved)?))
+    }
+
+    /// The block of the remote chain this database was forked from, if any.
+    pub fn fork_block_n(&self) -> Option<u64> {
+        self.fork.get().map(|provider| provider.fork_block_n())
+    }
+
+    /// Storage value on the remote chain. Returns `None` when this database is not a fork.
+    #[tracing::instrument(skip(self), fields(module = "ForkDB"))]
+    pub fn get_forked_storage_at(&self, contract_address: &Felt, key: &Felt) -> Result<Option<Felt>> {
+        self.forked_value(&(STORAGE_PREFIX, *contract_address, *key), |provider| {
+            provider.get_storage_at(contract_address, key)
+        })
+    }
+
+    /// Nonce on the remote chain. Returns `None` when this database is not a fork.
+    #[tracing::instrument(skip(self), fields(module = "ForkDB"))]
+    pub fn get_forked_nonce_at(&self, contract_address: &Felt) -> Result<Option<Felt>> {
+        self.forked_value(&(NONCE_PREFIX, *contract_address), |provider| provider.get_nonce_at(contract_address))
+    }
+
+    /// Class hash on the remote chain. Returns `None` when this database is not a fork.
+    #[tracing::instrument(skip(self), fields(module = "ForkDB"))]
+    pub fn get_forked_class_hash_at(&self, contract_address: &Felt) -> Result<Option<Felt>> {
+        self.forked_value(&(CLASS_HASH_PREFIX, *contract_address), |provider| {
+            provider.get_class_hash_at(contract_address)
+        })
+    }
+
+    /// Class declared on the remote chain. Returns `None` when this database is not a fork, or when the class is not
+    /// declared on the remote chain either.
+    #[tracing::instrument(skip(self), fields(module = "ForkDB"))]
+    pub fn get_forked_class(&self, class_hash: &Felt) -> Result<Option<ConvertedClass>> {
+        Ok(self
+            .forked_value(&(CLASS_PREFIX, *class_hash), |provider| provider.get_class(class_hash))?
+            // Classes missing on the remote chain are cached too.
+            .flatten())
+    }
+
+    fn forked_value<V: Serialize + DeserializeOwned>(
+        &self,
+        key: &impl Serialize,
+        fetch: impl FnOnce(&dyn ForkedStateProvider) -> anyhow::Result<V>,
+    ) -> Result<Option<V>> {
+        let Some(provider) = self.fork.get() else { return Ok(None) };
+
+        let col = self.db.get_column(Column::Fork);
+        let key = bincode::serialize(key)?;
+        if let Some(cached) = self.db.get_cf(&col, &key)? {
+            return Ok(Some(bincode::deserialize(&cached)?));
+        }
+
+        let value = fetch(provider.as_ref()).map_err(MadaraStorageError::ForkedStateProvider)?;
+        self.db.put_cf_opt(&col, &key, bincode::serialize(&value)?, &self.writeopts_no_wal)?;
+        Ok(Some(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contract_db::ContractDbBlockUpdate;
+    use mp_block::{BlockId, BlockTag};
+    use mp_chain_config::ChainConfig;
+    use mp_state_update::{ContractStorageDiffItem, NonceUpdate, StateDiff, StorageEntry};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Debug, Default)]
+    struct CountingProvider {
+        calls: AtomicUsize,
+    }
+
+    impl ForkedStateProvider for CountingProvider {
+        fn fork_block_n(&self) -> u64 {
+            42
+        }
+        fn get_storage_at(&self, _contract_address: &Felt, key: &Felt) -> anyhow::Result<Felt> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Ok(*key + Felt::ONE)
+        }
+        fn get_nonce_at(&self, _contract_address: &Felt) -> anyhow::Result<Felt> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Ok(Felt::TWO)
+        }
+        fn get_class_hash_at(&self, _contract_address: &Felt) -> anyhow::Result<Felt> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Ok(Felt::THREE)
+        }
+        fn get_class(&self, _class_hash: &Felt) -> anyhow::Result<Option<ConvertedClass>> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Ok(None)
+        }
+    }
+
+    #[test]
+    fn test_forked_values_are_cached() {
+        let backend = MadaraBackend::open_for_testing(Arc::new(ChainConfig::madara_test()));
+        assert_eq!(backend.get_forked_storage_at(&Felt::ONE, &Felt::ONE).unwrap(), None);
+
+        assert_eq!(backend.saved_fork_block_n().unwrap(), None);
+
+        let provider = Arc::new(CountingProvider::default());
+        backend.set_forked_state_provider(provider.clone()).unwrap();
+        assert_eq!(backend.fork_block_n(), Some(42));
+        assert_eq!(backend.saved_fork_block_n().unwrap(), Some(42));
+
+        for _ in 0..2 {
+            assert_eq!(backend.get_forked_storage_at(&Felt::ONE, &Felt::ONE).unwrap(), Some(Felt::TWO));
+            assert_eq!(backend.get_forked_nonce_at(&Felt::ONE).unwrap(), Some(Felt::TWO));
+            assert_eq!(backend.get_forked_class_hash_at(&Felt::ONE).unwrap(), Some(Felt::THREE));
+            assert_eq!(backend.get_forked_class(&Felt::ONE).unwrap(), None);
+        }
+        assert_eq!(provider.calls.load(Ordering::SeqCst), 4);
+
+        assert_eq!(backend.get_forked_storage_at(&Felt::ONE, &Felt::TWO).unwrap(), Some(Felt::THREE));
+        assert_eq!(provider.calls.load(Ordering::SeqCst), 5);
+    }
+
+    #[test]
+    fn test_state_reads_fall_through_to_fork() {
+        let backend = MadaraBackend::open_for_testing(Arc::new(ChainConfig::madara_test()));
+        let pending = BlockId::Tag(BlockTag::Pending);
+        assert_eq!(backend.get_contract_storage_at(&pending, &Felt::ONE, &Felt::ONE).unwrap(), None);
+        assert_eq!(backend.get_contract_nonce_at(&pending, &Felt::ONE).unwrap(), None);
+
+        backend.set_forked_state_provider(Arc::new(CountingProvider::default())).unwrap();
+        assert_eq!(backend.get_contract_storage_at(&pending, &Felt::ONE, &Felt::ONE).unwrap(), Some(Felt::TWO));
+        assert_eq!(backend.get_contract_nonce_at(&pending, &Felt::ONE).unwrap(), Some(Felt::TWO));
+        assert_eq!(backend.get_contract_class_hash_at(&pending, &Felt::ONE).unwrap(), Some(Felt::THREE));
+        assert!(backend.is_contract_deployed_at(&pending, &Felt::ONE).unwrap());
+        assert_eq!(backend.get_class_info(&pending, &Felt::ONE).unwrap(), None);
+        assert_eq!(backend.get_converted_class(&pending, &Felt::ONE).unwrap(), None);
+
+        // Local values take precedence over the forked ones.
+        backend
+            .contract_db_store_pending(ContractDbBlockUpdate::from_state_diff(StateDiff {
+                storage_diffs: vec![ContractStorageDiffItem {
+                    address: Felt::ONE,
+                    storage_entries: vec![StorageEntry { key: Felt::ONE, value: Felt::from(10) }],
+                }],
+                nonces: vec![NonceUpdate { contract_address: Felt::ONE, nonce: Felt::from(11) }],
+                ..Default::default()
+            }))
+            .unwrap();
+        assert_eq!(backend.get_contract_storage_at(&pending, &Felt::ONE, &Felt::ONE).unwrap(), Some(Felt::from(10)));
+        assert_eq!(backend.get_contract_nonce_at(&pending, &Felt::ONE).unwrap(), Some(Felt::from(11)));
+    }
+
+    #[test]
+    fn test_undeployed_forked_contract() {
+        #[derive(Debug)]
+        struct EmptyChain;
+        impl ForkedStateProvider for EmptyChain {
+            fn fork_block_n(&self) -> u64 {
+                42
+            }
+            fn get_storage_at(&self, _contract_address: &Felt, _key: &Felt) -> anyhow::Result<Felt> {
+                Ok(Felt::ZERO)
+            }
+            fn get_nonce_at(&self, _contract_address: &Felt) -> anyhow::Result<Felt> {
+                Ok(Felt::ZERO)
+            }
+            fn get_class_hash_at(&self, _contract_address: &Felt) -> anyhow::Result<Felt> {
+                Ok(Felt::ZERO)
+            }
+            fn get_class(&self, _class_hash: &Felt) -> anyhow::Result<Option<ConvertedClass>> {
+                Ok(None)
+            }
+        }
+
+        let backend = MadaraBackend::open_for_testing(Arc::new(ChainConfig::madara_test()));
+        backend.set_forked_state_provider(Arc::new(EmptyChain)).unwrap();
+        let pending = BlockId::Tag(BlockTag::Pending);
+        assert_eq!(backend.get_contract_class_hash_at(&pending, &Felt::ONE).unwrap(), None);
+        assert!(!backend.is_contract_deployed_at(&pending, &Felt::ONE).unwrap());
+    }
+
+    #[test]
+    fn test_fork_block_mismatch() {
+        #[derive(Debug)]
+        struct OtherBlock;
+        impl ForkedStateProvider for OtherBlock {
+            fn fork_block_n(&self) -> u64 {
+                43
+            }
+            fn get_storage_at(&self, _contract_address: &Felt, _key: &Felt) -> anyhow::Result<Felt> {
+                unreachable!()
+            }
+            fn get_nonce_at(&self, _contract_address: &Felt) -> anyhow::Result<Felt> {
+                unreachable!()
+            }
+            fn get_class_hash_at(&self, _contract_address: &Felt) -> anyhow::Result<Felt> {
+                unreachable!()
+            }
+            fn get_class(&self, _class_hash: &Felt) -> anyhow::Result<Option<ConvertedClass>> {
+                unreachable!()
+            }
+        }
+
+        let backend = MadaraBackend::open_for_testing(Arc::new(ChainConfig::madara_test()));
+        backend.set_forked_state_provider(Arc::new(CountingProvider::default())).unwrap();
+        assert!(matches!(
+            backend.set_forked_state_provider(Arc::new(OtherBlock)),
+            Err(MadaraStorageError::ForkBlockMismatch { saved: 42, requested: 43 })
+        ));
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{Column, DatabaseExt, MadaraBackend, MadaraStorageError, WriteBatchWithTransaction};
-use mp_convert::Felt;
+use mp_convert::{Felt, L1TransactionHash};
 use mp_receipt::L1HandlerTransactionReceipt;
 use mp_transactions::{L1HandlerTransaction, L1HandlerTransactionWithFee};
 
@@ -26,6 +26,26 @@ impl MadaraBackend {
         Ok(())
     }
 
+    /// Removes the core contract nonce to L2 transaction hash mapping for the given l1 handler transactions.
+    /// This is used when reverting blocks, so that the messages can be consumed again.
+    ///
+    /// The l1 transaction hash index is left untouched: the status of a message is derived from its l1 handler
+    /// transaction and the pending messages when it is queried, so it follows the revert without touching the index.
+    pub fn l1_db_revert_transactions<'a>(
+        &self,
+        txs: impl IntoIterator<Item = &'a L1HandlerTransaction>,
+    ) -> Result<(), MadaraStorageError> {
+        let mut batch = WriteBatchWithTransaction::default();
+        let on_l2_cf = self.db.get_column(Column::CoreContractNonceToTxnHash);
+
+        for txn in txs {
+            batch.delete_cf(&on_l2_cf, txn.nonce.to_be_bytes());
+        }
+
+        self.db.write_opt(batch, &self.writeopts_no_wal)?;
+        Ok(())
+    }
+
     /// If the message is already pending, this will overwrite it.
     pub fn add_pending_message_to_l2(&self, msg: L1HandlerTransactionWithFee) -> Result<(), MadaraStorageError> {
         let pending_cf = self.db.get_column(Column::CoreContractNonceToPendingMsg);
@@ -97,6 +117,45 @@ impl MadaraBackend {
         Ok(())
     }
 
+    /// Indexes the l1 handler transaction of a message to l2 by the hash of the l1 transaction that sent it. An l1
+    /// transaction can send several messages, they are told apart by their core contract nonce.
+    pub fn add_l1_handler_txn_by_l1_txn_hash(
+        &self,
+        l1_txn_hash: &L1TransactionHash,
+        core_contract_nonce: u64,
+        txn_hash: Felt,
+    ) -> Result<(), MadaraStorageError> {
+        let by_l1_txn_cf = self.db.get_column(Column::L1TxnHashToL1HandlerTxns);
+        let key = [l1_txn_hash.0.as_slice(), &core_contract_nonce.to_be_bytes()].concat();
+        self.db.put_cf_opt(&by_l1_txn_cf, key, txn_hash.to_bytes_be(), &self.writeopts_no_wal)?;
+        Ok(())
+    }
+
+    /// Returns the core contract nonces and l1 handler transaction hashes of the messages to l2 sent by the given l1
+    /// transaction, ordered by nonce.
+    pub fn get_l1_handler_txns_by_l1_txn_hash(
+        &self,
+        l1_txn_hash: &L1TransactionHash,
+    ) -> Result<Vec<(u64, Felt)>, MadaraStorageError> {
+        let by_l1_txn_cf = self.db.get_column(Column::L1TxnHashToL1HandlerTxns);
+        let prefix = l1_txn_hash.0;
+        let mode = rocksdb::IteratorMode::From(&prefix, rocksdb::Direction::Forward);
+
+        let mut txns = Vec::new();
+        for res in self.db.iterator_cf(&by_l1_txn_cf, mode) {
+            let (key, value) = res?;
+            let Some(nonce) = key.strip_prefix(prefix.as_slice()) else { break };
+            let nonce = u64::from_be_bytes(
+                nonce.try_into().map_err(|_| MadaraStorageError::InconsistentStorage("Malformated nonce".into()))?,
+            );
+            let txn_hash = Felt::from_bytes_be(
+                value[..].try_into().map_err(|_| MadaraStorageError::InconsistentStorage("Malformated felt".into()))?,
+            );
+            txns.push((nonce, txn_hash));
+        }
+        Ok(txns)
+    }
+
     /// Set the latest l1_block synced for the messaging worker.
     pub fn set_l1_messaging_sync_tip(&self, l1_block_n: u64) -> Result<(), MadaraStorageError> {
         let meta_cf = self.db.get_column(Column::BlockStorageMeta);
@@ -55,7 +55,7 @@ use rocksdb_options::rocksdb_global_options;
 use snapshots::Snapshots;
 use starknet_types_core::hash::{Pedersen, Poseidon, StarkHash};
 use std::path::{Path, PathBuf};
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 use std::{fmt, fs};
 use tokio::sync::{mpsc, oneshot, RwLock};
 use watch::BlockWatch;
@@ -66,6 +66,7 @@ mod error;
 mod events;
 mod events_bloom_filter;
 mod rocksdb_options;
+mod revert;
 mod rocksdb_snapshot;
 mod snapshots;
 mod watch;
@@ -77,8 +78,10 @@ pub mod contract_db;
 pub mod db_block_id;
 pub mod db_metrics;
 pub mod devnet_db;
+pub mod fork_db;
 pub mod l1_db;
 pub mod mempool_db;
+pub mod migration;
 pub mod storage_updates;
 pub mod stream;
 #[cfg(any(test, feature = "testing"))]
@@ -89,7 +92,9 @@ pub use bonsai_db::GlobalTrie;
 pub use bonsai_trie::{id::BasicId, MultiProof, ProofNode};
 pub use error::{BonsaiStorageError, MadaraStorageError, TrieType};
 pub use rocksdb_options::{RocksDBConfig, StatsLevel};
-pub use watch::{ClosedBlocksReceiver, LastBlockOnL1Receiver, PendingBlockReceiver, PendingTxsReceiver};
+pub use watch::{
+    ChainRevertReceiver, ClosedBlocksReceiver, LastBlockOnL1Receiver, PendingBlockReceiver, PendingTxsReceiver,
+};
 pub type DB = DBWithThreadMode<MultiThreaded>;
 pub use rocksdb;
 pub type WriteBatchWithTransaction = rocksdb::WriteBatchWithTransaction<false>;
@@ -200,9 +205,13 @@ pub enum Column {
     CoreContractNonceToTxnHash,
     // List of pending l1 to l2 messages to handle.
     CoreContractNonceToPendingMsg,
+    // (l1_txn_hash, core_contract_nonce) => l1 handler txn hash
+    L1TxnHashToL1HandlerTxns,
 
     /// Devnet: stores the private keys for the devnet predeployed contracts
     Devnet,
+    /// Fork: values read from the chain a devnet was forked from, see [`fork_db`]
+    Fork,
 
     MempoolTransactions,
 }
@@ -248,10 +257,12 @@ impl Column {
             BonsaiClassesLog,
             CoreContractNonceToTxnHash,
             CoreContractNonceToPendingMsg,
+            L1TxnHashToL1HandlerTxns,
             PendingContractToClassHashes,
             PendingContractToNonces,
             PendingContractStorage,
             Devnet,
+            Fork,
             MempoolTransactions,
         ]
     };
@@ -285,10 +296,12 @@ impl Column {
             ContractStorage => "contract_storage",
             CoreContractNonceToTxnHash => "core_contract_nonce_to_txn_hash",
             CoreContractNonceToPendingMsg => "core_contract_nonce_to_pending_msg",
+            L1TxnHashToL1HandlerTxns => "l1_txn_hash_to_l1_handler_txns",
             PendingContractToClassHashes => "pending_contract_to_class_hashes",
             PendingContractToNonces => "pending_contract_to_nonces",
             PendingContractStorage => "pending_contract_storage",
             Devnet => "devnet",
+            Fork => "fork",
             MempoolTransactions => "mempool_transactions",
         }
     }
@@ -373,6 +386,8 @@ pub struct MadaraBackend {
     _temp_dir: Option<tempfile::TempDir>,
     sync_status: SyncStatusCell,
     starting_block: Option<u64>,
+    /// Set when this is a forked devnet, see [`fork_db`].
+    fork: OnceLock<Arc<dyn fork_db::ForkedStateProvider>>,
 }
 
 impl fmt::Debug for MadaraBackend {
@@ -516,6 +531,7 @@ impl MadaraBackend {
             config,
             starting_block: None,
             sync_status: SyncStatusCell::default(),
+            fork: OnceLock::new(),
             head_status: ChainHead::default(),
             snapshots,
             watch_blocks: BlockWatch::new(),
@@ -528,8 +544,16 @@ impl MadaraBackend {
 
     #[cfg(any(test, feature = "testing"))]
     pub fn open_for_testing(chain_config: Arc<ChainConfig>) -> Arc<MadaraBackend> {
+        Self::open_for_testing_with_trie_log(chain_config, TrieLogConfig::default())
+    }
+
+    #[cfg(any(test, feature = "testing"))]
+    pub fn open_for_testing_with_trie_log(
+        chain_config: Arc<ChainConfig>,
+        trie_log: TrieLogConfig,
+    ) -> Arc<MadaraBackend> {
         let temp_dir = tempfile::TempDir::with_prefix("madara-test").unwrap();
-        let config = MadaraBackendConfig::new(&temp_dir);
+        let config = MadaraBackendConfig::new(&temp_dir).trie_log(trie_log);
         let db = open_rocksdb(temp_dir.as_ref(), &config.rocksdb).unwrap();
         let mut backend = Self::new(None, db, chain_config, config).unwrap();
         backend._temp_dir = Some(temp_dir);
@@ -543,9 +567,8 @@ impl MadaraBackend {
     ) -> anyhow::Result<Arc<MadaraBackend>> {
         // check if the db version is compatible with the current binary
         tracing::debug!("checking db version");
-        if let Some(db_version) =
-            db_version::check_db_version(&config.base_path).context("Checking database version")?
-        {
+        let db_version = db_version::check_db_version(&config.base_path).context("Checking database version")?;
+        if let Some(db_version) = db_version {
             tracing::debug!("version of existing db is {db_version}");
         }
 
@@ -579,10 +602,14 @@ impl MadaraBackend {
         };
 
         let db = open_rocksdb(&db_path, &config.rocksdb)?;
+        if let Some(db_version) = db_version {
+            migration::migrate(&db, &config.base_path, db_version).context("Migrating database")?;
+        }
 
         let mut backend = Self::new(backup_handle, db, chain_config, config)?;
         backend.check_configuration()?;
         backend.load_head_status_from_db()?;
+        backend.resume_interrupted_revert()?;
         backend.update_metrics();
         backend.set_starting_block(backend.head_status.latest_full_block_n());
         Ok(Arc::new(backend))
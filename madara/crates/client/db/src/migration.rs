@@ -0,0 +1,293 @@
+//! Database schema migrations.
+//!
+//! When an existing database has an older version than the one expected by the binary, the steps registered in
+//! [`MIGRATIONS`] are run in order at startup, each one upgrading the database by exactly one version. Steps rewrite
+//! column families in place through [`MigrationContext::rewrite_column`].
+//!
+//! Progress is saved in the [`Column::BlockStorageMeta`] column atomically with each batch of rewritten rows, so that
+//! a migration interrupted by a crash resumes where it stopped on the next startup instead of starting over.
+//!
+//! # Adding a migration
+//! When bumping the database version in `.db-versions.yml`, add a [`Migration`] with `from_version` set to the
+//! previous version at the end of [`MIGRATIONS`]. Databases for which no migration path exists still need to be
+//! resynced.
+
+use crate::{db_version, Column, DatabaseExt, DB, DB_UPDATES_BATCH_SIZE};
+use anyhow::Context;
+use rocksdb::{Direction, IteratorMode, WriteBatchWithTransaction};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+mod l2_gas_prices;
+
+const ROW_MIGRATION_PROGRESS: &[u8] = b"migration_progress";
+
+/// A migration step, upgrading the database from `from_version` to `from_version + 1`.
+pub struct Migration {
+    pub from_version: u32,
+    /// Shown in the logs.
+    pub name: &'static str,
+    pub run: fn(&mut MigrationContext<'_>) -> anyhow::Result<()>,
+}
+
+/// Ordered registry of the migrations.
+pub const MIGRATIONS: &[Migration] = &[Migration { from_version: 5, name: "l2_gas_prices", run: l2_gas_prices::run }];
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct MigrationProgress {
+    /// The database is fully migrated up to this version.
+    version: u32,
+    /// Columns that have been fully rewritten by the migration currently in progress.
+    completed_columns: Vec<String>,
+    /// Last key rewritten in the column currently being rewritten.
+    cursor: Option<MigrationCursor>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct MigrationCursor {
+    column: String,
+    last_key: Vec<u8>,
+}
+
+/// What to do with a row when rewriting a column.
+pub enum RowUpdate {
+    Keep,
+    Put(Vec<u8>),
+    Delete,
+}
+
+pub struct MigrationContext<'a> {
+    db: &'a DB,
+    progress: MigrationProgress,
+    batch_size: usize,
+}
+
+impl MigrationContext<'_> {
+    pub fn db(&self) -> &DB {
+        self.db
+    }
+
+    /// Rewrites every row of `column` in place using `f`. Each column can only be rewritten once per migration: when
+    /// resuming an interrupted migration, columns that have already been rewritten are skipped, and the rewrite of
+    /// an interrupted column restarts after the last key that was saved.
+    pub fn rewrite_column(
+        &mut self,
+        column: Column,
+        mut f: impl FnMut(&[u8], &[u8]) -> anyhow::Result<RowUpdate>,
+    ) -> anyhow::Result<()> {
+        let name = column.rocksdb_name();
+        if self.progress.completed_columns.iter().any(|c| c == name) {
+            tracing::debug!("Migration: column {name} already rewritten, skipping");
+            return Ok(());
+        }
+
+        let start_after = self.progress.cursor.take().filter(|cursor| cursor.column == name).map(|c| c.last_key);
+        let col = self.db.get_column(column);
+        let meta_col = self.db.get_column(Column::BlockStorageMeta);
+
+        let mode = match &start_after {
+            Some(key) => IteratorMode::From(key, Direction::Forward),
+            None => IteratorMode::Start,
+        };
+        // The iterator works on an implicit snapshot, it is not affected by the rows we rewrite.
+        let mut iter = self.db.iterator_cf(&col, mode).peekable();
+        let mut n_rows = 0usize;
+        loop {
+            let mut batch = WriteBatchWithTransaction::default();
+            let mut last_key = None;
+            for _ in 0..self.batch_size {
+                let Some(res) = iter.next() else { break };
+                let (key, value) = res?;
+                if start_after.as_deref() == Some(&*key) {
+                    last_key = Some(key);
+                    continue;
+                }
+                match f(&key, &value).with_context(|| format!("Rewriting row in column {name}"))? {
+                    RowUpdate::Keep => {}
+                    RowUpdate::Put(value) => batch.put_cf(&col, &key, value),
+                    RowUpdate::Delete => batch.delete_cf(&col, &key),
+                }
+                n_rows += 1;
+                last_key = Some(key);
+            }
+
+            let done = iter.peek().is_none();
+            if done {
+                self.progress.completed_columns.push(name.into());
+            } else if let Some(last_key) = last_key {
+                self.progress.cursor = Some(MigrationCursor { column: name.into(), last_key: last_key.into() });
+            }
+            // The rewritten rows and the progress are committed together.
+            batch.put_cf(&meta_col, ROW_MIGRATION_PROGRESS, bincode::serialize(&self.progress)?);
+            self.db.write(batch)?;
+
+            if done {
+                break;
+            }
+        }
+
+        tracing::debug!("Migration: rewrote {n_rows} rows in column {name}");
+        Ok(())
+    }
+}
+
+/// Upgrades the database from `db_version` to the version expected by the binary.
+pub(crate) fn migrate(db: &DB, base_path: &Path, db_version: u32) -> anyhow::Result<()> {
+    migrate_with(db, base_path, db_version, db_version::required_db_version(), MIGRATIONS, DB_UPDATES_BATCH_SIZE)
+}
+
+fn migrate_with(
+    db: &DB,
+    base_path: &Path,
+    db_version: u32,
+    required_version: u32,
+    migrations: &[Migration],
+    batch_size: usize,
+) -> anyhow::Result<()> {
+    let meta_col = db.get_column(Column::BlockStorageMeta);
+    let mut progress = match db.get_cf(&meta_col, ROW_MIGRATION_PROGRESS)? {
+        Some(bytes) => bincode::deserialize::<MigrationProgress>(&bytes).context("Parsing migration progress")?,
+        None => MigrationProgress { version: db_version, ..Default::default() },
+    };
+    // The version file is written after the progress, it may lag behind if we crashed in between.
+    if progress.version < db_version {
+        progress = MigrationProgress { version: db_version, ..Default::default() };
+    }
+    if progress.version > required_version {
+        anyhow::bail!(db_version::DbVersionError::IncompatibleVersion {
+            db_version: progress.version,
+            required_version
+        });
+    }
+
+    if progress.version < required_version {
+        tracing::info!("⏳ Migrating database from version {} to {required_version}...", progress.version);
+    }
+    while progress.version < required_version {
+        let version = progress.version;
+        let migration = migrations.iter().find(|m| m.from_version == version).with_context(|| {
+            format!(
+                "No migration from database version {version} to {}, the database needs to be resynced",
+                version + 1
+            )
+        })?;
+        tracing::info!("⏳ Running migration '{}' (version {version} to {})", migration.name, version + 1);
+
+        let mut ctx = MigrationContext { db, progress, batch_size };
+        (migration.run)(&mut ctx).with_context(|| format!("Running migration '{}'", migration.name))?;
+
+        progress = MigrationProgress { version: version + 1, ..Default::default() };
+        db.put_cf(&meta_col, ROW_MIGRATION_PROGRESS, bincode::serialize(&progress)?)?;
+        db_version::write_db_version(base_path, progress.version).context("Writing database version")?;
+    }
+
+    if db.get_cf(&meta_col, ROW_MIGRATION_PROGRESS)?.is_some() {
+        db.delete_cf(&meta_col, ROW_MIGRATION_PROGRESS)?;
+        tracing::info!("✅ Database migrated to version {required_version}");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{open_rocksdb, RocksDBConfig};
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use tempfile::TempDir;
+
+    const TEST_COLUMN: Column = Column::Devnet;
+
+    fn setup(n_rows: u8) -> (TempDir, std::sync::Arc<DB>) {
+        let temp_dir = TempDir::new().unwrap();
+        let db = open_rocksdb(&temp_dir.path().join("db"), &RocksDBConfig::default()).unwrap();
+        let col = db.get_column(TEST_COLUMN);
+        for i in 0..n_rows {
+            db.put_cf(&col, [i], [i]).unwrap();
+        }
+        (temp_dir, db)
+    }
+
+    fn rows(db: &DB) -> Vec<(Vec<u8>, Vec<u8>)> {
+        db.iterator_cf(&db.get_column(TEST_COLUMN), IteratorMode::Start)
+            .map(|res| res.map(|(k, v)| (k.into(), v.into())).unwrap())
+            .collect()
+    }
+
+    fn double_values(ctx: &mut MigrationContext<'_>) -> anyhow::Result<()> {
+        ctx.rewrite_column(TEST_COLUMN, |_, value| Ok(RowUpdate::Put(value.iter().map(|v| v * 2).collect())))
+    }
+
+    fn delete_odd_keys(ctx: &mut MigrationContext<'_>) -> anyhow::Result<()> {
+        ctx.rewrite_column(TEST_COLUMN, |key, _| Ok(if key[0] % 2 == 1 { RowUpdate::Delete } else { RowUpdate::Keep }))
+    }
+
+    #[test]
+    fn test_migrate() {
+        let (temp_dir, db) = setup(5);
+        let migrations = [
+            Migration { from_version: 0, name: "double_values", run: double_values },
+            Migration { from_version: 1, name: "delete_odd_keys", run: delete_odd_keys },
+        ];
+
+        migrate_with(&db, temp_dir.path(), 0, 2, &migrations, 2).unwrap();
+
+        assert_eq!(rows(&db), vec![(vec![0], vec![0]), (vec![2], vec![4]), (vec![4], vec![8])]);
+        assert_eq!(std::fs::read_to_string(temp_dir.path().join(".db-version")).unwrap(), "2");
+        assert!(db.get_cf(&db.get_column(Column::BlockStorageMeta), ROW_MIGRATION_PROGRESS).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_migrate_missing_step() {
+        let (temp_dir, db) = setup(2);
+        let migrations = [Migration { from_version: 0, name: "double_values", run: double_values }];
+
+        assert!(migrate_with(&db, temp_dir.path(), 0, 2, &migrations, 2).is_err());
+        // The first migration has been applied and is not run again.
+        assert_eq!(std::fs::read_to_string(temp_dir.path().join(".db-version")).unwrap(), "1");
+        migrate_with(&db, temp_dir.path(), 1, 1, &migrations, 2).unwrap();
+        assert_eq!(rows(&db), vec![(vec![0], vec![0]), (vec![1], vec![2])]);
+    }
+
+    #[test]
+    fn test_migrate_resume() {
+        static RESUMED: AtomicBool = AtomicBool::new(false);
+        fn interrupted_double_values(ctx: &mut MigrationContext<'_>) -> anyhow::Result<()> {
+            ctx.rewrite_column(TEST_COLUMN, |key, value| {
+                if key[0] == 3 && !RESUMED.load(Ordering::SeqCst) {
+                    anyhow::bail!("Simulated crash")
+                }
+                Ok(RowUpdate::Put(value.iter().map(|v| v * 2).collect()))
+            })
+        }
+
+        let (temp_dir, db) = setup(6);
+        let migrations = [Migration { from_version: 0, name: "double_values", run: interrupted_double_values }];
+
+        assert!(migrate_with(&db, temp_dir.path(), 0, 1, &migrations, 2).is_err());
+        // Only the first committed batch has been rewritten.
+        assert_eq!(
+            rows(&db),
+            vec![
+                (vec![0], vec![0]),
+                (vec![1], vec![2]),
+                (vec![2], vec![2]),
+                (vec![3], vec![3]),
+                (vec![4], vec![4]),
+                (vec![5], vec![5])
+            ]
+        );
+
+        RESUMED.store(true, Ordering::SeqCst);
+        migrate_with(&db, temp_dir.path(), 0, 1, &migrations, 2).unwrap();
+
+        // Every row has been rewritten exactly once.
+        assert_eq!(rows(&db), (0..6).map(|i| (vec![i], vec![i * 2])).collect::<Vec<_>>());
+        assert_eq!(std::fs::read_to_string(temp_dir.path().join(".db-version")).unwrap(), "1");
+    }
+
+    #[test]
+    fn test_migrate_newer_database() {
+        let (temp_dir, db) = setup(0);
+        assert!(migrate_with(&db, temp_dir.path(), 3, 2, &[], 2).is_err());
+    }
+}
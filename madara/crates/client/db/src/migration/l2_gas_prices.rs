@@ -0,0 +1,214 @@
+//! Version 5 to 6: block headers store the L2 gas prices.
+//!
+//! Blocks stored before this migration get zero L2 gas prices, which stands for blocks produced before L2 gas was
+//! priced.
+
+use super::{MigrationContext, RowUpdate};
+use crate::{block_db::ROW_PENDING_INFO, Column};
+use mp_block::{
+    header::{BlockTimestamp, GasPrices, Header, PendingHeader},
+    MadaraBlockInfo, MadaraPendingBlockInfo,
+};
+use mp_chain_config::{L1DataAvailabilityMode, StarknetVersion};
+use serde::{Deserialize, Serialize};
+use starknet_types_core::felt::Felt;
+
+#[derive(Serialize, Deserialize)]
+struct GasPricesV5 {
+    eth_l1_gas_price: u128,
+    strk_l1_gas_price: u128,
+    eth_l1_data_gas_price: u128,
+    strk_l1_data_gas_price: u128,
+}
+
+#[derive(Serialize, Deserialize)]
+struct PendingHeaderV5 {
+    parent_block_hash: Felt,
+    sequencer_address: Felt,
+    block_timestamp: BlockTimestamp,
+    protocol_version: StarknetVersion,
+    l1_gas_price: GasPricesV5,
+    l1_da_mode: L1DataAvailabilityMode,
+}
+
+#[derive(Serialize, Deserialize)]
+struct HeaderV5 {
+    parent_block_hash: Felt,
+    block_number: u64,
+    global_state_root: Felt,
+    sequencer_address: Felt,
+    block_timestamp: BlockTimestamp,
+    transaction_count: u64,
+    transaction_commitment: Felt,
+    event_count: u64,
+    event_commitment: Felt,
+    state_diff_length: Option<u64>,
+    state_diff_commitment: Option<Felt>,
+    receipt_commitment: Option<Felt>,
+    protocol_version: StarknetVersion,
+    l1_gas_price: GasPricesV5,
+    l1_da_mode: L1DataAvailabilityMode,
+}
+
+#[derive(Serialize, Deserialize)]
+struct MadaraBlockInfoV5 {
+    header: HeaderV5,
+    block_hash: Felt,
+    tx_hashes: Vec<Felt>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct MadaraPendingBlockInfoV5 {
+    header: PendingHeaderV5,
+    tx_hashes: Vec<Felt>,
+}
+
+impl From<GasPricesV5> for GasPrices {
+    fn from(gas_prices: GasPricesV5) -> Self {
+        Self {
+            eth_l1_gas_price: gas_prices.eth_l1_gas_price,
+            strk_l1_gas_price: gas_prices.strk_l1_gas_price,
+            eth_l1_data_gas_price: gas_prices.eth_l1_data_gas_price,
+            strk_l1_data_gas_price: gas_prices.strk_l1_data_gas_price,
+            eth_l2_gas_price: 0,
+            strk_l2_gas_price: 0,
+        }
+    }
+}
+
+impl From<MadaraBlockInfoV5> for MadaraBlockInfo {
+    fn from(info: MadaraBlockInfoV5) -> Self {
+        let header = info.header;
+        Self {
+            header: Header {
+                parent_block_hash: header.parent_block_hash,
+                block_number: header.block_number,
+                global_state_root: header.global_state_root,
+                sequencer_address: header.sequencer_address,
+                block_timestamp: header.block_timestamp,
+                transaction_count: header.transaction_count,
+                transaction_commitment: header.transaction_commitment,
+                event_count: header.event_count,
+                event_commitment: header.event_commitment,
+                state_diff_length: header.state_diff_length,
+                state_diff_commitment: header.state_diff_commitment,
+                receipt_commitment: header.receipt_commitment,
+                protocol_version: header.protocol_version,
+                l1_gas_price: header.l1_gas_price.into(),
+                l1_da_mode: header.l1_da_mode,
+            },
+            block_hash: info.block_hash,
+            tx_hashes: info.tx_hashes,
+        }
+    }
+}
+
+impl From<MadaraPendingBlockInfoV5> for MadaraPendingBlockInfo {
+    fn from(info: MadaraPendingBlockInfoV5) -> Self {
+        let header = info.header;
+        Self {
+            header: PendingHeader {
+                parent_block_hash: header.parent_block_hash,
+                sequencer_address: header.sequencer_address,
+                block_timestamp: header.block_timestamp,
+                protocol_version: header.protocol_version,
+                l1_gas_price: header.l1_gas_price.into(),
+                l1_da_mode: header.l1_da_mode,
+            },
+            tx_hashes: info.tx_hashes,
+        }
+    }
+}
+
+pub(super) fn run(ctx: &mut MigrationContext<'_>) -> anyhow::Result<()> {
+    ctx.rewrite_column(Column::BlockNToBlockInfo, |_, value| {
+        let info: MadaraBlockInfo = bincode::deserialize::<MadaraBlockInfoV5>(value)?.into();
+        Ok(RowUpdate::Put(bincode::serialize(&info)?))
+    })?;
+    ctx.rewrite_column(Column::BlockStorageMeta, |key, value| {
+        if key != ROW_PENDING_INFO {
+            return Ok(RowUpdate::Keep);
+        }
+        let info: MadaraPendingBlockInfo = bincode::deserialize::<MadaraPendingBlockInfoV5>(value)?.into();
+        Ok(RowUpdate::Put(bincode::serialize(&info)?))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{migration::migrate_with, open_rocksdb, DatabaseExt, RocksDBConfig};
+    use tempfile::TempDir;
+
+    fn gas_prices_v5() -> GasPricesV5 {
+        GasPricesV5 { eth_l1_gas_price: 1, strk_l1_gas_price: 2, eth_l1_data_gas_price: 3, strk_l1_data_gas_price: 4 }
+    }
+
+    #[test]
+    fn test_migrate_l2_gas_prices() {
+        let temp_dir = TempDir::new().unwrap();
+        let db = open_rocksdb(&temp_dir.path().join("db"), &RocksDBConfig::default()).unwrap();
+        let info_col = db.get_column(Column::BlockNToBlockInfo);
+        let meta_col = db.get_column(Column::BlockStorageMeta);
+
+        let block_info = MadaraBlockInfoV5 {
+            header: HeaderV5 {
+                parent_block_hash: Felt::ONE,
+                block_number: 0,
+                global_state_root: Felt::TWO,
+                sequencer_address: Felt::THREE,
+                block_timestamp: BlockTimestamp(10),
+                transaction_count: 1,
+                transaction_commitment: Felt::from(5),
+                event_count: 0,
+                event_commitment: Felt::from(6),
+                state_diff_length: Some(2),
+                state_diff_commitment: None,
+                receipt_commitment: None,
+                protocol_version: StarknetVersion::V0_13_2,
+                l1_gas_price: gas_prices_v5(),
+                l1_da_mode: L1DataAvailabilityMode::Blob,
+            },
+            block_hash: Felt::from(7),
+            tx_hashes: vec![Felt::from(8)],
+        };
+        db.put_cf(&info_col, bincode::serialize(&0u64).unwrap(), bincode::serialize(&block_info).unwrap()).unwrap();
+        let pending_info = MadaraPendingBlockInfoV5 {
+            header: PendingHeaderV5 {
+                parent_block_hash: Felt::from(7),
+                sequencer_address: Felt::THREE,
+                block_timestamp: BlockTimestamp(20),
+                protocol_version: StarknetVersion::V0_13_2,
+                l1_gas_price: gas_prices_v5(),
+                l1_da_mode: L1DataAvailabilityMode::Blob,
+            },
+            tx_hashes: vec![],
+        };
+        db.put_cf(&meta_col, ROW_PENDING_INFO, bincode::serialize(&pending_info).unwrap()).unwrap();
+        db.put_cf(&meta_col, b"other", [1, 2, 3]).unwrap();
+
+        let migrations = [super::super::Migration { from_version: 5, name: "l2_gas_prices", run }];
+        migrate_with(&db, temp_dir.path(), 5, 6, &migrations, 2).unwrap();
+
+        let expected_gas_prices = GasPrices {
+            eth_l1_gas_price: 1,
+            strk_l1_gas_price: 2,
+            eth_l1_data_gas_price: 3,
+            strk_l1_data_gas_price: 4,
+            eth_l2_gas_price: 0,
+            strk_l2_gas_price: 0,
+        };
+        let info: MadaraBlockInfo =
+            bincode::deserialize(&db.get_cf(&info_col, bincode::serialize(&0u64).unwrap()).unwrap().unwrap()).unwrap();
+        assert_eq!(info.header.l1_gas_price, expected_gas_prices);
+        assert_eq!(info.header.block_timestamp, BlockTimestamp(10));
+        assert_eq!(info.block_hash, Felt::from(7));
+        assert_eq!(info.tx_hashes, vec![Felt::from(8)]);
+
+        let pending: MadaraPendingBlockInfo =
+            bincode::deserialize(&db.get_cf(&meta_col, ROW_PENDING_INFO).unwrap().unwrap()).unwrap();
+        assert_eq!(pending.header.l1_gas_price, expected_gas_prices);
+        assert_eq!(pending.header.parent_block_hash, Felt::from(7));
+        assert_eq!(db.get_cf(&meta_col, b"other").unwrap().unwrap(), vec![1, 2, 3]);
+    }
+}
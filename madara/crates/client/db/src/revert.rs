@@ -0,0 +1,149 @@
+use crate::contract_db::ContractDbBlockUpdate;
+use crate::db_block_id::DbBlockId;
+use crate::{Column, DatabaseExt, MadaraBackend, MadaraStorageError};
+use anyhow::Context;
+use bonsai_trie::id::BasicId;
+use rocksdb::WriteOptions;
+
+const ROW_REVERT_TARGET: &[u8] = b"revert_target";
+
+impl MadaraBackend {
+    /// Revert the chain so that `new_tip` becomes the latest block in the database. Every block above it is removed
+    /// from the block, contract, class and event bloom columns, and the global tries are rolled back using their
+    /// trie logs.
+    ///
+    /// Reverting the global tries is only possible when enough trie logs have been saved, see
+    /// [`crate::TrieLogConfig::max_saved_trie_logs`].
+    ///
+    /// The latest full block is moved back in memory before anything is deleted, so that readers never see partially
+    /// removed blocks. Partial blocks past the latest full block (see the [module documentation](crate)) are removed
+    /// too.
+    ///
+    /// The block columns are written without the WAL and the head status is only persisted once everything has been
+    /// removed, so the revert target is first written to the database with a synced WAL write. If the node stops
+    /// midway, it restarts on the old head and [`MadaraBackend::open`] finishes the revert before anything else reads
+    /// the database.
+    #[tracing::instrument(skip(self), fields(module = "Revert"))]
+    pub fn revert_to(&self, new_tip: u64) -> anyhow::Result<()> {
+        let latest = self.head_status.latest_full_block_n();
+        if latest.is_none_or(|latest| latest < new_tip) {
+            return Err(MadaraStorageError::InvalidRevertTarget { target: new_tip, latest }.into());
+        }
+
+        let trie_tip = self.head_status.global_trie.current().filter(|trie_tip| *trie_tip > new_tip);
+        if let Some(trie_tip) = trie_tip {
+            let depth = trie_tip - new_tip;
+            if depth > self.config.trie_log.max_saved_trie_logs as u64 {
+                return Err(MadaraStorageError::RevertTooDeep {
+                    depth,
+                    max_saved_trie_logs: self.config.trie_log.max_saved_trie_logs,
+                }
+                .into());
+            }
+        }
+
+        // The sync pipelines may have stored partial blocks past the latest full block.
+        let mut highest = [
+            latest,
+            self.head_status.headers.current(),
+            self.head_status.state_diffs.current(),
+            self.head_status.classes.current(),
+            self.head_status.transactions.current(),
+            self.head_status.events.current(),
+        ]
+        .into_iter()
+        .flatten()
+        .max()
+        .unwrap_or(new_tip);
+        while self.get_block_info_from_block_n(highest + 1)?.is_some() {
+            highest += 1;
+        }
+
+        tracing::info!("⏪ Reverting chain from block #{highest} to #{new_tip}");
+
+        self.set_revert_target(Some(new_tip))?;
+
+        self.head_status.set_latest_full_block_n(Some(new_tip));
+        self.clear_pending_block()?;
+
+        // Remove the blocks from the highest down, so that the remaining chain is always contiguous.
+        for block_n in (new_tip + 1..=highest).rev() {
+            self.revert_block(block_n).with_context(|| format!("Reverting block #{block_n}"))?;
+        }
+
+        if let Some(trie_tip) = trie_tip {
+            tracing::debug!("reverting global tries from {trie_tip} to {new_tip}");
+            let (contracts, classes) = rayon::join(
+                || {
+                    self.contract_storage_trie().revert_to(BasicId::new(new_tip), BasicId::new(trie_tip))?;
+                    self.contract_trie().revert_to(BasicId::new(new_tip), BasicId::new(trie_tip))
+                },
+                || self.class_trie().revert_to(BasicId::new(new_tip), BasicId::new(trie_tip)),
+            );
+            contracts.map_err(MadaraStorageError::from).context("Reverting the contract tries")?;
+            classes.map_err(MadaraStorageError::from).context("Reverting the class trie")?;
+            self.head_status.global_trie.set_current(Some(new_tip));
+        }
+
+        for status in [
+            &self.head_status.headers,
+            &self.head_status.state_diffs,
+            &self.head_status.classes,
+            &self.head_status.transactions,
+            &self.head_status.events,
+        ] {
+            if status.current().is_some_and(|n| n > new_tip) {
+                status.set_current(Some(new_tip));
+            }
+        }
+        self.save_head_status_to_db()?;
+
+        self.snapshots.revert_head(Some(new_tip));
+        let parent_block = self
+            .get_block_info(&DbBlockId::Number(new_tip))?
+            .and_then(|info| info.into_closed())
+            .context("Can't find the block info of the new chain tip")?;
+        self.watch_blocks.on_revert(Some(&parent_block));
+
+        self.flush().context("Flushing database")?;
+        self.set_revert_target(None)?;
+        Ok(())
+    }
+
+    /// Finish a revert which was interrupted by the node stopping, see [`MadaraBackend::revert_to`].
+    pub(crate) fn resume_interrupted_revert(&self) -> anyhow::Result<()> {
+        let col = self.db.get_column(Column::BlockStorageMeta);
+        let Some(res) = self.db.get_pinned_cf(&col, ROW_REVERT_TARGET)? else { return Ok(()) };
+        let new_tip: u64 = bincode::deserialize(&res)?;
+
+        tracing::warn!("⏪ The node stopped while reverting the chain to block #{new_tip}, resuming the revert");
+        self.revert_to(new_tip).with_context(|| format!("Resuming the revert to block #{new_tip}"))
+    }
+
+    pub(crate) fn set_revert_target(&self, new_tip: Option<u64>) -> Result<(), MadaraStorageError> {
+        let col = self.db.get_column(Column::BlockStorageMeta);
+        let mut writeopts = WriteOptions::default();
+        writeopts.set_sync(true);
+        match new_tip {
+            Some(new_tip) => self.db.put_cf_opt(&col, ROW_REVERT_TARGET, bincode::serialize(&new_tip)?, &writeopts)?,
+            None => self.db.delete_cf_opt(&col, ROW_REVERT_TARGET, &writeopts)?,
+        }
+        Ok(())
+    }
+
+    fn revert_block(&self, block_n: u64) -> Result<(), MadaraStorageError> {
+        tracing::debug!("reverting block_n={block_n}");
+
+        if let Some(state_diff) = self.get_state_update(block_n)? {
+            let class_hashes = state_diff.all_declared_classes().into_keys();
+            self.class_db_revert_block(block_n, class_hashes)?;
+            self.contract_db_revert_block(block_n, ContractDbBlockUpdate::from_state_diff(state_diff))?;
+        }
+        if let Some(inner) = self.get_block_inner_from_block_n(block_n)? {
+            self.l1_db_revert_transactions(inner.transactions.iter().filter_map(|tx| tx.as_l1_handler()))?;
+        }
+        self.block_db_revert_block(block_n)?;
+
+        Ok(())
+    }
+}
@@ -72,6 +72,21 @@ impl Snapshots {
         }
     }
 
+    /// Called when the chain has been reverted to `block_n`. Snapshots taken after that block are not valid anymore
+    /// and are discarded, and the head snapshot is replaced by a snapshot of the reverted database.
+    #[tracing::instrument(skip(self), fields(module = "BonsaiDB"))]
+    pub fn revert_head(&self, block_n: Option<u64>) {
+        let snapshot = Arc::new(SnapshotWithDBArc::new(Arc::clone(&self.db)));
+
+        let mut inner = self.inner.write().expect("Poisoned lock");
+        match block_n {
+            Some(block_n) => inner.historical.retain(|n, _| *n <= block_n),
+            None => inner.historical.clear(),
+        }
+        inner.head = snapshot;
+        inner.head_block_n = block_n;
+    }
+
     /// Get the closest snapshot that had been made at or after the provided `block_n`.
     /// Also returns the block_n, which can be null if no block is in database in that snapshot.
     #[tracing::instrument(skip(self), fields(module = "BonsaiDB"))]
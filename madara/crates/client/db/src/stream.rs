@@ -1,5 +1,5 @@
 use crate::{db_block_id::DbBlockId, MadaraBackend, MadaraStorageError};
-use crate::{ClosedBlocksReceiver, Column, DatabaseExt};
+use crate::{ChainRevertReceiver, ClosedBlocksReceiver, Column, DatabaseExt};
 use futures::{stream, Stream};
 use mp_block::MadaraBlockInfo;
 use std::iter;
@@ -219,7 +219,8 @@ impl MadaraBackend {
 
         // TODO: use db iterators to fill a VecDeque buffer (we don't want to hold a db iterator across an await point!)
         //   => reuse block_info_iterator logic
-        // TODO: what should we do about reorgs?! i would assume we go back and rereturn the new blocks..?
+        // When the chain is reverted (see [`MadaraBackend::revert_to`]), forward iteration goes back to the first block
+        //  that was removed and returns the new blocks from there.
 
         struct State {
             iteration: BlockStreamConfig,
@@ -231,6 +232,7 @@ impl MadaraBackend {
             /// block), this field will be 0.
             latest_plus_one: Option<u64>,
             subscription: Option<ClosedBlocksReceiver>,
+            reverts: ChainRevertReceiver,
         }
 
         impl State {
@@ -246,8 +248,30 @@ impl MadaraBackend {
                 Ok(latest_plus_one)
             }
 
+            /// Rewind `next_to_return` if the chain has been reverted below it since the last call.
+            fn handle_revert(&mut self) {
+                if !self.reverts.has_changed().unwrap_or(false) {
+                    return;
+                }
+                let Some(reverted_to) = *self.reverts.borrow_and_update() else { return };
+                let Some(mut next_to_return) = self.next_to_return else { return };
+                if next_to_return <= reverted_to + 1 {
+                    return;
+                }
+
+                // Keep the same step alignment as the iteration start.
+                let step = self.iteration.step.get();
+                while next_to_return.checked_sub(step).is_some_and(|prev| prev > reverted_to) {
+                    next_to_return -= step;
+                }
+                self.next_to_return = Some(next_to_return);
+                self.subscription = None;
+                self.latest_plus_one = None;
+            }
+
             async fn next_forward(&mut self) -> Result<Option<Arc<MadaraBlockInfo>>, MadaraStorageError> {
                 'retry: loop {
+                    self.handle_revert();
                     let Some(next_to_return) = self.next_to_return else { return Ok(None) };
 
                     // If we have a subscription, return blocks from it.
@@ -348,6 +372,7 @@ impl MadaraBackend {
                 latest_plus_one: None,
                 backend: Arc::clone(self),
                 subscription: None,
+                reverts: self.subscribe_reverts(),
             },
             |mut s| async { s.try_next().await.transpose().map(|el| (el, s)) },
         )
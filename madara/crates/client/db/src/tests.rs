@@ -1,3 +1,4 @@
 pub mod common;
 pub mod test_block;
 pub mod test_open;
+pub mod test_revert;
@@ -0,0 +1,156 @@
+#[cfg(test)]
+mod revert_tests {
+    use super::super::common::temp_db::temp_db;
+    use super::super::common::*;
+    use crate::db_block_id::{DbBlockId, RawDbBlockId};
+    use crate::MadaraStorageError;
+    use mp_block::{BlockId, BlockTag, Header};
+    use mp_convert::L1TransactionHash;
+    use mp_state_update::{ContractStorageDiffItem, NonceUpdate, StateDiff, StorageEntry};
+    use mp_transactions::L1HandlerTransaction;
+    use starknet_api::felt;
+
+    fn state_diff(value: u64) -> StateDiff {
+        StateDiff {
+            storage_diffs: vec![ContractStorageDiffItem {
+                address: felt!("0x1"),
+                storage_entries: vec![StorageEntry { key: felt!("0x1"), value: value.into() }],
+            }],
+            nonces: vec![NonceUpdate { contract_address: felt!("0x1"), nonce: value.into() }],
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_revert_to() {
+        let db = temp_db().await;
+        let backend = db.backend();
+
+        backend.store_block(finalized_block_zero(Header::default()), state_diff(1), vec![]).unwrap();
+        backend.store_block(finalized_block_one(), state_diff(2), vec![]).unwrap();
+        // Global tries are not applied in this test.
+        backend.head_status().global_trie.set_current(None);
+
+        let tx_hash = finalized_block_one().info.tx_hashes()[0];
+        assert!(backend.find_tx_hash_block_info(&tx_hash).unwrap().is_some());
+
+        backend.revert_to(0).unwrap();
+
+        assert_eq!(backend.get_latest_block_n().unwrap(), Some(0));
+        assert!(backend.get_block_info(&RawDbBlockId::Number(1)).unwrap().is_none());
+        assert!(backend.get_block_state_diff(&RawDbBlockId::Number(1)).unwrap().is_none());
+        assert!(backend.find_tx_hash_block_info(&tx_hash).unwrap().is_none());
+        assert_eq!(
+            backend.get_contract_storage_at(&BlockId::Tag(BlockTag::Latest), &felt!("0x1"), &felt!("0x1")).unwrap(),
+            Some(felt!("0x1"))
+        );
+        assert_eq!(
+            backend.get_contract_nonce_at(&BlockId::Tag(BlockTag::Latest), &felt!("0x1")).unwrap(),
+            Some(felt!("0x1"))
+        );
+        assert_eq!(
+            backend
+                .get_block_info(&DbBlockId::Pending)
+                .unwrap()
+                .unwrap()
+                .as_pending()
+                .unwrap()
+                .header
+                .parent_block_hash,
+            felt!("0x12345")
+        );
+
+        // The chain can grow again on top of the reverted block.
+        backend.store_block(finalized_block_one(), state_diff(3), vec![]).unwrap();
+        assert_eq!(
+            backend.get_contract_storage_at(&BlockId::Tag(BlockTag::Latest), &felt!("0x1"), &felt!("0x1")).unwrap(),
+            Some(felt!("0x3"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_revert_keeps_l1_handler_txns_by_l1_txn_hash() {
+        let db = temp_db().await;
+        let backend = db.backend();
+
+        let mut block_one = finalized_block_one();
+        block_one.inner.transactions[1] = L1HandlerTransaction { nonce: 3, ..Default::default() }.into();
+        backend.store_block(finalized_block_zero(Header::default()), state_diff(1), vec![]).unwrap();
+        backend.store_block(block_one, state_diff(2), vec![]).unwrap();
+        backend.head_status().global_trie.set_current(None);
+
+        // The l1 handler transaction of block 1 was sent along with another message that is still pending
+        let l1_txn_hash = L1TransactionHash([0xaa; 32]);
+        backend.add_l1_handler_txn_by_l1_txn_hash(&l1_txn_hash, 3, felt!("0x11")).unwrap();
+        backend.add_l1_handler_txn_by_l1_txn_hash(&l1_txn_hash, 4, felt!("0x44")).unwrap();
+        let other_l1_txn_hash = L1TransactionHash([0xbb; 32]);
+        backend.add_l1_handler_txn_by_l1_txn_hash(&other_l1_txn_hash, 5, felt!("0x55")).unwrap();
+
+        backend.revert_to(0).unwrap();
+
+        assert_eq!(backend.get_l1_handler_txn_hash_by_nonce(3).unwrap(), None);
+        assert_eq!(
+            backend.get_l1_handler_txns_by_l1_txn_hash(&l1_txn_hash).unwrap(),
+            vec![(3, felt!("0x11")), (4, felt!("0x44"))]
+        );
+        assert_eq!(backend.get_l1_handler_txns_by_l1_txn_hash(&other_l1_txn_hash).unwrap(), vec![(5, felt!("0x55"))]);
+    }
+
+    #[tokio::test]
+    async fn test_resume_interrupted_revert() {
+        let db = temp_db().await;
+        let backend = db.backend();
+
+        backend.store_block(finalized_block_zero(Header::default()), state_diff(1), vec![]).unwrap();
+        backend.store_block(finalized_block_one(), state_diff(2), vec![]).unwrap();
+        backend.head_status().global_trie.set_current(None);
+
+        // The node stopped right after persisting the revert target.
+        backend.set_revert_target(Some(0)).unwrap();
+        backend.resume_interrupted_revert().unwrap();
+
+        assert_eq!(backend.get_latest_block_n().unwrap(), Some(0));
+        assert!(backend.get_block_info(&RawDbBlockId::Number(1)).unwrap().is_none());
+
+        // The revert is finished, so it is not resumed again.
+        backend.store_block(finalized_block_one(), state_diff(3), vec![]).unwrap();
+        backend.resume_interrupted_revert().unwrap();
+        assert_eq!(backend.get_latest_block_n().unwrap(), Some(1));
+    }
+
+    #[tokio::test]
+    async fn test_revert_to_invalid_target() {
+        let db = temp_db().await;
+        let backend = db.backend();
+
+        let err = backend.revert_to(0).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<MadaraStorageError>(),
+            Some(MadaraStorageError::InvalidRevertTarget { target: 0, latest: None })
+        ));
+
+        backend.store_block(finalized_block_zero(Header::default()), finalized_state_diff_zero(), vec![]).unwrap();
+        let err = backend.revert_to(1).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<MadaraStorageError>(),
+            Some(MadaraStorageError::InvalidRevertTarget { target: 1, latest: Some(0) })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_revert_too_deep() {
+        let db = temp_db().await;
+        let backend = db.backend();
+
+        backend.store_block(finalized_block_zero(Header::default()), finalized_state_diff_zero(), vec![]).unwrap();
+        backend.store_block(finalized_block_one(), finalized_state_diff_one(), vec![]).unwrap();
+
+        // No trie logs are kept by default.
+        let err = backend.revert_to(0).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<MadaraStorageError>(),
+            Some(MadaraStorageError::RevertTooDeep { depth: 1, max_saved_trie_logs: 0 })
+        ));
+        assert_eq!(backend.get_latest_block_n().unwrap(), Some(1));
+    }
+}
@@ -6,6 +6,8 @@ pub type ClosedBlocksReceiver = tokio::sync::broadcast::Receiver<Arc<MadaraBlock
 pub type PendingBlockReceiver = tokio::sync::watch::Receiver<Arc<MadaraPendingBlockInfo>>;
 pub type PendingTxsReceiver = tokio::sync::broadcast::Receiver<mp_block::TransactionWithReceipt>;
 pub type LastBlockOnL1Receiver = tokio::sync::watch::Receiver<Option<u64>>;
+/// Holds the block number the chain was last reverted to, if any. See [`MadaraBackend::revert_to`].
+pub type ChainRevertReceiver = tokio::sync::watch::Receiver<Option<u64>>;
 
 fn make_fake_pending_block(parent_block: Option<&MadaraBlockInfo>) -> Arc<MadaraPendingBlockInfo> {
     let Some(parent_block) = parent_block else {
@@ -30,6 +32,7 @@ pub(crate) struct BlockWatch {
     pending_block: tokio::sync::watch::Sender<Arc<MadaraPendingBlockInfo>>,
     pending_txs: tokio::sync::broadcast::Sender<mp_block::TransactionWithReceipt>,
     last_block_on_l1: tokio::sync::watch::Sender<Option<u64>>,
+    reverted_to: tokio::sync::watch::Sender<Option<u64>>,
 }
 
 impl BlockWatch {
@@ -39,6 +42,7 @@ impl BlockWatch {
             pending_block: tokio::sync::watch::channel(make_fake_pending_block(None)).0,
             pending_txs: tokio::sync::broadcast::channel(100).0,
             last_block_on_l1: tokio::sync::watch::channel(None).0,
+            reverted_to: tokio::sync::watch::channel(None).0,
         }
     }
 
@@ -71,6 +75,11 @@ impl BlockWatch {
         self.update_pending(make_fake_pending_block(Some(&block)));
     }
 
+    pub fn on_revert(&self, parent_block: Option<&MadaraBlockInfo>) {
+        self.reverted_to.send_replace(parent_block.map(|block| block.header.block_number));
+        self.clear_pending(parent_block);
+    }
+
     pub fn subscribe_closed_blocks(&self) -> ClosedBlocksReceiver {
         self.closed_blocks.subscribe()
     }
@@ -83,6 +92,9 @@ impl BlockWatch {
     pub fn subscribe_last_block_on_l1(&self) -> LastBlockOnL1Receiver {
         self.last_block_on_l1.subscribe()
     }
+    pub fn subscribe_reverts(&self) -> ChainRevertReceiver {
+        self.reverted_to.subscribe()
+    }
     pub fn latest_pending_block(&self) -> Arc<MadaraPendingBlockInfo> {
         self.pending_block.borrow().clone()
     }
@@ -110,6 +122,10 @@ impl MadaraBackend {
         self.watch_blocks.subscribe_last_block_on_l1()
     }
     #[tracing::instrument(skip_all, fields(module = "MadaraBackendWatch"))]
+    pub fn subscribe_reverts(&self) -> ChainRevertReceiver {
+        self.watch_blocks.subscribe_reverts()
+    }
+    #[tracing::instrument(skip_all, fields(module = "MadaraBackendWatch"))]
     pub fn latest_pending_block(&self) -> Arc<MadaraPendingBlockInfo> {
         self.watch_blocks.latest_pending_block()
     }
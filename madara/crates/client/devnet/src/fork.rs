@@ -0,0 +1,147 @@
+use std::{future::Future, sync::Arc};
+
+use anyhow::Context;
+use mc_db::fork_db::ForkedStateProvider;
+use mp_class::{
+    CompiledSierra, ContractClass, ConvertedClass, LegacyClassInfo, LegacyConvertedClass, SierraClassInfo,
+    SierraConvertedClass,
+};
+use starknet_core::types::{BlockId, StarknetError};
+use starknet_providers::{jsonrpc::HttpTransport, JsonRpcClient, Provider, ProviderError};
+use starknet_types_core::felt::Felt;
+use url::Url;
+
+/// Reads the state of a remote chain through its JSON-RPC api, at the block a devnet was forked from.
+#[derive(Debug)]
+pub struct JsonRpcForkedStateProvider {
+    client: Arc<JsonRpcClient<HttpTransport>>,
+    fork_block_n: u64,
+    /// Backend state reads are synchronous, so the requests are run on the node runtime and waited on.
+    runtime: tokio::runtime::Handle,
+}
+
+impl JsonRpcForkedStateProvider {
+    /// Forks the chain at `fork_block_n`, or at its latest block when it is not set.
+    pub async fn new(url: Url, fork_block_n: Option<u64>) -> anyhow::Result<Self> {
+        let client = JsonRpcClient::new(HttpTransport::new(url.clone()));
+        let fork_block_n = match fork_block_n {
+            Some(block_n) => block_n,
+            None => client.block_number().await.with_context(|| format!("Getting the latest block number of {url}"))?,
+        };
+
+        Ok(Self { client: Arc::new(client), fork_block_n, runtime: tokio::runtime::Handle::current() })
+    }
+
+    fn block_id(&self) -> BlockId {
+        BlockId::Number(self.fork_block_n)
+    }
+
+    /// Runs a request on the node runtime and blocks until it is done. When called from a runtime worker, the other
+    /// tasks of that worker are moved to another thread while it blocks. This must not be called from a
+    /// current-thread runtime, which cannot give its thread away.
+    fn request<R>(&self, request: impl Future<Output = Result<R, ProviderError>>) -> Result<R, ProviderError> {
+        tokio::task::block_in_place(|| self.runtime.block_on(request))
+    }
+}
+
+/// Contracts that are not deployed on the forked chain have a zero nonce, class hash and storage.
+fn zero_if_contract_not_found(res: Result<Felt, ProviderError>) -> anyhow::Result<Felt> {
+    match res {
+        Err(ProviderError::StarknetError(StarknetError::ContractNotFound)) => Ok(Felt::ZERO),
+        res => Ok(res?),
+    }
+}
+
+impl ForkedStateProvider for JsonRpcForkedStateProvider {
+    fn fork_block_n(&self) -> u64 {
+        self.fork_block_n
+    }
+
+    fn get_storage_at(&self, contract_address: &Felt, key: &Felt) -> anyhow::Result<Felt> {
+        zero_if_contract_not_found(self.request(self.client.get_storage_at(contract_address, key, self.block_id())))
+            .with_context(|| format!("Getting storage of {contract_address:#x} at key {key:#x} on the forked chain"))
+    }
+
+    fn get_nonce_at(&self, contract_address: &Felt) -> anyhow::Result<Felt> {
+        zero_if_contract_not_found(self.request(self.client.get_nonce(self.block_id(), contract_address)))
+            .with_context(|| format!("Getting nonce of {contract_address:#x} on the forked chain"))
+    }
+
+    fn get_class_hash_at(&self, contract_address: &Felt) -> anyhow::Result<Felt> {
+        zero_if_contract_not_found(self.request(self.client.get_class_hash_at(self.block_id(), contract_address)))
+            .with_context(|| format!("Getting class hash of {contract_address:#x} on the forked chain"))
+    }
+
+    fn get_class(&self, class_hash: &Felt) -> anyhow::Result<Option<ConvertedClass>> {
+        let class = match self.request(self.client.get_class(self.block_id(), class_hash)) {
+            Ok(class) => class,
+            Err(ProviderError::StarknetError(StarknetError::ClassHashNotFound)) => return Ok(None),
+            Err(err) => return Err(err).with_context(|| format!("Getting class {class_hash:#x} on the forked chain")),
+        };
+        let class_hash = *class_hash;
+
+        tracing::debug!("Converting class {class_hash:#x} from the forked chain");
+        let converted = match ContractClass::from(class) {
+            ContractClass::Sierra(contract_class) => {
+                let (compiled_class_hash, compiled) = contract_class
+                    .compile_to_casm()
+                    .with_context(|| format!("Compiling class {class_hash:#x} from the forked chain"))?;
+                ConvertedClass::Sierra(SierraConvertedClass {
+                    class_hash,
+                    info: SierraClassInfo { contract_class, compiled_class_hash },
+                    compiled: Arc::new(CompiledSierra::try_from(&compiled)?),
+                })
+            }
+            ContractClass::Legacy(contract_class) => {
+                ConvertedClass::Legacy(LegacyConvertedClass { class_hash, info: LegacyClassInfo { contract_class } })
+            }
+        };
+        Ok(Some(converted))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use httpmock::MockServer;
+    use mc_db::MadaraBackend;
+    use mp_block::{BlockId as DbBlockId, BlockTag};
+    use mp_chain_config::ChainConfig;
+    use serde_json::json;
+
+    fn mock_method(server: &MockServer, method: &str, response: serde_json::Value) {
+        server.mock(|when, then| {
+            when.path("/").body_includes(format!("\"{method}\""));
+            then.status(200).json_body(response);
+        });
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_backend_reads_forked_chain() {
+        let server = MockServer::start();
+        mock_method(&server, "starknet_getStorageAt", json!({ "id": 1, "jsonrpc": "2.0", "result": "0x5" }));
+        mock_method(&server, "starknet_getNonce", json!({ "id": 1, "jsonrpc": "2.0", "result": "0x6" }));
+        mock_method(
+            &server,
+            "starknet_getClassHashAt",
+            json!({ "id": 1, "jsonrpc": "2.0", "error": { "code": 20, "message": "Contract not found" } }),
+        );
+
+        let provider = JsonRpcForkedStateProvider::new(server.base_url().parse().unwrap(), Some(10)).await.unwrap();
+        let backend = MadaraBackend::open_for_testing(Arc::new(ChainConfig::madara_test()));
+        backend.set_forked_state_provider(Arc::new(provider)).unwrap();
+
+        // The reads block a runtime worker, as the rpc handlers do.
+        let pending = DbBlockId::Tag(BlockTag::Pending);
+        assert_eq!(backend.get_contract_storage_at(&pending, &Felt::ONE, &Felt::TWO).unwrap(), Some(Felt::from(5)));
+        assert_eq!(backend.get_contract_nonce_at(&pending, &Felt::ONE).unwrap(), Some(Felt::from(6)));
+        assert_eq!(backend.get_contract_class_hash_at(&pending, &Felt::ONE).unwrap(), None);
+
+        // And from a thread outside of the runtime, as the block production executor does.
+        let storage = std::thread::spawn(move || backend.get_contract_storage_at(&pending, &Felt::TWO, &Felt::TWO))
+            .join()
+            .unwrap()
+            .unwrap();
+        assert_eq!(storage, Some(Felt::from(5)));
+    }
+}
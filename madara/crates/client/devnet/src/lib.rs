@@ -21,14 +21,18 @@ mod balances;
 mod classes;
 mod contracts;
 mod entrypoint;
+mod fork;
 mod predeployed_contracts;
+mod state_dump;
 
 pub use balances::*;
 pub use classes::*;
 pub use contracts::*;
 pub use entrypoint::*;
+pub use fork::*;
 use mp_transactions::compute_hash::calculate_contract_address;
 pub use predeployed_contracts::*;
+pub use state_dump::*;
 
 // 1 ETH = 1e18 WEI
 const ETH_WEI_DECIMALS: u128 = 1_000_000_000_000_000_000;
@@ -176,6 +180,9 @@ impl ChainGenesisDescription {
                         strk_l1_gas_price: 5,
                         eth_l1_data_gas_price: 5,
                         strk_l1_data_gas_price: 5,
+                        // Block production starts from the configured initial L2 gas price.
+                        eth_l2_gas_price: 0,
+                        strk_l2_gas_price: 0,
                     },
                     l1_da_mode: chain_config.l1_da_mode,
                 },
@@ -358,6 +365,7 @@ mod tests {
             strk_l1_gas_price: 128,
             eth_l1_data_gas_price: 128,
             strk_l1_data_gas_price: 128,
+            ..Default::default()
         });
         let l1_data_provider = Arc::new(l1_data_provider) as Arc<dyn L1DataProvider>;
         let mempool = Arc::new(Mempool::new(Arc::clone(&backend), MempoolConfig::default()));
@@ -760,4 +768,109 @@ mod tests {
             }
         }
     }
+
+    #[tokio::test]
+    async fn test_dump_and_load_state_with_transactions() {
+        let mut chain = test_chain().await;
+        let contract_0 = &chain.contracts.0[0];
+        let contract_1 = &chain.contracts.0[1];
+
+        // A block with a declared class, and a transfer emitting events and changing balances.
+        let sierra_class: SierraClass = serde_json::from_slice(m_cairo_test_contracts::TEST_CONTRACT_SIERRA).unwrap();
+        let flattened_class: FlattenedSierraClass = sierra_class.clone().flatten().unwrap().into();
+        let (compiled_class_hash, _compiled_class) = flattened_class.compile_to_casm().unwrap();
+        let declared = chain
+            .sign_and_add_declare_tx(
+                BroadcastedDeclareTxn::V3(BroadcastedDeclareTxnV3 {
+                    sender_address: contract_0.address,
+                    compiled_class_hash,
+                    signature: vec![].into(),
+                    nonce: Felt::ZERO,
+                    contract_class: flattened_class.into(),
+                    resource_bounds: ResourceBoundsMapping {
+                        l1_gas: ResourceBounds { max_amount: 220000, max_price_per_unit: 10000 },
+                        l2_gas: ResourceBounds { max_amount: 60000, max_price_per_unit: 10000 },
+                    },
+                    tip: 0,
+                    paymaster_data: vec![],
+                    account_deployment_data: vec![],
+                    nonce_data_availability_mode: DaMode::L1,
+                    fee_data_availability_mode: DaMode::L1,
+                }),
+                contract_0,
+            )
+            .await
+            .unwrap();
+        chain
+            .sign_and_add_invoke_tx(
+                BroadcastedInvokeTxn::V3(InvokeTxnV3 {
+                    sender_address: contract_1.address,
+                    calldata: Multicall::default()
+                        .with(Call {
+                            to: ERC20_STRK_CONTRACT_ADDRESS,
+                            selector: Selector::from("transfer"),
+                            calldata: vec![contract_0.address, 1234.into(), Felt::ZERO],
+                        })
+                        .flatten()
+                        .collect::<Vec<_>>()
+                        .into(),
+                    signature: vec![].into(),
+                    nonce: Felt::ZERO,
+                    resource_bounds: ResourceBoundsMapping {
+                        l1_gas: ResourceBounds { max_amount: 60000, max_price_per_unit: 10000 },
+                        l2_gas: ResourceBounds { max_amount: 60000, max_price_per_unit: 10000 },
+                    },
+                    tip: 0,
+                    paymaster_data: vec![],
+                    account_deployment_data: vec![],
+                    nonce_data_availability_mode: DaMode::L1,
+                    fee_data_availability_mode: DaMode::L1,
+                }),
+                contract_1,
+            )
+            .await
+            .unwrap();
+
+        let mut block_production = chain.block_production.take().unwrap();
+        let handle = block_production.handle();
+        let mut notifications = block_production.subscribe_state_notifications();
+        let _task =
+            AbortOnDrop::spawn(async move { block_production.run(ServiceContext::new_for_testing()).await.unwrap() });
+        for _ in 0..10 {
+            assert_eq!(notifications.recv().await.unwrap(), BlockProductionStateNotification::UpdatedPendingBlock);
+            if chain.backend.get_block_info(&BlockId::Tag(BlockTag::Pending)).unwrap().unwrap().tx_hashes().len() == 2 {
+                break;
+            }
+        }
+        handle.close_block().await.unwrap();
+        while notifications.recv().await.unwrap() != BlockProductionStateNotification::ClosedBlock {}
+        assert_eq!(chain.backend.get_latest_block_n().unwrap(), Some(1));
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("dump.json");
+        DevnetStateDump::from_backend(&chain.backend).unwrap().write_to_file(&path).unwrap();
+
+        let loaded = MadaraBackend::open_for_testing(chain.backend.chain_config().clone());
+        DevnetStateDump::read_from_file(&path).unwrap().load_into(&loaded).await.unwrap();
+
+        let latest = BlockId::Tag(BlockTag::Latest);
+        assert_eq!(loaded.get_latest_block_n().unwrap(), Some(1));
+        assert_eq!(loaded.get_block_hash(&latest).unwrap(), chain.backend.get_block_hash(&latest).unwrap());
+        let block = loaded.get_block(&latest).unwrap().unwrap();
+        let expected = chain.backend.get_block(&latest).unwrap().unwrap();
+        assert_eq!(block.inner.transactions.len(), 2);
+        assert_eq!(block.inner, expected.inner);
+        assert_eq!(loaded.get_block_state_diff(&latest).unwrap(), chain.backend.get_block_state_diff(&latest).unwrap());
+        assert_matches!(
+            loaded.get_class_info(&latest, &declared.class_hash).unwrap(),
+            Some(ClassInfo::Sierra(info)) if info.compiled_class_hash == compiled_class_hash
+        );
+        for contract in [contract_0, contract_1] {
+            assert_eq!(
+                get_fee_tokens_balance(&loaded, contract.address).unwrap().as_u128_fri_wei().unwrap(),
+                chain.get_bal_strk_eth(contract.address)
+            );
+            assert_eq!(loaded.get_contract_nonce_at(&latest, &contract.address).unwrap(), Some(Felt::ONE));
+        }
+    }
 }
@@ -0,0 +1,235 @@
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter},
+    path::Path,
+};
+
+use anyhow::Context;
+use mc_db::{devnet_db::DevnetPredeployedKeys, MadaraBackend};
+use mp_block::{header::PendingHeader, BlockId, PendingFullBlock, TransactionWithReceipt};
+use mp_class::ConvertedClass;
+use mp_convert::ToFelt;
+use mp_receipt::{EventWithTransactionHash, TransactionReceipt};
+use mp_state_update::{DeclaredClassItem, StateDiff};
+use mp_transactions::Transaction;
+use serde::{Deserialize, Serialize};
+use starknet_types_core::felt::Felt;
+
+/// The closed blocks of a devnet chain, with everything needed to replay them into another database. The pending
+/// block is not part of the dump.
+#[derive(Serialize, Deserialize)]
+pub struct DevnetStateDump {
+    pub chain_id: Felt,
+    pub blocks: Vec<DumpedBlock>,
+    pub predeployed_keys: Option<DevnetPredeployedKeys>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct DumpedBlock {
+    /// Used to check that the block replays to the same hash.
+    pub block_hash: Felt,
+    pub header: PendingHeader,
+    pub state_diff: StateDiff,
+    pub transactions: Vec<Transaction>,
+    pub receipts: Vec<TransactionReceipt>,
+    pub classes: Vec<ConvertedClass>,
+}
+
+impl DevnetStateDump {
+    #[tracing::instrument(skip(backend), fields(module = "DevnetStateDump"))]
+    pub fn from_backend(backend: &MadaraBackend) -> anyhow::Result<Self> {
+        let latest_block_n = backend.get_latest_block_n().context("Getting latest block number")?;
+        let blocks = latest_block_n
+            .map(|latest| (0..=latest).map(|block_n| dump_block(backend, block_n)).collect::<anyhow::Result<_>>())
+            .transpose()?
+            .unwrap_or_default();
+
+        Ok(Self {
+            chain_id: backend.chain_config().chain_id.to_felt(),
+            blocks,
+            predeployed_keys: backend.get_devnet_predeployed_keys().context("Getting devnet predeployed keys")?,
+        })
+    }
+
+    pub fn read_from_file(path: &Path) -> anyhow::Result<Self> {
+        let file = File::open(path).with_context(|| format!("Opening devnet state dump {path:?}"))?;
+        serde_json::from_reader(BufReader::new(file)).with_context(|| format!("Reading devnet state dump {path:?}"))
+    }
+
+    pub fn write_to_file(&self, path: &Path) -> anyhow::Result<()> {
+        let file = File::create(path).with_context(|| format!("Creating devnet state dump {path:?}"))?;
+        serde_json::to_writer(BufWriter::new(file), self).with_context(|| format!("Writing devnet state dump {path:?}"))
+    }
+
+    /// Replays the dumped chain into the database. Blocks the database already has are kept, and the database is
+    /// reverted to the last block it has in common with the dump before replaying the rest. Block production must not
+    /// be running while the dump is loaded.
+    #[tracing::instrument(skip(self, backend), fields(module = "DevnetStateDump"))]
+    pub async fn load_into(self, backend: &MadaraBackend) -> anyhow::Result<()> {
+        let chain_id = backend.chain_config().chain_id.to_felt();
+        if self.chain_id != chain_id {
+            anyhow::bail!(
+                "The state dump is for chain id {:#x}, but the node is running chain id {chain_id:#x}",
+                self.chain_id
+            );
+        }
+
+        let first_new_block = self.first_block_not_in_db(backend)?;
+        let latest_block_n = backend.get_latest_block_n().context("Getting latest block number")?;
+        if latest_block_n.is_some_and(|latest| latest + 1 > first_new_block) {
+            let Some(new_tip) = first_new_block.checked_sub(1) else {
+                anyhow::bail!("The state dump was made from a chain with a different genesis block");
+            };
+            backend.revert_to(new_tip).context("Reverting to the last block in common with the state dump")?;
+        }
+        backend.clear_pending_block().context("Clearing pending block")?;
+
+        tracing::info!("📥 Loading {} blocks from devnet state dump", self.blocks.len() as u64 - first_new_block);
+        for (block_n, block) in self.blocks.into_iter().enumerate().skip(first_new_block as usize) {
+            let block_n = block_n as u64;
+            let events = block
+                .receipts
+                .iter()
+                .flat_map(|receipt| {
+                    receipt.events().iter().map(|event| EventWithTransactionHash {
+                        transaction_hash: receipt.transaction_hash(),
+                        event: event.clone(),
+                    })
+                })
+                .collect();
+            let pending = PendingFullBlock {
+                header: block.header,
+                state_diff: block.state_diff,
+                transactions: block
+                    .transactions
+                    .into_iter()
+                    .zip(block.receipts)
+                    .map(|(transaction, receipt)| TransactionWithReceipt { transaction, receipt })
+                    .collect(),
+                events,
+            };
+            let block_hash = backend
+                .add_full_block_with_classes(
+                    pending,
+                    block_n,
+                    &block.classes,
+                    /* pre_v0_13_2_hash_override */ true,
+                )
+                .await
+                .with_context(|| format!("Importing block #{block_n} from state dump"))?;
+            if block_hash != block.block_hash {
+                anyhow::bail!(
+                    "Block #{block_n} replayed to hash {block_hash:#x} instead of {:#x}, the node chain config does \
+                     not match the dumped chain",
+                    block.block_hash
+                );
+            }
+        }
+
+        if let Some(keys) = self.predeployed_keys {
+            backend.set_devnet_predeployed_keys(keys).context("Saving devnet predeployed keys")?;
+        }
+        Ok(())
+    }
+
+    fn first_block_not_in_db(&self, backend: &MadaraBackend) -> anyhow::Result<u64> {
+        for (block_n, block) in self.blocks.iter().enumerate() {
+            let hash = backend.get_block_hash(&BlockId::Number(block_n as u64)).context("Getting block hash")?;
+            if hash != Some(block.block_hash) {
+                return Ok(block_n as u64);
+            }
+        }
+        Ok(self.blocks.len() as u64)
+    }
+}
+
+fn dump_block(backend: &MadaraBackend, block_n: u64) -> anyhow::Result<DumpedBlock> {
+    let block_id = BlockId::Number(block_n);
+    let block = backend
+        .get_block(&block_id)
+        .context("Getting block")?
+        .and_then(|block| block.into_closed())
+        .with_context(|| format!("Block #{block_n} not found"))?;
+    let state_diff = backend
+        .get_block_state_diff(&block_id)
+        .context("Getting block state diff")?
+        .with_context(|| format!("State diff of block #{block_n} not found"))?;
+
+    let classes = state_diff
+        .deprecated_declared_classes
+        .iter()
+        .chain(state_diff.declared_classes.iter().map(|DeclaredClassItem { class_hash, .. }| class_hash))
+        .map(|class_hash| {
+            backend
+                .get_converted_class(&block_id, class_hash)
+                .with_context(|| format!("Retrieving declared class with hash {class_hash:#x}"))?
+                .with_context(|| format!("Declared class with hash {class_hash:#x} not found"))
+        })
+        .collect::<anyhow::Result<_>>()?;
+
+    let header = block.info.header;
+    Ok(DumpedBlock {
+        block_hash: block.info.block_hash,
+        header: PendingHeader {
+            parent_block_hash: header.parent_block_hash,
+            sequencer_address: header.sequencer_address,
+            block_timestamp: header.block_timestamp,
+            protocol_version: header.protocol_version,
+            l1_gas_price: header.l1_gas_price,
+            l1_da_mode: header.l1_da_mode,
+        },
+        state_diff,
+        transactions: block.inner.transactions,
+        receipts: block.inner.receipts,
+        classes,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ChainGenesisDescription;
+    use mp_block::BlockTag;
+    use mp_chain_config::ChainConfig;
+    use std::sync::Arc;
+
+    async fn devnet_backend(n_contracts: u64) -> Arc<MadaraBackend> {
+        let backend = MadaraBackend::open_for_testing(Arc::new(ChainConfig::madara_devnet()));
+        let mut genesis = ChainGenesisDescription::base_config().unwrap();
+        let contracts = genesis.add_devnet_contracts(n_contracts).unwrap();
+        contracts.save_to_db(&backend).unwrap();
+        genesis.build_and_store(&backend).await.unwrap();
+        backend
+    }
+
+    #[tokio::test]
+    async fn test_dump_and_load_state() {
+        let backend = devnet_backend(5).await;
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("dump.json");
+        DevnetStateDump::from_backend(&backend).unwrap().write_to_file(&path).unwrap();
+
+        let loaded = MadaraBackend::open_for_testing(Arc::new(ChainConfig::madara_devnet()));
+        DevnetStateDump::read_from_file(&path).unwrap().load_into(&loaded).await.unwrap();
+
+        let latest = BlockId::Tag(BlockTag::Latest);
+        assert_eq!(loaded.get_latest_block_n().unwrap(), Some(0));
+        assert_eq!(loaded.get_block_hash(&latest).unwrap(), backend.get_block_hash(&latest).unwrap());
+        assert_eq!(loaded.get_block_state_diff(&latest).unwrap(), backend.get_block_state_diff(&latest).unwrap());
+        assert_eq!(
+            loaded.get_devnet_predeployed_keys().unwrap().unwrap().0.len(),
+            backend.get_devnet_predeployed_keys().unwrap().unwrap().0.len(),
+        );
+
+        // Loading the dump again keeps the blocks in common.
+        DevnetStateDump::read_from_file(&path).unwrap().load_into(&loaded).await.unwrap();
+        assert_eq!(loaded.get_latest_block_n().unwrap(), Some(0));
+    }
+
+    #[tokio::test]
+    async fn test_load_state_different_genesis() {
+        let dump = DevnetStateDump::from_backend(&devnet_backend(5).await).unwrap();
+        let other = devnet_backend(2).await;
+        assert!(dump.load_into(&other).await.is_err());
+    }
+}
@@ -15,6 +15,9 @@ use mp_convert::ToFelt;
 ///
 /// There is no actual mutable logic here - when using block production, the actual key value
 /// changes in db are evaluated at the end only from the produced state diff.
+///
+/// On a forked devnet, the backend reads the values missing from the local db from the forked chain, see
+/// [`mc_db::fork_db`].
 pub struct BlockifierStateAdapter {
     backend: Arc<MadaraBackend>,
     /// When this value is None, we are executing the genesis block.
@@ -137,6 +140,14 @@ impl StateReader for BlockifierStateAdapter {
             class_hash.to_felt()
         );
 
+        #[cfg(feature = "cairo_native")]
+        if let mp_class::ConvertedClass::Sierra(sierra) = &converted_class {
+            // Falls back to the VM while the class is compiling.
+            if let Some(native) = crate::native::native_class_cache().and_then(|cache| cache.get(sierra)) {
+                return Ok(native);
+            }
+        }
+
         (&converted_class).try_into().map_err(|err| {
             tracing::error!("Failed to convert class {class_hash:#} to blockifier format: {err:#}");
             StateError::StateReadError(format!("Failed to convert class {class_hash:#}"))
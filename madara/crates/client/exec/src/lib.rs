@@ -16,6 +16,8 @@ mod call;
 pub mod execution;
 mod fee;
 mod layered_state_adapter;
+#[cfg(feature = "cairo_native")]
+pub mod native;
 pub mod state_diff;
 mod trace;
 pub mod transaction;
@@ -25,6 +27,15 @@ pub use blockifier_state_adapter::BlockifierStateAdapter;
 pub use layered_state_adapter::LayeredStateAdapter;
 pub use trace::execution_result_to_tx_trace;
 
+/// Starts compiling a newly declared class to Cairo Native in the background, so that it runs natively by the time it
+/// is called. Does nothing unless Cairo Native execution is enabled.
+pub fn on_class_declared(class: &mp_class::ConvertedClass) {
+    #[cfg(feature = "cairo_native")]
+    native::compile_declared_class(class);
+    #[cfg(not(feature = "cairo_native"))]
+    let _ = class;
+}
+
 #[derive(Debug)]
 struct OnTopOf(Option<DbBlockId>);
 impl fmt::Display for OnTopOf {
@@ -0,0 +1,250 @@
+//! Cairo Native execution of Sierra classes.
+//!
+//! Sierra classes are compiled ahead of time to shared libraries, which are kept on disk under
+//! [`NativeConfig::cache_dir`] so that they survive restarts. Compilation is slow, so it never happens on the
+//! execution path: a class is compiled in the background when it is declared or first executed, and is executed in
+//! the Cairo VM until its native artifact is ready.
+//!
+//! The artifacts are only valid for the Cairo Native version which built them, so they are kept in a directory named
+//! after it. A node owns its cache directory: it must not be shared with another running node.
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock, RwLock};
+
+use blockifier::execution::contract_class::RunnableCompiledClass;
+use blockifier::execution::native::contract_class::NativeCompiledClassV1;
+use cairo_native::executor::AotContractExecutor;
+use mp_class::{ConvertedClass, SierraConvertedClass};
+use starknet_types_core::felt::Felt;
+
+static NATIVE_CLASS_CACHE: OnceLock<Arc<NativeClassCache>> = OnceLock::new();
+
+/// Version of the `cairo-native` compiler, which must be kept in sync with the workspace manifest.
+const NATIVE_COMPILER_VERSION: &str = "0.5.0-rc.5";
+
+#[derive(Debug, Clone)]
+pub struct NativeConfig {
+    /// Directory holding the compiled native artifacts, one per class hash.
+    pub cache_dir: PathBuf,
+    /// Maximum number of classes being compiled at the same time. Classes executed while this limit is reached are
+    /// executed in the VM, and are compiled on a later execution.
+    pub max_concurrent_compilations: usize,
+}
+
+/// Enables Cairo Native execution for the whole process. Every executor created afterwards runs Sierra classes
+/// natively once they are compiled.
+pub fn init_native_execution(config: NativeConfig) -> anyhow::Result<()> {
+    let cache = NativeClassCache::open(config)?;
+    tracing::info!("🚀 Cairo Native execution enabled, with class cache at {:?}", cache.artifacts_dir);
+    NATIVE_CLASS_CACHE
+        .set(Arc::new(cache))
+        .map_err(|_| anyhow::anyhow!("Cairo Native execution is already initialized"))
+}
+
+/// The process-wide class cache, if Cairo Native execution is enabled.
+pub fn native_class_cache() -> Option<&'static Arc<NativeClassCache>> {
+    NATIVE_CLASS_CACHE.get()
+}
+
+/// Starts compiling a newly declared class in the background, if Cairo Native execution is enabled.
+pub fn compile_declared_class(class: &ConvertedClass) {
+    if let (Some(cache), ConvertedClass::Sierra(class)) = (native_class_cache(), class) {
+        cache.compile_in_background(class);
+    }
+}
+
+pub struct NativeClassCache {
+    config: NativeConfig,
+    artifacts_dir: PathBuf,
+    classes: RwLock<HashMap<Felt, NativeCompiledClassV1>>,
+    compiling: Mutex<HashSet<Felt>>,
+}
+
+impl NativeClassCache {
+    /// Opens the artifacts directory of the current compiler version, creating it if needed. The lock files left by
+    /// compilations which were interrupted are removed: the compiler refuses to build an artifact while its lock file
+    /// exists, so the class would never be compiled again.
+    pub fn open(config: NativeConfig) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            config.max_concurrent_compilations > 0,
+            "The maximum number of native compilations must be at least 1"
+        );
+
+        let artifacts_dir = config.cache_dir.join(format!("cairo-native-{NATIVE_COMPILER_VERSION}"));
+        std::fs::create_dir_all(&artifacts_dir)
+            .map_err(|err| anyhow::anyhow!("Creating native class cache directory {artifacts_dir:?}: {err:#}"))?;
+        remove_stale_locks(&artifacts_dir)
+            .map_err(|err| anyhow::anyhow!("Removing stale lock files from {artifacts_dir:?}: {err:#}"))?;
+
+        Ok(Self { config, artifacts_dir, classes: Default::default(), compiling: Default::default() })
+    }
+
+    fn artifact_path(&self, class_hash: &Felt) -> PathBuf {
+        self.artifacts_dir.join(format!("{class_hash:#x}.so"))
+    }
+
+    /// Returns the native class, or `None` if it is not compiled yet. In that case, the class is compiled in the
+    /// background and the caller should execute it in the VM.
+    pub fn get(self: &Arc<Self>, class: &SierraConvertedClass) -> Option<RunnableCompiledClass> {
+        if let Some(native) = self.classes.read().expect("Poisoned lock").get(&class.class_hash) {
+            return Some(RunnableCompiledClass::V1Native(native.clone()));
+        }
+
+        let path = self.artifact_path(&class.class_hash);
+        if path.exists() && !self.compiling.lock().expect("Poisoned lock").contains(&class.class_hash) {
+            match AotContractExecutor::from_path(&path) {
+                Ok(Some(executor)) => return self.insert(class, executor).map(RunnableCompiledClass::V1Native),
+                // Still being written by another process.
+                Ok(None) => return None,
+                Err(err) => {
+                    tracing::warn!(
+                        "Failed to load native class {:#x} from {path:?}, compiling it again: {err:#}",
+                        class.class_hash
+                    );
+                }
+            }
+        }
+
+        self.compile_in_background(class);
+        None
+    }
+
+    /// Compiles the class to a native artifact on a separate thread, unless it is already compiled or compiling.
+    pub fn compile_in_background(self: &Arc<Self>, class: &SierraConvertedClass) {
+        if self.classes.read().expect("Poisoned lock").contains_key(&class.class_hash) {
+            return;
+        }
+        {
+            let mut compiling = self.compiling.lock().expect("Poisoned lock");
+            if compiling.len() >= self.config.max_concurrent_compilations || !compiling.insert(class.class_hash) {
+                return;
+            }
+        }
+
+        let cache = Arc::clone(self);
+        let class = class.clone();
+        std::thread::spawn(move || {
+            let path = cache.artifact_path(&class.class_hash);
+            let started = std::time::Instant::now();
+            match class.info.contract_class.compile_to_native_into(&path) {
+                Ok(executor) => {
+                    tracing::debug!("Compiled class {:#x} to native in {:?}", class.class_hash, started.elapsed());
+                    cache.insert(&class, executor);
+                }
+                Err(err) => tracing::warn!("Failed to compile class {:#x} to native: {err:#}", class.class_hash),
+            }
+            cache.compiling.lock().expect("Poisoned lock").remove(&class.class_hash);
+        });
+    }
+
+    fn insert(&self, class: &SierraConvertedClass, executor: AotContractExecutor) -> Option<NativeCompiledClassV1> {
+        // Native classes keep the casm class around, to compute gas costs and to fall back to it for some syscalls.
+        let casm = match RunnableCompiledClass::try_from(&ConvertedClass::Sierra(class.clone())) {
+            Ok(RunnableCompiledClass::V1(casm)) => casm,
+            Ok(_) => return None,
+            Err(err) => {
+                tracing::warn!("Failed to convert class {:#x} to blockifier format: {err:#}", class.class_hash);
+                return None;
+            }
+        };
+        let native = NativeCompiledClassV1::new(executor, casm);
+        self.classes.write().expect("Poisoned lock").insert(class.class_hash, native.clone());
+        Some(native)
+    }
+}
+
+fn remove_stale_locks(dir: &Path) -> std::io::Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|ext| ext == "lock") {
+            tracing::debug!("Removing stale native compilation lock {path:?}");
+            std::fs::remove_file(&path)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mp_class::{FlattenedSierraClass, SierraClassInfo};
+    use std::time::Duration;
+
+    fn test_class() -> SierraConvertedClass {
+        let sierra_class: starknet_core::types::contract::SierraClass =
+            serde_json::from_slice(m_cairo_test_contracts::TEST_CONTRACT_SIERRA).unwrap();
+        let contract_class: FlattenedSierraClass = sierra_class.flatten().unwrap().into();
+        let (compiled_class_hash, compiled) = contract_class.compile_to_casm().unwrap();
+        SierraConvertedClass {
+            class_hash: contract_class.compute_class_hash().unwrap(),
+            info: SierraClassInfo { contract_class, compiled_class_hash },
+            compiled: Arc::new((&compiled).try_into().unwrap()),
+        }
+    }
+
+    fn wait_for_native(cache: &Arc<NativeClassCache>, class: &SierraConvertedClass) -> RunnableCompiledClass {
+        for _ in 0..600 {
+            if let Some(native) = cache.get(class) {
+                return native;
+            }
+            std::thread::sleep(Duration::from_millis(100));
+        }
+        panic!("Class was not compiled to native in time");
+    }
+
+    #[test]
+    fn test_native_class_cache() {
+        let cache_dir = tempfile::tempdir().unwrap();
+        let config = NativeConfig { cache_dir: cache_dir.path().into(), max_concurrent_compilations: 1 };
+        let class = test_class();
+
+        // The first execution falls back to the VM while the class compiles.
+        let cache = Arc::new(NativeClassCache::open(config.clone()).unwrap());
+        assert!(cache.get(&class).is_none());
+        assert!(matches!(wait_for_native(&cache, &class), RunnableCompiledClass::V1Native(_)));
+        assert!(cache.artifact_path(&class.class_hash).exists());
+
+        // After a restart, the class is loaded from the disk cache without compiling it again.
+        let cache = Arc::new(NativeClassCache::open(config).unwrap());
+        assert!(matches!(cache.get(&class), Some(RunnableCompiledClass::V1Native(_))));
+        assert!(cache.compiling.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_native_class_cache_removes_stale_locks() {
+        let cache_dir = tempfile::tempdir().unwrap();
+        let config = NativeConfig { cache_dir: cache_dir.path().into(), max_concurrent_compilations: 1 };
+        let class = test_class();
+
+        // A node stopped while the class was compiling.
+        let cache = NativeClassCache::open(config.clone()).unwrap();
+        let lock_path = cache.artifact_path(&class.class_hash).with_extension("lock");
+        std::fs::write(&lock_path, b"").unwrap();
+
+        let cache = Arc::new(NativeClassCache::open(config).unwrap());
+        assert!(!lock_path.exists());
+        assert!(matches!(wait_for_native(&cache, &class), RunnableCompiledClass::V1Native(_)));
+    }
+
+    #[test]
+    fn test_native_class_cache_is_versioned() {
+        let cache_dir = tempfile::tempdir().unwrap();
+        let config = NativeConfig { cache_dir: cache_dir.path().into(), max_concurrent_compilations: 1 };
+        let cache = NativeClassCache::open(config).unwrap();
+        assert_eq!(cache.artifacts_dir, cache_dir.path().join(format!("cairo-native-{NATIVE_COMPILER_VERSION}")));
+
+        let manifest = include_str!("../../../../Cargo.toml");
+        assert!(
+            manifest.lines().any(|line| line.trim() == format!("cairo-native = \"{NATIVE_COMPILER_VERSION}\"")),
+            "NATIVE_COMPILER_VERSION does not match the cairo-native version of the workspace manifest"
+        );
+    }
+
+    #[test]
+    fn test_native_class_cache_rejects_no_compilations() {
+        let cache_dir = tempfile::tempdir().unwrap();
+        let config = NativeConfig { cache_dir: cache_dir.path().into(), max_concurrent_compilations: 0 };
+        assert!(NativeClassCache::open(config).is_err());
+    }
+}
@@ -195,6 +195,24 @@ impl From<StarknetRpcApiError> for GatewayError {
                 StarknetErrorCode::TransactionFailed,
                 format!("An unexpected error occurred: {}", error),
             )),
+            StarknetRpcApiError::ContractNotFound { error } => GatewayError::StarknetError(StarknetError::new(
+                StarknetErrorCode::UninitializedContract,
+                err_message(error, "Requested contract is not deployed"),
+            )),
+            StarknetRpcApiError::ClassHashNotFound { error } => GatewayError::StarknetError(StarknetError::new(
+                StarknetErrorCode::UndeclaredClass,
+                err_message(error, "Class is not declared"),
+            )),
+            StarknetRpcApiError::ContractError => GatewayError::StarknetError(StarknetError::new(
+                StarknetErrorCode::TransactionFailed,
+                "Execution of the contract call failed".into(),
+            )),
+            StarknetRpcApiError::TxnExecutionError { tx_index, error } => {
+                GatewayError::StarknetError(StarknetError::new(
+                    StarknetErrorCode::TransactionFailed,
+                    format!("Transaction {tx_index} failed: {error}"),
+                ))
+            }
             e => GatewayError::InternalServerError(format!("Unexpected error: {:#?}", e)),
         }
     }
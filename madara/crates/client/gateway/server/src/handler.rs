@@ -2,7 +2,7 @@ use super::{
     error::{GatewayError, OptionExt, ResultExt},
     helpers::{
         block_id_from_params, create_json_response, create_response_with_json_body, create_string_response,
-        get_params_from_request, include_block_params,
+        felt_from_params, get_params_from_request, include_block_params, skip_validate_params,
     },
 };
 use crate::helpers::not_found_response;
@@ -12,28 +12,47 @@ use http_body_util::BodyExt;
 use hyper::{body::Incoming, Request, Response, StatusCode};
 use mc_db::MadaraBackend;
 use mc_rpc::{
-    versions::user::v0_7_1::methods::trace::trace_block_transactions::trace_block_transactions as v0_7_1_trace_block_transactions,
+    versions::user::v0_7_1::methods::{
+        read::{call::call as v0_7_1_call, estimate_fee::estimate_fee as v0_7_1_estimate_fee},
+        trace::{
+            simulate_transactions::simulate_transactions as v0_7_1_simulate_transactions,
+            trace_block_transactions::trace_block_transactions as v0_7_1_trace_block_transactions,
+        },
+    },
     Starknet,
 };
 use mc_submit_tx::{SubmitTransaction, SubmitValidatedTransaction};
-use mp_block::{BlockId, BlockTag, MadaraBlock, MadaraMaybePendingBlockInfo, MadaraPendingBlock};
+use mp_block::{
+    BlockId, BlockTag, MadaraBlock, MadaraMaybePendingBlock, MadaraMaybePendingBlockInfo, MadaraPendingBlock,
+};
 use mp_class::{ClassInfo, ContractClass};
 use mp_gateway::user_transaction::{
     AddTransactionResult, UserDeclareTransaction, UserDeployAccountTransaction, UserInvokeFunctionTransaction,
-    UserTransaction,
+    UserTransaction, UserTransactionConversionError,
 };
 use mp_gateway::{
     block::{BlockStatus, ProviderBlock, ProviderBlockPending, ProviderBlockSignature},
+    fee::ProviderFeeEstimate,
+    receipt::{ConfirmedReceipt, ExecutionStatus},
     state_update::{ProviderStateUpdate, ProviderStateUpdatePending},
+    transaction::Transaction,
+    transaction_status::{
+        ProviderTransaction, ProviderTransactionReceipt, ProviderTransactionStatus, TransactionFinalityStatus,
+        TransactionStatus,
+    },
 };
 use mp_gateway::{
     error::{StarknetError, StarknetErrorCode},
     user_transaction::{AddDeclareTransactionResult, AddDeployAccountTransactionResult, AddInvokeTransactionResult},
 };
-use mp_rpc::{BroadcastedDeclareTxn, TraceBlockTransactionsResult};
+use mp_receipt::{ExecutionResult, MsgToL2};
+use mp_rpc::{
+    BroadcastedDeclareTxn, BroadcastedTxn, FunctionCall, SimulationFlag, SimulationFlagForEstimateFee,
+    TraceBlockTransactionsResult, TransactionTrace,
+};
 use mp_transactions::validated::ValidatedMempoolTx;
 use mp_utils::service::ServiceContext;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use serde_json::json;
 use starknet_types_core::felt::Felt;
 use std::sync::Arc;
@@ -270,20 +289,7 @@ pub async fn handle_get_class_by_hash(
         .or_internal_server_error(format!("Retrieving class info from class hash {class_hash:x}"))?
         .ok_or(StarknetError::class_not_found(class_hash))?;
 
-    let json_response = match class_info.contract_class() {
-        ContractClass::Sierra(flattened_sierra_class) => {
-            create_json_response(hyper::StatusCode::OK, flattened_sierra_class.as_ref())
-        }
-        ContractClass::Legacy(compressed_legacy_contract_class) => {
-            let class = compressed_legacy_contract_class
-                .as_ref()
-                .serialize_to_json()
-                .or_internal_server_error("Failed to serialize legacy class")?;
-            create_response_with_json_body(hyper::StatusCode::OK, class)
-        }
-    };
-
-    Ok(json_response)
+    class_json_response(&class_info)
 }
 
 pub async fn handle_get_compiled_class_by_class_hash(
@@ -332,6 +338,452 @@ pub async fn handle_get_public_key(backend: Arc<MadaraBackend>) -> Result<Respon
     Ok(create_string_response(hyper::StatusCode::OK, format!("\"{:#x}\"", public_key)))
 }
 
+pub async fn handle_get_transaction(
+    req: Request<Incoming>,
+    backend: Arc<MadaraBackend>,
+    add_transaction_provider: Arc<dyn SubmitTransaction>,
+) -> Result<Response<String>, GatewayError> {
+    let params = get_params_from_request(&req);
+    let transaction_hash = felt_from_params(&params, "transactionHash")?;
+
+    let Some(location) = find_transaction(&backend, &transaction_hash)? else {
+        let mut transaction = ProviderTransaction::not_received();
+        if add_transaction_provider.received_transaction(transaction_hash).await.unwrap_or(false) {
+            transaction.status = TransactionStatus::Received;
+            transaction.finality_status = TransactionFinalityStatus::Received;
+        }
+        return Ok(create_json_response(hyper::StatusCode::OK, &transaction));
+    };
+
+    let (execution_status, revert_error) = location.execution_status();
+    let transaction = ProviderTransaction {
+        status: TransactionStatus::new(&location.finality_status, Some(&execution_status)),
+        finality_status: location.finality_status.clone(),
+        execution_status: Some(execution_status),
+        block_hash: location.block_hash(),
+        block_number: location.block_number(),
+        transaction_index: Some(location.tx_index),
+        transaction: Some(location.transaction()?),
+        revert_error,
+    };
+    Ok(create_json_response(hyper::StatusCode::OK, &transaction))
+}
+
+pub async fn handle_get_transaction_receipt(
+    req: Request<Incoming>,
+    backend: Arc<MadaraBackend>,
+    add_transaction_provider: Arc<dyn SubmitTransaction>,
+) -> Result<Response<String>, GatewayError> {
+    let params = get_params_from_request(&req);
+    let transaction_hash = felt_from_params(&params, "transactionHash")?;
+
+    let Some(location) = find_transaction(&backend, &transaction_hash)? else {
+        let status = if add_transaction_provider.received_transaction(transaction_hash).await.unwrap_or(false) {
+            (TransactionStatus::Received, TransactionFinalityStatus::Received)
+        } else {
+            (TransactionStatus::NotReceived, TransactionFinalityStatus::NotReceived)
+        };
+        let receipt = ProviderTransactionReceipt {
+            status: status.0,
+            finality_status: status.1,
+            block_hash: None,
+            block_number: None,
+            receipt: None,
+        };
+        return Ok(create_json_response(hyper::StatusCode::OK, &receipt));
+    };
+
+    let (execution_status, _) = location.execution_status();
+    let receipt = ProviderTransactionReceipt {
+        status: TransactionStatus::new(&location.finality_status, Some(&execution_status)),
+        finality_status: location.finality_status.clone(),
+        block_hash: location.block_hash(),
+        block_number: location.block_number(),
+        receipt: Some(location.receipt()?),
+    };
+    Ok(create_json_response(hyper::StatusCode::OK, &receipt))
+}
+
+pub async fn handle_get_transaction_status(
+    req: Request<Incoming>,
+    backend: Arc<MadaraBackend>,
+    add_transaction_provider: Arc<dyn SubmitTransaction>,
+) -> Result<Response<String>, GatewayError> {
+    let params = get_params_from_request(&req);
+    let transaction_hash = felt_from_params(&params, "transactionHash")?;
+
+    let status = match find_transaction(&backend, &transaction_hash)? {
+        Some(location) => {
+            let (execution_status, tx_revert_reason) = location.execution_status();
+            ProviderTransactionStatus {
+                tx_status: TransactionStatus::new(&location.finality_status, Some(&execution_status)),
+                finality_status: location.finality_status.clone(),
+                execution_status: Some(execution_status),
+                block_hash: location.block_hash(),
+                tx_revert_reason,
+            }
+        }
+        None => {
+            let (tx_status, finality_status) =
+                if add_transaction_provider.received_transaction(transaction_hash).await.unwrap_or(false) {
+                    (TransactionStatus::Received, TransactionFinalityStatus::Received)
+                } else {
+                    (TransactionStatus::NotReceived, TransactionFinalityStatus::NotReceived)
+                };
+            ProviderTransactionStatus {
+                tx_status,
+                finality_status,
+                execution_status: None,
+                block_hash: None,
+                tx_revert_reason: None,
+            }
+        }
+    };
+    Ok(create_json_response(hyper::StatusCode::OK, &status))
+}
+
+pub async fn handle_get_storage_at(
+    req: Request<Incoming>,
+    backend: Arc<MadaraBackend>,
+) -> Result<Response<String>, GatewayError> {
+    let params = get_params_from_request(&req);
+    let block_id = block_id_from_params(&params)?;
+    let contract_address = felt_from_params(&params, "contractAddress")?;
+    let key = felt_from_params(&params, "key")?;
+
+    ensure_block_exists(&backend, &block_id)?;
+    // Like the Starknet feeder gateway, unset storage and undeployed contracts read as zero.
+    let value = backend
+        .get_contract_storage_at(&block_id, &contract_address, &key)
+        .or_internal_server_error(format!("Retrieving storage of contract {contract_address:#x} at key {key:#x}"))?
+        .unwrap_or(Felt::ZERO);
+
+    Ok(create_json_response(hyper::StatusCode::OK, &value))
+}
+
+pub async fn handle_get_nonce(
+    req: Request<Incoming>,
+    backend: Arc<MadaraBackend>,
+) -> Result<Response<String>, GatewayError> {
+    let params = get_params_from_request(&req);
+    let block_id = block_id_from_params(&params)?;
+    let contract_address = felt_from_params(&params, "contractAddress")?;
+
+    ensure_block_exists(&backend, &block_id)?;
+    let nonce = backend
+        .get_contract_nonce_at(&block_id, &contract_address)
+        .or_internal_server_error(format!("Retrieving nonce of contract {contract_address:#x}"))?
+        .unwrap_or(Felt::ZERO);
+
+    Ok(create_json_response(hyper::StatusCode::OK, &nonce))
+}
+
+pub async fn handle_get_class_hash_at(
+    req: Request<Incoming>,
+    backend: Arc<MadaraBackend>,
+) -> Result<Response<String>, GatewayError> {
+    let params = get_params_from_request(&req);
+    let block_id = block_id_from_params(&params)?;
+    let contract_address = felt_from_params(&params, "contractAddress")?;
+
+    let class_hash = get_class_hash_at(&backend, &block_id, &contract_address)?;
+    Ok(create_json_response(hyper::StatusCode::OK, &class_hash))
+}
+
+pub async fn handle_get_full_contract(
+    req: Request<Incoming>,
+    backend: Arc<MadaraBackend>,
+) -> Result<Response<String>, GatewayError> {
+    let params = get_params_from_request(&req);
+    let block_id = block_id_from_params(&params)?;
+    let contract_address = felt_from_params(&params, "contractAddress")?;
+
+    let class_hash = get_class_hash_at(&backend, &block_id, &contract_address)?;
+    let class_info = backend
+        .get_class_info(&block_id, &class_hash)
+        .or_internal_server_error(format!("Retrieving class info from class hash {class_hash:x}"))?
+        .ok_or(StarknetError::class_not_found(class_hash))?;
+
+    class_json_response(&class_info)
+}
+
+pub async fn handle_get_block_hash_by_id(
+    req: Request<Incoming>,
+    backend: Arc<MadaraBackend>,
+) -> Result<Response<String>, GatewayError> {
+    let params = get_params_from_request(&req);
+    let block_n: u64 = params
+        .get("blockId")
+        .ok_or_else(|| StarknetError::new(StarknetErrorCode::MalformedRequest, "Missing blockId parameter".into()))?
+        .parse()
+        .map_err(|e: std::num::ParseIntError| StarknetError::new(StarknetErrorCode::MalformedRequest, e.to_string()))?;
+
+    let block_info = backend
+        .get_block_info(&BlockId::Number(block_n))
+        .or_internal_server_error(format!("Retrieving block info for block {block_n}"))?
+        .ok_or(StarknetError::block_not_found())?;
+    let block_info = block_info.as_closed().ok_or_internal_server_error("Converting block to non-pending")?;
+
+    Ok(create_json_response(hyper::StatusCode::OK, &block_info.block_hash))
+}
+
+pub async fn handle_get_block_id_by_hash(
+    req: Request<Incoming>,
+    backend: Arc<MadaraBackend>,
+) -> Result<Response<String>, GatewayError> {
+    let params = get_params_from_request(&req);
+    let block_hash = felt_from_params(&params, "blockHash")?;
+
+    let block_n = backend
+        .get_block_n(&BlockId::Hash(block_hash))
+        .or_internal_server_error(format!("Resolving block hash {block_hash:#x}"))?
+        .ok_or(StarknetError::block_not_found())?;
+
+    Ok(create_json_response(hyper::StatusCode::OK, &block_n))
+}
+
+pub async fn handle_call_contract(
+    req: Request<Incoming>,
+    backend: Arc<MadaraBackend>,
+    add_transaction_provider: Arc<dyn SubmitTransaction>,
+    ctx: ServiceContext,
+) -> Result<Response<String>, GatewayError> {
+    let params = get_params_from_request(&req);
+    let block_id = block_id_from_params(&params)?;
+
+    #[derive(Deserialize)]
+    struct CallContractRequest {
+        contract_address: Felt,
+        entry_point_selector: Felt,
+        calldata: Vec<Felt>,
+    }
+
+    #[derive(Serialize)]
+    struct CallContractResult {
+        result: Vec<Felt>,
+    }
+
+    let whole_body = req.collect().await.or_internal_server_error("Failed to read request body")?.aggregate();
+    let request = serde_json::from_reader::<_, CallContractRequest>(whole_body.reader())
+        .map_err(|e| GatewayError::StarknetError(StarknetError::malformed_request(e)))?;
+
+    let result = v0_7_1_call(
+        &starknet(backend, add_transaction_provider, ctx),
+        FunctionCall {
+            calldata: request.calldata,
+            contract_address: request.contract_address,
+            entry_point_selector: request.entry_point_selector,
+        },
+        block_id,
+    )?;
+
+    Ok(create_json_response(hyper::StatusCode::OK, &CallContractResult { result }))
+}
+
+pub async fn handle_estimate_fee(
+    req: Request<Incoming>,
+    backend: Arc<MadaraBackend>,
+    add_transaction_provider: Arc<dyn SubmitTransaction>,
+    ctx: ServiceContext,
+) -> Result<Response<String>, GatewayError> {
+    let params = get_params_from_request(&req);
+    let block_id = block_id_from_params(&params)?;
+    let simulation_flags =
+        if skip_validate_params(&params) { vec![SimulationFlagForEstimateFee::SkipValidate] } else { vec![] };
+    let transaction = broadcasted_transaction_from_body(req).await?;
+
+    let fee_estimate = v0_7_1_estimate_fee(
+        &starknet(backend, add_transaction_provider, ctx),
+        vec![transaction],
+        simulation_flags,
+        block_id,
+    )
+    .await?
+    .pop()
+    .ok_or_internal_server_error("Fee estimation returned no estimate")?;
+    let fee_estimate =
+        ProviderFeeEstimate::try_from(fee_estimate).or_internal_server_error("Converting fee estimate")?;
+
+    Ok(create_json_response(hyper::StatusCode::OK, &fee_estimate))
+}
+
+pub async fn handle_simulate_transaction(
+    req: Request<Incoming>,
+    backend: Arc<MadaraBackend>,
+    add_transaction_provider: Arc<dyn SubmitTransaction>,
+    ctx: ServiceContext,
+) -> Result<Response<String>, GatewayError> {
+    let params = get_params_from_request(&req);
+    let block_id = block_id_from_params(&params)?;
+    let simulation_flags = if skip_validate_params(&params) { vec![SimulationFlag::SkipValidate] } else { vec![] };
+    let transaction = broadcasted_transaction_from_body(req).await?;
+
+    #[derive(Serialize)]
+    struct SimulatedTransaction {
+        trace: TransactionTrace,
+        fee_estimation: ProviderFeeEstimate,
+    }
+
+    let simulation = v0_7_1_simulate_transactions(
+        &starknet(backend, add_transaction_provider, ctx),
+        block_id,
+        vec![transaction],
+        simulation_flags,
+    )
+    .await?
+    .pop()
+    .ok_or_internal_server_error("Simulation returned no result")?;
+    let simulated = SimulatedTransaction {
+        trace: simulation.transaction_trace,
+        fee_estimation: ProviderFeeEstimate::try_from(simulation.fee_estimation)
+            .or_internal_server_error("Converting fee estimate")?,
+    };
+
+    Ok(create_json_response(hyper::StatusCode::OK, &simulated))
+}
+
+fn starknet(
+    backend: Arc<MadaraBackend>,
+    add_transaction_provider: Arc<dyn SubmitTransaction>,
+    ctx: ServiceContext,
+) -> Starknet {
+    Starknet::new(backend, add_transaction_provider, Default::default(), None, ctx)
+}
+
+async fn broadcasted_transaction_from_body(req: Request<Incoming>) -> Result<BroadcastedTxn, GatewayError> {
+    let whole_body = req.collect().await.or_internal_server_error("Failed to read request body")?.aggregate();
+    let transaction = serde_json::from_reader::<_, UserTransaction>(whole_body.reader())
+        .map_err(|e| GatewayError::StarknetError(StarknetError::malformed_request(e)))?;
+    transaction.try_into().map_err(|e: UserTransactionConversionError| {
+        GatewayError::StarknetError(StarknetError::new(StarknetErrorCode::InvalidContractDefinition, e.to_string()))
+    })
+}
+
+fn ensure_block_exists(backend: &MadaraBackend, block_id: &BlockId) -> Result<(), GatewayError> {
+    if !backend.contains_block(block_id).or_internal_server_error(format!("Checking if block {block_id:?} exists"))? {
+        return Err(StarknetError::block_not_found().into());
+    }
+    Ok(())
+}
+
+fn get_class_hash_at(
+    backend: &MadaraBackend,
+    block_id: &BlockId,
+    contract_address: &Felt,
+) -> Result<Felt, GatewayError> {
+    ensure_block_exists(backend, block_id)?;
+    let class_hash = backend
+        .get_contract_class_hash_at(block_id, contract_address)
+        .or_internal_server_error(format!("Retrieving class hash of contract {contract_address:#x}"))?
+        .ok_or_else(|| {
+            StarknetError::new(
+                StarknetErrorCode::UninitializedContract,
+                format!("Requested contract address {contract_address:#x} is not deployed."),
+            )
+        })?;
+    Ok(class_hash)
+}
+
+fn class_json_response(class_info: &ClassInfo) -> Result<Response<String>, GatewayError> {
+    let json_response = match class_info.contract_class() {
+        ContractClass::Sierra(flattened_sierra_class) => {
+            create_json_response(hyper::StatusCode::OK, flattened_sierra_class.as_ref())
+        }
+        ContractClass::Legacy(compressed_legacy_contract_class) => {
+            let class = compressed_legacy_contract_class
+                .as_ref()
+                .serialize_to_json()
+                .or_internal_server_error("Failed to serialize legacy class")?;
+            create_response_with_json_body(hyper::StatusCode::OK, class)
+        }
+    };
+    Ok(json_response)
+}
+
+/// A transaction found in a block of the chain.
+struct TransactionLocation {
+    block: MadaraMaybePendingBlock,
+    tx_index: u64,
+    finality_status: TransactionFinalityStatus,
+}
+
+impl TransactionLocation {
+    fn block_hash(&self) -> Option<Felt> {
+        self.block.info.as_closed().map(|info| info.block_hash)
+    }
+
+    fn block_number(&self) -> Option<u64> {
+        self.block.info.as_closed().map(|info| info.header.block_number)
+    }
+
+    fn receipt_ref(&self) -> Result<&mp_receipt::TransactionReceipt, GatewayError> {
+        self.block
+            .inner
+            .receipts
+            .get(self.tx_index as usize)
+            .ok_or_internal_server_error("Transaction index out of the block receipts")
+    }
+
+    fn transaction_ref(&self) -> Result<&mp_transactions::Transaction, GatewayError> {
+        self.block
+            .inner
+            .transactions
+            .get(self.tx_index as usize)
+            .ok_or_internal_server_error("Transaction index out of the block transactions")
+    }
+
+    fn execution_status(&self) -> (ExecutionStatus, Option<String>) {
+        match self.receipt_ref().map(|receipt| receipt.execution_result()) {
+            Ok(ExecutionResult::Reverted { reason }) => (ExecutionStatus::Reverted, Some(reason)),
+            _ => (ExecutionStatus::Succeeded, None),
+        }
+    }
+
+    fn transaction(&self) -> Result<Transaction, GatewayError> {
+        let receipt = self.receipt_ref()?;
+        let transaction_with_hash = mp_transactions::TransactionWithHash {
+            transaction: self.transaction_ref()?.clone(),
+            hash: receipt.transaction_hash(),
+        };
+        Ok(Transaction::new(transaction_with_hash, receipt.contract_address()))
+    }
+
+    fn receipt(&self) -> Result<ConfirmedReceipt, GatewayError> {
+        let l1_to_l2_consumed_message = match self.transaction_ref()? {
+            mp_transactions::Transaction::L1Handler(tx) => MsgToL2::try_from(tx).ok(),
+            _ => None,
+        };
+        Ok(ConfirmedReceipt::new(self.receipt_ref()?.clone(), l1_to_l2_consumed_message, self.tx_index))
+    }
+}
+
+fn find_transaction(
+    backend: &MadaraBackend,
+    transaction_hash: &Felt,
+) -> Result<Option<TransactionLocation>, GatewayError> {
+    let Some((block, tx_index)) = backend
+        .find_tx_hash_block(transaction_hash)
+        .or_internal_server_error(format!("Retrieving block of transaction {transaction_hash:#x}"))?
+    else {
+        return Ok(None);
+    };
+
+    let finality_status = match &block.info {
+        MadaraMaybePendingBlockInfo::Pending(_) => TransactionFinalityStatus::AcceptedOnL2,
+        MadaraMaybePendingBlockInfo::NotPending(info) => {
+            let last_l1_confirmed_block =
+                backend.get_l1_last_confirmed_block().or_internal_server_error("Retrieving last l1 confirmed block")?;
+            if Some(info.header.block_number) <= last_l1_confirmed_block {
+                TransactionFinalityStatus::AcceptedOnL1
+            } else {
+                TransactionFinalityStatus::AcceptedOnL2
+            }
+        }
+    };
+
+    Ok(Some(TransactionLocation { block, tx_index: tx_index.0, finality_status }))
+}
+
 pub async fn handle_add_validated_transaction(
     req: Request<Incoming>,
     submit_validated: Option<Arc<dyn SubmitValidatedTransaction>>,
@@ -422,3 +874,474 @@ async fn invoke_transaction(
         Err(e) => GatewayError::from(e).into(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{router::main_router, service::GatewayServerConfig};
+    use bytes::Bytes;
+    use http_body_util::Full;
+    use hyper::{server::conn::http1, service::service_fn};
+    use hyper_util::rt::TokioIo;
+    use mc_devnet::{Call, ChainGenesisDescription, DevnetKeys, Multicall, Selector};
+    use mc_submit_tx::SubmitTransactionError;
+    use mp_block::{header::PendingHeader, Header, MadaraBlockInfo, MadaraBlockInner, MadaraPendingBlockInfo};
+    use mp_chain_config::ChainConfig;
+    use mp_convert::ToFelt;
+    use mp_gateway::fee::FeeUnit;
+    use mp_receipt::{InvokeTransactionReceipt, TransactionReceipt};
+    use mp_rpc::{
+        admin::BroadcastedDeclareTxnV0, AddInvokeTransactionResult, BroadcastedDeployAccountTxn, BroadcastedInvokeTxn,
+        ClassAndTxnHash, ContractAndTxnHash, DaMode, InvokeTxnV3, ResourceBounds, ResourceBoundsMapping,
+    };
+    use mp_state_update::{ContractStorageDiffItem, DeployedContractItem, NonceUpdate, StateDiff, StorageEntry};
+    use mp_transactions::{InvokeTransaction, InvokeTransactionV0};
+    use rstest::{fixture, rstest};
+    use std::net::SocketAddr;
+    use tokio::net::{TcpListener, TcpStream};
+
+    const CONTRACT: Felt = Felt::from_hex_unchecked("0xc0ffee");
+    const CLASS_HASH: Felt = Felt::from_hex_unchecked("0xc1a55");
+    const BLOCK_0_HASH: Felt = Felt::from_hex_unchecked("0xb0");
+    const BLOCK_1_HASH: Felt = Felt::from_hex_unchecked("0xb1");
+    const TX_BLOCK_0: Felt = Felt::from_hex_unchecked("0x7a0");
+    const TX_BLOCK_1_REVERTED: Felt = Felt::from_hex_unchecked("0x7a1");
+    const TX_PENDING: Felt = Felt::from_hex_unchecked("0x7a2");
+    const TX_IN_MEMPOOL: Felt = Felt::from_hex_unchecked("0x7a3");
+
+    /// Only knows about [`TX_IN_MEMPOOL`].
+    struct TestTransactionProvider;
+
+    #[async_trait::async_trait]
+    impl SubmitTransaction for TestTransactionProvider {
+        async fn submit_declare_v0_transaction(
+            &self,
+            _tx: BroadcastedDeclareTxnV0,
+        ) -> Result<ClassAndTxnHash, SubmitTransactionError> {
+            unimplemented!()
+        }
+        async fn submit_declare_transaction(
+            &self,
+            _tx: BroadcastedDeclareTxn,
+        ) -> Result<ClassAndTxnHash, SubmitTransactionError> {
+            unimplemented!()
+        }
+        async fn submit_deploy_account_transaction(
+            &self,
+            _tx: BroadcastedDeployAccountTxn,
+        ) -> Result<ContractAndTxnHash, SubmitTransactionError> {
+            unimplemented!()
+        }
+        async fn submit_invoke_transaction(
+            &self,
+            _tx: BroadcastedInvokeTxn,
+        ) -> Result<AddInvokeTransactionResult, SubmitTransactionError> {
+            unimplemented!()
+        }
+        async fn received_transaction(&self, hash: Felt) -> Option<bool> {
+            Some(hash == TX_IN_MEMPOOL)
+        }
+        async fn subscribe_new_transactions(&self) -> Option<tokio::sync::broadcast::Receiver<Felt>> {
+            None
+        }
+    }
+
+    fn invoke(tx_hash: Felt, execution_result: ExecutionResult) -> (mp_transactions::Transaction, TransactionReceipt) {
+        (
+            mp_transactions::Transaction::Invoke(InvokeTransaction::V0(InvokeTransactionV0 {
+                contract_address: CONTRACT,
+                ..Default::default()
+            })),
+            TransactionReceipt::Invoke(InvokeTransactionReceipt {
+                transaction_hash: tx_hash,
+                execution_result,
+                ..Default::default()
+            }),
+        )
+    }
+
+    /// Block 0 deploys [`CONTRACT`], block 1 bumps its nonce and storage, and there is a pending block.
+    fn sample_chain(backend: &MadaraBackend) {
+        let (tx, receipt) = invoke(TX_BLOCK_0, ExecutionResult::Succeeded);
+        backend
+            .store_block(
+                MadaraMaybePendingBlock {
+                    info: MadaraMaybePendingBlockInfo::NotPending(MadaraBlockInfo::new(
+                        Header { block_number: 0, transaction_count: 1, ..Default::default() },
+                        vec![TX_BLOCK_0],
+                        BLOCK_0_HASH,
+                    )),
+                    inner: MadaraBlockInner { transactions: vec![tx], receipts: vec![receipt] },
+                },
+                StateDiff {
+                    deployed_contracts: vec![DeployedContractItem { address: CONTRACT, class_hash: CLASS_HASH }],
+                    ..Default::default()
+                },
+                vec![],
+            )
+            .unwrap();
+
+        let (tx, receipt) = invoke(TX_BLOCK_1_REVERTED, ExecutionResult::Reverted { reason: "too bad".into() });
+        backend
+            .store_block(
+                MadaraMaybePendingBlock {
+                    info: MadaraMaybePendingBlockInfo::NotPending(MadaraBlockInfo::new(
+                        Header {
+                            parent_block_hash: BLOCK_0_HASH,
+                            block_number: 1,
+                            transaction_count: 1,
+                            ..Default::default()
+                        },
+                        vec![TX_BLOCK_1_REVERTED],
+                        BLOCK_1_HASH,
+                    )),
+                    inner: MadaraBlockInner { transactions: vec![tx], receipts: vec![receipt] },
+                },
+                StateDiff {
+                    storage_diffs: vec![ContractStorageDiffItem {
+                        address: CONTRACT,
+                        storage_entries: vec![StorageEntry { key: Felt::ONE, value: Felt::TWO }],
+                    }],
+                    nonces: vec![NonceUpdate { contract_address: CONTRACT, nonce: Felt::THREE }],
+                    ..Default::default()
+                },
+                vec![],
+            )
+            .unwrap();
+
+        let (tx, receipt) = invoke(TX_PENDING, ExecutionResult::Succeeded);
+        backend
+            .store_block(
+                MadaraMaybePendingBlock {
+                    info: MadaraMaybePendingBlockInfo::Pending(MadaraPendingBlockInfo::new(
+                        PendingHeader { parent_block_hash: BLOCK_1_HASH, ..Default::default() },
+                        vec![TX_PENDING],
+                    )),
+                    inner: MadaraBlockInner { transactions: vec![tx], receipts: vec![receipt] },
+                },
+                StateDiff::default(),
+                vec![],
+            )
+            .unwrap();
+
+        backend.write_last_confirmed_block(0).unwrap();
+    }
+
+    /// Serves the feeder gateway over the sample chain on a local port.
+    #[fixture]
+    async fn feeder_gateway() -> SocketAddr {
+        let backend = MadaraBackend::open_for_testing(Arc::new(ChainConfig::madara_test()));
+        sample_chain(&backend);
+        serve(backend).await
+    }
+
+    /// Serves the feeder gateway over a devnet genesis block, which deploys the fee tokens and funded accounts.
+    #[fixture]
+    async fn devnet_gateway() -> (SocketAddr, DevnetKeys) {
+        let backend = MadaraBackend::open_for_testing(Arc::new(ChainConfig::madara_devnet()));
+        let mut genesis = ChainGenesisDescription::base_config().unwrap();
+        let contracts = genesis.add_devnet_contracts(2).unwrap();
+        genesis.build_and_store(&backend).await.unwrap();
+        (serve(backend).await, contracts)
+    }
+
+    async fn serve(backend: Arc<MadaraBackend>) -> SocketAddr {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let config = GatewayServerConfig { feeder_gateway_enable: true, ..Default::default() };
+        let add_transaction_provider: Arc<dyn SubmitTransaction> = Arc::new(TestTransactionProvider);
+
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let (backend, add_transaction_provider, config) =
+                    (Arc::clone(&backend), Arc::clone(&add_transaction_provider), config.clone());
+                tokio::spawn(async move {
+                    let service = service_fn(move |req| {
+                        main_router(
+                            req,
+                            Arc::clone(&backend),
+                            Arc::clone(&add_transaction_provider),
+                            None,
+                            ServiceContext::new_for_testing(),
+                            config.clone(),
+                        )
+                    });
+                    http1::Builder::new().serve_connection(TokioIo::new(stream), service).await.unwrap();
+                });
+            }
+        });
+
+        addr
+    }
+
+    async fn send(addr: SocketAddr, req: Request<Full<Bytes>>) -> (StatusCode, serde_json::Value) {
+        let stream = TcpStream::connect(addr).await.unwrap();
+        let (mut sender, conn) = hyper::client::conn::http1::handshake(TokioIo::new(stream)).await.unwrap();
+        tokio::spawn(conn);
+
+        let res = sender.send_request(req).await.unwrap();
+        let status = res.status();
+        let body = res.into_body().collect().await.unwrap().to_bytes();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    async fn get(addr: SocketAddr, path_and_query: &str) -> (StatusCode, serde_json::Value) {
+        let req = Request::get(format!("http://{addr}/feeder_gateway/{path_and_query}")).body(Full::default()).unwrap();
+        send(addr, req).await
+    }
+
+    async fn post(addr: SocketAddr, path_and_query: &str, body: serde_json::Value) -> (StatusCode, serde_json::Value) {
+        let req = Request::post(format!("http://{addr}/feeder_gateway/{path_and_query}"))
+            .body(Full::new(Bytes::from(body.to_string())))
+            .unwrap();
+        send(addr, req).await
+    }
+
+    /// Transfers 1 fri of the fee token from `from` to `to`. The tx is not signed, it has to be executed without
+    /// validation.
+    fn transfer(chain_config: &ChainConfig, from: Felt, to: Felt) -> serde_json::Value {
+        let tx = BroadcastedTxn::Invoke(BroadcastedInvokeTxn::V3(InvokeTxnV3 {
+            sender_address: from,
+            calldata: Multicall::default()
+                .with(Call {
+                    to: chain_config.native_fee_token_address.to_felt(),
+                    selector: Selector::from("transfer"),
+                    calldata: vec![to, Felt::ONE, Felt::ZERO],
+                })
+                .flatten()
+                .collect::<Vec<_>>()
+                .into(),
+            signature: vec![].into(),
+            nonce: Felt::ZERO,
+            resource_bounds: ResourceBoundsMapping {
+                l1_gas: ResourceBounds { max_amount: 60000, max_price_per_unit: 10000 },
+                l2_gas: ResourceBounds { max_amount: 60000, max_price_per_unit: 10000 },
+            },
+            tip: 0,
+            paymaster_data: vec![],
+            account_deployment_data: vec![],
+            nonce_data_availability_mode: DaMode::L1,
+            fee_data_availability_mode: DaMode::L1,
+        }));
+        serde_json::to_value(UserTransaction::try_from(tx).unwrap()).unwrap()
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_get_transaction_status(#[future] feeder_gateway: SocketAddr) {
+        let addr = feeder_gateway.await;
+
+        let (status, body) = get(addr, &format!("get_transaction_status?transactionHash={TX_BLOCK_0:#x}")).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(
+            body,
+            json!({
+                "tx_status": "ACCEPTED_ON_L1",
+                "finality_status": "ACCEPTED_ON_L1",
+                "execution_status": "SUCCEEDED",
+                "block_hash": "0xb0",
+            })
+        );
+
+        let (_, body) = get(addr, &format!("get_transaction_status?transactionHash={TX_BLOCK_1_REVERTED:#x}")).await;
+        assert_eq!(
+            body,
+            json!({
+                "tx_status": "REVERTED",
+                "finality_status": "ACCEPTED_ON_L2",
+                "execution_status": "REVERTED",
+                "block_hash": "0xb1",
+                "tx_revert_reason": "too bad",
+            })
+        );
+
+        let (_, body) = get(addr, &format!("get_transaction_status?transactionHash={TX_PENDING:#x}")).await;
+        assert_eq!(
+            body,
+            json!({ "tx_status": "ACCEPTED_ON_L2", "finality_status": "ACCEPTED_ON_L2", "execution_status": "SUCCEEDED" })
+        );
+
+        let (_, body) = get(addr, &format!("get_transaction_status?transactionHash={TX_IN_MEMPOOL:#x}")).await;
+        assert_eq!(body, json!({ "tx_status": "RECEIVED", "finality_status": "RECEIVED" }));
+
+        let (_, body) = get(addr, "get_transaction_status?transactionHash=0x404").await;
+        assert_eq!(body, json!({ "tx_status": "NOT_RECEIVED", "finality_status": "NOT_RECEIVED" }));
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_get_transaction_and_receipt(#[future] feeder_gateway: SocketAddr) {
+        let addr = feeder_gateway.await;
+
+        let (status, body) = get(addr, &format!("get_transaction?transactionHash={TX_BLOCK_1_REVERTED:#x}")).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["transaction"]["transaction_hash"], json!("0x7a1"));
+        let transaction: ProviderTransaction = serde_json::from_value(body).unwrap();
+        assert_eq!(transaction.status, TransactionStatus::Reverted);
+        assert_eq!(transaction.block_hash, Some(BLOCK_1_HASH));
+        assert_eq!(transaction.block_number, Some(1));
+        assert_eq!(transaction.transaction_index, Some(0));
+        assert_eq!(transaction.revert_error.as_deref(), Some("too bad"));
+        assert!(transaction.transaction.is_some());
+
+        let (_, body) = get(addr, "get_transaction?transactionHash=0x404").await;
+        assert_eq!(serde_json::from_value::<ProviderTransaction>(body).unwrap(), ProviderTransaction::not_received());
+
+        let (status, body) = get(addr, &format!("get_transaction_receipt?transactionHash={TX_BLOCK_0:#x}")).await;
+        assert_eq!(status, StatusCode::OK);
+        let receipt: ProviderTransactionReceipt = serde_json::from_value(body).unwrap();
+        assert_eq!(receipt.status, TransactionStatus::AcceptedOnL1);
+        assert_eq!(receipt.block_number, Some(0));
+        let receipt = receipt.receipt.unwrap();
+        assert_eq!(receipt.transaction_hash, TX_BLOCK_0);
+        assert_eq!(receipt.execution_status, ExecutionStatus::Succeeded);
+
+        let (_, body) = get(addr, &format!("get_transaction_receipt?transactionHash={TX_IN_MEMPOOL:#x}")).await;
+        assert_eq!(body, json!({ "status": "RECEIVED", "finality_status": "RECEIVED" }));
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_get_contract_state(#[future] feeder_gateway: SocketAddr) {
+        let addr = feeder_gateway.await;
+
+        let (status, body) = get(addr, &format!("get_storage_at?contractAddress={CONTRACT:#x}&key=0x1")).await;
+        assert_eq!((status, body), (StatusCode::OK, json!("0x2")));
+        let (_, body) = get(addr, &format!("get_storage_at?contractAddress={CONTRACT:#x}&key=0x1&blockNumber=0")).await;
+        assert_eq!(body, json!("0x0"));
+
+        let (_, body) = get(addr, &format!("get_nonce?contractAddress={CONTRACT:#x}&blockNumber=1")).await;
+        assert_eq!(body, json!("0x3"));
+        // Decimal addresses are accepted as well.
+        let (_, body) = get(addr, &format!("get_nonce?contractAddress={CONTRACT}&blockNumber=0")).await;
+        assert_eq!(body, json!("0x0"));
+
+        let (_, body) = get(addr, &format!("get_class_hash_at?contractAddress={CONTRACT:#x}")).await;
+        assert_eq!(body, json!("0xc1a55"));
+
+        let (status, body) = get(addr, "get_class_hash_at?contractAddress=0x404").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], json!("StarknetErrorCode.UNINITIALIZED_CONTRACT"));
+
+        let (status, body) = get(addr, &format!("get_nonce?contractAddress={CONTRACT:#x}&blockNumber=10")).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], json!("StarknetErrorCode.BLOCK_NOT_FOUND"));
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_get_block_hash_and_id(#[future] feeder_gateway: SocketAddr) {
+        let addr = feeder_gateway.await;
+
+        let (status, body) = get(addr, "get_block_hash_by_id?blockId=1").await;
+        assert_eq!((status, body), (StatusCode::OK, json!("0xb1")));
+        let (status, body) = get(addr, &format!("get_block_id_by_hash?blockHash={BLOCK_0_HASH:#x}")).await;
+        assert_eq!((status, body), (StatusCode::OK, json!(0)));
+
+        let (status, body) = get(addr, "get_block_hash_by_id?blockId=2").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], json!("StarknetErrorCode.BLOCK_NOT_FOUND"));
+        let (status, _) = get(addr, "get_block_id_by_hash?blockHash=0x404").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_call_contract(#[future] devnet_gateway: (SocketAddr, DevnetKeys)) {
+        let (addr, contracts) = devnet_gateway.await;
+        let fee_token = ChainConfig::madara_devnet().native_fee_token_address.to_felt();
+        let account = &contracts.0[0];
+
+        let (status, body) = post(
+            addr,
+            "call_contract",
+            json!({
+                "contract_address": fee_token,
+                "entry_point_selector": Felt::from(Selector::from("balance_of")),
+                "calldata": [account.address],
+            }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, json!({ "result": [account.balance.fri, Felt::ZERO] }));
+
+        let (status, body) = post(
+            addr,
+            "call_contract",
+            json!({ "contract_address": "0x404", "entry_point_selector": "0x1", "calldata": [] }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], json!("StarknetErrorCode.TRANSACTION_FAILED"));
+
+        let (status, body) = post(addr, "call_contract", json!({ "contract_address": "0x404" })).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], json!("StarkErrorCode.MALFORMED_REQUEST"));
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_estimate_fee(#[future] devnet_gateway: (SocketAddr, DevnetKeys)) {
+        let (addr, contracts) = devnet_gateway.await;
+        let tx = transfer(&ChainConfig::madara_devnet(), contracts.0[0].address, contracts.0[1].address);
+
+        let (status, body) = post(addr, "estimate_fee?skipValidate=true", tx.clone()).await;
+        assert_eq!(status, StatusCode::OK);
+        let fee_estimate: ProviderFeeEstimate = serde_json::from_value(body).unwrap();
+        assert_eq!(fee_estimate.unit, FeeUnit::Fri);
+        assert!(fee_estimate.gas_usage > 0);
+        assert!(fee_estimate.overall_fee > 0);
+
+        let (status, body) = post(addr, "estimate_fee?skipValidate=true&blockNumber=1", tx).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], json!("StarknetErrorCode.BLOCK_NOT_FOUND"));
+
+        let (status, body) = post(addr, "estimate_fee", json!({ "type": "INVOKE_FUNCTION" })).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], json!("StarkErrorCode.MALFORMED_REQUEST"));
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_simulate_transaction(#[future] devnet_gateway: (SocketAddr, DevnetKeys)) {
+        let (addr, contracts) = devnet_gateway.await;
+        let tx = transfer(&ChainConfig::madara_devnet(), contracts.0[0].address, contracts.0[1].address);
+
+        let (status, body) = post(addr, "simulate_transaction?skipValidate=true", tx.clone()).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["trace"]["type"], json!("INVOKE"));
+        // The validation is skipped
+        assert_eq!(body["trace"]["validate_invocation"], serde_json::Value::Null);
+        assert!(body["trace"]["execute_invocation"].is_object());
+        let fee_estimate: ProviderFeeEstimate = serde_json::from_value(body["fee_estimation"].clone()).unwrap();
+        assert_eq!(fee_estimate.unit, FeeUnit::Fri);
+        assert!(fee_estimate.overall_fee > 0);
+
+        // The tx is not signed
+        let (status, body) = post(addr, "simulate_transaction", tx).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], json!("StarknetErrorCode.TRANSACTION_FAILED"));
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_get_full_contract(#[future] devnet_gateway: (SocketAddr, DevnetKeys)) {
+        let (addr, contracts) = devnet_gateway.await;
+        let account = &contracts.0[0];
+
+        let (status, body) = get(addr, &format!("get_full_contract?contractAddress={:#x}", account.address)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(body["sierra_program"].is_array());
+        let (_, class) = get(addr, &format!("get_class_by_hash?classHash={:#x}", account.class_hash)).await;
+        assert_eq!(body, class);
+
+        let (status, body) = get(addr, "get_full_contract?contractAddress=0x404").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], json!("StarknetErrorCode.UNINITIALIZED_CONTRACT"));
+
+        let (status, body) =
+            get(addr, &format!("get_full_contract?contractAddress={:#x}&blockNumber=1", account.address)).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], json!("StarknetErrorCode.BLOCK_NOT_FOUND"));
+    }
+}
@@ -117,3 +117,19 @@ pub(crate) fn block_id_from_params(params: &HashMap<String, String>) -> Result<B
 pub(crate) fn include_block_params(params: &HashMap<String, String>) -> bool {
     params.get("includeBlock").is_some_and(|v| v == "true")
 }
+
+/// Parses a required felt parameter, as a 0x-prefixed hex string or a decimal string.
+pub(crate) fn felt_from_params(params: &HashMap<String, String>, name: &str) -> Result<Felt, StarknetError> {
+    let value = params
+        .get(name)
+        .ok_or_else(|| StarknetError::new(StarknetErrorCode::MalformedRequest, format!("Missing {name} parameter")))?;
+    let felt = match value.strip_prefix("0x") {
+        Some(_) => Felt::from_hex(value).map_err(|e| e.to_string()),
+        None => Felt::from_dec_str(value).map_err(|e| e.to_string()),
+    };
+    felt.map_err(|e| StarknetError::new(StarknetErrorCode::MalformedRequest, format!("Invalid {name}: {e}")))
+}
+
+pub(crate) fn skip_validate_params(params: &HashMap<String, String>) -> bool {
+    params.get("skipValidate").is_some_and(|v| v == "true")
+}
@@ -1,7 +1,9 @@
 use super::handler::{
-    handle_add_transaction, handle_get_block, handle_get_block_traces, handle_get_class_by_hash,
-    handle_get_compiled_class_by_class_hash, handle_get_contract_addresses, handle_get_public_key,
-    handle_get_signature, handle_get_state_update,
+    handle_add_transaction, handle_call_contract, handle_estimate_fee, handle_get_block, handle_get_block_hash_by_id,
+    handle_get_block_id_by_hash, handle_get_block_traces, handle_get_class_by_hash, handle_get_class_hash_at,
+    handle_get_compiled_class_by_class_hash, handle_get_contract_addresses, handle_get_full_contract, handle_get_nonce,
+    handle_get_public_key, handle_get_signature, handle_get_state_update, handle_get_storage_at,
+    handle_get_transaction, handle_get_transaction_receipt, handle_get_transaction_status, handle_simulate_transaction,
 };
 use super::helpers::{not_found_response, service_unavailable_response};
 use crate::handler::handle_add_validated_transaction;
@@ -78,6 +80,44 @@ async fn feeder_gateway_router(
         (&Method::GET, "feeder_gateway/get_public_key") => {
             Ok(handle_get_public_key(backend).await.unwrap_or_else(Into::into))
         }
+        (&Method::GET, "feeder_gateway/get_transaction") => {
+            Ok(handle_get_transaction(req, backend, add_transaction_provider).await.unwrap_or_else(Into::into))
+        }
+        (&Method::GET, "feeder_gateway/get_transaction_receipt") => {
+            Ok(handle_get_transaction_receipt(req, backend, add_transaction_provider).await.unwrap_or_else(Into::into))
+        }
+        (&Method::GET, "feeder_gateway/get_transaction_status") => {
+            Ok(handle_get_transaction_status(req, backend, add_transaction_provider).await.unwrap_or_else(Into::into))
+        }
+        (&Method::GET, "feeder_gateway/get_storage_at") => {
+            Ok(handle_get_storage_at(req, backend).await.unwrap_or_else(Into::into))
+        }
+        (&Method::GET, "feeder_gateway/get_nonce") => {
+            Ok(handle_get_nonce(req, backend).await.unwrap_or_else(Into::into))
+        }
+        (&Method::GET, "feeder_gateway/get_class_hash_at") => {
+            Ok(handle_get_class_hash_at(req, backend).await.unwrap_or_else(Into::into))
+        }
+        (&Method::GET, "feeder_gateway/get_full_contract") => {
+            Ok(handle_get_full_contract(req, backend).await.unwrap_or_else(Into::into))
+        }
+        (&Method::GET, "feeder_gateway/get_block_hash_by_id") => {
+            Ok(handle_get_block_hash_by_id(req, backend).await.unwrap_or_else(Into::into))
+        }
+        (&Method::GET, "feeder_gateway/get_block_id_by_hash") => {
+            Ok(handle_get_block_id_by_hash(req, backend).await.unwrap_or_else(Into::into))
+        }
+        (&Method::POST, "feeder_gateway/call_contract") => {
+            Ok(handle_call_contract(req, backend, add_transaction_provider, ctx).await.unwrap_or_else(Into::into))
+        }
+        (&Method::POST, "feeder_gateway/estimate_fee") => {
+            Ok(handle_estimate_fee(req, backend, add_transaction_provider, ctx).await.unwrap_or_else(Into::into))
+        }
+        (&Method::POST, "feeder_gateway/simulate_transaction") => {
+            Ok(handle_simulate_transaction(req, backend, add_transaction_provider, ctx)
+                .await
+                .unwrap_or_else(Into::into))
+        }
         _ => {
             tracing::debug!(target: "feeder_gateway", "Feeder gateway received invalid request: {path}");
             Ok(not_found_response())
@@ -6,6 +6,7 @@ use crate::{
     tx::{Score, TxSummary},
 };
 use starknet_api::core::{ContractAddress, Nonce};
+use starknet_types_core::felt::Felt;
 use std::{
     collections::{btree_map, hash_map, BTreeMap, HashMap},
     iter,
@@ -97,6 +98,19 @@ impl AccountState {
         }
     }
 
+    /// Whether the queued transaction at `nonce` can be executed once the transactions before it are, meaning there is
+    /// no nonce gap between the account nonce and this transaction.
+    pub fn is_in_ready_sequence(&self, nonce: &Nonce) -> bool {
+        let mut expected = self.current_nonce;
+        for queued_nonce in self.queued_txs.range(..=nonce).map(|kv| kv.0) {
+            if *queued_nonce != expected {
+                return false;
+            }
+            expected = Nonce(expected.0 + Felt::ONE);
+        }
+        true
+    }
+
     pub fn last_queued_tx(&self) -> Option<&'_ MempoolTransaction> {
         self.queued_txs.last_key_value().map(|kv| kv.1)
     }
@@ -391,6 +405,13 @@ impl Accounts {
         }
     }
 
+    pub fn get_account(&self, contract_address: &ContractAddress) -> Option<&AccountState> {
+        self.accounts.get(contract_address)
+    }
+    pub fn iter(&self) -> impl Iterator<Item = (&ContractAddress, &AccountState)> {
+        self.accounts.iter()
+    }
+
     pub fn get_tx_by_key(&self, TxKey(contract_address, nonce): &TxKey) -> Option<&MempoolTransaction> {
         self.accounts.get(contract_address).and_then(|account| account.queued_txs.get(nonce))
     }
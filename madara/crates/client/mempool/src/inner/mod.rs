@@ -5,9 +5,12 @@ use crate::inner::{
     limits::{MempoolLimitReached, MempoolLimiter},
     ready_queue::ReadyQueue,
     timestamp_queue::TimestampQueue,
-    tx::{EvictionScore, MempoolTransaction, ScoreFunction},
+    tx::{AccountKey, EvictionScore, MempoolTransaction, ScoreFunction, TxKey},
+};
+use mp_transactions::{
+    validated::{TxTimestamp, ValidatedMempoolTx},
+    Transaction,
 };
-use mp_transactions::validated::{TxTimestamp, ValidatedMempoolTx};
 use starknet_api::{
     core::{ContractAddress, Nonce},
     transaction::TransactionHash,
@@ -43,6 +46,33 @@ pub enum TxInsertionError {
     Limit(#[from] MempoolLimitReached),
 }
 
+/// Status of a transaction in the mempool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MempoolTxStatus {
+    /// There is no nonce gap between the account nonce and this transaction: it will be executed once the transactions
+    /// before it are.
+    Ready,
+    /// Waiting on a transaction with a lower nonce that is not in the mempool.
+    Pending,
+}
+
+/// Summary of the mempool content.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MempoolStats {
+    pub transactions: usize,
+    pub accounts: usize,
+    /// Number of accounts that have a transaction ready to be executed.
+    pub ready_accounts: usize,
+    pub invoke_transactions: usize,
+    pub declare_transactions: usize,
+    pub deploy_account_transactions: usize,
+    pub deploy_transactions: usize,
+    pub l1_handler_transactions: usize,
+    pub max_transactions: usize,
+    pub max_declare_transactions: Option<usize>,
+    pub oldest_tx_arrived_at: Option<TxTimestamp>,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct InnerMempoolConfig {
     pub score_function: ScoreFunction,
@@ -117,13 +147,6 @@ impl InnerMempool {
         self.accounts.all_accounts().get(contract_address).map(|acc| &acc.current_nonce)
     }
 
-    pub fn account_nonces(&self) -> impl Iterator<Item = (&ContractAddress, &Nonce)> {
-        self.accounts
-            .all_accounts()
-            .iter()
-            .map(|(contract_address, account)| (contract_address, &account.current_nonce))
-    }
-
     pub fn transactions(&self) -> impl Iterator<Item = &ValidatedMempoolTx> {
         self.accounts.all_accounts().iter().flat_map(|(_, acc)| acc.queued_txs.values()).map(|t| &t.inner)
     }
@@ -316,6 +339,85 @@ impl InnerMempool {
         self.accounts.get_transaction(contract_address, nonce).map(|tx| &tx.inner)
     }
 
+    /// Remove a transaction from the mempool. Returns false if no transaction with this hash is in the mempool.
+    pub fn remove_tx_by_hash(
+        &mut self,
+        tx_hash: &TransactionHash,
+        removed_txs: &mut impl Extend<ValidatedMempoolTx>,
+    ) -> bool {
+        let Some(tx_key) = self.by_tx_hash.get(tx_hash).copied() else { return false };
+        let account_update = self.accounts.remove_tx(&tx_key);
+        self.apply_update(account_update, removed_txs);
+        true
+    }
+
+    /// Remove all of the queued transactions of an account.
+    pub fn remove_account_txs(
+        &mut self,
+        contract_address: &ContractAddress,
+        removed_txs: &mut impl Extend<ValidatedMempoolTx>,
+    ) {
+        let account_key = AccountKey(*contract_address);
+        // Popping from the back of the queue, so that the account never has a front nonce gap it did not have before.
+        while self.accounts.get_account(contract_address).is_some() {
+            let account_update = self.accounts.pop_last_tx_from_account(&account_key);
+            self.apply_update(account_update, removed_txs);
+        }
+    }
+
+    /// Account nonce for every account that has queued transactions.
+    pub fn account_nonces(&self) -> impl Iterator<Item = (&ContractAddress, &Nonce)> {
+        self.accounts.iter().map(|(contract_address, account)| (contract_address, &account.current_nonce))
+    }
+
+    /// Nonces of the queued transactions of an account, in ascending order.
+    pub fn queued_nonces(&self, contract_address: &ContractAddress) -> impl Iterator<Item = &Nonce> {
+        self.accounts.get_account(contract_address).into_iter().flat_map(|account| account.queued_txs.keys())
+    }
+
+    /// Caller must supply a valid TxKey.
+    fn tx_with_status(&self, TxKey(contract_address, nonce): &TxKey) -> (&ValidatedMempoolTx, MempoolTxStatus) {
+        let account = self.accounts.get_account(contract_address).expect("Invariant violation: TxKey is invalid");
+        let tx = account.queued_txs.get(nonce).expect("Invariant violation: TxKey is invalid");
+        let status =
+            if account.is_in_ready_sequence(nonce) { MempoolTxStatus::Ready } else { MempoolTxStatus::Pending };
+        (&tx.inner, status)
+    }
+
+    /// All transactions with their status, oldest first.
+    pub fn transactions_by_arrival(&self) -> impl Iterator<Item = (&ValidatedMempoolTx, MempoolTxStatus)> {
+        self.timestamp_queue.iter().map(|tx_key| self.tx_with_status(tx_key))
+    }
+
+    pub fn get_transaction_with_status_by_hash(
+        &self,
+        tx_hash: &TransactionHash,
+    ) -> Option<(&ValidatedMempoolTx, MempoolTxStatus)> {
+        self.by_tx_hash.get(tx_hash).map(|tx_key| self.tx_with_status(tx_key))
+    }
+
+    pub fn stats(&self) -> MempoolStats {
+        let mut stats = MempoolStats {
+            accounts: self.accounts.iter().count(),
+            ready_accounts: self.ready_queue.ready_transactions(),
+            max_transactions: self.config.max_transactions,
+            max_declare_transactions: self.config.max_declare_transactions,
+            oldest_tx_arrived_at: self.timestamp_queue.oldest(),
+            ..Default::default()
+        };
+        for tx in self.accounts.iter().flat_map(|(_, account)| account.queued_txs.values()) {
+            stats.transactions += 1;
+            match tx.inner.tx {
+                Transaction::Invoke(_) => stats.invoke_transactions += 1,
+                Transaction::Declare(_) => stats.declare_transactions += 1,
+                Transaction::DeployAccount(_) => stats.deploy_account_transactions += 1,
+                Transaction::Deploy(_) => stats.deploy_transactions += 1,
+                Transaction::L1Handler(_) => stats.l1_handler_transactions += 1,
+            }
+        }
+        stats
+    }
+
     pub fn contains_tx_by_hash(&self, tx_hash: &TransactionHash) -> bool {
         self.by_tx_hash.contains(tx_hash)
     }
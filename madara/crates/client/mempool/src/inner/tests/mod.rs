@@ -1,6 +1,8 @@
 #![cfg(test)]
 
-use crate::{limits::MempoolLimitReached, tx::ScoreFunction, InnerMempool, InnerMempoolConfig, TxInsertionError};
+use crate::{
+    limits::MempoolLimitReached, tx::ScoreFunction, InnerMempool, InnerMempoolConfig, MempoolTxStatus, TxInsertionError,
+};
 use assert_matches::assert_matches;
 use mp_convert::{Felt, ToFelt};
 use mp_transactions::validated::{TxTimestamp, ValidatedMempoolTx};
@@ -176,6 +178,37 @@ impl MempoolTester {
         self.check_invariants();
     }
 
+    pub fn remove_tx_by_hash(&mut self, tx_hash: Felt) -> bool {
+        let mut removed = vec![];
+        tracing::debug!("REMOVE_TX_BY_HASH {tx_hash:#x}");
+        let res = self.inner.remove_tx_by_hash(&TransactionHash(tx_hash), &mut removed);
+        assert_eq!(removed.len(), usize::from(res));
+        for el in removed {
+            let removed = self.added_txs.remove(&el.into());
+            assert!(removed);
+        }
+        self.check_invariants();
+        res
+    }
+
+    pub fn remove_account_txs(&mut self, contract_address: Felt) -> usize {
+        let mut removed = vec![];
+        tracing::debug!("REMOVE_ACCOUNT_TXS {contract_address:#x}");
+        self.inner.remove_account_txs(&contract_address.try_into().unwrap(), &mut removed);
+        let n_removed = removed.len();
+        for el in removed {
+            let removed = self.added_txs.remove(&el.into());
+            assert!(removed);
+        }
+        self.check_invariants();
+        n_removed
+    }
+
+    /// Tx hashes with their status, oldest first.
+    pub fn transactions_by_arrival(&self) -> Vec<(Felt, MempoolTxStatus)> {
+        self.inner.transactions_by_arrival().map(|(tx, status)| (tx.tx_hash, status)).collect()
+    }
+
     pub fn get_transaction_by_hash(&self, tx_hash: Felt) -> Option<TestTx> {
         self.inner.get_transaction_by_hash(&TransactionHash(tx_hash)).cloned().map(Into::into)
     }
@@ -1001,3 +1034,105 @@ fn test_get_tx_by_hash(mut fcfs_mempool: MempoolTester) {
     assert!(!fcfs_mempool.contains_tx_by_hash(felt!("0x999")));
     assert_eq!(fcfs_mempool.get_transaction_by_hash(felt!("0x999")), None);
 }
+
+#[rstest]
+fn test_remove_tx_by_hash(mut fcfs_mempool: MempoolTester) {
+    let tx_1 = TestTx {
+        nonce: felt!("0x1"),
+        contract_address: felt!("0x123"),
+        arrived_at: 1000,
+        tip: None,
+        tx_hash: felt!("0xabc"),
+        is_declare: false,
+    };
+    let tx_2 = TestTx { nonce: felt!("0x2"), arrived_at: 2000, tx_hash: felt!("0xdef"), ..tx_1.clone() };
+
+    assert_matches!(fcfs_mempool.insert_tx(tx_1.clone(), felt!("0x1")), Ok(()));
+    assert_matches!(fcfs_mempool.insert_tx(tx_2.clone(), felt!("0x1")), Ok(()));
+
+    assert!(!fcfs_mempool.remove_tx_by_hash(felt!("0x999")));
+    // Removing the front tx leaves a nonce gap: the account is still there, but nothing is ready anymore.
+    assert!(fcfs_mempool.remove_tx_by_hash(felt!("0xabc")));
+    assert_eq!(fcfs_mempool.transactions(), [tx_2].into());
+    assert_eq!(fcfs_mempool.account_nonces(), [(felt!("0x123"), felt!("0x1"))].into());
+    assert_eq!(fcfs_mempool.pop_next_ready(), None);
+
+    assert!(fcfs_mempool.remove_tx_by_hash(felt!("0xdef")));
+    assert_eq!(fcfs_mempool.transactions(), [].into());
+    assert_eq!(fcfs_mempool.account_nonces(), [].into());
+}
+
+#[rstest]
+fn test_remove_account_txs(mut fcfs_mempool: MempoolTester) {
+    let tx = TestTx {
+        nonce: felt!("0x1"),
+        contract_address: felt!("0x123"),
+        arrived_at: 1000,
+        tip: None,
+        tx_hash: felt!("0x1"),
+        is_declare: false,
+    };
+    let other_account = TestTx { contract_address: felt!("0x456"), tx_hash: felt!("0x4"), ..tx.clone() };
+
+    assert_matches!(fcfs_mempool.insert_tx(tx.clone(), felt!("0x1")), Ok(()));
+    assert_matches!(
+        fcfs_mempool.insert_tx(TestTx { nonce: felt!("0x2"), tx_hash: felt!("0x2"), ..tx.clone() }, felt!("0x1")),
+        Ok(())
+    );
+    assert_matches!(
+        fcfs_mempool.insert_tx(TestTx { nonce: felt!("0x5"), tx_hash: felt!("0x3"), ..tx.clone() }, felt!("0x1")),
+        Ok(())
+    );
+    assert_matches!(fcfs_mempool.insert_tx(other_account.clone(), felt!("0x1")), Ok(()));
+
+    assert_eq!(fcfs_mempool.remove_account_txs(felt!("0x123")), 3);
+    assert_eq!(fcfs_mempool.transactions(), [other_account].into());
+    assert_eq!(fcfs_mempool.remove_account_txs(felt!("0x123")), 0);
+}
+
+#[rstest]
+fn test_transactions_by_arrival_and_stats(mut fcfs_mempool: MempoolTester) {
+    let tx = TestTx {
+        nonce: felt!("0x1"),
+        contract_address: felt!("0x123"),
+        arrived_at: 3000,
+        tip: None,
+        tx_hash: felt!("0x1"),
+        is_declare: false,
+    };
+    // In sequence with the first tx.
+    let tx_next = TestTx { nonce: felt!("0x2"), arrived_at: 1000, tx_hash: felt!("0x2"), ..tx.clone() };
+    // Nonce gap.
+    let tx_gap = TestTx { nonce: felt!("0x4"), arrived_at: 2000, tx_hash: felt!("0x3"), ..tx.clone() };
+    let declare = TestTx {
+        contract_address: felt!("0x456"),
+        arrived_at: 4000,
+        tx_hash: felt!("0x4"),
+        is_declare: true,
+        ..tx.clone()
+    };
+
+    for tx in [tx, tx_next, tx_gap, declare] {
+        assert_matches!(fcfs_mempool.insert_tx(tx, felt!("0x1")), Ok(()));
+    }
+
+    assert_eq!(
+        fcfs_mempool.transactions_by_arrival(),
+        vec![
+            (felt!("0x2"), MempoolTxStatus::Ready),
+            (felt!("0x3"), MempoolTxStatus::Pending),
+            (felt!("0x1"), MempoolTxStatus::Ready),
+            (felt!("0x4"), MempoolTxStatus::Ready),
+        ]
+    );
+
+    let stats = fcfs_mempool.inner.stats();
+    assert_eq!(stats.transactions, 4);
+    assert_eq!(stats.accounts, 2);
+    assert_eq!(stats.ready_accounts, 2);
+    assert_eq!(stats.invoke_transactions, 3);
+    assert_eq!(stats.declare_transactions, 1);
+    assert_eq!(stats.max_transactions, 4);
+    assert_eq!(stats.max_declare_transactions, Some(2));
+    assert_eq!(stats.oldest_tx_arrived_at, Some(TxTimestamp(1000)));
+}
@@ -39,4 +39,13 @@ impl TimestampQueue {
         // Oldest is first (min `arrived_at`)
         self.0.first().filter(|tx| tx.0 < ts).map(|e| &e.1)
     }
+
+    /// All transactions, oldest first.
+    pub fn iter(&self) -> impl Iterator<Item = &TxKey> {
+        self.0.iter().map(|e| &e.1)
+    }
+
+    pub fn oldest(&self) -> Option<TxTimestamp> {
+        self.0.first().map(|e| e.0)
+    }
 }
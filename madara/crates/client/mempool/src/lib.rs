@@ -11,7 +11,7 @@ use mp_state_update::NonceUpdate;
 use mp_transactions::validated::{TxTimestamp, ValidatedMempoolTx, ValidatedToBlockifierTxError};
 use mp_utils::service::ServiceContext;
 use notify::MempoolInnerWithNotify;
-use starknet_api::core::Nonce;
+use starknet_api::{core::Nonce, transaction::TransactionHash};
 use starknet_types_core::felt::Felt;
 use std::borrow::Cow;
 use std::collections::HashSet;
@@ -63,6 +63,15 @@ impl MempoolConfig {
     }
 }
 
+/// Nonces of an account with transactions in the mempool.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MempoolAccountNonces {
+    pub contract_address: Felt,
+    pub account_nonce: Felt,
+    /// Nonces of the queued transactions, in ascending order.
+    pub queued_nonces: Vec<Felt>,
+}
+
 pub struct Mempool {
     backend: Arc<MadaraBackend>,
     inner: MempoolInnerWithNotify,
@@ -205,7 +214,8 @@ impl Mempool {
     }
 
     /// Update secondary state when a new transaction has been successfully removed from the mempool.
-    fn on_txs_removed(&self, removed: &[ValidatedMempoolTx]) {
+    /// This is also called by block production for the transactions it pops from the mempool but does not execute.
+    pub fn on_txs_removed(&self, removed: &[ValidatedMempoolTx]) {
         if self.config.save_to_db {
             if let Err(err) = self.backend.remove_mempool_transactions(removed.iter().map(|tx| tx.tx_hash)) {
                 tracing::error!("Could not remove mempool transactions from database: {err:#}");
@@ -303,6 +313,68 @@ impl Mempool {
         lock.get_transaction(&contract_address.try_into().ok()?, &Nonce(nonce)).map(f)
     }
 
+    /// Transactions in the mempool, oldest first, optionally filtered by status.
+    pub async fn list_transactions<R>(
+        &self,
+        status: Option<MempoolTxStatus>,
+        offset: usize,
+        limit: usize,
+        mut f: impl FnMut(&ValidatedMempoolTx, MempoolTxStatus) -> R,
+    ) -> Vec<R> {
+        let lock = self.inner.read().await;
+        lock.transactions_by_arrival()
+            .filter(|(_, tx_status)| status.is_none_or(|status| status == *tx_status))
+            .skip(offset)
+            .take(limit)
+            .map(|(tx, tx_status)| f(tx, tx_status))
+            .collect()
+    }
+
+    pub async fn get_transaction_by_hash<R>(
+        &self,
+        tx_hash: Felt,
+        f: impl FnOnce(&ValidatedMempoolTx, MempoolTxStatus) -> R,
+    ) -> Option<R> {
+        let lock = self.inner.read().await;
+        lock.get_transaction_with_status_by_hash(&TransactionHash(tx_hash)).map(|(tx, status)| f(tx, status))
+    }
+
+    /// Account nonce and queued transaction nonces for every account in the mempool.
+    pub async fn account_nonces(&self) -> Vec<MempoolAccountNonces> {
+        let lock = self.inner.read().await;
+        lock.account_nonces()
+            .map(|(contract_address, account_nonce)| MempoolAccountNonces {
+                contract_address: contract_address.to_felt(),
+                account_nonce: account_nonce.to_felt(),
+                queued_nonces: lock.queued_nonces(contract_address).map(|nonce| nonce.to_felt()).collect(),
+            })
+            .collect()
+    }
+
+    pub async fn stats(&self) -> MempoolStats {
+        self.inner.read().await.stats()
+    }
+
+    /// Evict a transaction from the mempool. Returns false if the transaction is not in the mempool.
+    #[tracing::instrument(skip(self), fields(module = "Mempool"))]
+    pub async fn remove_transaction(&self, tx_hash: Felt) -> bool {
+        let mut removed_txs = smallvec::SmallVec::<[ValidatedMempoolTx; 1]>::new();
+        let removed = self.inner.write().await.remove_tx_by_hash(&TransactionHash(tx_hash), &mut removed_txs);
+        self.on_txs_removed(&removed_txs);
+        removed
+    }
+
+    /// Evict all of the transactions of an account from the mempool. Returns the number of removed transactions.
+    #[tracing::instrument(skip(self), fields(module = "Mempool"))]
+    pub async fn remove_account_transactions(&self, contract_address: Felt) -> usize {
+        // Out of range addresses cannot have transactions in the mempool.
+        let Ok(contract_address) = contract_address.try_into() else { return 0 };
+        let mut removed_txs = Vec::new();
+        self.inner.write().await.remove_account_txs(&contract_address, &mut removed_txs);
+        self.on_txs_removed(&removed_txs);
+        removed_txs.len()
+    }
+
     #[tracing::instrument(skip(self), fields(module = "Mempool"))]
     /// Returns a view of the mempool intended for consuming transactions from the mempool.
     /// If the mempool has no mempool that can be consumed, this function will wait until there is at least 1 transaction to consume.
@@ -413,4 +485,27 @@ pub(crate) mod tests {
 
         mempool.inner.read().await.check_invariants();
     }
+
+    #[rstest::rstest]
+    #[timeout(Duration::from_millis(1_000))]
+    #[tokio::test]
+    async fn mempool_remove_tx_removes_from_db(
+        #[future] backend: Arc<mc_db::MadaraBackend>,
+        tx_account: ValidatedMempoolTx,
+    ) {
+        let backend = backend.await;
+        let mempool = Mempool::new(Arc::clone(&backend), MempoolConfig::default());
+        let tx_hash = tx_account.tx_hash;
+        mempool.accept_tx(tx_account).await.unwrap();
+        assert_eq!(backend.get_mempool_transactions().count(), 1);
+        assert_eq!(mempool.get_transaction_by_hash(tx_hash, |_, status| status).await, Some(MempoolTxStatus::Ready));
+
+        assert!(!mempool.remove_transaction(Felt::ONE + tx_hash).await);
+        assert!(mempool.remove_transaction(tx_hash).await);
+        assert!(mempool.is_empty().await);
+        assert_eq!(backend.get_mempool_transactions().count(), 0);
+        assert_eq!(mempool.received_transaction(tx_hash).await, Some(false));
+
+        mempool.inner.read().await.check_invariants();
+    }
 }
@@ -19,6 +19,7 @@ use mp_chain_config::ChainConfig;
 use mp_convert::ToFelt;
 use mp_utils::service::ServiceContext;
 use starknet_types_core::felt::Felt;
+use std::path::PathBuf;
 use std::sync::Arc;
 use utils::ResultExt;
 
@@ -49,6 +50,10 @@ pub struct Starknet {
     pub(crate) add_transaction_provider: Arc<dyn SubmitTransaction>,
     storage_proof_config: StorageProofConfig,
     pub(crate) block_prod_handle: Option<mc_block_production::BlockProductionHandle>,
+    /// Only set when the node runs its own mempool, for the mempool admin methods.
+    pub(crate) mempool: Option<Arc<mc_mempool::Mempool>>,
+    /// Only set when the devnet state dumps are enabled. The dump files are kept in this directory.
+    pub(crate) devnet_state_dir: Option<PathBuf>,
     pub ctx: ServiceContext,
 }
 
@@ -61,7 +66,26 @@ impl Starknet {
         ctx: ServiceContext,
     ) -> Self {
         let ws_handles = Arc::new(WsSubscribeHandles::new());
-        Self { backend, ws_handles, add_transaction_provider, storage_proof_config, block_prod_handle, ctx }
+        Self {
+            backend,
+            ws_handles,
+            add_transaction_provider,
+            storage_proof_config,
+            block_prod_handle,
+            mempool: None,
+            devnet_state_dir: None,
+            ctx,
+        }
+    }
+
+    pub fn with_mempool(mut self, mempool: Option<Arc<mc_mempool::Mempool>>) -> Self {
+        self.mempool = mempool;
+        self
+    }
+
+    pub fn with_devnet_state_dir(mut self, devnet_state_dir: Option<PathBuf>) -> Self {
+        self.devnet_state_dir = devnet_state_dir;
+        self
     }
 
     pub fn clone_backend(&self) -> Arc<MadaraBackend> {
@@ -111,6 +135,34 @@ impl Starknet {
             .or_internal_server_error("Error getting L1 last confirmed block")?
             .unwrap_or_default())
     }
+
+    /// Converts a v0.9 block id to a block id of the backend. The `pre_confirmed` tag refers to the pending block,
+    /// and the `l1_accepted` tag to the last block confirmed on L1.
+    pub fn resolve_block_id_v0_9_0(&self, block_id: mp_rpc::v0_9_0::BlockId) -> StarknetRpcResult<BlockId> {
+        use mp_rpc::v0_9_0::{BlockId as BlockIdV0_9_0, BlockTag as BlockTagV0_9_0};
+
+        Ok(match block_id {
+            BlockIdV0_9_0::Hash(block_hash) => BlockId::Hash(block_hash),
+            BlockIdV0_9_0::Number(block_n) => BlockId::Number(block_n),
+            BlockIdV0_9_0::Tag(BlockTagV0_9_0::Latest) => BlockId::Tag(BlockTag::Latest),
+            BlockIdV0_9_0::Tag(BlockTagV0_9_0::PreConfirmed) => BlockId::Tag(BlockTag::Pending),
+            BlockIdV0_9_0::Tag(BlockTagV0_9_0::L1Accepted) => BlockId::Number(
+                self.backend
+                    .get_l1_last_confirmed_block()
+                    .or_internal_server_error("Error getting L1 last confirmed block")?
+                    .ok_or(StarknetRpcApiError::BlockNotFound)?,
+            ),
+        })
+    }
+
+    /// The number of the pre-confirmed block, which follows the latest block.
+    pub fn pre_confirmed_block_n(&self) -> StarknetRpcResult<u64> {
+        Ok(self
+            .backend
+            .get_latest_block_n()
+            .or_internal_server_error("Error getting latest block number")?
+            .map_or(0, |block_n| block_n + 1))
+    }
 }
 
 /// Returns the RpcModule merged with all the supported RPC versions.
@@ -119,8 +171,10 @@ pub fn rpc_api_user(starknet: &Starknet) -> anyhow::Result<RpcModule<()>> {
 
     rpc_api.merge(versions::user::v0_7_1::StarknetReadRpcApiV0_7_1Server::into_rpc(starknet.clone()))?;
     rpc_api.merge(versions::user::v0_8_0::StarknetReadRpcApiV0_8_0Server::into_rpc(starknet.clone()))?;
+    rpc_api.merge(versions::user::v0_9_0::StarknetReadRpcApiV0_9_0Server::into_rpc(starknet.clone()))?;
     rpc_api.merge(versions::user::v0_7_1::StarknetWriteRpcApiV0_7_1Server::into_rpc(starknet.clone()))?;
     rpc_api.merge(versions::user::v0_7_1::StarknetTraceRpcApiV0_7_1Server::into_rpc(starknet.clone()))?;
+    rpc_api.merge(versions::user::v0_9_0::StarknetTraceRpcApiV0_9_0Server::into_rpc(starknet.clone()))?;
     rpc_api.merge(versions::user::v0_8_0::StarknetWsRpcApiV0_8_0Server::into_rpc(starknet.clone()))?;
 
     Ok(rpc_api)
@@ -132,6 +186,17 @@ pub fn rpc_api_admin(starknet: &Starknet) -> anyhow::Result<RpcModule<()>> {
     rpc_api.merge(versions::admin::v0_1_0::MadaraWriteRpcApiV0_1_0Server::into_rpc(starknet.clone()))?;
     rpc_api.merge(versions::admin::v0_1_0::MadaraStatusRpcApiV0_1_0Server::into_rpc(starknet.clone()))?;
     rpc_api.merge(versions::admin::v0_1_0::MadaraServicesRpcApiV0_1_0Server::into_rpc(starknet.clone()))?;
+    rpc_api.merge(versions::admin::v0_1_0::MadaraMempoolRpcApiV0_1_0Server::into_rpc(starknet.clone()))?;
+    rpc_api.merge(versions::admin::v0_1_0::MadaraPolicyRpcApiV0_1_0Server::into_rpc(starknet.clone()))?;
+
+    Ok(rpc_api)
+}
+
+/// Test helpers for the devnet. These must never be exposed outside of devnet mode.
+pub fn rpc_api_devnet(starknet: &Starknet) -> anyhow::Result<RpcModule<()>> {
+    let mut rpc_api = RpcModule::new(());
+
+    rpc_api.merge(versions::admin::v0_1_0::DevnetRpcApiV0_1_0Server::into_rpc(starknet.clone()))?;
 
     Ok(rpc_api)
 }
@@ -216,3 +281,44 @@ impl Drop for WsSubscriptionGuard {
         self.map.remove(&self.id);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::rpc_test_setup;
+    use mp_rpc::v0_9_0::{BlockId as BlockIdV0_9_0, BlockTag as BlockTagV0_9_0};
+    use rstest::rstest;
+
+    #[rstest]
+    fn test_resolve_block_id_v0_9_0(rpc_test_setup: (Arc<MadaraBackend>, Starknet)) {
+        let (backend, rpc) = rpc_test_setup;
+
+        assert_eq!(rpc.resolve_block_id_v0_9_0(BlockIdV0_9_0::Number(4)).unwrap(), BlockId::Number(4));
+        assert_eq!(rpc.resolve_block_id_v0_9_0(BlockIdV0_9_0::Hash(Felt::ONE)).unwrap(), BlockId::Hash(Felt::ONE));
+        assert_eq!(
+            rpc.resolve_block_id_v0_9_0(BlockIdV0_9_0::Tag(BlockTagV0_9_0::Latest)).unwrap(),
+            BlockId::Tag(BlockTag::Latest)
+        );
+        assert_eq!(
+            rpc.resolve_block_id_v0_9_0(BlockIdV0_9_0::Tag(BlockTagV0_9_0::PreConfirmed)).unwrap(),
+            BlockId::Tag(BlockTag::Pending)
+        );
+
+        // No block has been confirmed on L1 yet
+        assert_eq!(
+            rpc.resolve_block_id_v0_9_0(BlockIdV0_9_0::Tag(BlockTagV0_9_0::L1Accepted)),
+            Err(StarknetRpcApiError::BlockNotFound)
+        );
+        // The tag follows the last block confirmed on L1
+        backend.write_last_confirmed_block(2).unwrap();
+        assert_eq!(
+            rpc.resolve_block_id_v0_9_0(BlockIdV0_9_0::Tag(BlockTagV0_9_0::L1Accepted)).unwrap(),
+            BlockId::Number(2)
+        );
+        backend.write_last_confirmed_block(5).unwrap();
+        assert_eq!(
+            rpc.resolve_block_id_v0_9_0(BlockIdV0_9_0::Tag(BlockTagV0_9_0::L1Accepted)).unwrap(),
+            BlockId::Number(5)
+        );
+    }
+}
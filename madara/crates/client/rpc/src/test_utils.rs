@@ -57,7 +57,8 @@ impl SubmitTransaction for TestTransactionProvider {
         unimplemented!()
     }
     async fn received_transaction(&self, _hash: mp_convert::Felt) -> Option<bool> {
-        unimplemented!()
+        // The test mempool never holds any transaction.
+        Some(false)
     }
     async fn subscribe_new_transactions(&self) -> Option<tokio::sync::broadcast::Receiver<mp_convert::Felt>> {
         unimplemented!()
@@ -223,6 +224,8 @@ pub fn make_sample_chain_for_block_getters(backend: &MadaraBackend) -> SampleCha
                                 strk_l1_gas_price: 12,
                                 eth_l1_data_gas_price: 44,
                                 strk_l1_data_gas_price: 52,
+                                eth_l2_gas_price: 0,
+                                strk_l2_gas_price: 0,
                             },
                             l1_da_mode: L1DataAvailabilityMode::Blob,
                         },
@@ -1,11 +1,14 @@
 use jsonrpsee::core::RpcResult;
 use m_proc_macros::versioned_rpc;
+use mp_block::{header::GasPrices, BlockId};
+use mp_chain_config::TransactionPolicyConfig;
 use mp_rpc::{
     admin::BroadcastedDeclareTxnV0, AddInvokeTransactionResult, BroadcastedDeclareTxn, BroadcastedDeployAccountTxn,
-    BroadcastedInvokeTxn, ClassAndTxnHash, ContractAndTxnHash,
+    BroadcastedInvokeTxn, ClassAndTxnHash, ContractAndTxnHash, PriceUnit, Txn,
 };
 use mp_utils::service::{MadaraServiceId, MadaraServiceStatus};
 use serde::{Deserialize, Serialize};
+use starknet_types_core::felt::Felt;
 
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "snake_case")]
@@ -15,6 +18,62 @@ pub enum ServiceRequest {
     Restart,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct MintResult {
+    pub new_balance: Felt,
+    pub unit: PriceUnit,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct PredeployedAccount {
+    pub address: Felt,
+    pub class_hash: Felt,
+    pub public_key: Felt,
+    pub private_key: Felt,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MempoolTransactionStatus {
+    /// There is no nonce gap before this transaction: it will be executed once the transactions before it are.
+    Ready,
+    /// Waiting on a transaction with a lower nonce.
+    Pending,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct MempoolTransaction {
+    pub transaction_hash: Felt,
+    pub contract_address: Felt,
+    pub status: MempoolTransactionStatus,
+    /// Unix time in milliseconds.
+    pub arrived_at: u64,
+    pub transaction: Txn,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct MempoolAccountNonces {
+    pub contract_address: Felt,
+    pub account_nonce: Felt,
+    pub queued_nonces: Vec<Felt>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct MempoolStatistics {
+    pub transactions: u64,
+    pub accounts: u64,
+    pub ready_accounts: u64,
+    pub invoke_transactions: u64,
+    pub declare_transactions: u64,
+    pub deploy_account_transactions: u64,
+    pub deploy_transactions: u64,
+    pub l1_handler_transactions: u64,
+    pub max_transactions: u64,
+    pub max_declare_transactions: Option<u64>,
+    /// Time since the oldest transaction arrived, in milliseconds.
+    pub oldest_transaction_age: Option<u64>,
+}
+
 /// This is an admin method, so semver is different!
 #[versioned_rpc("V0_1_0", "madara")]
 pub trait MadaraWriteRpcApi {
@@ -92,4 +151,116 @@ pub trait MadaraServicesRpcApi {
     /// * 'on' if any service was active before being toggled, 'off' otherwise.
     #[method(name = "service")]
     async fn service(&self, service: Vec<MadaraServiceId>, status: ServiceRequest) -> RpcResult<MadaraServiceStatus>;
+
+    /// Reverts the chain so that the given block becomes the latest block, dropping every block after it.
+    ///
+    /// Block production and sync are stopped while the revert is taking place, and restarted afterwards if they were
+    /// running. This is only possible up to `--db-max-saved-trie-logs` blocks back.
+    #[method(name = "revertTo")]
+    async fn revert_to(&self, block_id: BlockId) -> RpcResult<()>;
+}
+
+#[versioned_rpc("V0_1_0", "madara")]
+pub trait MadaraMempoolRpcApi {
+    /// Lists the transactions in the mempool, oldest first. Passing a status only lists the transactions with that
+    /// status.
+    #[method(name = "mempoolTransactions")]
+    async fn mempool_transactions(
+        &self,
+        status: Option<MempoolTransactionStatus>,
+        offset: u64,
+        limit: u64,
+    ) -> RpcResult<Vec<MempoolTransaction>>;
+
+    /// Gets a transaction from the mempool by its hash.
+    #[method(name = "mempoolTransactionByHash")]
+    async fn mempool_transaction_by_hash(&self, transaction_hash: Felt) -> RpcResult<MempoolTransaction>;
+
+    /// Lists the accounts with transactions in the mempool, with their nonce and the nonces of their queued
+    /// transactions.
+    #[method(name = "mempoolAccountNonces")]
+    async fn mempool_account_nonces(&self) -> RpcResult<Vec<MempoolAccountNonces>>;
+
+    /// Number of transactions in the mempool per type, compared to the mempool limits.
+    #[method(name = "mempoolStats")]
+    async fn mempool_stats(&self) -> RpcResult<MempoolStatistics>;
+
+    /// Evicts a transaction from the mempool. The transactions of the account with a higher nonce stay in the mempool
+    /// until the nonce gap is filled.
+    #[method(name = "mempoolRemoveTransaction")]
+    async fn mempool_remove_transaction(&self, transaction_hash: Felt) -> RpcResult<()>;
+
+    /// Evicts all of the transactions of an account from the mempool.
+    ///
+    /// # Returns
+    ///
+    /// * The number of evicted transactions.
+    #[method(name = "mempoolRemoveAccountTransactions")]
+    async fn mempool_remove_account_transactions(&self, contract_address: Felt) -> RpcResult<u64>;
+}
+
+#[versioned_rpc("V0_1_0", "madara")]
+pub trait MadaraPolicyRpcApi {
+    /// Gets the transaction policies currently enforced by the sequencer.
+    #[method(name = "getTransactionPolicies")]
+    async fn get_transaction_policies(&self) -> RpcResult<TransactionPolicyConfig>;
+
+    /// Replaces all of the transaction policies, which also applies to the transactions already in the mempool. Rate
+    /// limits start over. This is not persisted: the policies from the chain config are loaded again on restart.
+    #[method(name = "setTransactionPolicies")]
+    async fn set_transaction_policies(&self, policies: TransactionPolicyConfig) -> RpcResult<()>;
+}
+
+/// Test helpers, only available in devnet mode.
+#[versioned_rpc("V0_1_0", "devnet")]
+pub trait DevnetRpcApi {
+    /// Mints fee tokens to an address, by adding to its ERC-20 balance and to the total supply. This happens in a new
+    /// block which is closed right away, after the current block is closed. The amount is at most a u128, and minting
+    /// fails if the balance or the total supply overflows a u256.
+    ///
+    /// # Returns
+    ///
+    /// * The new balance of the address.
+    #[method(name = "mint")]
+    async fn mint(&self, address: Felt, amount: Felt, unit: PriceUnit) -> RpcResult<MintResult>;
+
+    /// Sets the unix timestamp of the next block, in seconds. Blocks after it keep advancing from there.
+    #[method(name = "setTime")]
+    async fn set_time(&self, time: u64) -> RpcResult<()>;
+
+    /// Moves the timestamp of the next blocks forward by a number of seconds.
+    #[method(name = "increaseTime")]
+    async fn increase_time(&self, time: u64) -> RpcResult<()>;
+
+    /// Sets the L1 gas prices of the next blocks. Passing `null` goes back to the gas prices followed from L1.
+    #[method(name = "setGasPrices")]
+    async fn set_gas_prices(&self, gas_prices: Option<GasPrices>) -> RpcResult<()>;
+
+    /// Accepts transactions from an account without running its `__validate__` entrypoint, so that they can be sent
+    /// without a valid signature.
+    #[method(name = "impersonateAccount")]
+    async fn impersonate_account(&self, address: Felt) -> RpcResult<()>;
+
+    /// Stops impersonating an account.
+    #[method(name = "stopImpersonateAccount")]
+    async fn stop_impersonate_account(&self, address: Felt) -> RpcResult<()>;
+
+    /// Closes the current block, or creates an empty block if none is being built.
+    #[method(name = "createBlock")]
+    async fn create_block(&self) -> RpcResult<()>;
+
+    /// Lists the accounts predeployed in the devnet genesis, with their private keys.
+    #[method(name = "getPredeployedAccounts")]
+    async fn get_predeployed_accounts(&self) -> RpcResult<Vec<PredeployedAccount>>;
+
+    /// Writes the closed blocks of the chain and the predeployed accounts to a file of the node's devnet state
+    /// directory, see `--rpc-devnet-state-dir`. The file name cannot hold a path.
+    #[method(name = "dumpState")]
+    async fn dump_state(&self, file_name: String) -> RpcResult<()>;
+
+    /// Loads a file written by `devnet_dumpState` from the node's devnet state directory. Blocks the chain has in
+    /// common with the dump are kept, and the chain is reverted to the last of them before replaying the rest of the
+    /// dump. Block production is paused meanwhile.
+    #[method(name = "loadState")]
+    async fn load_state(&self, file_name: String) -> RpcResult<()>;
 }
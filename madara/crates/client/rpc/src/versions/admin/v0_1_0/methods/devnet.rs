@@ -0,0 +1,250 @@
+use super::services::with_chain_head_services_stopped;
+use crate::{
+    utils::{display_internal_server_error, ResultExt},
+    versions::admin::v0_1_0::{DevnetRpcApiV0_1_0Server, MintResult, PredeployedAccount},
+    Starknet, StarknetRpcApiError,
+};
+use anyhow::Context;
+use jsonrpsee::core::{async_trait, RpcResult};
+use mc_block_production::{BlockProductionHandle, BlockTimestampUpdate, ExecutorCommandError};
+use mc_devnet::DevnetStateDump;
+use mp_block::header::GasPrices;
+use mp_rpc::PriceUnit;
+use starknet_api::{
+    abi::abi_utils::{get_fee_token_var_address, get_storage_var_address},
+    core::ContractAddress,
+};
+use starknet_types_core::felt::Felt;
+use std::path::{Component, Path, PathBuf};
+
+impl Starknet {
+    fn devnet_block_prod_handle(&self) -> RpcResult<&BlockProductionHandle> {
+        Ok(self.block_prod_handle.as_ref().ok_or(StarknetRpcApiError::UnimplementedMethod)?)
+    }
+
+    /// State dumps are only read and written in the configured directory, so the file name must not hold a path.
+    fn devnet_state_path(&self, file_name: &str) -> RpcResult<PathBuf> {
+        let dir = self.devnet_state_dir.as_ref().ok_or(StarknetRpcApiError::UnimplementedMethod)?;
+        let mut components = Path::new(file_name).components();
+        match (components.next(), components.next()) {
+            (Some(Component::Normal(name)), None) => Ok(dir.join(name)),
+            _ => Err(invalid_params("The state dump must be a file name, without a path")),
+        }
+    }
+}
+
+fn unexpected_error(err: anyhow::Error) -> StarknetRpcApiError {
+    StarknetRpcApiError::ErrUnexpectedError { error: format!("{err:#}").into() }
+}
+
+fn invalid_params(message: impl Into<String>) -> jsonrpsee::types::ErrorObjectOwned {
+    jsonrpsee::types::ErrorObject::owned(jsonrpsee::types::ErrorCode::InvalidParams.code(), message.into(), Some(()))
+}
+
+/// Commands rejected because of their parameters are reported as invalid params.
+fn command_error(err: ExecutorCommandError, context: &str) -> jsonrpsee::types::ErrorObjectOwned {
+    match err {
+        ExecutorCommandError::ChannelClosed => {
+            display_internal_server_error(format!("{context}: {err:#}"));
+            StarknetRpcApiError::InternalServerError.into()
+        }
+        ExecutorCommandError::BlockTimestampOutOfRange | ExecutorCommandError::StorageOverflow => {
+            invalid_params(err.to_string())
+        }
+    }
+}
+
+#[async_trait]
+impl DevnetRpcApiV0_1_0Server for Starknet {
+    #[tracing::instrument(skip(self), fields(module = "Devnet"))]
+    async fn mint(&self, address: Felt, amount: Felt, unit: PriceUnit) -> RpcResult<MintResult> {
+        let contract_address =
+            ContractAddress::try_from(address).map_err(|_| invalid_params("Contract address out of range"))?;
+        let amount = u128::try_from(amount).map_err(|_| invalid_params("Amount does not fit in a u128"))?;
+
+        let chain_config = self.clone_chain_config();
+        let fee_token_address = match unit {
+            PriceUnit::Fri => chain_config.native_fee_token_address,
+            PriceUnit::Wei => chain_config.parent_fee_token_address,
+        };
+        // The balances and the total supply are u256, stored in the storage of the OpenZeppelin ERC20 component.
+        let balance_key = get_fee_token_var_address(contract_address);
+        let total_supply_key = get_storage_var_address("ERC20_total_supply", &[]);
+
+        let (low, high) = self
+            .devnet_block_prod_handle()?
+            .increase_storage(vec![
+                ((fee_token_address, balance_key), amount),
+                ((fee_token_address, total_supply_key), amount),
+            ])
+            .await
+            .map_err(|err| command_error(err, "Minting fee tokens"))?
+            .into_iter()
+            .next()
+            .ok_or(StarknetRpcApiError::InternalServerError)?;
+        // A balance does not come close to the felt range.
+        let new_balance = Felt::from(high) * Felt::TWO.pow(128u32) + Felt::from(low);
+
+        tracing::info!("💸 Minted {amount} {unit:?} to {address:#x}");
+        Ok(MintResult { new_balance, unit })
+    }
+
+    async fn set_time(&self, time: u64) -> RpcResult<()> {
+        Ok(self
+            .devnet_block_prod_handle()?
+            .set_block_timestamp(BlockTimestampUpdate::Set(time))
+            .await
+            .map_err(|err| command_error(err, "Setting block timestamp"))?)
+    }
+
+    async fn increase_time(&self, time: u64) -> RpcResult<()> {
+        Ok(self
+            .devnet_block_prod_handle()?
+            .set_block_timestamp(BlockTimestampUpdate::Increase(time))
+            .await
+            .map_err(|err| command_error(err, "Increasing block timestamp"))?)
+    }
+
+    async fn set_gas_prices(&self, gas_prices: Option<GasPrices>) -> RpcResult<()> {
+        Ok(self
+            .devnet_block_prod_handle()?
+            .set_gas_prices(gas_prices)
+            .await
+            .or_internal_server_error("Setting gas prices")?)
+    }
+
+    async fn impersonate_account(&self, address: Felt) -> RpcResult<()> {
+        self.devnet_block_prod_handle()?.impersonated_accounts().insert(address);
+        tracing::info!("🥸 Impersonating account {address:#x}");
+        Ok(())
+    }
+
+    async fn stop_impersonate_account(&self, address: Felt) -> RpcResult<()> {
+        self.devnet_block_prod_handle()?.impersonated_accounts().remove(&address);
+        Ok(())
+    }
+
+    async fn create_block(&self) -> RpcResult<()> {
+        Ok(self.devnet_block_prod_handle()?.close_block().await.or_internal_server_error("Creating block")?)
+    }
+
+    async fn get_predeployed_accounts(&self) -> RpcResult<Vec<PredeployedAccount>> {
+        let keys = self
+            .backend
+            .get_devnet_predeployed_keys()
+            .or_internal_server_error("Getting devnet predeployed keys")?
+            .map(|keys| keys.0)
+            .unwrap_or_default();
+
+        Ok(keys
+            .into_iter()
+            .map(|account| PredeployedAccount {
+                address: account.address,
+                class_hash: account.class_hash,
+                public_key: account.pubkey,
+                private_key: account.secret,
+            })
+            .collect())
+    }
+
+    #[tracing::instrument(skip(self), fields(module = "Devnet"))]
+    async fn dump_state(&self, file_name: String) -> RpcResult<()> {
+        let path = self.devnet_state_path(&file_name)?;
+        let backend = self.clone_backend();
+        let dump_path = path.clone();
+        mp_utils::rayon::global_spawn_rayon_task(move || {
+            if let Some(dir) = dump_path.parent() {
+                std::fs::create_dir_all(dir).with_context(|| format!("Creating devnet state directory {dir:?}"))?;
+            }
+            DevnetStateDump::from_backend(&backend)?.write_to_file(&dump_path)
+        })
+        .await
+        .map_err(unexpected_error)?;
+
+        tracing::info!("💾 Dumped devnet state to {path:?}");
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self), fields(module = "Devnet"))]
+    async fn load_state(&self, file_name: String) -> RpcResult<()> {
+        let path = self.devnet_state_path(&file_name)?;
+        let dump_path = path.clone();
+        let dump = mp_utils::rayon::global_spawn_rayon_task(move || DevnetStateDump::read_from_file(&dump_path))
+            .await
+            .map_err(unexpected_error)?;
+
+        let backend = self.clone_backend();
+        with_chain_head_services_stopped(&self.ctx, async move { dump.load_into(&backend).await })
+            .await?
+            .map_err(unexpected_error)?;
+
+        tracing::info!("📥 Loaded devnet state from {path:?}");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::rpc_test_setup;
+    use mc_db::{
+        devnet_db::{DevnetPredeployedContractAccount, DevnetPredeployedKeys},
+        MadaraBackend,
+    };
+    use std::sync::Arc;
+
+    #[rstest::rstest]
+    #[tokio::test]
+    async fn test_get_predeployed_accounts(rpc_test_setup: (Arc<MadaraBackend>, Starknet)) {
+        let (backend, rpc) = rpc_test_setup;
+        assert_eq!(rpc.get_predeployed_accounts().await.unwrap(), vec![]);
+
+        backend
+            .set_devnet_predeployed_keys(DevnetPredeployedKeys(vec![DevnetPredeployedContractAccount {
+                address: Felt::from(1),
+                secret: Felt::from(2),
+                pubkey: Felt::from(3),
+                class_hash: Felt::from(4),
+            }]))
+            .unwrap();
+        assert_eq!(
+            rpc.get_predeployed_accounts().await.unwrap(),
+            vec![PredeployedAccount {
+                address: Felt::from(1),
+                class_hash: Felt::from(4),
+                public_key: Felt::from(3),
+                private_key: Felt::from(2),
+            }]
+        );
+    }
+
+    #[rstest::rstest]
+    #[tokio::test]
+    async fn test_devnet_methods_need_block_production(rpc_test_setup: (Arc<MadaraBackend>, Starknet)) {
+        let (_backend, rpc) = rpc_test_setup;
+        assert!(rpc.create_block().await.is_err());
+        assert!(rpc.impersonate_account(Felt::ONE).await.is_err());
+        assert!(rpc.mint(Felt::ONE, Felt::ONE, PriceUnit::Fri).await.is_err());
+    }
+
+    #[rstest::rstest]
+    #[tokio::test]
+    async fn test_devnet_state_dumps_stay_in_state_dir(rpc_test_setup: (Arc<MadaraBackend>, Starknet)) {
+        let (_backend, rpc) = rpc_test_setup;
+        assert!(rpc.dump_state("dump.json".into()).await.is_err());
+
+        let dir = tempfile::tempdir().unwrap();
+        let state_dir = dir.path().join("devnet");
+        let rpc = rpc.with_devnet_state_dir(Some(state_dir.clone()));
+        rpc.dump_state("dump.json".into()).await.unwrap();
+        assert!(state_dir.join("dump.json").exists());
+
+        for file_name in ["", ".", "..", "../dump.json", "nested/dump.json", "/tmp/dump.json"] {
+            let err = rpc.dump_state(file_name.into()).await.unwrap_err();
+            assert_eq!(err.code(), jsonrpsee::types::ErrorCode::InvalidParams.code(), "{file_name}");
+            let err = rpc.load_state(file_name.into()).await.unwrap_err();
+            assert_eq!(err.code(), jsonrpsee::types::ErrorCode::InvalidParams.code(), "{file_name}");
+        }
+        assert!(!dir.path().join("dump.json").exists());
+    }
+}
@@ -0,0 +1,155 @@
+use crate::{
+    constants::MAX_MEMPOOL_PAGE_SIZE,
+    versions::admin::v0_1_0::{
+        MadaraMempoolRpcApiV0_1_0Server, MempoolAccountNonces, MempoolStatistics, MempoolTransaction,
+        MempoolTransactionStatus,
+    },
+    Starknet, StarknetRpcApiError,
+};
+use jsonrpsee::core::{async_trait, RpcResult};
+use mc_mempool::{Mempool, MempoolTxStatus};
+use mp_transactions::validated::{TxTimestamp, ValidatedMempoolTx};
+use starknet_types_core::felt::Felt;
+
+impl Starknet {
+    fn admin_mempool(&self) -> RpcResult<&Mempool> {
+        Ok(self.mempool.as_deref().ok_or(StarknetRpcApiError::UnimplementedMethod)?)
+    }
+}
+
+impl From<MempoolTxStatus> for MempoolTransactionStatus {
+    fn from(status: MempoolTxStatus) -> Self {
+        match status {
+            MempoolTxStatus::Ready => Self::Ready,
+            MempoolTxStatus::Pending => Self::Pending,
+        }
+    }
+}
+
+impl From<MempoolTransactionStatus> for MempoolTxStatus {
+    fn from(status: MempoolTransactionStatus) -> Self {
+        match status {
+            MempoolTransactionStatus::Ready => Self::Ready,
+            MempoolTransactionStatus::Pending => Self::Pending,
+        }
+    }
+}
+
+fn to_mempool_transaction(tx: &ValidatedMempoolTx, status: MempoolTransactionStatus) -> MempoolTransaction {
+    MempoolTransaction {
+        transaction_hash: tx.tx_hash,
+        contract_address: tx.contract_address,
+        status,
+        arrived_at: tx.arrived_at.0,
+        transaction: tx.tx.clone().into(),
+    }
+}
+
+#[async_trait]
+impl MadaraMempoolRpcApiV0_1_0Server for Starknet {
+    async fn mempool_transactions(
+        &self,
+        status: Option<MempoolTransactionStatus>,
+        offset: u64,
+        limit: u64,
+    ) -> RpcResult<Vec<MempoolTransaction>> {
+        let limit = usize::try_from(limit).unwrap_or(usize::MAX);
+        if limit > MAX_MEMPOOL_PAGE_SIZE {
+            return Err(StarknetRpcApiError::PageSizeTooBig.into());
+        }
+        let offset = usize::try_from(offset).unwrap_or(usize::MAX);
+
+        Ok(self
+            .admin_mempool()?
+            .list_transactions(status.map(Into::into), offset, limit, |tx, status| {
+                to_mempool_transaction(tx, status.into())
+            })
+            .await)
+    }
+
+    async fn mempool_transaction_by_hash(&self, transaction_hash: Felt) -> RpcResult<MempoolTransaction> {
+        Ok(self
+            .admin_mempool()?
+            .get_transaction_by_hash(transaction_hash, |tx, status| to_mempool_transaction(tx, status.into()))
+            .await
+            .ok_or(StarknetRpcApiError::TxnHashNotFound)?)
+    }
+
+    async fn mempool_account_nonces(&self) -> RpcResult<Vec<MempoolAccountNonces>> {
+        Ok(self
+            .admin_mempool()?
+            .account_nonces()
+            .await
+            .into_iter()
+            .map(|account| MempoolAccountNonces {
+                contract_address: account.contract_address,
+                account_nonce: account.account_nonce,
+                queued_nonces: account.queued_nonces,
+            })
+            .collect())
+    }
+
+    async fn mempool_stats(&self) -> RpcResult<MempoolStatistics> {
+        let stats = self.admin_mempool()?.stats().await;
+        let now = TxTimestamp::now();
+        Ok(MempoolStatistics {
+            transactions: stats.transactions as u64,
+            accounts: stats.accounts as u64,
+            ready_accounts: stats.ready_accounts as u64,
+            invoke_transactions: stats.invoke_transactions as u64,
+            declare_transactions: stats.declare_transactions as u64,
+            deploy_account_transactions: stats.deploy_account_transactions as u64,
+            deploy_transactions: stats.deploy_transactions as u64,
+            l1_handler_transactions: stats.l1_handler_transactions as u64,
+            max_transactions: stats.max_transactions as u64,
+            max_declare_transactions: stats.max_declare_transactions.map(|max| max as u64),
+            oldest_transaction_age: stats
+                .oldest_tx_arrived_at
+                .map(|arrived_at| now.duration_since(arrived_at).unwrap_or_default().as_millis() as u64),
+        })
+    }
+
+    #[tracing::instrument(skip(self), fields(module = "Admin"))]
+    async fn mempool_remove_transaction(&self, transaction_hash: Felt) -> RpcResult<()> {
+        if !self.admin_mempool()?.remove_transaction(transaction_hash).await {
+            return Err(StarknetRpcApiError::TxnHashNotFound.into());
+        }
+        tracing::info!("🗑️ Removed transaction {transaction_hash:#x} from the mempool");
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self), fields(module = "Admin"))]
+    async fn mempool_remove_account_transactions(&self, contract_address: Felt) -> RpcResult<u64> {
+        let removed = self.admin_mempool()?.remove_account_transactions(contract_address).await;
+        tracing::info!("🗑️ Removed {removed} transactions of {contract_address:#x} from the mempool");
+        Ok(removed as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::rpc_test_setup;
+    use mc_db::MadaraBackend;
+    use mc_mempool::MempoolConfig;
+    use std::sync::Arc;
+
+    #[rstest::rstest]
+    #[tokio::test]
+    async fn test_mempool_methods(rpc_test_setup: (Arc<MadaraBackend>, Starknet)) {
+        let (backend, rpc) = rpc_test_setup;
+        assert!(rpc.mempool_stats().await.is_err());
+
+        let rpc = rpc.with_mempool(Some(Arc::new(Mempool::new(backend, MempoolConfig::default()))));
+        assert_eq!(rpc.mempool_transactions(None, 0, 10).await.unwrap(), vec![]);
+        assert!(rpc.mempool_transactions(None, 0, MAX_MEMPOOL_PAGE_SIZE as u64 + 1).await.is_err());
+        assert_eq!(rpc.mempool_account_nonces().await.unwrap(), vec![]);
+        assert!(rpc.mempool_transaction_by_hash(Felt::ONE).await.is_err());
+        assert!(rpc.mempool_remove_transaction(Felt::ONE).await.is_err());
+        assert_eq!(rpc.mempool_remove_account_transactions(Felt::ONE).await.unwrap(), 0);
+
+        let stats = rpc.mempool_stats().await.unwrap();
+        assert_eq!(stats.transactions, 0);
+        assert_eq!(stats.oldest_transaction_age, None);
+    }
+}
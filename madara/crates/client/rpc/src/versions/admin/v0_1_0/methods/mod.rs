@@ -1,3 +1,6 @@
+pub mod devnet;
+pub mod mempool;
+pub mod policy;
 pub mod services;
 pub mod status;
 pub mod write;
@@ -0,0 +1,51 @@
+use crate::{versions::admin::v0_1_0::MadaraPolicyRpcApiV0_1_0Server, Starknet, StarknetRpcApiError};
+use jsonrpsee::core::{async_trait, RpcResult};
+use mc_submit_tx::TransactionPolicies;
+use mp_chain_config::TransactionPolicyConfig;
+
+impl Starknet {
+    fn admin_transaction_policies(&self) -> RpcResult<&TransactionPolicies> {
+        Ok(self
+            .block_prod_handle
+            .as_ref()
+            .map(|handle| handle.transaction_policies())
+            .ok_or(StarknetRpcApiError::UnimplementedMethod)?)
+    }
+}
+
+#[async_trait]
+impl MadaraPolicyRpcApiV0_1_0Server for Starknet {
+    async fn get_transaction_policies(&self) -> RpcResult<TransactionPolicyConfig> {
+        Ok(self.admin_transaction_policies()?.config())
+    }
+
+    #[tracing::instrument(skip(self), fields(module = "Admin"))]
+    async fn set_transaction_policies(&self, policies: TransactionPolicyConfig) -> RpcResult<()> {
+        policies.check().map_err(|err| {
+            jsonrpsee::types::ErrorObject::owned(
+                jsonrpsee::types::ErrorCode::InvalidParams.code(),
+                format!("{err:#}"),
+                Some(()),
+            )
+        })?;
+        self.admin_transaction_policies()?.replace(policies);
+        tracing::info!("📜 Transaction policies replaced");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::rpc_test_setup;
+    use mc_db::MadaraBackend;
+    use std::sync::Arc;
+
+    #[rstest::rstest]
+    #[tokio::test]
+    async fn test_policy_methods_need_block_production(rpc_test_setup: (Arc<MadaraBackend>, Starknet)) {
+        let (_backend, rpc) = rpc_test_setup;
+        assert!(rpc.get_transaction_policies().await.is_err());
+        assert!(rpc.set_transaction_policies(TransactionPolicyConfig::default()).await.is_err());
+    }
+}
@@ -1,9 +1,12 @@
 use std::time::Duration;
 
 use jsonrpsee::core::{async_trait, RpcResult};
+use mc_db::MadaraStorageError;
+use mp_block::BlockId;
 use mp_utils::service::{MadaraServiceId, MadaraServiceStatus, ServiceContext};
 
 use crate::{
+    utils::ResultExt,
     versions::admin::v0_1_0::{MadaraServicesRpcApiV0_1_0Server, ServiceRequest},
     Starknet,
 };
@@ -27,6 +30,56 @@ impl MadaraServicesRpcApiV0_1_0Server for Starknet {
             }
         }
     }
+
+    #[tracing::instrument(skip(self), fields(module = "Admin"))]
+    async fn revert_to(&self, block_id: BlockId) -> RpcResult<()> {
+        let block_n = self.get_block_n(&block_id)?;
+
+        let backend = self.clone_backend();
+        let res = with_chain_head_services_stopped(&self.ctx, async move {
+            tracing::info!("⏪ Reverting chain to block #{block_n}...");
+            mp_utils::rayon::global_spawn_rayon_task(move || backend.revert_to(block_n)).await
+        })
+        .await?;
+
+        if let Err(err) = &res {
+            if let Some(
+                err @ (MadaraStorageError::InvalidRevertTarget { .. } | MadaraStorageError::RevertTooDeep { .. }),
+            ) = err.downcast_ref::<MadaraStorageError>()
+            {
+                return Err(jsonrpsee::types::ErrorObject::owned(
+                    jsonrpsee::types::ErrorCode::InvalidParams.code(),
+                    err.to_string(),
+                    Some(()),
+                ));
+            }
+        }
+        res.or_internal_server_error("Reverting chain")?;
+
+        tracing::info!("⏪ Reverted chain to block #{block_n}");
+        Ok(())
+    }
+}
+
+/// Runs `f` once the services writing to the chain head have shut down. The services that were running are restarted
+/// afterwards, whether `f` succeeded or not.
+pub(super) async fn with_chain_head_services_stopped<T>(
+    ctx: &ServiceContext,
+    f: impl std::future::Future<Output = T>,
+) -> RpcResult<T> {
+    let services: Vec<_> = [MadaraServiceId::BlockProduction, MadaraServiceId::L2Sync]
+        .into_iter()
+        .filter(|svc| ctx.service_status(*svc).is_on())
+        .collect();
+    service_stop(ctx, &services)?;
+    for svc in &services {
+        ctx.service_stopped(*svc).await;
+    }
+
+    let res = f.await;
+
+    service_start(ctx, &services)?;
+    Ok(res)
 }
 
 fn service_start(ctx: &ServiceContext, svcs: &[MadaraServiceId]) -> RpcResult<MadaraServiceStatus> {
@@ -65,3 +118,27 @@ async fn service_restart(ctx: &ServiceContext, svcs: &[MadaraServiceId]) -> RpcR
 
     Ok(status)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{make_sample_chain_for_state_updates, rpc_test_setup};
+    use mc_db::MadaraBackend;
+    use std::sync::Arc;
+
+    #[rstest::rstest]
+    #[tokio::test]
+    async fn test_revert_to(rpc_test_setup: (Arc<MadaraBackend>, Starknet)) {
+        let (backend, rpc) = rpc_test_setup;
+        make_sample_chain_for_state_updates(&backend);
+
+        rpc.revert_to(BlockId::Number(1)).await.unwrap();
+        assert_eq!(backend.get_latest_block_n().unwrap(), Some(1));
+
+        // No trie log is kept by the test backend.
+        backend.head_status().global_trie.set_current(Some(1));
+        let err = rpc.revert_to(BlockId::Number(0)).await.unwrap_err();
+        assert_eq!(err.code(), jsonrpsee::types::ErrorCode::InvalidParams.code());
+        assert_eq!(backend.get_latest_block_n().unwrap(), Some(1));
+    }
+}
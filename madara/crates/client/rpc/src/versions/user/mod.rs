@@ -1,2 +1,3 @@
 pub mod v0_7_1;
 pub mod v0_8_0;
+pub mod v0_9_0;
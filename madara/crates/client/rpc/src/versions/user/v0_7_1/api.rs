@@ -20,21 +20,21 @@ use starknet_types_core::felt::Felt;
 #[versioned_rpc("V0_7_1", "starknet")]
 pub trait StarknetWriteRpcApi {
     /// Submit a new transaction to be added to the chain
-    #[method(name = "addInvokeTransaction", and_versions = ["V0_8_0"])]
+    #[method(name = "addInvokeTransaction", and_versions = ["V0_8_0", "V0_9_0"])]
     async fn add_invoke_transaction(
         &self,
         invoke_transaction: BroadcastedInvokeTxn,
     ) -> RpcResult<AddInvokeTransactionResult>;
 
     /// Submit a new deploy account transaction
-    #[method(name = "addDeployAccountTransaction", and_versions = ["V0_8_0"])]
+    #[method(name = "addDeployAccountTransaction", and_versions = ["V0_8_0", "V0_9_0"])]
     async fn add_deploy_account_transaction(
         &self,
         deploy_account_transaction: BroadcastedDeployAccountTxn,
     ) -> RpcResult<ContractAndTxnHash>;
 
     /// Submit a new class declaration transaction
-    #[method(name = "addDeclareTransaction", and_versions = ["V0_8_0"])]
+    #[method(name = "addDeclareTransaction", and_versions = ["V0_8_0", "V0_9_0"])]
     async fn add_declare_transaction(&self, declare_transaction: BroadcastedDeclareTxn) -> RpcResult<ClassAndTxnHash>;
 }
 
@@ -45,11 +45,11 @@ pub trait StarknetReadRpcApi {
     fn spec_version(&self) -> RpcResult<String>;
 
     /// Get the most recent accepted block number
-    #[method(name = "blockNumber", and_versions = ["V0_8_0"])]
+    #[method(name = "blockNumber", and_versions = ["V0_8_0", "V0_9_0"])]
     fn block_number(&self) -> RpcResult<u64>;
 
     // Get the most recent accepted block hash and number
-    #[method(name = "blockHashAndNumber", and_versions = ["V0_8_0"])]
+    #[method(name = "blockHashAndNumber", and_versions = ["V0_8_0", "V0_9_0"])]
     fn block_hash_and_number(&self) -> RpcResult<BlockHashAndNumber>;
 
     /// Call a contract function at a given block id
@@ -57,7 +57,7 @@ pub trait StarknetReadRpcApi {
     fn call(&self, request: FunctionCall, block_id: BlockId) -> RpcResult<Vec<Felt>>;
 
     /// Get the chain id
-    #[method(name = "chainId", and_versions = ["V0_8_0"])]
+    #[method(name = "chainId", and_versions = ["V0_8_0", "V0_9_0"])]
     fn chain_id(&self) -> RpcResult<Felt>;
 
     /// Get the number of transactions in a block given a block id
@@ -119,7 +119,7 @@ pub trait StarknetReadRpcApi {
     fn get_transaction_by_block_id_and_index(&self, block_id: BlockId, index: u64) -> RpcResult<TxnWithHash>;
 
     /// Returns the information about a transaction by transaction hash.
-    #[method(name = "getTransactionByHash", and_versions = ["V0_8_0"])]
+    #[method(name = "getTransactionByHash", and_versions = ["V0_8_0", "V0_9_0"])]
     fn get_transaction_by_hash(&self, transaction_hash: Felt) -> RpcResult<TxnWithHash>;
 
     /// Returns the receipt of a transaction by transaction hash.
@@ -131,7 +131,7 @@ pub trait StarknetReadRpcApi {
     async fn get_transaction_status(&self, transaction_hash: Felt) -> RpcResult<TxnFinalityAndExecutionStatus>;
 
     /// Get an object about the sync status, or false if the node is not syncing
-    #[method(name = "syncing", and_versions = ["V0_8_0"])]
+    #[method(name = "syncing", and_versions = ["V0_8_0", "V0_9_0"])]
     async fn syncing(&self) -> RpcResult<SyncingStatus>;
 
     /// Get the information about the result of executing the requested block
@@ -154,7 +154,7 @@ pub trait StarknetTraceRpcApi {
     /// Returns the execution traces of all transactions included in the given block
     async fn trace_block_transactions(&self, block_id: BlockId) -> RpcResult<Vec<TraceBlockTransactionsResult>>;
 
-    #[method(name = "traceTransaction", and_versions = ["V0_8_0"])]
+    #[method(name = "traceTransaction", and_versions = ["V0_8_0", "V0_9_0"])]
     /// Returns the execution trace of a transaction
     async fn trace_transaction(&self, transaction_hash: Felt) -> RpcResult<TraceTransactionResult>;
 }
@@ -9,7 +9,7 @@ use starknet_types_core::felt::Felt;
 use trace_block_transactions::trace_block_transactions;
 use trace_transaction::trace_transaction;
 
-pub(crate) mod simulate_transactions;
+pub mod simulate_transactions;
 pub mod trace_block_transactions;
 pub(crate) mod trace_transaction;
 
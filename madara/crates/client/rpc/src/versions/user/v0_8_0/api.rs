@@ -107,7 +107,7 @@ pub trait StarknetReadRpcApi {
     #[method(name = "specVersion")]
     fn spec_version(&self) -> RpcResult<String>;
 
-    #[method(name = "getCompiledCasm")]
+    #[method(name = "getCompiledCasm", and_versions = ["V0_9_0"])]
     fn get_compiled_casm(&self, class_hash: Felt) -> RpcResult<serde_json::Value>;
 
     #[method(name = "getStorageProof")]
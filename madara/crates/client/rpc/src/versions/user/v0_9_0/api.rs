@@ -0,0 +1,130 @@
+use jsonrpsee::core::RpcResult;
+use m_proc_macros::versioned_rpc;
+use mp_rpc::v0_9_0::{
+    BlockId, EventFilterWithPageRequest, L1TxnHash, MaybePreConfirmedBlockWithReceipts,
+    MaybePreConfirmedBlockWithTxHashes, MaybePreConfirmedBlockWithTxs, MessageStatus, TxnFinalityAndExecutionStatus,
+    TxnReceiptWithBlockInfo,
+};
+use mp_rpc::{
+    BroadcastedTxn, EventsChunk, FeeEstimate, FunctionCall, MaybeDeprecatedContractClass, MaybePendingStateUpdate,
+    MsgFromL1, SimulateTransactionsResult, SimulationFlag, SimulationFlagForEstimateFee, TraceBlockTransactionsResult,
+    TxnWithHash,
+};
+use starknet_types_core::felt::Felt;
+
+use crate::versions::user::v0_8_0::{ContractStorageKeysItem, GetStorageProofResult};
+
+// Only the methods whose parameters or results changed in v0.9 are defined here, the others are served by the
+// previous versions through `and_versions`. The v0.9 block ids accept the `pre_confirmed` and `l1_accepted` tags.
+// The websocket subscriptions are not served in v0.9 yet, their v0.8 definitions don't know about pre-confirmed blocks.
+
+#[versioned_rpc("V0_9_0", "starknet")]
+pub trait StarknetReadRpcApi {
+    /// Get the Version of the StarkNet JSON-RPC Specification Being Used
+    #[method(name = "specVersion")]
+    fn spec_version(&self) -> RpcResult<String>;
+
+    /// Call a contract function at a given block id
+    #[method(name = "call")]
+    fn call(&self, request: FunctionCall, block_id: BlockId) -> RpcResult<Vec<Felt>>;
+
+    /// Get the number of transactions in a block given a block id
+    #[method(name = "getBlockTransactionCount")]
+    fn get_block_transaction_count(&self, block_id: BlockId) -> RpcResult<u128>;
+
+    /// Estimate the fee associated with transaction
+    #[method(name = "estimateFee")]
+    async fn estimate_fee(
+        &self,
+        request: Vec<BroadcastedTxn>,
+        simulation_flags: Vec<SimulationFlagForEstimateFee>,
+        block_id: BlockId,
+    ) -> RpcResult<Vec<FeeEstimate>>;
+
+    /// Estimate the L2 fee of a message sent on L1
+    #[method(name = "estimateMessageFee")]
+    async fn estimate_message_fee(&self, message: MsgFromL1, block_id: BlockId) -> RpcResult<FeeEstimate>;
+
+    /// Get block information with full transactions and receipts given the block id
+    #[method(name = "getBlockWithReceipts")]
+    async fn get_block_with_receipts(&self, block_id: BlockId) -> RpcResult<MaybePreConfirmedBlockWithReceipts>;
+
+    /// Get block information with transaction hashes given the block id
+    #[method(name = "getBlockWithTxHashes")]
+    fn get_block_with_tx_hashes(&self, block_id: BlockId) -> RpcResult<MaybePreConfirmedBlockWithTxHashes>;
+
+    /// Get block information with full transactions given the block id
+    #[method(name = "getBlockWithTxs")]
+    fn get_block_with_txs(&self, block_id: BlockId) -> RpcResult<MaybePreConfirmedBlockWithTxs>;
+
+    /// Get the contract class at a given contract address for a given block id
+    #[method(name = "getClassAt")]
+    fn get_class_at(&self, block_id: BlockId, contract_address: Felt) -> RpcResult<MaybeDeprecatedContractClass>;
+
+    /// Get the contract class hash in the given block for the contract deployed at the given
+    /// address
+    #[method(name = "getClassHashAt")]
+    fn get_class_hash_at(&self, block_id: BlockId, contract_address: Felt) -> RpcResult<Felt>;
+
+    /// Get the contract class definition in the given block associated with the given hash
+    #[method(name = "getClass")]
+    fn get_class(&self, block_id: BlockId, class_hash: Felt) -> RpcResult<MaybeDeprecatedContractClass>;
+
+    /// Returns all events matching the given filter
+    #[method(name = "getEvents")]
+    async fn get_events(&self, filter: EventFilterWithPageRequest) -> RpcResult<EventsChunk>;
+
+    /// Get the nonce associated with the given address at the given block
+    #[method(name = "getNonce")]
+    fn get_nonce(&self, block_id: BlockId, contract_address: Felt) -> RpcResult<Felt>;
+
+    /// Get the value of the storage at the given address and key, at the given block id
+    #[method(name = "getStorageAt")]
+    fn get_storage_at(&self, contract_address: Felt, key: Felt, block_id: BlockId) -> RpcResult<Felt>;
+
+    /// Get the details of a transaction by a given block id and index
+    #[method(name = "getTransactionByBlockIdAndIndex")]
+    fn get_transaction_by_block_id_and_index(&self, block_id: BlockId, index: u64) -> RpcResult<TxnWithHash>;
+
+    /// Returns the receipt of a transaction by transaction hash.
+    #[method(name = "getTransactionReceipt")]
+    async fn get_transaction_receipt(&self, transaction_hash: Felt) -> RpcResult<TxnReceiptWithBlockInfo>;
+
+    /// Gets the Transaction Status, Including Mempool Status and Execution Details
+    #[method(name = "getTransactionStatus")]
+    async fn get_transaction_status(&self, transaction_hash: Felt) -> RpcResult<TxnFinalityAndExecutionStatus>;
+
+    /// Given an L1 transaction hash, returns the statuses of the L1 handler transactions of the messages it sent
+    #[method(name = "getMessagesStatus")]
+    async fn get_messages_status(&self, transaction_hash: L1TxnHash) -> RpcResult<Vec<MessageStatus>>;
+
+    /// Get the information about the result of executing the requested block
+    #[method(name = "getStateUpdate")]
+    fn get_state_update(&self, block_id: BlockId) -> RpcResult<MaybePendingStateUpdate>;
+
+    /// Get merkle paths in one of the state tries: global state, classes, individual contract
+    #[method(name = "getStorageProof")]
+    fn get_storage_proof(
+        &self,
+        block_id: BlockId,
+        class_hashes: Option<Vec<Felt>>,
+        contract_addresses: Option<Vec<Felt>>,
+        contracts_storage_keys: Option<Vec<ContractStorageKeysItem>>,
+    ) -> RpcResult<GetStorageProofResult>;
+}
+
+#[versioned_rpc("V0_9_0", "starknet")]
+pub trait StarknetTraceRpcApi {
+    /// Returns the execution trace of a transaction by simulating it in the runtime.
+    #[method(name = "simulateTransactions")]
+    async fn simulate_transactions(
+        &self,
+        block_id: BlockId,
+        transactions: Vec<BroadcastedTxn>,
+        simulation_flags: Vec<SimulationFlag>,
+    ) -> RpcResult<Vec<SimulateTransactionsResult>>;
+
+    #[method(name = "traceBlockTransactions")]
+    /// Returns the execution traces of all transactions included in the given block
+    async fn trace_block_transactions(&self, block_id: BlockId) -> RpcResult<Vec<TraceBlockTransactionsResult>>;
+}
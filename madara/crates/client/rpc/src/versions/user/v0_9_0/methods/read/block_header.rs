@@ -0,0 +1,53 @@
+use mp_block::header::PendingHeader;
+use mp_block::MadaraBlockInfo;
+use mp_rpc::v0_9_0::{BlockHeader, BlockStatus, PreConfirmedBlockHeader, TxnFinalityStatus};
+
+use crate::errors::StarknetRpcResult;
+use crate::Starknet;
+
+pub(crate) fn block_header(block: &MadaraBlockInfo) -> BlockHeader {
+    BlockHeader {
+        block_hash: block.block_hash,
+        parent_hash: block.header.parent_block_hash,
+        block_number: block.header.block_number,
+        new_root: block.header.global_state_root,
+        timestamp: block.header.block_timestamp.0,
+        sequencer_address: block.header.sequencer_address,
+        l1_gas_price: block.header.l1_gas_price.l1_gas_price(),
+        l1_data_gas_price: block.header.l1_gas_price.l1_data_gas_price(),
+        l2_gas_price: block.header.l1_gas_price.l2_gas_price(),
+        l1_da_mode: block.header.l1_da_mode.into(),
+        starknet_version: block.header.protocol_version.to_string(),
+    }
+}
+
+pub(crate) fn pre_confirmed_block_header(header: &PendingHeader, block_number: u64) -> PreConfirmedBlockHeader {
+    PreConfirmedBlockHeader {
+        block_number,
+        timestamp: header.block_timestamp.0,
+        sequencer_address: header.sequencer_address,
+        l1_gas_price: header.l1_gas_price.l1_gas_price(),
+        l1_data_gas_price: header.l1_gas_price.l1_data_gas_price(),
+        l2_gas_price: header.l1_gas_price.l2_gas_price(),
+        l1_da_mode: header.l1_da_mode.into(),
+        starknet_version: header.protocol_version.to_string(),
+    }
+}
+
+/// The status of a closed block, depending on whether its state has been settled on L1.
+pub(crate) fn block_status(starknet: &Starknet, block_n: u64) -> StarknetRpcResult<BlockStatus> {
+    if block_n <= starknet.get_l1_last_confirmed_block()? {
+        Ok(BlockStatus::AcceptedOnL1)
+    } else {
+        Ok(BlockStatus::AcceptedOnL2)
+    }
+}
+
+/// The finality status of the transactions of a block, `None` being the pre-confirmed block.
+pub(crate) fn txn_finality_status(starknet: &Starknet, block_n: Option<u64>) -> StarknetRpcResult<TxnFinalityStatus> {
+    match block_n {
+        None => Ok(TxnFinalityStatus::PreConfirmed),
+        Some(block_n) if block_n <= starknet.get_l1_last_confirmed_block()? => Ok(TxnFinalityStatus::L1),
+        Some(_) => Ok(TxnFinalityStatus::L2),
+    }
+}
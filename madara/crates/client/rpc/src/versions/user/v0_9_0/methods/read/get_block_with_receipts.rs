@@ -0,0 +1,48 @@
+use mp_block::MadaraMaybePendingBlockInfo;
+use mp_rpc::v0_9_0::{
+    BlockId, BlockWithReceipts, MaybePreConfirmedBlockWithReceipts, PreConfirmedBlockWithReceipts,
+    TransactionAndReceipt,
+};
+
+use super::block_header::{block_header, block_status, pre_confirmed_block_header, txn_finality_status};
+use crate::errors::StarknetRpcResult;
+use crate::Starknet;
+
+/// Get block information with full transactions and receipts given the block id.
+///
+/// The receipts of the pre-confirmed block have the `PRE_CONFIRMED` finality status.
+pub fn get_block_with_receipts(
+    starknet: &Starknet,
+    block_id: BlockId,
+) -> StarknetRpcResult<MaybePreConfirmedBlockWithReceipts> {
+    tracing::debug!("get_block_with_receipts called with {:?}", block_id);
+    let block_id = starknet.resolve_block_id_v0_9_0(block_id)?;
+    let block = starknet.get_block(&block_id)?;
+
+    let finality_status = txn_finality_status(starknet, block.info.block_n())?;
+    let transactions = block.inner.transactions.into_iter().map(|tx| tx.into());
+    let receipts = block.inner.receipts.into_iter().map(|receipt| receipt.to_rpc_v0_9_0(finality_status.clone()));
+
+    let transactions_with_receipts = Iterator::zip(transactions, receipts)
+        .map(|(transaction, receipt)| TransactionAndReceipt { receipt, transaction })
+        .collect();
+
+    match block.info {
+        MadaraMaybePendingBlockInfo::Pending(block) => {
+            Ok(MaybePreConfirmedBlockWithReceipts::PreConfirmed(PreConfirmedBlockWithReceipts {
+                transactions: transactions_with_receipts,
+                pre_confirmed_block_header: pre_confirmed_block_header(
+                    &block.header,
+                    starknet.pre_confirmed_block_n()?,
+                ),
+            }))
+        }
+        MadaraMaybePendingBlockInfo::NotPending(block) => {
+            Ok(MaybePreConfirmedBlockWithReceipts::Block(BlockWithReceipts {
+                transactions: transactions_with_receipts,
+                status: block_status(starknet, block.header.block_number)?,
+                block_header: block_header(&block),
+            }))
+        }
+    }
+}
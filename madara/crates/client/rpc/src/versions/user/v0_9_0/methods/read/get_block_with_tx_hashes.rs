@@ -0,0 +1,91 @@
+use mp_block::MadaraMaybePendingBlockInfo;
+use mp_rpc::v0_9_0::{BlockId, BlockWithTxHashes, MaybePreConfirmedBlockWithTxHashes, PreConfirmedBlockWithTxHashes};
+
+use super::block_header::{block_header, block_status, pre_confirmed_block_header};
+use crate::errors::StarknetRpcResult;
+use crate::Starknet;
+
+/// Get block information with transaction hashes given the block id.
+///
+/// The pending block is returned as the pre-confirmed block, which has a block number but no hash or state root yet.
+pub fn get_block_with_tx_hashes(
+    starknet: &Starknet,
+    block_id: BlockId,
+) -> StarknetRpcResult<MaybePreConfirmedBlockWithTxHashes> {
+    let block_id = starknet.resolve_block_id_v0_9_0(block_id)?;
+    let block = starknet.get_block_info(&block_id)?;
+
+    let transactions = block.tx_hashes().to_vec();
+
+    match block {
+        MadaraMaybePendingBlockInfo::Pending(block) => {
+            Ok(MaybePreConfirmedBlockWithTxHashes::PreConfirmed(PreConfirmedBlockWithTxHashes {
+                transactions,
+                pre_confirmed_block_header: pre_confirmed_block_header(
+                    &block.header,
+                    starknet.pre_confirmed_block_n()?,
+                ),
+            }))
+        }
+        MadaraMaybePendingBlockInfo::NotPending(block) => {
+            Ok(MaybePreConfirmedBlockWithTxHashes::Block(BlockWithTxHashes {
+                transactions,
+                status: block_status(starknet, block.header.block_number)?,
+                block_header: block_header(&block),
+            }))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::StarknetRpcApiError;
+    use crate::test_utils::{sample_chain_for_block_getters, SampleChainForBlockGetters};
+    use mp_rpc::v0_9_0::{BlockStatus, BlockTag};
+    use rstest::rstest;
+
+    #[rstest]
+    fn test_get_block_with_tx_hashes(sample_chain_for_block_getters: (SampleChainForBlockGetters, Starknet)) {
+        let (SampleChainForBlockGetters { block_hashes, tx_hashes, .. }, rpc) = sample_chain_for_block_getters;
+
+        let MaybePreConfirmedBlockWithTxHashes::Block(block) =
+            get_block_with_tx_hashes(&rpc, BlockId::Tag(BlockTag::Latest)).unwrap()
+        else {
+            panic!("Expected a closed block")
+        };
+        assert_eq!(block.block_header.block_hash, block_hashes[2]);
+        assert_eq!(block.transactions, vec![tx_hashes[1], tx_hashes[2]]);
+
+        // The pre-confirmed block follows the latest block
+        let MaybePreConfirmedBlockWithTxHashes::PreConfirmed(block) =
+            get_block_with_tx_hashes(&rpc, BlockId::Tag(BlockTag::PreConfirmed)).unwrap()
+        else {
+            panic!("Expected the pre-confirmed block")
+        };
+        assert_eq!(block.pre_confirmed_block_header.block_number, 3);
+        assert_eq!(block.transactions, vec![tx_hashes[3]]);
+    }
+
+    #[rstest]
+    fn test_get_block_with_tx_hashes_l1_accepted(
+        sample_chain_for_block_getters: (SampleChainForBlockGetters, Starknet),
+    ) {
+        let (SampleChainForBlockGetters { block_hashes, .. }, rpc) = sample_chain_for_block_getters;
+
+        // No block has been confirmed on L1 yet
+        assert_eq!(
+            get_block_with_tx_hashes(&rpc, BlockId::Tag(BlockTag::L1Accepted)),
+            Err(StarknetRpcApiError::BlockNotFound)
+        );
+
+        rpc.backend.write_last_confirmed_block(1).unwrap();
+        let MaybePreConfirmedBlockWithTxHashes::Block(block) =
+            get_block_with_tx_hashes(&rpc, BlockId::Tag(BlockTag::L1Accepted)).unwrap()
+        else {
+            panic!("Expected a closed block")
+        };
+        assert_eq!(block.block_header.block_hash, block_hashes[1]);
+        assert_eq!(block.status, BlockStatus::AcceptedOnL1);
+    }
+}
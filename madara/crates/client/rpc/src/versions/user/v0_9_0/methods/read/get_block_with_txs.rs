@@ -0,0 +1,36 @@
+use mp_block::MadaraMaybePendingBlockInfo;
+use mp_rpc::v0_9_0::{BlockId, BlockWithTxs, MaybePreConfirmedBlockWithTxs, PreConfirmedBlockWithTxs};
+use mp_rpc::TxnWithHash;
+
+use super::block_header::{block_header, block_status, pre_confirmed_block_header};
+use crate::errors::StarknetRpcResult;
+use crate::Starknet;
+
+/// Get block information with full transactions given the block id.
+///
+/// The pending block is returned as the pre-confirmed block, which has a block number but no hash or state root yet.
+pub fn get_block_with_txs(starknet: &Starknet, block_id: BlockId) -> StarknetRpcResult<MaybePreConfirmedBlockWithTxs> {
+    let block_id = starknet.resolve_block_id_v0_9_0(block_id)?;
+    let block = starknet.get_block(&block_id)?;
+
+    let transactions = Iterator::zip(block.inner.transactions.into_iter(), block.info.tx_hashes())
+        .map(|(transaction, hash)| TxnWithHash { transaction: transaction.into(), transaction_hash: *hash })
+        .collect();
+
+    match block.info {
+        MadaraMaybePendingBlockInfo::Pending(block) => {
+            Ok(MaybePreConfirmedBlockWithTxs::PreConfirmed(PreConfirmedBlockWithTxs {
+                transactions,
+                pre_confirmed_block_header: pre_confirmed_block_header(
+                    &block.header,
+                    starknet.pre_confirmed_block_n()?,
+                ),
+            }))
+        }
+        MadaraMaybePendingBlockInfo::NotPending(block) => Ok(MaybePreConfirmedBlockWithTxs::Block(BlockWithTxs {
+            transactions,
+            status: block_status(starknet, block.header.block_number)?,
+            block_header: block_header(&block),
+        })),
+    }
+}
@@ -0,0 +1,145 @@
+use mp_convert::L1TransactionHash;
+use mp_rpc::v0_9_0::{L1TxnHash, MessageStatus, TxnFinalityAndExecutionStatus, TxnStatus};
+
+use super::get_transaction_status::get_transaction_status;
+use crate::errors::{StarknetRpcApiError, StarknetRpcResult};
+use crate::utils::ResultExt;
+use crate::Starknet;
+
+/// Gets the status of the L1 handler transactions of the messages sent by an L1 transaction.
+///
+/// Messages are indexed by the hash of the L1 transaction that sent them when the node syncs the messages to L2 from
+/// the settlement layer. The statuses are those of [`get_transaction_status`], messages which are known but have not
+/// been executed yet are [`Received`]. Messages that have been cancelled on L1 are left out.
+///
+/// Returns [`TxnHashNotFound`] if the node has not seen any message sent by this L1 transaction.
+///
+/// [`Received`]: mp_rpc::v0_9_0::TxnStatus::Received
+/// [`TxnHashNotFound`]: StarknetRpcApiError::TxnHashNotFound
+pub async fn get_messages_status(
+    starknet: &Starknet,
+    transaction_hash: L1TxnHash,
+) -> StarknetRpcResult<Vec<MessageStatus>> {
+    let l1_handler_txns = starknet
+        .backend
+        .get_l1_handler_txns_by_l1_txn_hash(&L1TransactionHash(transaction_hash.0))
+        .or_internal_server_error("Error getting the messages sent by an L1 transaction")?;
+    if l1_handler_txns.is_empty() {
+        return Err(StarknetRpcApiError::TxnHashNotFound);
+    }
+
+    let mut statuses = Vec::with_capacity(l1_handler_txns.len());
+    for (core_contract_nonce, l1_handler_txn_hash) in l1_handler_txns {
+        let status = match get_transaction_status(starknet, l1_handler_txn_hash).await {
+            Ok(status) => status,
+            Err(StarknetRpcApiError::TxnHashNotFound) => {
+                // Not executed yet: the message is received as long as it's waiting to be consumed.
+                if starknet
+                    .backend
+                    .get_pending_message_to_l2(core_contract_nonce)
+                    .or_internal_server_error("Error getting pending message to L2")?
+                    .is_none()
+                {
+                    continue;
+                }
+                TxnFinalityAndExecutionStatus {
+                    finality_status: TxnStatus::Received,
+                    execution_status: None,
+                    failure_reason: None,
+                }
+            }
+            Err(err) => return Err(err),
+        };
+        statuses.push(MessageStatus {
+            transaction_hash: l1_handler_txn_hash,
+            finality_status: status.finality_status,
+            execution_status: status.execution_status,
+            failure_reason: status.failure_reason,
+        });
+    }
+
+    Ok(statuses)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{sample_chain_for_block_getters, SampleChainForBlockGetters};
+    use mp_rpc::v0_9_0::TxnExecutionStatus;
+    use mp_transactions::{L1HandlerTransaction, L1HandlerTransactionWithFee};
+    use rstest::rstest;
+    use starknet_types_core::felt::Felt;
+
+    const L1_TXN_HASH: L1TxnHash = L1TxnHash([0xee; 32]);
+
+    fn index_message(rpc: &Starknet, core_contract_nonce: u64, txn_hash: Felt) {
+        rpc.backend
+            .add_l1_handler_txn_by_l1_txn_hash(&L1TransactionHash(L1_TXN_HASH.0), core_contract_nonce, txn_hash)
+            .unwrap();
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_get_messages_status(sample_chain_for_block_getters: (SampleChainForBlockGetters, Starknet)) {
+        let (SampleChainForBlockGetters { tx_hashes, .. }, rpc) = sample_chain_for_block_getters;
+        rpc.backend.write_last_confirmed_block(0).unwrap();
+
+        // Executed in block 0, settled on L1
+        index_message(&rpc, 1, tx_hashes[0]);
+        // Reverted in block 2
+        index_message(&rpc, 2, tx_hashes[2]);
+        // Executed in the pre-confirmed block
+        index_message(&rpc, 3, tx_hashes[3]);
+        // Waiting to be consumed
+        index_message(&rpc, 4, Felt::from_hex_unchecked("0x4444"));
+        rpc.backend
+            .add_pending_message_to_l2(L1HandlerTransactionWithFee::new(
+                L1HandlerTransaction { nonce: 4, ..Default::default() },
+                1000,
+            ))
+            .unwrap();
+        // Cancelled on L1, never made it to L2
+        index_message(&rpc, 5, Felt::from_hex_unchecked("0x5555"));
+
+        assert_eq!(
+            get_messages_status(&rpc, L1_TXN_HASH).await.unwrap(),
+            vec![
+                MessageStatus {
+                    transaction_hash: tx_hashes[0],
+                    finality_status: TxnStatus::AcceptedOnL1,
+                    execution_status: Some(TxnExecutionStatus::Succeeded),
+                    failure_reason: None,
+                },
+                MessageStatus {
+                    transaction_hash: tx_hashes[2],
+                    finality_status: TxnStatus::AcceptedOnL2,
+                    execution_status: Some(TxnExecutionStatus::Reverted),
+                    failure_reason: Some("too bad".into()),
+                },
+                MessageStatus {
+                    transaction_hash: tx_hashes[3],
+                    finality_status: TxnStatus::PreConfirmed,
+                    execution_status: Some(TxnExecutionStatus::Succeeded),
+                    failure_reason: None,
+                },
+                MessageStatus {
+                    transaction_hash: Felt::from_hex_unchecked("0x4444"),
+                    finality_status: TxnStatus::Received,
+                    execution_status: None,
+                    failure_reason: None,
+                },
+            ]
+        );
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_get_messages_status_not_found(
+        sample_chain_for_block_getters: (SampleChainForBlockGetters, Starknet),
+    ) {
+        let (SampleChainForBlockGetters { .. }, rpc) = sample_chain_for_block_getters;
+        index_message(&rpc, 1, Felt::ONE);
+
+        assert_eq!(get_messages_status(&rpc, L1TxnHash([0xaa; 32])).await, Err(StarknetRpcApiError::TxnHashNotFound));
+    }
+}
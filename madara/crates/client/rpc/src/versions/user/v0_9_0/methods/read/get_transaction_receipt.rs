@@ -0,0 +1,85 @@
+use mp_block::MadaraMaybePendingBlockInfo;
+use mp_rpc::v0_9_0::TxnReceiptWithBlockInfo;
+use starknet_types_core::felt::Felt;
+
+use super::block_header::txn_finality_status;
+use crate::errors::{StarknetRpcApiError, StarknetRpcResult};
+use crate::utils::ResultExt;
+use crate::Starknet;
+
+/// Get the transaction receipt by the transaction hash.
+///
+/// The receipt of a transaction of the pre-confirmed block has the number of the pre-confirmed block, but no block
+/// hash.
+pub fn get_transaction_receipt(
+    starknet: &Starknet,
+    transaction_hash: Felt,
+) -> StarknetRpcResult<TxnReceiptWithBlockInfo> {
+    tracing::debug!("get_transaction_receipt {:#x}", transaction_hash);
+    let (block, tx_index) = starknet
+        .backend
+        .find_tx_hash_block(&transaction_hash)
+        .or_internal_server_error("Error getting block from tx_hash")?
+        .ok_or(StarknetRpcApiError::TxnHashNotFound)?;
+
+    let finality_status = txn_finality_status(starknet, block.info.block_n())?;
+
+    let transaction_receipt = block
+        .inner
+        .receipts
+        .get(tx_index.0 as usize)
+        .ok_or(StarknetRpcApiError::TxnHashNotFound)?
+        .clone()
+        .to_rpc_v0_9_0(finality_status);
+
+    let (block_number, block_hash) = match block.info {
+        MadaraMaybePendingBlockInfo::Pending(_) => (starknet.pre_confirmed_block_n()?, None),
+        MadaraMaybePendingBlockInfo::NotPending(block) => (block.header.block_number, Some(block.block_hash)),
+    };
+
+    Ok(TxnReceiptWithBlockInfo { transaction_receipt, block_hash, block_number })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{sample_chain_for_block_getters, SampleChainForBlockGetters};
+    use mp_rpc::v0_9_0::TxnFinalityStatus;
+    use rstest::rstest;
+
+    fn finality_status(receipt: &TxnReceiptWithBlockInfo) -> TxnFinalityStatus {
+        match &receipt.transaction_receipt {
+            mp_rpc::v0_9_0::TxnReceipt::Invoke(receipt) => receipt.common_receipt_properties.finality_status.clone(),
+            _ => panic!("Expected an invoke receipt"),
+        }
+    }
+
+    #[rstest]
+    fn test_get_transaction_receipt(sample_chain_for_block_getters: (SampleChainForBlockGetters, Starknet)) {
+        let (SampleChainForBlockGetters { block_hashes, tx_hashes, .. }, rpc) = sample_chain_for_block_getters;
+        rpc.backend.write_last_confirmed_block(0).unwrap();
+
+        // Block 0, settled on L1
+        let receipt = get_transaction_receipt(&rpc, tx_hashes[0]).unwrap();
+        assert_eq!((receipt.block_hash, receipt.block_number), (Some(block_hashes[0]), 0));
+        assert_eq!(finality_status(&receipt), TxnFinalityStatus::L1);
+
+        // Block 2
+        let receipt = get_transaction_receipt(&rpc, tx_hashes[1]).unwrap();
+        assert_eq!((receipt.block_hash, receipt.block_number), (Some(block_hashes[2]), 2));
+        assert_eq!(finality_status(&receipt), TxnFinalityStatus::L2);
+
+        // Pre-confirmed
+        let receipt = get_transaction_receipt(&rpc, tx_hashes[3]).unwrap();
+        assert_eq!((receipt.block_hash, receipt.block_number), (None, 3));
+        assert_eq!(finality_status(&receipt), TxnFinalityStatus::PreConfirmed);
+    }
+
+    #[rstest]
+    fn test_get_transaction_receipt_not_found(sample_chain_for_block_getters: (SampleChainForBlockGetters, Starknet)) {
+        let (SampleChainForBlockGetters { .. }, rpc) = sample_chain_for_block_getters;
+
+        let does_not_exist = Felt::from_hex_unchecked("0x7128638126378");
+        assert_eq!(get_transaction_receipt(&rpc, does_not_exist), Err(StarknetRpcApiError::TxnHashNotFound));
+    }
+}
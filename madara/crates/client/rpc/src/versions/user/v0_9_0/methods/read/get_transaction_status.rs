@@ -0,0 +1,62 @@
+use mp_block::MadaraMaybePendingBlockInfo;
+use mp_receipt::ExecutionResult;
+use mp_rpc::v0_9_0::{TxnExecutionStatus, TxnFinalityAndExecutionStatus, TxnStatus};
+use starknet_types_core::felt::Felt;
+
+use crate::errors::{StarknetRpcApiError, StarknetRpcResult};
+use crate::utils::ResultExt;
+use crate::Starknet;
+
+/// Gets the status of a transaction.
+///
+/// Supported statuses are:
+///
+/// - [`Received`]: tx has been inserted into the mempool.
+/// - [`PreConfirmed`]: tx has been executed in the pre-confirmed block.
+/// - [`AcceptedOnL2`]: tx has been included in a closed block.
+/// - [`AcceptedOnL1`]: tx has been finalized on L1.
+///
+/// We do not currently support the **Candidate** transaction status.
+///
+/// [`Received`]: mp_rpc::v0_9_0::TxnStatus::Received
+/// [`PreConfirmed`]: mp_rpc::v0_9_0::TxnStatus::PreConfirmed
+/// [`AcceptedOnL2`]: mp_rpc::v0_9_0::TxnStatus::AcceptedOnL2
+/// [`AcceptedOnL1`]: mp_rpc::v0_9_0::TxnStatus::AcceptedOnL1
+pub async fn get_transaction_status(
+    starknet: &Starknet,
+    transaction_hash: Felt,
+) -> StarknetRpcResult<TxnFinalityAndExecutionStatus> {
+    if let Some((block, tx_index)) =
+        starknet.backend.find_tx_hash_block(&transaction_hash).or_else_internal_server_error(|| {
+            format!("GetTransactionStatus failed to retrieve block for tx {transaction_hash:#x}")
+        })?
+    {
+        let tx_receipt = block.inner.receipts.get(tx_index.0 as usize).ok_or(StarknetRpcApiError::TxnHashNotFound)?;
+
+        let (execution_status, failure_reason) = match tx_receipt.execution_result() {
+            ExecutionResult::Reverted { reason } => (TxnExecutionStatus::Reverted, Some(reason)),
+            ExecutionResult::Succeeded => (TxnExecutionStatus::Succeeded, None),
+        };
+
+        let finality_status = match block.info {
+            MadaraMaybePendingBlockInfo::Pending(_) => TxnStatus::PreConfirmed,
+            MadaraMaybePendingBlockInfo::NotPending(block) => {
+                if block.header.block_number <= starknet.get_l1_last_confirmed_block()? {
+                    TxnStatus::AcceptedOnL1
+                } else {
+                    TxnStatus::AcceptedOnL2
+                }
+            }
+        };
+
+        Ok(TxnFinalityAndExecutionStatus { finality_status, execution_status: Some(execution_status), failure_reason })
+    } else if starknet.add_transaction_provider.received_transaction(transaction_hash).await.is_some_and(|b| b) {
+        Ok(TxnFinalityAndExecutionStatus {
+            finality_status: TxnStatus::Received,
+            execution_status: None,
+            failure_reason: None,
+        })
+    } else {
+        Err(StarknetRpcApiError::TxnHashNotFound)
+    }
+}
@@ -0,0 +1,139 @@
+use crate::versions::user::v0_7_1::methods::read::{
+    call::call, estimate_fee::estimate_fee, estimate_message_fee::estimate_message_fee,
+    get_block_transaction_count::get_block_transaction_count, get_class::get_class, get_class_at::get_class_at,
+    get_class_hash_at::get_class_hash_at, get_events::get_events, get_nonce::get_nonce,
+    get_state_update::get_state_update, get_storage_at::get_storage_at,
+    get_transaction_by_block_id_and_index::get_transaction_by_block_id_and_index,
+};
+use crate::versions::user::v0_8_0::methods::read::get_storage_proof::get_storage_proof;
+use crate::versions::user::v0_8_0::{ContractStorageKeysItem, GetStorageProofResult};
+use crate::versions::user::v0_9_0::StarknetReadRpcApiV0_9_0Server;
+use crate::Starknet;
+use jsonrpsee::core::{async_trait, RpcResult};
+use mp_chain_config::RpcVersion;
+use mp_rpc::v0_9_0::{
+    BlockId, EventFilterWithPageRequest, L1TxnHash, MaybePreConfirmedBlockWithReceipts,
+    MaybePreConfirmedBlockWithTxHashes, MaybePreConfirmedBlockWithTxs, MessageStatus, TxnFinalityAndExecutionStatus,
+    TxnReceiptWithBlockInfo,
+};
+use mp_rpc::{
+    BroadcastedTxn, EventsChunk, FeeEstimate, FunctionCall, MaybeDeprecatedContractClass, MaybePendingStateUpdate,
+    MsgFromL1, SimulationFlagForEstimateFee, TxnWithHash,
+};
+use starknet_types_core::felt::Felt;
+
+mod block_header;
+pub mod get_block_with_receipts;
+pub mod get_block_with_tx_hashes;
+pub mod get_block_with_txs;
+pub mod get_messages_status;
+pub mod get_transaction_receipt;
+pub mod get_transaction_status;
+
+#[async_trait]
+impl StarknetReadRpcApiV0_9_0Server for Starknet {
+    fn spec_version(&self) -> RpcResult<String> {
+        Ok(RpcVersion::RPC_VERSION_0_9_0.to_string())
+    }
+
+    fn call(&self, request: FunctionCall, block_id: BlockId) -> RpcResult<Vec<Felt>> {
+        Ok(call(self, request, self.resolve_block_id_v0_9_0(block_id)?)?)
+    }
+
+    fn get_block_transaction_count(&self, block_id: BlockId) -> RpcResult<u128> {
+        Ok(get_block_transaction_count(self, self.resolve_block_id_v0_9_0(block_id)?)?)
+    }
+
+    async fn estimate_fee(
+        &self,
+        request: Vec<BroadcastedTxn>,
+        simulation_flags: Vec<SimulationFlagForEstimateFee>,
+        block_id: BlockId,
+    ) -> RpcResult<Vec<FeeEstimate>> {
+        Ok(estimate_fee(self, request, simulation_flags, self.resolve_block_id_v0_9_0(block_id)?).await?)
+    }
+
+    async fn estimate_message_fee(&self, message: MsgFromL1, block_id: BlockId) -> RpcResult<FeeEstimate> {
+        Ok(estimate_message_fee(self, message, self.resolve_block_id_v0_9_0(block_id)?).await?)
+    }
+
+    async fn get_block_with_receipts(&self, block_id: BlockId) -> RpcResult<MaybePreConfirmedBlockWithReceipts> {
+        Ok(get_block_with_receipts::get_block_with_receipts(self, block_id)?)
+    }
+
+    fn get_block_with_tx_hashes(&self, block_id: BlockId) -> RpcResult<MaybePreConfirmedBlockWithTxHashes> {
+        Ok(get_block_with_tx_hashes::get_block_with_tx_hashes(self, block_id)?)
+    }
+
+    fn get_block_with_txs(&self, block_id: BlockId) -> RpcResult<MaybePreConfirmedBlockWithTxs> {
+        Ok(get_block_with_txs::get_block_with_txs(self, block_id)?)
+    }
+
+    fn get_class_at(&self, block_id: BlockId, contract_address: Felt) -> RpcResult<MaybeDeprecatedContractClass> {
+        Ok(get_class_at(self, self.resolve_block_id_v0_9_0(block_id)?, contract_address)?)
+    }
+
+    fn get_class_hash_at(&self, block_id: BlockId, contract_address: Felt) -> RpcResult<Felt> {
+        Ok(get_class_hash_at(self, self.resolve_block_id_v0_9_0(block_id)?, contract_address)?)
+    }
+
+    fn get_class(&self, block_id: BlockId, class_hash: Felt) -> RpcResult<MaybeDeprecatedContractClass> {
+        Ok(get_class(self, self.resolve_block_id_v0_9_0(block_id)?, class_hash)?)
+    }
+
+    async fn get_events(&self, filter: EventFilterWithPageRequest) -> RpcResult<EventsChunk> {
+        let filter = mp_rpc::EventFilterWithPageRequest {
+            address: filter.address,
+            from_block: filter.from_block.map(|block_id| self.resolve_block_id_v0_9_0(block_id)).transpose()?,
+            keys: filter.keys,
+            to_block: filter.to_block.map(|block_id| self.resolve_block_id_v0_9_0(block_id)).transpose()?,
+            chunk_size: filter.chunk_size,
+            continuation_token: filter.continuation_token,
+        };
+        Ok(get_events(self, filter).await?)
+    }
+
+    fn get_nonce(&self, block_id: BlockId, contract_address: Felt) -> RpcResult<Felt> {
+        Ok(get_nonce(self, self.resolve_block_id_v0_9_0(block_id)?, contract_address)?)
+    }
+
+    fn get_storage_at(&self, contract_address: Felt, key: Felt, block_id: BlockId) -> RpcResult<Felt> {
+        Ok(get_storage_at(self, contract_address, key, self.resolve_block_id_v0_9_0(block_id)?)?)
+    }
+
+    fn get_transaction_by_block_id_and_index(&self, block_id: BlockId, index: u64) -> RpcResult<TxnWithHash> {
+        Ok(get_transaction_by_block_id_and_index(self, self.resolve_block_id_v0_9_0(block_id)?, index)?)
+    }
+
+    async fn get_transaction_receipt(&self, transaction_hash: Felt) -> RpcResult<TxnReceiptWithBlockInfo> {
+        Ok(get_transaction_receipt::get_transaction_receipt(self, transaction_hash)?)
+    }
+
+    async fn get_transaction_status(&self, transaction_hash: Felt) -> RpcResult<TxnFinalityAndExecutionStatus> {
+        Ok(get_transaction_status::get_transaction_status(self, transaction_hash).await?)
+    }
+
+    async fn get_messages_status(&self, transaction_hash: L1TxnHash) -> RpcResult<Vec<MessageStatus>> {
+        Ok(get_messages_status::get_messages_status(self, transaction_hash).await?)
+    }
+
+    fn get_state_update(&self, block_id: BlockId) -> RpcResult<MaybePendingStateUpdate> {
+        Ok(get_state_update(self, self.resolve_block_id_v0_9_0(block_id)?)?)
+    }
+
+    fn get_storage_proof(
+        &self,
+        block_id: BlockId,
+        class_hashes: Option<Vec<Felt>>,
+        contract_addresses: Option<Vec<Felt>>,
+        contracts_storage_keys: Option<Vec<ContractStorageKeysItem>>,
+    ) -> RpcResult<GetStorageProofResult> {
+        get_storage_proof(
+            self,
+            self.resolve_block_id_v0_9_0(block_id)?,
+            class_hashes,
+            contract_addresses,
+            contracts_storage_keys,
+        )
+    }
+}
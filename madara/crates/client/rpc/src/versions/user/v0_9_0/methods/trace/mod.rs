@@ -0,0 +1,22 @@
+use crate::versions::user::v0_7_1::methods::trace::simulate_transactions::simulate_transactions;
+use crate::versions::user::v0_7_1::methods::trace::trace_block_transactions::trace_block_transactions;
+use crate::{versions::user::v0_9_0::StarknetTraceRpcApiV0_9_0Server, Starknet};
+use jsonrpsee::core::{async_trait, RpcResult};
+use mp_rpc::v0_9_0::BlockId;
+use mp_rpc::{BroadcastedTxn, SimulateTransactionsResult, SimulationFlag, TraceBlockTransactionsResult};
+
+#[async_trait]
+impl StarknetTraceRpcApiV0_9_0Server for Starknet {
+    async fn simulate_transactions(
+        &self,
+        block_id: BlockId,
+        transactions: Vec<BroadcastedTxn>,
+        simulation_flags: Vec<SimulationFlag>,
+    ) -> RpcResult<Vec<SimulateTransactionsResult>> {
+        Ok(simulate_transactions(self, self.resolve_block_id_v0_9_0(block_id)?, transactions, simulation_flags).await?)
+    }
+
+    async fn trace_block_transactions(&self, block_id: BlockId) -> RpcResult<Vec<TraceBlockTransactionsResult>> {
+        Ok(trace_block_transactions(self, self.resolve_block_id_v0_9_0(block_id)?).await?)
+    }
+}
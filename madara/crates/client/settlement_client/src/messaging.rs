@@ -4,6 +4,7 @@ use alloy::primitives::{B256, U256};
 use anyhow::Context;
 use futures::{StreamExt, TryStreamExt};
 use mc_db::MadaraBackend;
+use mp_convert::{L1TransactionHash, ToFelt};
 use mp_transactions::L1HandlerTransactionWithFee;
 use mp_utils::service::ServiceContext;
 use starknet_types_core::felt::Felt;
@@ -126,6 +127,21 @@ async fn sync_inner(
                     message.message.tx.calldata[0],
                 );
 
+                // Index the message by its l1 transaction hash, so that its status can be queried from it. Cancelled
+                // messages are indexed too, they are filtered out when queried as they never make it to l2.
+                let txn_hash = message.message.tx.compute_hash(
+                    backend.chain_config().chain_id.to_felt(),
+                    /* offset_version */ false,
+                    /* legacy */ false,
+                );
+                backend
+                    .add_l1_handler_txn_by_l1_txn_hash(
+                        &L1TransactionHash(message.l1_transaction_hash.to_be_bytes()),
+                        message.message.tx.nonce,
+                        txn_hash,
+                    )
+                    .map_err(|e| SettlementClientError::DatabaseError(format!("Indexing l1 to l2 message by l1 transaction hash: {}", e)))?;
+
                 if check_message_to_l2_validity(&settlement_client, &backend, &message.message).await
                     .with_context(|| format!("Checking validity for message in {}, {}", message.l1_transaction_hash, message.l1_block_number))? {
                     // Add the pending message to db.
@@ -304,6 +320,61 @@ mod messaging_module_tests {
         Ok(())
     }
 
+    #[rstest]
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_sync_indexes_messages_by_l1_transaction_hash(
+        #[future] setup_messaging_tests: MessagingTestRunner,
+    ) -> anyhow::Result<()> {
+        let MessagingTestRunner { mut client, db, ctx } = setup_messaging_tests.await;
+
+        // Two messages sent by the same l1 transaction, and one sent by another l1 transaction
+        let mock_event1 = create_mock_event(100, 1);
+        let mock_event2 = create_mock_event(100, 18);
+        let mut mock_event3 = create_mock_event(101, 19);
+        mock_event3.l1_transaction_hash = U256::from(2);
+        let backend = db.backend();
+        let notify = Arc::new(Notify::new());
+
+        backend.set_l1_messaging_sync_tip(99)?;
+
+        let events = vec![mock_event1.clone(), mock_event2.clone(), mock_event3.clone()];
+        client
+            .expect_messages_to_l2_stream()
+            .times(1)
+            .returning(move |_| Ok(stream::iter(events.clone()).map(Ok).boxed()));
+
+        // nonce 1 is pending on l1, nonces 18 and 19 have already been consumed on l2.
+        mock_l1_handler_tx(&mut client, 1, true, false);
+        backend.set_l1_handler_txn_hash_by_nonce(18, Felt::ONE).unwrap();
+        backend.set_l1_handler_txn_hash_by_nonce(19, Felt::TWO).unwrap();
+
+        let client = Arc::new(client) as Arc<dyn SettlementLayerProvider>;
+        let ctx_clone = ctx.clone();
+        let db_backend_clone = backend.clone();
+        let sync_handle = tokio::spawn(async move { sync(client, db_backend_clone, notify, ctx).await });
+
+        tokio::time::sleep(Duration::from_secs(5)).await;
+
+        let chain_id = backend.chain_config().chain_id.to_felt();
+        let expected_txn = |event: &MessageToL2WithMetadata| {
+            (event.message.tx.nonce, event.message.tx.compute_hash(chain_id, false, false))
+        };
+        assert_eq!(
+            backend.get_l1_handler_txns_by_l1_txn_hash(&L1TransactionHash(U256::from(1).to_be_bytes()))?,
+            vec![expected_txn(&mock_event1), expected_txn(&mock_event2)]
+        );
+        assert_eq!(
+            backend.get_l1_handler_txns_by_l1_txn_hash(&L1TransactionHash(U256::from(2).to_be_bytes()))?,
+            vec![expected_txn(&mock_event3)]
+        );
+        assert_eq!(backend.get_l1_handler_txns_by_l1_txn_hash(&L1TransactionHash([0; 32]))?, vec![]);
+
+        ctx_clone.cancel_global();
+        sync_handle.abort();
+
+        Ok(())
+    }
+
     #[rstest]
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     async fn test_sync_catches_earlier_messages(
@@ -0,0 +1,28 @@
+use starknet_types_core::felt::Felt;
+use std::{
+    collections::HashSet,
+    sync::{Arc, RwLock},
+};
+
+/// Accounts whose transactions are accepted and executed without running their `__validate__` entrypoint, which
+/// means that they can be sent without a valid signature. This is only ever filled in devnet mode.
+///
+/// This is shared between the transaction validator and block production, cloning it does not copy the set.
+#[derive(Clone, Debug, Default)]
+pub struct ImpersonatedAccounts(Arc<RwLock<HashSet<Felt>>>);
+
+impl ImpersonatedAccounts {
+    /// Returns false if the account was already impersonated.
+    pub fn insert(&self, address: Felt) -> bool {
+        self.0.write().expect("Poisoned lock").insert(address)
+    }
+
+    /// Returns false if the account was not impersonated.
+    pub fn remove(&self, address: &Felt) -> bool {
+        self.0.write().expect("Poisoned lock").remove(address)
+    }
+
+    pub fn contains(&self, address: &Felt) -> bool {
+        self.0.read().expect("Poisoned lock").contains(address)
+    }
+}
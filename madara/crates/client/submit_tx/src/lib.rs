@@ -6,9 +6,13 @@ use mp_rpc::{
 use mp_transactions::{validated::ValidatedMempoolTx, L1HandlerTransaction, L1HandlerTransactionResult};
 
 mod error;
+mod impersonation;
+mod policy;
 mod validation;
 
 pub use error::*;
+pub use impersonation::ImpersonatedAccounts;
+pub use policy::TransactionPolicies;
 pub use validation::{TransactionValidator, TransactionValidatorConfig};
 
 /// Abstraction layer over where transactions are submitted.
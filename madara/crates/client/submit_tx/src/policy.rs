@@ -0,0 +1,383 @@
+use crate::{RejectedTransactionError, RejectedTransactionErrorKind};
+use mp_chain_config::TransactionPolicyConfig;
+use mp_convert::ToFelt;
+use starknet_api::{
+    abi::abi_utils::selector_from_name, executable_transaction::AccountTransaction as ApiAccountTransaction,
+    transaction::InvokeTransaction as ApiInvokeTransaction,
+};
+use starknet_types_core::felt::Felt;
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    sync::{Arc, Mutex, RwLock},
+    time::Instant,
+};
+
+/// Expired rate limit windows are only cleaned up once this many accounts are being tracked.
+const RATE_LIMIT_PRUNE_THRESHOLD: usize = 10_000;
+
+fn rejected(kind: RejectedTransactionErrorKind, message: impl Into<Cow<'static, str>>) -> RejectedTransactionError {
+    RejectedTransactionError::new(kind, message)
+}
+
+/// Transaction filtering rules of the sequencer, see [`TransactionPolicyConfig`].
+///
+/// This is shared between the transaction validator, block production and the admin RPC, cloning it does not copy
+/// the policies. Block production checks the mempool transactions again before executing them, so that replacing the
+/// policies also applies to the transactions which were already accepted.
+#[derive(Clone, Debug, Default)]
+pub struct TransactionPolicies(Arc<Inner>);
+
+#[derive(Debug, Default)]
+struct Inner {
+    rules: RwLock<Rules>,
+    /// Rate limit window of each account: when the window started, and how many transactions were submitted since.
+    submissions: Mutex<HashMap<Felt, (Instant, u32)>>,
+}
+
+#[derive(Debug)]
+struct Rules {
+    config: TransactionPolicyConfig,
+    allowed_senders: Option<HashSet<Felt>>,
+    denied_senders: HashSet<Felt>,
+    allowed_contracts: Option<HashSet<Felt>>,
+    denied_contracts: HashSet<Felt>,
+    allowed_declared_classes: Option<HashSet<Felt>>,
+    private_deploy: Option<HashSet<Felt>>,
+    /// Universal deployers and their entrypoints, used to detect deployments in private deploy mode.
+    universal_deployers: HashSet<Felt>,
+    deploy_selectors: [Felt; 2],
+}
+
+impl From<TransactionPolicyConfig> for Rules {
+    fn from(config: TransactionPolicyConfig) -> Self {
+        let to_set = |list: &Vec<Felt>| list.iter().copied().collect::<HashSet<_>>();
+        Self {
+            allowed_senders: config.allowed_senders.as_ref().map(to_set),
+            denied_senders: to_set(&config.denied_senders),
+            allowed_contracts: config.allowed_contracts.as_ref().map(to_set),
+            denied_contracts: to_set(&config.denied_contracts),
+            allowed_declared_classes: config.allowed_declared_classes.as_ref().map(to_set),
+            private_deploy: config.private_deploy.as_ref().map(to_set),
+            universal_deployers: to_set(&config.universal_deployers()),
+            deploy_selectors: [selector_from_name("deployContract").0, selector_from_name("deploy_contract").0],
+            config,
+        }
+    }
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        TransactionPolicyConfig::default().into()
+    }
+}
+
+impl Rules {
+    fn check(&self, tx: &ApiAccountTransaction) -> Result<(), RejectedTransactionError> {
+        use RejectedTransactionErrorKind::*;
+
+        // This is the deployed address for deploy account transactions.
+        let sender = tx.contract_address().to_felt();
+        if self.denied_senders.contains(&sender)
+            || self.allowed_senders.as_ref().is_some_and(|allowed| !allowed.contains(&sender))
+        {
+            return Err(rejected(
+                NotPermittedContract,
+                format!("Account {sender:#x} is not allowed to send transactions"),
+            ));
+        }
+
+        match tx {
+            ApiAccountTransaction::Declare(tx) => {
+                let class_hash = tx.class_hash().to_felt();
+                if self.allowed_declared_classes.as_ref().is_some_and(|allowed| !allowed.contains(&class_hash)) {
+                    return Err(rejected(
+                        NotPermittedContract,
+                        format!("Class hash {class_hash:#x} is not allowed to be declared"),
+                    ));
+                }
+            }
+            ApiAccountTransaction::DeployAccount(_) => {
+                if !self.can_deploy(&sender) {
+                    return Err(rejected(
+                        NotPermittedContract,
+                        format!("Private deploy mode: account {sender:#x} is not allowed to be deployed"),
+                    ));
+                }
+            }
+            ApiAccountTransaction::Invoke(tx) => self.check_calls(sender, &tx.tx)?,
+        }
+        Ok(())
+    }
+
+    fn can_deploy(&self, sender: &Felt) -> bool {
+        self.private_deploy.as_ref().is_none_or(|deployers| deployers.contains(sender))
+    }
+
+    fn check_calls(&self, sender: Felt, tx: &ApiInvokeTransaction) -> Result<(), RejectedTransactionError> {
+        use RejectedTransactionErrorKind::*;
+
+        if self.allowed_contracts.is_none() && self.denied_contracts.is_empty() && self.private_deploy.is_none() {
+            return Ok(());
+        }
+
+        let calls = match tx {
+            // Invoke v0 transactions directly call an entrypoint of the contract.
+            ApiInvokeTransaction::V0(tx) => vec![(tx.contract_address.to_felt(), tx.entry_point_selector.0)],
+            tx => decode_calls(&tx.calldata().0).ok_or_else(|| {
+                rejected(
+                    NotPermittedContract,
+                    "The calls of the transaction could not be decoded to be checked against the contract policies",
+                )
+            })?,
+        };
+
+        for (to, selector) in calls {
+            if self.denied_contracts.contains(&to)
+                || self.allowed_contracts.as_ref().is_some_and(|allowed| !allowed.contains(&to))
+            {
+                return Err(rejected(NotPermittedContract, format!("Calling contract {to:#x} is not allowed")));
+            }
+            if self.universal_deployers.contains(&to)
+                && self.deploy_selectors.contains(&selector)
+                && !self.can_deploy(&sender)
+            {
+                return Err(rejected(
+                    UnauthorizedEntryPointForInvoke,
+                    format!("Private deploy mode: account {sender:#x} is not allowed to deploy contracts"),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Decodes the `(to, selector)` of each call from the standard account `__execute__` calldata:
+/// `[n_calls, (to, selector, calldata_len, ...calldata)*]`.
+fn decode_calls(calldata: &[Felt]) -> Option<Vec<(Felt, Felt)>> {
+    let (n_calls, mut rest) = calldata.split_first()?;
+    let n_calls: u64 = (*n_calls).try_into().ok()?;
+
+    let mut calls = Vec::new();
+    for _ in 0..n_calls {
+        let [to, selector, calldata_len, tail @ ..] = rest else { return None };
+        let calldata_len: usize = (*calldata_len).try_into().ok()?;
+        rest = tail.get(calldata_len..)?;
+        calls.push((*to, *selector));
+    }
+    // Trailing felts mean this is not the standard layout.
+    rest.is_empty().then_some(calls)
+}
+
+impl TransactionPolicies {
+    pub fn new(config: TransactionPolicyConfig) -> Self {
+        Self(Arc::new(Inner { rules: RwLock::new(config.into()), submissions: Default::default() }))
+    }
+
+    pub fn config(&self) -> TransactionPolicyConfig {
+        self.0.rules.read().expect("Poisoned lock").config.clone()
+    }
+
+    /// Replaces all of the policies. Rate limit windows start over.
+    pub fn replace(&self, config: TransactionPolicyConfig) {
+        *self.0.rules.write().expect("Poisoned lock") = config.into();
+        self.0.submissions.lock().expect("Poisoned lock").clear();
+    }
+
+    /// Checks the sender, contract, declared class and private deploy rules. This does not count towards the rate
+    /// limit.
+    pub fn check(&self, tx: &ApiAccountTransaction) -> Result<(), RejectedTransactionError> {
+        self.0.rules.read().expect("Poisoned lock").check(tx)
+    }
+
+    /// Counts a transaction submitted by this account, rejecting it when the account is over its rate limit.
+    pub fn check_rate_limit(&self, sender: Felt) -> Result<(), RejectedTransactionError> {
+        self.check_rate_limit_at(sender, Instant::now())
+    }
+
+    fn check_rate_limit_at(&self, sender: Felt, now: Instant) -> Result<(), RejectedTransactionError> {
+        let Some(rate_limit) = self.0.rules.read().expect("Poisoned lock").config.rate_limit.clone() else {
+            return Ok(());
+        };
+
+        let mut submissions = self.0.submissions.lock().expect("Poisoned lock");
+        let is_expired = |started_at: Instant| now.saturating_duration_since(started_at) >= rate_limit.period;
+        if submissions.len() >= RATE_LIMIT_PRUNE_THRESHOLD {
+            submissions.retain(|_, (started_at, _)| !is_expired(*started_at));
+        }
+
+        let (started_at, count) = submissions.entry(sender).or_insert((now, 0));
+        if is_expired(*started_at) {
+            *started_at = now;
+            *count = 0;
+        }
+        if *count >= rate_limit.max_transactions {
+            return Err(rejected(
+                RejectedTransactionErrorKind::RateLimited,
+                format!(
+                    "Account {sender:#x} already submitted {} transactions in the last {:?}",
+                    rate_limit.max_transactions, rate_limit.period
+                ),
+            ));
+        }
+        *count += 1;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mp_chain_config::RateLimitConfig;
+    use std::time::Duration;
+
+    #[test]
+    fn test_decode_calls() {
+        let f = Felt::from;
+        assert_eq!(decode_calls(&[f(0)]), Some(vec![]));
+        assert_eq!(
+            decode_calls(&[f(2), f(10), f(11), f(1), f(100), f(20), f(21), f(0)]),
+            Some(vec![(f(10), f(11)), (f(20), f(21))])
+        );
+        // Truncated call data, and trailing felts.
+        assert_eq!(decode_calls(&[f(1), f(10), f(11), f(2), f(100)]), None);
+        assert_eq!(decode_calls(&[f(1), f(10), f(11), f(0), f(100)]), None);
+        assert_eq!(decode_calls(&[]), None);
+    }
+
+    fn invoke(sender: Felt, calldata: Vec<Felt>) -> ApiAccountTransaction {
+        ApiAccountTransaction::Invoke(starknet_api::executable_transaction::InvokeTransaction {
+            tx: ApiInvokeTransaction::V1(starknet_api::transaction::InvokeTransactionV1 {
+                max_fee: Default::default(),
+                signature: Default::default(),
+                nonce: Default::default(),
+                sender_address: sender.try_into().unwrap(),
+                calldata: starknet_api::transaction::fields::Calldata(calldata.into()),
+            }),
+            tx_hash: Default::default(),
+        })
+    }
+
+    fn call(to: Felt, selector: Felt) -> Vec<Felt> {
+        vec![Felt::ONE, to, selector, Felt::ZERO]
+    }
+
+    fn assert_rejected(policies: &TransactionPolicies, tx: &ApiAccountTransaction, kind: RejectedTransactionErrorKind) {
+        let err = policies.check(tx).unwrap_err();
+        assert_eq!(err.kind.to_string(), kind.to_string(), "{err}");
+    }
+
+    #[test]
+    fn test_sender_and_contract_lists() {
+        let (alice, bob, contract, other_contract) = (Felt::ONE, Felt::TWO, Felt::from(10), Felt::from(11));
+        let selector = selector_from_name("transfer").0;
+        let policies = TransactionPolicies::new(TransactionPolicyConfig {
+            denied_senders: vec![bob],
+            allowed_contracts: Some(vec![contract]),
+            ..Default::default()
+        });
+
+        policies.check(&invoke(alice, call(contract, selector))).unwrap();
+        assert_rejected(
+            &policies,
+            &invoke(bob, call(contract, selector)),
+            RejectedTransactionErrorKind::NotPermittedContract,
+        );
+        assert_rejected(
+            &policies,
+            &invoke(alice, call(other_contract, selector)),
+            RejectedTransactionErrorKind::NotPermittedContract,
+        );
+        // Calldata which is not a list of calls cannot be checked.
+        assert_rejected(&policies, &invoke(alice, vec![contract]), RejectedTransactionErrorKind::NotPermittedContract);
+
+        // Hot reload.
+        policies.replace(TransactionPolicyConfig {
+            allowed_senders: Some(vec![bob]),
+            denied_contracts: vec![contract],
+            ..Default::default()
+        });
+        policies.check(&invoke(bob, call(other_contract, selector))).unwrap();
+        assert_rejected(
+            &policies,
+            &invoke(alice, call(other_contract, selector)),
+            RejectedTransactionErrorKind::NotPermittedContract,
+        );
+        assert_rejected(
+            &policies,
+            &invoke(bob, call(contract, selector)),
+            RejectedTransactionErrorKind::NotPermittedContract,
+        );
+    }
+
+    #[test]
+    fn test_private_deploy() {
+        let (deployer, alice) = (Felt::ONE, Felt::TWO);
+        let [udc, cairo_1_udc] = TransactionPolicyConfig::DEFAULT_UNIVERSAL_DEPLOYERS;
+        let deploy = call(udc, selector_from_name("deployContract").0);
+        let policies = TransactionPolicies::default();
+        policies.check(&invoke(alice, deploy.clone())).unwrap();
+        // Calldata is not decoded when there are no contract rules.
+        policies.check(&invoke(alice, vec![])).unwrap();
+
+        policies.replace(TransactionPolicyConfig { private_deploy: Some(vec![deployer]), ..Default::default() });
+        policies.check(&invoke(deployer, deploy.clone())).unwrap();
+        policies.check(&invoke(alice, call(udc, selector_from_name("transfer").0))).unwrap();
+        assert_rejected(
+            &policies,
+            &invoke(alice, deploy),
+            RejectedTransactionErrorKind::UnauthorizedEntryPointForInvoke,
+        );
+        assert_rejected(
+            &policies,
+            &invoke(alice, call(cairo_1_udc, selector_from_name("deploy_contract").0)),
+            RejectedTransactionErrorKind::UnauthorizedEntryPointForInvoke,
+        );
+        // Contracts which are not universal deployers can have a deploy entrypoint.
+        policies.check(&invoke(alice, call(Felt::from(10), selector_from_name("deployContract").0))).unwrap();
+
+        policies.replace(TransactionPolicyConfig {
+            private_deploy: Some(vec![deployer]),
+            universal_deployers: Some(vec![Felt::from(10)]),
+            ..Default::default()
+        });
+        policies.check(&invoke(alice, call(udc, selector_from_name("deployContract").0))).unwrap();
+        assert_rejected(
+            &policies,
+            &invoke(alice, call(Felt::from(10), selector_from_name("deployContract").0)),
+            RejectedTransactionErrorKind::UnauthorizedEntryPointForInvoke,
+        );
+    }
+
+    #[test]
+    fn test_rate_limit() {
+        let policies = TransactionPolicies::default();
+        let now = Instant::now();
+        // No rate limit.
+        for _ in 0..10 {
+            policies.check_rate_limit_at(Felt::ONE, now).unwrap();
+        }
+
+        policies.replace(TransactionPolicyConfig {
+            rate_limit: Some(RateLimitConfig { max_transactions: 2, period: Duration::from_secs(60) }),
+            ..Default::default()
+        });
+        policies.check_rate_limit_at(Felt::ONE, now).unwrap();
+        policies.check_rate_limit_at(Felt::ONE, now + Duration::from_secs(1)).unwrap();
+        let err = policies.check_rate_limit_at(Felt::ONE, now + Duration::from_secs(2)).unwrap_err();
+        assert!(matches!(err.kind, RejectedTransactionErrorKind::RateLimited));
+        // Other accounts have their own limit.
+        policies.check_rate_limit_at(Felt::TWO, now + Duration::from_secs(2)).unwrap();
+        // The window starts over once the period has elapsed.
+        policies.check_rate_limit_at(Felt::ONE, now + Duration::from_secs(60)).unwrap();
+    }
+
+    #[test]
+    fn test_config_roundtrip() {
+        let config = TransactionPolicyConfig { denied_senders: vec![Felt::ONE], ..Default::default() };
+        let policies = TransactionPolicies::new(config.clone());
+        assert_eq!(policies.config(), config);
+        policies.replace(TransactionPolicyConfig::default());
+        assert_eq!(policies.config(), TransactionPolicyConfig::default());
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
-    RejectedTransactionError, RejectedTransactionErrorKind, SubmitTransaction, SubmitTransactionError,
-    SubmitValidatedTransaction,
+    ImpersonatedAccounts, RejectedTransactionError, RejectedTransactionErrorKind, SubmitTransaction,
+    SubmitTransactionError, SubmitValidatedTransaction, TransactionPolicies,
 };
 use async_trait::async_trait;
 use blockifier::{
@@ -182,6 +182,8 @@ pub struct TransactionValidator {
     inner: Arc<dyn SubmitValidatedTransaction>,
     backend: Arc<MadaraBackend>,
     config: TransactionValidatorConfig,
+    impersonated_accounts: Option<ImpersonatedAccounts>,
+    policies: Option<TransactionPolicies>,
 }
 
 impl fmt::Debug for TransactionValidator {
@@ -196,7 +198,44 @@ impl TransactionValidator {
         backend: Arc<MadaraBackend>,
         config: TransactionValidatorConfig,
     ) -> Self {
-        Self { inner, backend, config }
+        Self { inner, backend, config, impersonated_accounts: None, policies: None }
+    }
+
+    /// Skip the `__validate__` entrypoint for transactions sent by these accounts. Devnet only.
+    pub fn with_impersonated_accounts(mut self, impersonated_accounts: ImpersonatedAccounts) -> Self {
+        self.impersonated_accounts = Some(impersonated_accounts);
+        self
+    }
+
+    /// Reject transactions which do not follow the sequencer transaction policies.
+    pub fn with_transaction_policies(mut self, policies: TransactionPolicies) -> Self {
+        self.policies = Some(policies);
+        self
+    }
+
+    fn is_impersonated(&self, tx: &ApiAccountTransaction) -> bool {
+        self.impersonated_accounts.as_ref().is_some_and(|accounts| accounts.contains(&tx.contract_address().to_felt()))
+    }
+
+    /// L2 gas bounds are not kept in the blockifier transaction, so they are checked on the broadcasted transaction.
+    /// Transactions which do not use L2 gas are accepted whatever their max price is.
+    fn check_l2_gas_price(&self, tx: &BroadcastedTxn) -> Result<(), SubmitTransactionError> {
+        let Some(resource_bounds) = tx.resource_bounds() else { return Ok(()) };
+        if self.config.disable_validation || resource_bounds.l2_gas.max_amount == 0 {
+            return Ok(());
+        }
+
+        let l2_gas_price = self.backend.latest_pending_block().header.l1_gas_price.strk_l2_gas_price;
+        if resource_bounds.l2_gas.max_price_per_unit < l2_gas_price {
+            return Err(rejected(
+                RejectedTransactionErrorKind::InsufficientMaxFee,
+                format!(
+                    "L2 gas max price per unit {} is lower than the current L2 gas price {l2_gas_price}",
+                    resource_bounds.l2_gas.max_price_per_unit
+                ),
+            ));
+        }
+        Ok(())
     }
 
     #[tracing::instrument(skip(self, tx, converted_class), fields(module = "TxValidation"))]
@@ -216,9 +255,16 @@ impl TransactionValidator {
             )
             .into());
         };
+
+        if let Some(policies) = &self.policies {
+            policies.check(&tx)?;
+        }
+        let sender_address = tx.contract_address().to_felt();
+
         // We have to skip part of the validation in the very specific case where you send an invoke tx directly after a deploy account:
         // the account is not deployed yet but the tx should be accepted.
-        let validate = !(tx.tx_type() == TransactionType::InvokeFunction && tx.nonce().to_felt() == Felt::ONE);
+        let validate = !(tx.tx_type() == TransactionType::InvokeFunction && tx.nonce().to_felt() == Felt::ONE)
+            && !self.is_impersonated(&tx);
 
         // No charge_fee for Admin DeclareV0
         let charge_fee = !((tx.tx_type() == TransactionType::Declare
@@ -246,6 +292,11 @@ impl TransactionValidator {
             validator.perform_validations(account_tx.clone())?
         }
 
+        // Only the transactions which passed `__validate__` count towards the rate limit of their sender.
+        if let Some(policies) = &self.policies {
+            policies.check_rate_limit(sender_address)?;
+        }
+
         // Forward the validated tx.
         let tx = ValidatedMempoolTx::from_starknet_api(account_tx.tx, arrived_at, converted_class);
         self.inner.submit_validated_transaction(tx).await?;
@@ -301,6 +352,7 @@ impl SubmitTransaction for TransactionValidator {
 
         let arrived_at = TxTimestamp::now();
         let tx: BroadcastedTxn = BroadcastedTxn::Declare(tx);
+        self.check_l2_gas_price(&tx)?;
         let (api_tx, class) = tx.into_starknet_api(
             self.backend.chain_config().chain_id.to_felt(),
             self.backend.chain_config().latest_protocol_version,
@@ -332,6 +384,7 @@ impl SubmitTransaction for TransactionValidator {
 
         let arrived_at = TxTimestamp::now();
         let tx = BroadcastedTxn::DeployAccount(tx);
+        self.check_l2_gas_price(&tx)?;
         let (api_tx, class) = tx.into_starknet_api(
             self.backend.chain_config().chain_id.to_felt(),
             self.backend.chain_config().latest_protocol_version,
@@ -363,6 +416,7 @@ impl SubmitTransaction for TransactionValidator {
 
         let arrived_at = TxTimestamp::now();
         let tx = BroadcastedTxn::Invoke(tx);
+        self.check_l2_gas_price(&tx)?;
         let (api_tx, class) = tx.into_starknet_api(
             self.backend.chain_config().chain_id.to_felt(),
             self.backend.chain_config().latest_protocol_version,
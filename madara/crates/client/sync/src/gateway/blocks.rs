@@ -2,11 +2,16 @@ use crate::{
     import::BlockImporter,
     pipeline::{ApplyOutcome, PipelineController, PipelineSteps},
     probe::ThrottledRepeatedFuture,
+    source::{SourceBlock, SyncSource},
 };
 use anyhow::Context;
 use mc_db::MadaraBackend;
 use mc_gateway_client::GatewayProvider;
-use mp_block::{BlockHeaderWithSignatures, BlockId, BlockTag, FullBlock, Header, PendingFullBlock};
+use mp_block::{
+    commitments::{BlockCommitments, CommitmentComputationContext},
+    BlockHeaderWithSignatures, BlockId, BlockTag, Header, PendingFullBlock,
+};
+use mp_convert::ToFelt;
 use mp_gateway::{
     error::{SequencerError, StarknetErrorCode},
     state_update::ProviderStateUpdateWithBlockPendingMaybe,
@@ -16,32 +21,31 @@ use mp_utils::AbortOnDrop;
 use starknet_core::types::Felt;
 use std::{ops::Range, sync::Arc, time::Duration};
 
-pub type GatewayBlockSync = PipelineController<GatewaySyncSteps>;
-pub fn block_with_state_update_pipeline(
+pub type BlockSync<S> = PipelineController<BlockSyncSteps<S>>;
+pub fn block_with_state_update_pipeline<S: SyncSource>(
     backend: Arc<MadaraBackend>,
     importer: Arc<BlockImporter>,
-    client: Arc<GatewayProvider>,
+    client: Arc<S>,
     starting_block_n: u64,
     parallelization: usize,
     batch_size: usize,
     keep_pre_v0_13_2_hashes: bool,
-) -> GatewayBlockSync {
+) -> BlockSync<S> {
     PipelineController::new(
-        GatewaySyncSteps { backend, importer, client, keep_pre_v0_13_2_hashes },
+        BlockSyncSteps { backend, importer, client, keep_pre_v0_13_2_hashes },
         parallelization,
         batch_size,
         starting_block_n,
     )
 }
 
-// TODO: check that the headers follow each other
-pub struct GatewaySyncSteps {
+pub struct BlockSyncSteps<S> {
     backend: Arc<MadaraBackend>,
     importer: Arc<BlockImporter>,
-    client: Arc<GatewayProvider>,
+    client: Arc<S>,
     keep_pre_v0_13_2_hashes: bool,
 }
-impl PipelineSteps for GatewaySyncSteps {
+impl<S: SyncSource> PipelineSteps for BlockSyncSteps<S> {
     type InputItem = ();
     type SequentialStepInput = Vec<StateDiff>;
     type Output = Vec<StateDiff>;
@@ -53,70 +57,88 @@ impl PipelineSteps for GatewaySyncSteps {
     ) -> anyhow::Result<Self::SequentialStepInput> {
         AbortOnDrop::spawn(async move {
             let mut out = vec![];
-            tracing::debug!("Gateway sync parallel step {:?}", block_range);
+            tracing::debug!("Block sync parallel step {:?}", block_range);
+            let verify_signatures = self.importer.verifies_signatures();
             for block_n in block_range {
-                let block = self
+                let SourceBlock { block, consensus_signatures } = self
                     .client
-                    .get_state_update_with_block(BlockId::Number(block_n))
+                    .get_block(block_n, verify_signatures)
                     .await
-                    .with_context(|| format!("Getting state update with block_n={block_n}"))?;
-
-                let ProviderStateUpdateWithBlockPendingMaybe::NonPending(block) = block else {
-                    anyhow::bail!("Asked for a block_n, got a pending one")
-                };
-
-                let gateway_block: FullBlock = block.into_full_block().context("Parsing gateway block")?;
+                    .with_context(|| format!("Getting block with block_n={block_n}"))?;
 
                 let keep_pre_v0_13_2_hashes = self.keep_pre_v0_13_2_hashes;
+                let chain_id = self.backend.chain_config().chain_id.to_felt();
 
                 let state_diff = self
                     .importer
                     .run_in_rayon_pool(move |importer| {
                         let mut signed_header = BlockHeaderWithSignatures {
-                            header: gateway_block.header,
-                            block_hash: gateway_block.block_hash,
-                            consensus_signatures: vec![],
+                            header: block.header,
+                            block_hash: block.block_hash,
+                            consensus_signatures,
                         };
 
-                        // Allow the gateway format, which has legacy commitments.
-                        let allow_pre_v0_13_2 = true;
-
-                        let state_diff_commitment = importer.verify_state_diff(
-                            block_n,
-                            &gateway_block.state_diff,
-                            &signed_header.header,
-                            allow_pre_v0_13_2,
-                        )?;
-                        let (transaction_commitment, receipt_commitment) = importer.verify_transactions(
-                            block_n,
-                            &gateway_block.transactions,
-                            &signed_header.header,
-                            allow_pre_v0_13_2,
-                        )?;
-                        let event_commitment = importer.verify_events(
-                            block_n,
-                            &gateway_block.events,
-                            &signed_header.header,
-                            allow_pre_v0_13_2,
-                        )?;
-                        if !keep_pre_v0_13_2_hashes {
-                            // Fill in the header with the commitments missing in pre-v0.13.2 headers from the gateway.
+                        if S::SERVES_COMMITMENTS {
+                            // Allow the gateway format, which has legacy commitments.
+                            let allow_pre_v0_13_2 = true;
+
+                            let state_diff_commitment = importer.verify_state_diff(
+                                block_n,
+                                &block.state_diff,
+                                &signed_header.header,
+                                allow_pre_v0_13_2,
+                            )?;
+                            let (transaction_commitment, receipt_commitment) = importer.verify_transactions(
+                                block_n,
+                                &block.transactions,
+                                &signed_header.header,
+                                allow_pre_v0_13_2,
+                            )?;
+                            let event_commitment = importer.verify_events(
+                                block_n,
+                                &block.events,
+                                &signed_header.header,
+                                allow_pre_v0_13_2,
+                            )?;
+                            if !keep_pre_v0_13_2_hashes {
+                                // Fill in the header with the commitments missing in pre-v0.13.2 headers from the
+                                // gateway.
+                                signed_header.header = Header {
+                                    state_diff_commitment: Some(state_diff_commitment),
+                                    transaction_commitment,
+                                    event_commitment,
+                                    receipt_commitment: Some(receipt_commitment),
+                                    ..signed_header.header
+                                };
+                            }
+                        } else {
+                            // The source does not serve the commitments: they are computed here, and checked along
+                            // with the rest of the header through the block hash.
+                            let ctx = CommitmentComputationContext {
+                                protocol_version: signed_header.header.protocol_version,
+                                chain_id,
+                            };
+                            let commitments =
+                                BlockCommitments::compute(&ctx, &block.transactions, &block.state_diff, &block.events);
                             signed_header.header = Header {
-                                state_diff_commitment: Some(state_diff_commitment),
-                                transaction_commitment,
-                                event_commitment,
-                                receipt_commitment: Some(receipt_commitment),
+                                transaction_count: commitments.transaction.transaction_count,
+                                transaction_commitment: commitments.transaction.transaction_commitment,
+                                receipt_commitment: Some(commitments.transaction.receipt_commitment),
+                                event_count: commitments.event.events_count,
+                                event_commitment: commitments.event.events_commitment,
+                                state_diff_length: Some(commitments.state_diff.state_diff_length),
+                                state_diff_commitment: Some(commitments.state_diff.state_diff_commitment),
                                 ..signed_header.header
                             };
                         }
                         importer.verify_header(block_n, &signed_header)?;
 
                         importer.save_header(block_n, signed_header)?;
-                        importer.save_state_diff(block_n, gateway_block.state_diff.clone())?;
-                        importer.save_transactions(block_n, gateway_block.transactions)?;
-                        importer.save_events(block_n, gateway_block.events)?;
+                        importer.save_state_diff(block_n, block.state_diff.clone())?;
+                        importer.save_transactions(block_n, block.transactions)?;
+                        importer.save_events(block_n, block.events)?;
 
-                        anyhow::Ok(gateway_block.state_diff)
+                        anyhow::Ok(block.state_diff)
                     })
                     .await
                     .with_context(|| format!("Verifying block for block_n={block_n:?}"))?;
@@ -131,7 +153,21 @@ impl PipelineSteps for GatewaySyncSteps {
         block_range: Range<u64>,
         input: Self::SequentialStepInput,
     ) -> anyhow::Result<ApplyOutcome<Self::Output>> {
-        tracing::debug!("Gateway sync sequential step: {block_range:?}");
+        tracing::debug!("Block sync sequential step: {block_range:?}");
+
+        // Headers are fetched out of order in the parallel step, this is the first place where we can check that they
+        // follow each other. A mismatch here means the source has reorged, the caller is in charge of rewinding.
+        let block_range_ = block_range.clone();
+        self.importer
+            .run_in_rayon_pool(move |importer| {
+                for block_n in block_range_ {
+                    let header = importer.get_header(block_n)?;
+                    importer.verify_parent_hash(block_n, &header)?;
+                }
+                anyhow::Ok(())
+            })
+            .await?;
+
         if let Some(block_n) = block_range.last() {
             self.backend.clear_pending_block().context("Clearing pending block")?;
             self.backend.head_status().headers.set_current(Some(block_n));
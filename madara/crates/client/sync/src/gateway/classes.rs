@@ -1,10 +1,10 @@
 use crate::{
     import::BlockImporter,
     pipeline::{ApplyOutcome, PipelineController, PipelineSteps},
+    source::SyncSource,
 };
 use anyhow::Context;
 use mc_db::MadaraBackend;
-use mc_gateway_client::GatewayProvider;
 use mp_block::BlockId;
 use mp_class::{ClassInfo, ClassInfoWithHash, ConvertedClass, LegacyClassInfo, SierraClassInfo, MISSED_CLASS_HASHES};
 use mp_state_update::DeclaredClassCompiledClass;
@@ -15,7 +15,10 @@ use std::{collections::HashMap, ops::Range, sync::Arc};
 
 /// for blocks before 2597 on mainnet new classes are not declared in the state update
 /// https://github.com/madara-alliance/madara/issues/233
-fn fixup_missed_mainnet_classes(block_n: u64, classes_from_state_diff: &mut HashMap<Felt, DeclaredClassCompiledClass>) {
+pub(crate) fn fixup_missed_mainnet_classes(
+    block_n: u64,
+    classes_from_state_diff: &mut HashMap<Felt, DeclaredClassCompiledClass>,
+) {
     if block_n < 2597 {
         classes_from_state_diff.extend(
             MISSED_CLASS_HASHES
@@ -28,8 +31,8 @@ fn fixup_missed_mainnet_classes(block_n: u64, classes_from_state_diff: &mut Hash
     }
 }
 
-pub(crate) async fn get_classes(
-    client: &Arc<GatewayProvider>,
+pub(crate) async fn get_classes<S: SyncSource>(
+    client: &Arc<S>,
     block_id: BlockId,
     classes: &HashMap<Felt, DeclaredClassCompiledClass>,
 ) -> anyhow::Result<Vec<ClassInfoWithHash>> {
@@ -38,23 +41,22 @@ pub(crate) async fn get_classes(
         let client = client.clone();
         async move {
             let class = client
-                .clone()
-                .get_class_by_hash(class_hash, block_id.clone())
+                .get_class(block_id.clone(), class_hash)
                 .await
                 .with_context(|| format!("Getting class_hash={class_hash:#x} with block_id={block_id:?}"))?;
 
-            let class_info = match &class {
+            let class_info = match class {
                 mp_class::ContractClass::Sierra(class) => {
                     let DeclaredClassCompiledClass::Sierra(compiled_class_hash) = compiled_class_hash else {
                         anyhow::bail!("Expected a Sierra class, found a Legacy class")
                     };
-                    ClassInfo::Sierra(SierraClassInfo { contract_class: class.clone(), compiled_class_hash })
+                    ClassInfo::Sierra(SierraClassInfo { contract_class: class, compiled_class_hash })
                 }
                 mp_class::ContractClass::Legacy(class) => {
                     if compiled_class_hash != DeclaredClassCompiledClass::Legacy {
                         anyhow::bail!("Expected a Legacy class, found a Sierra class")
                     }
-                    ClassInfo::Legacy(LegacyClassInfo { contract_class: class.clone() })
+                    ClassInfo::Legacy(LegacyClassInfo { contract_class: class })
                 }
             };
 
@@ -64,24 +66,24 @@ pub(crate) async fn get_classes(
     .await
 }
 
-pub type ClassesSync = PipelineController<ClassesSyncSteps>;
-pub fn classes_pipeline(
+pub type ClassesSync<S> = PipelineController<ClassesSyncSteps<S>>;
+pub fn classes_pipeline<S: SyncSource>(
     backend: Arc<MadaraBackend>,
     importer: Arc<BlockImporter>,
-    client: Arc<GatewayProvider>,
+    client: Arc<S>,
     starting_block: u64,
     parallelization: usize,
     batch_size: usize,
-) -> ClassesSync {
+) -> ClassesSync<S> {
     PipelineController::new(ClassesSyncSteps { backend, importer, client }, parallelization, batch_size, starting_block)
 }
 
-pub struct ClassesSyncSteps {
+pub struct ClassesSyncSteps<S> {
     backend: Arc<MadaraBackend>,
     importer: Arc<BlockImporter>,
-    client: Arc<GatewayProvider>,
+    client: Arc<S>,
 }
-impl PipelineSteps for ClassesSyncSteps {
+impl<S: SyncSource> PipelineSteps for ClassesSyncSteps<S> {
     type InputItem = HashMap<Felt, DeclaredClassCompiledClass>;
     type SequentialStepInput = Vec<Vec<ConvertedClass>>;
     type Output = ();
@@ -102,7 +104,7 @@ impl PipelineSteps for ClassesSyncSteps {
         }
 
         AbortOnDrop::spawn(async move {
-            tracing::debug!("Gateway classes parallel step: {block_range:?}");
+            tracing::debug!("Classes parallel step: {block_range:?}");
             let mut out = vec![];
             for (block_n, classes) in block_range.zip(input) {
                 let declared_classes = get_classes(&self.client, BlockId::Number(block_n), &classes).await?;
@@ -130,7 +132,7 @@ impl PipelineSteps for ClassesSyncSteps {
         if input.iter().all(|i| i.is_empty()) {
             return Ok(ApplyOutcome::Success(()));
         }
-        tracing::debug!("Gateway classes sequential step: {block_range:?}");
+        tracing::debug!("Classes sequential step: {block_range:?}");
         // Save classes in sequential step, because some chains have duplicate class declarations, and we want to be sure
         // we always record the earliest block_n
         let block_range_ = block_range.clone();
@@ -1,22 +1,27 @@
 use crate::{
     apply_state::ApplyStateSync,
-    import::BlockImporter,
+    import::{BlockImportError, BlockImporter},
     metrics::SyncMetrics,
     probe::ThrottledRepeatedFuture,
+    source::{SourceBlock, SyncSource},
     sync::{ForwardPipeline, SyncController, SyncControllerConfig},
 };
 use anyhow::Context;
-use blocks::{gateway_pending_block_sync, GatewayBlockSync};
+use blocks::{gateway_pending_block_sync, BlockSync};
 use classes::ClassesSync;
 use mc_db::{db_block_id::RawDbBlockId, MadaraBackend};
 use mc_gateway_client::GatewayProvider;
-use mp_block::{BlockId, BlockTag};
-use mp_gateway::block::ProviderBlockHeader;
+use mp_block::{BlockId, BlockTag, ConsensusSignature, FullBlock};
+use mp_class::ContractClass;
+use mp_gateway::{block::ProviderBlockHeader, state_update::ProviderStateUpdateWithBlockPendingMaybe};
+use mp_utils::rayon::global_spawn_rayon_task;
+use starknet_core::types::Felt;
 use std::{iter, sync::Arc, time::Duration};
 
 pub(crate) mod blocks;
 pub(crate) mod classes;
 
+#[derive(Clone)]
 pub struct ForwardSyncConfig {
     pub block_parallelization: usize,
     pub block_batch_size: usize,
@@ -52,7 +57,7 @@ impl ForwardSyncConfig {
     }
 }
 
-pub type GatewaySync = SyncController<GatewayForwardSync>;
+pub type GatewaySync = SyncController<ForwardSync<GatewayProvider>>;
 pub fn forward_sync(
     backend: Arc<MadaraBackend>,
     importer: Arc<BlockImporter>,
@@ -60,30 +65,32 @@ pub fn forward_sync(
     controller_config: SyncControllerConfig,
     config: ForwardSyncConfig,
 ) -> GatewaySync {
-    let probe = Arc::new(GatewayLatestProbe::new(client.clone()));
-    let probe = ThrottledRepeatedFuture::new(move |val| probe.clone().probe(val), Duration::from_secs(1));
     let get_pending_block = gateway_pending_block_sync(client.clone(), importer.clone(), backend.clone());
     SyncController::new(
         backend.clone(),
-        GatewayForwardSync::new(backend, importer, client, config),
-        probe,
+        ForwardSync::new(backend, importer, client.clone(), config),
+        latest_probe(client),
         controller_config,
         Some(get_pending_block),
     )
 }
 
-pub struct GatewayForwardSync {
-    blocks_pipeline: GatewayBlockSync,
-    classes_pipeline: ClassesSync,
+/// The block, classes and apply state pipelines, fed from any [`SyncSource`].
+pub struct ForwardSync<S: SyncSource> {
+    blocks_pipeline: BlockSync<S>,
+    classes_pipeline: ClassesSync<S>,
     apply_state_pipeline: ApplyStateSync,
     backend: Arc<MadaraBackend>,
+    importer: Arc<BlockImporter>,
+    client: Arc<S>,
+    config: ForwardSyncConfig,
 }
 
-impl GatewayForwardSync {
+impl<S: SyncSource> ForwardSync<S> {
     pub fn new(
         backend: Arc<MadaraBackend>,
         importer: Arc<BlockImporter>,
-        client: Arc<GatewayProvider>,
+        client: Arc<S>,
         config: ForwardSyncConfig,
     ) -> Self {
         let starting_block_n = backend.head_status().next_full_block();
@@ -112,7 +119,42 @@ impl GatewayForwardSync {
             config.apply_state_batch_size,
             config.disable_tries,
         );
-        Self { blocks_pipeline, classes_pipeline, apply_state_pipeline, backend }
+        Self { blocks_pipeline, classes_pipeline, apply_state_pipeline, backend, importer, client, config }
+    }
+
+    /// Called when the block at `block_n` does not follow the block we have in database. This walks back the chain
+    /// until it finds a block that the source agrees with, reverts the database to it and restarts the pipelines
+    /// from there.
+    async fn handle_reorg(&mut self, block_n: u64) -> anyhow::Result<()> {
+        let mut common_ancestor = block_n.checked_sub(1).context("Genesis block cannot have a parent")?;
+        loop {
+            let local =
+                self.backend.get_block_hash(&RawDbBlockId::Number(common_ancestor)).context("Getting block hash")?;
+            let remote = self
+                .client
+                .get_block_hash(common_ancestor)
+                .await
+                .with_context(|| format!("Getting block hash for block_n={common_ancestor}"))?;
+            if local == Some(remote) {
+                break;
+            }
+            common_ancestor = common_ancestor
+                .checked_sub(1)
+                .context("The source does not agree with our genesis block, it is serving a different chain")?;
+        }
+
+        tracing::warn!("🔀 Reorg detected at block #{block_n}, rewinding to block #{common_ancestor}");
+
+        // Only partial blocks may be above the latest full block, in which case we only need to remove those.
+        if let Some(revert_to) = self.backend.head_status().latest_full_block_n().map(|n| n.min(common_ancestor)) {
+            let backend = self.backend.clone();
+            global_spawn_rayon_task(move || backend.revert_to(revert_to))
+                .await
+                .with_context(|| format!("Reverting chain to block_n={revert_to}"))?;
+        }
+
+        *self = Self::new(self.backend.clone(), self.importer.clone(), self.client.clone(), self.config.clone());
+        Ok(())
     }
 
     fn pipeline_status(&self) -> PipelineStatus {
@@ -137,7 +179,7 @@ impl PipelineStatus {
     }
 }
 
-impl ForwardPipeline for GatewayForwardSync {
+impl<S: SyncSource> ForwardPipeline for ForwardSync<S> {
     async fn run(
         &mut self,
         target_height: u64,
@@ -164,9 +206,18 @@ impl ForwardPipeline for GatewayForwardSync {
                     res?;
                 }
                 Some(res) = self.blocks_pipeline.next(), if self.classes_pipeline.can_schedule_more() && self.apply_state_pipeline.can_schedule_more() => {
-                    let (range, state_diffs) = res?;
-                    self.classes_pipeline.push(range.clone(), state_diffs.iter().map(|s| s.all_declared_classes()));
-                    self.apply_state_pipeline.push(range, state_diffs);
+                    match res {
+                        Ok((range, state_diffs)) => {
+                            self.classes_pipeline.push(range.clone(), state_diffs.iter().map(|s| s.all_declared_classes()));
+                            self.apply_state_pipeline.push(range, state_diffs);
+                        }
+                        Err(err) => {
+                            let Some(&BlockImportError::ParentHash { block_n, .. }) = err.downcast_ref() else {
+                                return Err(err);
+                            };
+                            self.handle_reorg(block_n).await?;
+                        }
+                    }
                 }
                 // all pipelines are empty, we're done :)
                 else => done = true,
@@ -220,24 +271,69 @@ impl ForwardPipeline for GatewayForwardSync {
     }
 }
 
-struct GatewayLatestProbe {
-    client: Arc<GatewayProvider>,
+pub(crate) fn latest_probe<S: SyncSource>(client: Arc<S>) -> ThrottledRepeatedFuture<ProviderBlockHeader> {
+    let probe = Arc::new(LatestProbe { client });
+    ThrottledRepeatedFuture::new(move |val| probe.clone().probe(val), Duration::from_secs(1))
 }
 
-impl GatewayLatestProbe {
-    pub fn new(client: Arc<GatewayProvider>) -> Self {
-        Self { client }
-    }
+struct LatestProbe<S> {
+    client: Arc<S>,
+}
+
+impl<S: SyncSource> LatestProbe<S> {
     async fn probe(
         self: Arc<Self>,
         _highest_known_block: Option<ProviderBlockHeader>,
     ) -> anyhow::Result<Option<ProviderBlockHeader>> {
-        let header = self
-            .client
-            .get_header(BlockId::Tag(BlockTag::Latest))
-            .await
-            .context("Getting the latest block_n from the gateway")?;
+        let header = self.client.get_latest_block().await.context("Getting the latest block_n from the source")?;
         tracing::debug!("Probe got header {header:?}");
         Ok(Some(header))
     }
 }
+
+impl SyncSource for GatewayProvider {
+    async fn get_block(&self, block_n: u64, with_signatures: bool) -> anyhow::Result<SourceBlock> {
+        let (block, signature) = tokio::try_join!(
+            async {
+                self.get_state_update_with_block(BlockId::Number(block_n))
+                    .await
+                    .with_context(|| format!("Getting state update with block_n={block_n}"))
+            },
+            async {
+                if !with_signatures {
+                    return Ok(None);
+                }
+                self.get_signature(BlockId::Number(block_n))
+                    .await
+                    .map(Some)
+                    .with_context(|| format!("Getting signature with block_n={block_n}"))
+            },
+        )?;
+
+        let ProviderStateUpdateWithBlockPendingMaybe::NonPending(block) = block else {
+            anyhow::bail!("Asked for a block_n, got a pending one")
+        };
+        let block: FullBlock = block.into_full_block().context("Parsing gateway block")?;
+
+        let consensus_signatures = signature
+            .into_iter()
+            .filter_map(|signature| match signature.signature[..] {
+                [r, s] => Some(ConsensusSignature { r, s }),
+                _ => None,
+            })
+            .collect();
+        Ok(SourceBlock { block, consensus_signatures })
+    }
+
+    async fn get_block_hash(&self, block_n: u64) -> anyhow::Result<Felt> {
+        Ok(self.get_header(BlockId::Number(block_n)).await?.block_hash)
+    }
+
+    async fn get_latest_block(&self) -> anyhow::Result<ProviderBlockHeader> {
+        Ok(self.get_header(BlockId::Tag(BlockTag::Latest)).await?)
+    }
+
+    async fn get_class(&self, block_id: BlockId, class_hash: Felt) -> anyhow::Result<ContractClass> {
+        Ok(self.get_class_by_hash(class_hash, block_id).await?)
+    }
+}
@@ -25,11 +25,14 @@ pub struct BlockValidationConfig {
     /// Ignore the order of the blocks to allow starting at some height.
     pub trust_parent_hash: bool,
 
-    /// For testing purposes, do not check anything.
+    /// For testing purposes, do not check anything but the parent hashes, which are used to detect reorgs.
     pub no_check: bool,
 
     /// Save pre-v0.13.2 commitments.
     pub pre_v0_13_2_commitments: bool,
+
+    /// Do not check the block signatures against the sequencer public key of the chain config.
+    pub trust_signatures: bool,
 }
 
 impl BlockValidationConfig {
@@ -42,6 +45,9 @@ impl BlockValidationConfig {
     pub fn pre_v0_13_2_commitments(self, pre_v0_13_2_commitments: bool) -> Self {
         Self { pre_v0_13_2_commitments, ..self }
     }
+    pub fn trust_signatures(self, trust_signatures: bool) -> Self {
+        Self { trust_signatures, ..self }
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -83,6 +89,10 @@ pub enum BlockImportError {
     BlockNumber { got: u64, expected: u64 },
     #[error("Block hash mismatch: expected {expected:#x}, got {got:#x}")]
     BlockHash { got: Felt, expected: Felt },
+    #[error("Parent hash mismatch for block #{block_n}: expected {expected:#x}, got {got:#x}")]
+    ParentHash { block_n: u64, got: Felt, expected: Felt },
+    #[error("Invalid signature for block #{block_n} with hash {block_hash:#x}")]
+    Signature { block_n: u64, block_hash: Felt },
 
     #[error("Global state root mismatch: expected {expected:#x}, got {got:#x}")]
     GlobalStateRoot { got: Felt, expected: Felt },
@@ -136,6 +146,12 @@ impl BlockImporter {
     fn ctx(&self) -> BlockImporterCtx {
         BlockImporterCtx { db: self.db.clone(), config: self.config.clone() }
     }
+
+    /// Whether [`BlockImporterCtx::verify_header`] checks the block signatures, in which case they have to be fetched
+    /// along with the headers.
+    pub fn verifies_signatures(&self) -> bool {
+        self.ctx().sequencer_public_key().is_some()
+    }
 }
 
 pub struct BlockImporterCtx {
@@ -161,13 +177,15 @@ impl BlockImporterCtx {
 
     // HEADERS
 
+    fn sequencer_public_key(&self) -> Option<Felt> {
+        self.db.chain_config().sequencer_public_key.filter(|_| !self.config.no_check && !self.config.trust_signatures)
+    }
+
     pub fn verify_header(
         &self,
         block_n: u64,
         signed_header: &BlockHeaderWithSignatures,
     ) -> Result<(), BlockImportError> {
-        // TODO: verify signatures
-
         // verify block_number
         if !self.config.no_check && block_n != signed_header.header.block_number {
             return Err(BlockImportError::BlockNumber { expected: block_n, got: signed_header.header.block_number });
@@ -214,6 +232,53 @@ impl BlockImporterCtx {
             return Err(BlockImportError::BlockHash { got: signed_header.block_hash, expected: block_hash });
         }
 
+        // verify signatures
+        if let Some(public_key) = self.sequencer_public_key() {
+            let is_signed = signed_header.consensus_signatures.iter().any(|signature| {
+                let signature = starknet_core::crypto::Signature { r: signature.r, s: signature.s };
+                starknet_core::crypto::ecdsa_verify(&public_key, &block_hash, &signature).unwrap_or(false)
+            });
+            if !is_signed {
+                return Err(BlockImportError::Signature { block_n, block_hash });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Called in a rayon-pool context.
+    pub fn get_header(&self, block_n: u64) -> Result<Header, BlockImportError> {
+        let header = self
+            .db
+            .get_block_info(&RawDbBlockId::Number(block_n))
+            .map_err(|error| BlockImportError::InternalDb {
+                error,
+                context: format!("Getting block header for {block_n}").into(),
+            })?
+            .context("Block header cannot be found")?
+            .into_closed()
+            .context("Block is pending")?
+            .header;
+        Ok(header)
+    }
+
+    /// Checks that the header follows the block we have in database at `block_n - 1`. A mismatch means the chain we
+    /// are syncing from has reorged, see [`BlockImportError::ParentHash`].
+    pub fn verify_parent_hash(&self, block_n: u64, header: &Header) -> Result<(), BlockImportError> {
+        if self.config.trust_parent_hash {
+            return Ok(());
+        }
+        let Some(parent_block_n) = block_n.checked_sub(1) else { return Ok(()) };
+
+        let expected = self.db.get_block_hash(&RawDbBlockId::Number(parent_block_n)).map_err(|error| {
+            BlockImportError::InternalDb { error, context: format!("Getting block hash for {parent_block_n}").into() }
+        })?;
+        // The parent may not be in database if the sync was started at some height.
+        let Some(expected) = expected else { return Ok(()) };
+
+        if header.parent_block_hash != expected {
+            return Err(BlockImportError::ParentHash { block_n, got: header.parent_block_hash, expected });
+        }
         Ok(())
     }
 
@@ -573,12 +638,14 @@ mod tests {
     use super::{BlockImportError, BlockImporter, BlockImporterCtx, BlockValidationConfig};
     use assert_matches::assert_matches;
     use mc_db::MadaraBackend;
-    use mp_block::{BlockHeaderWithSignatures, FullBlock, Header};
+    use mp_block::{BlockHeaderWithSignatures, ConsensusSignature, FullBlock, Header};
     use mp_chain_config::ChainConfig;
+    use mp_convert::ToFelt;
     use mp_gateway::state_update::ProviderStateUpdateWithBlock;
     use mp_receipt::{ExecutionResult, TransactionReceipt};
     use mp_state_update::{ContractStorageDiffItem, DeployedContractItem, StateDiff, StorageEntry};
     use mp_transactions::{InvokeTransaction, Transaction};
+    use mp_utils::crypto::ZeroingPrivateKey;
     use rstest::*;
     use starknet_api::felt;
     use starknet_core::types::Felt;
@@ -663,7 +730,8 @@ mod tests {
         .unwrap();
 
         let backend = MadaraBackend::open_for_testing(Arc::new(ChainConfig::starknet_sepolia()));
-        let validation = BlockValidationConfig::default();
+        // The block fixture does not come with its signature.
+        let validation = BlockValidationConfig::default().trust_signatures(true);
         let importer = BlockImporter::new(backend.clone(), validation).ctx();
 
         let block: FullBlock = block.into_full_block().unwrap();
@@ -851,5 +919,64 @@ mod tests {
         );
     }
 
+    #[rstest]
+    fn test_error_parent_hash() {
+        let backend = MadaraBackend::open_for_testing(Arc::new(ChainConfig::madara_test()));
+        backend
+            .store_block_header(BlockHeaderWithSignatures::new_unsigned(
+                Header { block_number: 0, ..Default::default() },
+                felt!("0x1"),
+            ))
+            .unwrap();
+        let importer = BlockImporter::new(backend, BlockValidationConfig::default()).ctx();
+
+        importer.verify_parent_hash(1, &Header { parent_block_hash: felt!("0x1"), ..Default::default() }).unwrap();
+        assert_matches!(
+            importer.verify_parent_hash(1, &Header { parent_block_hash: felt!("0x2"), ..Default::default() }),
+            Err(BlockImportError::ParentHash { block_n: 1, got, expected }) => {
+                assert_eq!((got, expected), (felt!("0x2"), felt!("0x1")))
+            }
+        );
+        // Parent is not in database.
+        importer.verify_parent_hash(3, &Header { parent_block_hash: felt!("0x2"), ..Default::default() }).unwrap();
+    }
+
+    #[rstest]
+    fn test_error_signature() {
+        let private_key = ZeroingPrivateKey::default();
+        let chain_config = ChainConfig { sequencer_public_key: Some(private_key.public), ..ChainConfig::madara_test() };
+        let chain_id = chain_config.chain_id.to_felt();
+        let backend = MadaraBackend::open_for_testing(Arc::new(chain_config));
+        let importer = BlockImporter::new(backend.clone(), BlockValidationConfig::default()).ctx();
+
+        let header = Header::default();
+        let block_hash = header.compute_hash(chain_id, /* pre_v0_13_2_override */ true);
+        let signature = private_key.sign(&block_hash).unwrap();
+        let mut signed_header = BlockHeaderWithSignatures {
+            header,
+            block_hash,
+            consensus_signatures: vec![ConsensusSignature { r: signature.r, s: signature.s }],
+        };
+        importer.verify_header(0, &signed_header).unwrap();
+
+        signed_header.consensus_signatures = vec![ConsensusSignature { r: signature.s, s: signature.r }];
+        assert_matches!(
+            importer.verify_header(0, &signed_header),
+            Err(BlockImportError::Signature { block_n: 0, block_hash: got }) => assert_eq!(got, block_hash)
+        );
+        signed_header.consensus_signatures = vec![];
+        assert_matches!(importer.verify_header(0, &signed_header), Err(BlockImportError::Signature { .. }));
+
+        // Signatures are not checked when they are trusted, or when nothing is.
+        for validation in [
+            BlockValidationConfig::default().trust_signatures(true),
+            BlockValidationConfig::default().all_verifications_disabled(true),
+        ] {
+            let importer = BlockImporter::new(backend.clone(), validation);
+            assert!(!importer.verifies_signatures());
+            importer.ctx().verify_header(0, &signed_header).unwrap();
+        }
+    }
+
     // TODO: do those checks for classes and block hashes too.
 }
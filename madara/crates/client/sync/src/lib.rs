@@ -11,3 +11,5 @@ pub use sync::SyncControllerConfig;
 
 pub mod gateway;
 pub mod import;
+pub mod rpc;
+pub mod source;
@@ -0,0 +1,77 @@
+use anyhow::Context;
+use mp_block::{
+    header::{BlockTimestamp, GasPrices},
+    FullBlock, Header, TransactionWithReceipt,
+};
+use mp_chain_config::{L1DataAvailabilityMode, StarknetVersion};
+use mp_receipt::{EventWithTransactionHash, TransactionReceipt};
+use mp_rpc::v0_9_0::{BlockWithReceipts, L1DaMode};
+
+/// Converts a JSON-RPC block and its state update into our own types. The JSON-RPC does not serve the header
+/// commitments, they are left empty and filled in by the block pipeline.
+pub(super) fn convert_block(block: BlockWithReceipts, state_update: mp_rpc::StateUpdate) -> anyhow::Result<FullBlock> {
+    let header = block.block_header;
+    if state_update.block_hash != header.block_hash {
+        anyhow::bail!(
+            "State update block hash {:#x} does not match block hash {:#x}",
+            state_update.block_hash,
+            header.block_hash
+        );
+    }
+
+    let closed_header = Header {
+        parent_block_hash: header.parent_hash,
+        block_number: header.block_number,
+        global_state_root: header.new_root,
+        sequencer_address: header.sequencer_address,
+        block_timestamp: BlockTimestamp(header.timestamp),
+        protocol_version: header
+            .starknet_version
+            .parse::<StarknetVersion>()
+            .with_context(|| format!("Parsing starknet version {:?}", header.starknet_version))?,
+        l1_gas_price: GasPrices {
+            eth_l1_gas_price: u128::try_from(header.l1_gas_price.price_in_wei).context("Parsing l1 gas price")?,
+            strk_l1_gas_price: u128::try_from(header.l1_gas_price.price_in_fri).context("Parsing l1 gas price")?,
+            eth_l1_data_gas_price: u128::try_from(header.l1_data_gas_price.price_in_wei)
+                .context("Parsing l1 data gas price")?,
+            strk_l1_data_gas_price: u128::try_from(header.l1_data_gas_price.price_in_fri)
+                .context("Parsing l1 data gas price")?,
+            eth_l2_gas_price: u128::try_from(header.l2_gas_price.price_in_wei).context("Parsing l2 gas price")?,
+            strk_l2_gas_price: u128::try_from(header.l2_gas_price.price_in_fri).context("Parsing l2 gas price")?,
+        },
+        l1_da_mode: match header.l1_da_mode {
+            L1DaMode::Blob => L1DataAvailabilityMode::Blob,
+            L1DaMode::Calldata => L1DataAvailabilityMode::Calldata,
+        },
+        ..Default::default()
+    };
+
+    let transactions = block
+        .transactions
+        .into_iter()
+        .map(|tx| {
+            Ok(TransactionWithReceipt {
+                transaction: tx.transaction.into(),
+                receipt: TransactionReceipt::try_from(tx.receipt).context("Parsing transaction receipt")?,
+            })
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let events = transactions
+        .iter()
+        .flat_map(|tx| {
+            tx.receipt.events().iter().map(|event| EventWithTransactionHash {
+                transaction_hash: tx.receipt.transaction_hash(),
+                event: event.clone(),
+            })
+        })
+        .collect();
+
+    Ok(FullBlock {
+        block_hash: header.block_hash,
+        header: closed_header,
+        state_diff: state_update.state_diff.into(),
+        transactions,
+        events,
+    })
+}
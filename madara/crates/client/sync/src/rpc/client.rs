@@ -0,0 +1,57 @@
+use jsonrpsee::{
+    core::{client::ClientT, ClientError},
+    http_client::{HttpClient, HttpClientBuilder},
+    rpc_params,
+};
+use mp_block::BlockId;
+use mp_rpc::{
+    v0_9_0::{MaybePreConfirmedBlockWithReceipts, MaybePreConfirmedBlockWithTxHashes},
+    BlockHashAndNumber, MaybeDeprecatedContractClass, MaybePendingStateUpdate,
+};
+use starknet_core::types::Felt;
+use url::Url;
+
+/// Classes can be quite big, the jsonrpsee default of 10MiB is not enough for some of them.
+const MAX_RESPONSE_SIZE: u32 = 256 * 1024 * 1024;
+
+/// A thin client over the v0.9.0 Starknet JSON-RPC read methods needed by the sync.
+pub struct RpcProvider {
+    client: HttpClient,
+}
+
+impl RpcProvider {
+    pub fn new(url: &Url) -> anyhow::Result<Self> {
+        let client = HttpClientBuilder::default().max_response_size(MAX_RESPONSE_SIZE).build(url.as_str())?;
+        Ok(Self { client })
+    }
+
+    pub async fn block_hash_and_number(&self) -> Result<BlockHashAndNumber, ClientError> {
+        self.client.request("starknet_blockHashAndNumber", rpc_params![]).await
+    }
+
+    pub async fn get_block_with_tx_hashes(
+        &self,
+        block_id: BlockId,
+    ) -> Result<MaybePreConfirmedBlockWithTxHashes, ClientError> {
+        self.client.request("starknet_getBlockWithTxHashes", rpc_params![block_id]).await
+    }
+
+    pub async fn get_block_with_receipts(
+        &self,
+        block_id: BlockId,
+    ) -> Result<MaybePreConfirmedBlockWithReceipts, ClientError> {
+        self.client.request("starknet_getBlockWithReceipts", rpc_params![block_id]).await
+    }
+
+    pub async fn get_state_update(&self, block_id: BlockId) -> Result<MaybePendingStateUpdate, ClientError> {
+        self.client.request("starknet_getStateUpdate", rpc_params![block_id]).await
+    }
+
+    pub async fn get_class(
+        &self,
+        block_id: BlockId,
+        class_hash: Felt,
+    ) -> Result<MaybeDeprecatedContractClass, ClientError> {
+        self.client.request("starknet_getClass", rpc_params![block_id, class_hash]).await
+    }
+}
@@ -0,0 +1,98 @@
+//! Sync from a Starknet JSON-RPC endpoint, as an alternative to the feeder gateway. The blocks go through the same
+//! pipelines as the gateway sync, see [`ForwardSync`].
+//!
+//! The v0.9.0 JSON-RPC serves neither the block header commitments nor the block signatures. The commitments are
+//! computed from the block content and checked through the block hash. The pending block is not synced.
+
+use crate::{
+    gateway::{latest_probe, ForwardSync, ForwardSyncConfig},
+    import::BlockImporter,
+    source::{SourceBlock, SyncSource},
+    sync::{SyncController, SyncControllerConfig},
+};
+use anyhow::Context;
+use mc_db::MadaraBackend;
+use mp_block::BlockId;
+use mp_class::ContractClass;
+use mp_gateway::block::ProviderBlockHeader;
+use mp_rpc::{
+    v0_9_0::{MaybePreConfirmedBlockWithReceipts, MaybePreConfirmedBlockWithTxHashes},
+    MaybePendingStateUpdate,
+};
+use starknet_core::types::Felt;
+use std::sync::Arc;
+
+pub use client::RpcProvider;
+
+mod blocks;
+mod client;
+
+pub type RpcSync = SyncController<ForwardSync<RpcProvider>>;
+pub fn forward_sync(
+    backend: Arc<MadaraBackend>,
+    importer: Arc<BlockImporter>,
+    client: Arc<RpcProvider>,
+    controller_config: SyncControllerConfig,
+    config: ForwardSyncConfig,
+) -> RpcSync {
+    SyncController::new(
+        backend.clone(),
+        ForwardSync::new(backend, importer, client.clone(), config),
+        latest_probe(client),
+        controller_config,
+        None,
+    )
+}
+
+impl SyncSource for RpcProvider {
+    const SERVES_COMMITMENTS: bool = false;
+
+    async fn get_block(&self, block_n: u64, with_signatures: bool) -> anyhow::Result<SourceBlock> {
+        anyhow::ensure!(!with_signatures, "The JSON-RPC does not serve block signatures");
+
+        let (block, state_update) = tokio::try_join!(
+            async {
+                self.get_block_with_receipts(BlockId::Number(block_n))
+                    .await
+                    .with_context(|| format!("Getting block with receipts with block_n={block_n}"))
+            },
+            async {
+                self.get_state_update(BlockId::Number(block_n))
+                    .await
+                    .with_context(|| format!("Getting state update with block_n={block_n}"))
+            },
+        )?;
+
+        let MaybePreConfirmedBlockWithReceipts::Block(block) = block else {
+            anyhow::bail!("Asked for a block_n, got a pre-confirmed one")
+        };
+        let MaybePendingStateUpdate::Block(state_update) = state_update else {
+            anyhow::bail!("Asked for a block_n, got a pending state update")
+        };
+        if block.block_header.block_number != block_n {
+            anyhow::bail!("Asked for block_n={block_n}, got block_n={}", block.block_header.block_number);
+        }
+
+        let block = blocks::convert_block(block, state_update).context("Parsing rpc block")?;
+        Ok(SourceBlock { block, consensus_signatures: vec![] })
+    }
+
+    async fn get_block_hash(&self, block_n: u64) -> anyhow::Result<Felt> {
+        let MaybePreConfirmedBlockWithTxHashes::Block(block) =
+            self.get_block_with_tx_hashes(BlockId::Number(block_n)).await?
+        else {
+            anyhow::bail!("Asked for a block_n, got a pre-confirmed one")
+        };
+        Ok(block.block_header.block_hash)
+    }
+
+    async fn get_latest_block(&self) -> anyhow::Result<ProviderBlockHeader> {
+        let latest = self.block_hash_and_number().await?;
+        Ok(ProviderBlockHeader { block_number: latest.block_number, block_hash: latest.block_hash })
+    }
+
+    async fn get_class(&self, block_id: BlockId, class_hash: Felt) -> anyhow::Result<ContractClass> {
+        let class = RpcProvider::get_class(self, block_id, class_hash).await?;
+        ContractClass::try_from(class).with_context(|| format!("Parsing class_hash={class_hash:#x}"))
+    }
+}
@@ -0,0 +1,41 @@
+//! The forward sync pipelines are shared by every source of closed blocks, see [`SyncSource`].
+
+use mp_block::{BlockId, ConsensusSignature, FullBlock};
+use mp_class::ContractClass;
+use mp_gateway::block::ProviderBlockHeader;
+use starknet_core::types::Felt;
+use std::future::Future;
+
+/// A closed block fetched from a [`SyncSource`].
+pub struct SourceBlock {
+    /// The header commitments are left empty when the source does not serve them, see
+    /// [`SyncSource::SERVES_COMMITMENTS`].
+    pub block: FullBlock,
+    /// Empty unless the signatures were asked for.
+    pub consensus_signatures: Vec<ConsensusSignature>,
+}
+
+/// Where the forward sync fetches blocks, state diffs and classes from. Nothing coming from a source is trusted: it
+/// goes through the [`BlockImporter`](crate::import::BlockImporter) checks first.
+pub trait SyncSource: Send + Sync + 'static {
+    /// Whether the block headers come with their commitments. When they do not, the commitments are computed from the
+    /// block content, and only checked through the block hash.
+    const SERVES_COMMITMENTS: bool = true;
+
+    /// Fetches the block `block_n` along with its state diff, and its signatures when `with_signatures` is set.
+    fn get_block(
+        &self,
+        block_n: u64,
+        with_signatures: bool,
+    ) -> impl Future<Output = anyhow::Result<SourceBlock>> + Send;
+    /// Fetches the hash of the block `block_n`, used to find the common ancestor after a reorg.
+    fn get_block_hash(&self, block_n: u64) -> impl Future<Output = anyhow::Result<Felt>> + Send;
+    /// Fetches the header of the latest closed block.
+    fn get_latest_block(&self) -> impl Future<Output = anyhow::Result<ProviderBlockHeader>> + Send;
+    /// Fetches a class declared at or before `block_id`.
+    fn get_class(
+        &self,
+        block_id: BlockId,
+        class_hash: Felt,
+    ) -> impl Future<Output = anyhow::Result<ContractClass>> + Send;
+}
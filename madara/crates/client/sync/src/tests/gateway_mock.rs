@@ -57,8 +57,21 @@ impl GatewayMock {
         })
     }
 
-    pub fn mock_block(&self, block_number: u64, hash: Felt, parent_hash: Felt) {
-        self.mock_block_with_declared_class(block_number, hash, parent_hash, None);
+    pub fn mock_header(&self, block_number: u64, hash: Felt) -> Mock {
+        self.mock_server.mock(|when, then| {
+            when.method("GET")
+                .path_contains("get_block")
+                .query_param("headerOnly", "true")
+                .query_param("blockNumber", block_number.to_string());
+            then.status(200).header("content-type", "application/json").json_body(json!({
+                "block_number": block_number,
+                "block_hash": format!("{hash:#x}"),
+            }));
+        })
+    }
+
+    pub fn mock_block(&self, block_number: u64, hash: Felt, parent_hash: Felt) -> Mock {
+        self.mock_block_with_declared_class(block_number, hash, parent_hash, None)
     }
 
     pub fn mock_block_with_declared_class(
@@ -67,7 +80,7 @@ impl GatewayMock {
         hash: Felt,
         parent_hash: Felt,
         declared_class: Option<DeclaredClassItem>,
-    ) {
+    ) -> Mock {
         let declared_classes = declared_class
             .map(|item| {
                 json!({
@@ -172,7 +185,7 @@ impl GatewayMock {
                     }
                 }
             }));
-        });
+        })
     }
 
     pub fn mock_block_pending(&self, parent_hash: Felt) -> Mock {
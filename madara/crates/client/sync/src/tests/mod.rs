@@ -3,3 +3,4 @@
 mod gateway_mock;
 mod pipeline;
 mod realistic;
+mod rpc_pipeline;
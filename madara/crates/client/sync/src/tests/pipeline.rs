@@ -9,7 +9,7 @@ use crate::{
     util::ServiceStateSender,
     SyncControllerConfig,
 };
-use mc_db::{db_block_id::DbBlockId, MadaraBackend};
+use mc_db::{db_block_id::DbBlockId, MadaraBackend, TrieLogConfig};
 use mc_settlement_client::state_update::StateUpdate;
 use mp_chain_config::ChainConfig;
 use mp_state_update::DeclaredClassItem;
@@ -463,3 +463,59 @@ async fn test_pending_declared_class(mut ctx: TestContext) {
         .unwrap()
         .is_some());
 }
+
+#[rstest]
+#[tokio::test]
+/// The pipeline should rewind to the common ancestor when the gateway reorgs, and follow the new chain.
+async fn test_reorg(gateway_mock: GatewayMock) {
+    // Reverting the global tries needs their trie logs.
+    let backend = MadaraBackend::open_for_testing_with_trie_log(
+        Arc::new(ChainConfig::madara_test()),
+        TrieLogConfig { max_saved_trie_logs: 10, ..Default::default() },
+    );
+    let importer = Arc::new(BlockImporter::new(
+        backend.clone(),
+        BlockValidationConfig::default().all_verifications_disabled(true),
+    ));
+    let (service_state_sender, service_state_recv) = crate::util::service_state_channel();
+    let mut ctx = TestContext { backend, importer, service_state_sender, service_state_recv, gateway_mock };
+
+    ctx.gateway_mock.mock_block(0, felt!("0x10"), felt!("0x0"));
+    ctx.gateway_mock.mock_block(1, felt!("0x11"), felt!("0x10"));
+    let mut block_2_mock = ctx.gateway_mock.mock_block(2, felt!("0x12"), felt!("0x11"));
+    let mut latest_mock = ctx.gateway_mock.mock_header_latest(2, felt!("0x12"));
+    ctx.gateway_mock.mock_block_pending_not_found();
+
+    let mut sync = crate::gateway::forward_sync(
+        ctx.backend.clone(),
+        ctx.importer,
+        ctx.gateway_mock.client(),
+        SyncControllerConfig::default().service_state_sender(ctx.service_state_sender),
+        ForwardSyncConfig::default(),
+    );
+
+    let _task = AbortOnDrop::spawn(async move { sync.run(ServiceContext::default()).await.unwrap() });
+
+    assert_eq!(ctx.service_state_recv.recv().await.unwrap(), ServiceEvent::Starting);
+    assert_eq!(ctx.service_state_recv.recv().await.unwrap(), ServiceEvent::Idle);
+    assert_eq!(ctx.service_state_recv.recv().await.unwrap(), ServiceEvent::SyncingTo { target: 2 });
+    assert_eq!(ctx.service_state_recv.recv().await.unwrap(), ServiceEvent::Idle);
+    assert_eq!(ctx.backend.get_block_hash(&DbBlockId::Number(2)).unwrap().unwrap(), felt!("0x12"));
+
+    // Block 2 is replaced, the new block 3 does not follow the block 2 we have in database.
+    block_2_mock.delete();
+    latest_mock.delete();
+    ctx.gateway_mock.mock_header(1, felt!("0x11"));
+    ctx.gateway_mock.mock_header(2, felt!("0x22"));
+    ctx.gateway_mock.mock_block(2, felt!("0x22"), felt!("0x11"));
+    ctx.gateway_mock.mock_block(3, felt!("0x23"), felt!("0x22"));
+    ctx.gateway_mock.mock_header_latest(3, felt!("0x23"));
+
+    assert_eq!(ctx.service_state_recv.recv().await.unwrap(), ServiceEvent::SyncingTo { target: 3 });
+    assert_eq!(ctx.service_state_recv.recv().await.unwrap(), ServiceEvent::Idle);
+
+    assert_eq!(ctx.backend.get_latest_block_n().unwrap(), Some(3));
+    assert_eq!(ctx.backend.get_block_hash(&DbBlockId::Number(1)).unwrap().unwrap(), felt!("0x11"));
+    assert_eq!(ctx.backend.get_block_hash(&DbBlockId::Number(2)).unwrap().unwrap(), felt!("0x22"));
+    assert_eq!(ctx.backend.get_block_hash(&DbBlockId::Number(3)).unwrap().unwrap(), felt!("0x23"));
+}
@@ -0,0 +1,241 @@
+//! Mocks a JSON-RPC node, and checks that the blocks synced from it go through the same checks as the gateway ones.
+
+use crate::{
+    gateway::ForwardSyncConfig,
+    import::{BlockImporter, BlockValidationConfig},
+    rpc::RpcProvider,
+    SyncControllerConfig,
+};
+use jsonrpsee::{
+    server::{Server, ServerHandle},
+    types::ErrorObjectOwned,
+    RpcModule,
+};
+use mc_db::{db_block_id::DbBlockId, MadaraBackend};
+use mp_block::{
+    commitments::{BlockCommitments, CommitmentComputationContext},
+    header::{BlockTimestamp, GasPrices, PendingHeader},
+    TransactionWithReceipt,
+};
+use mp_chain_config::{ChainConfig, L1DataAvailabilityMode, StarknetVersion};
+use mp_convert::ToFelt;
+use mp_receipt::{L1Gas, TransactionReceipt};
+use mp_rpc::{
+    v0_9_0::{
+        BlockHeader, BlockStatus, BlockWithReceipts, CommonReceiptProperties, ExecutionResources, L1HandlerTxnReceipt,
+        TransactionAndReceipt, TxnFinalityStatus, TxnReceipt,
+    },
+    BlockHashAndNumber, ExecutionStatus, FeePayment, FunctionCall, L1DaMode, L1HandlerTxn, PriceUnit, ResourcePrice,
+    StateDiff, StateUpdate, Txn,
+};
+use mp_state_update::StateDiff as MadaraStateDiff;
+use mp_transactions::Transaction;
+use mp_utils::service::ServiceContext;
+use starknet_core::types::Felt;
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex},
+};
+
+type Blocks = Arc<Mutex<BTreeMap<u64, (BlockWithReceipts, StateUpdate)>>>;
+
+/// Serves the JSON-RPC methods used by the rpc sync from the blocks it was given.
+struct RpcMock {
+    blocks: Blocks,
+    client: Arc<RpcProvider>,
+    _handle: ServerHandle,
+}
+
+impl RpcMock {
+    async fn start() -> Self {
+        let blocks = Blocks::default();
+        let mut module = RpcModule::new(blocks.clone());
+        module
+            .register_method("starknet_blockHashAndNumber", |_params, blocks| {
+                let blocks = blocks.lock().unwrap();
+                let (block_number, (block, _)) = blocks.last_key_value().ok_or_else(block_not_found)?;
+                Ok::<_, ErrorObjectOwned>(BlockHashAndNumber {
+                    block_hash: block.block_header.block_hash,
+                    block_number: *block_number,
+                })
+            })
+            .unwrap();
+        module
+            .register_method("starknet_getBlockWithReceipts", |params, blocks| {
+                let block_n = block_number(params.one()?)?;
+                let (block, _) = blocks.lock().unwrap().get(&block_n).cloned().ok_or_else(block_not_found)?;
+                Ok::<_, ErrorObjectOwned>(block)
+            })
+            .unwrap();
+        module
+            .register_method("starknet_getStateUpdate", |params, blocks| {
+                let block_n = block_number(params.one()?)?;
+                let (_, state_update) = blocks.lock().unwrap().get(&block_n).cloned().ok_or_else(block_not_found)?;
+                Ok::<_, ErrorObjectOwned>(state_update)
+            })
+            .unwrap();
+
+        let server = Server::builder().build("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", server.local_addr().unwrap()).parse().unwrap();
+        let _handle = server.start(module);
+        Self { blocks, client: Arc::new(RpcProvider::new(&url).unwrap()), _handle }
+    }
+
+    fn add_block(&self, block: (BlockWithReceipts, StateUpdate)) {
+        self.blocks.lock().unwrap().insert(block.0.block_header.block_number, block);
+    }
+}
+
+fn block_number(block_id: mp_rpc::BlockId) -> Result<u64, ErrorObjectOwned> {
+    match block_id {
+        mp_rpc::BlockId::Number(block_n) => Ok(block_n),
+        _ => Err(block_not_found()),
+    }
+}
+
+fn block_not_found() -> ErrorObjectOwned {
+    ErrorObjectOwned::owned(24, "Block not found", None::<()>)
+}
+
+/// A block with one L1 handler transaction and an empty state diff, with its block hash computed from its content.
+fn rpc_block(chain_id: Felt, block_number: u64, parent_hash: Felt) -> (BlockWithReceipts, StateUpdate) {
+    let transaction = Txn::L1Handler(L1HandlerTxn {
+        nonce: block_number,
+        version: "0x0".into(),
+        function_call: FunctionCall {
+            calldata: Arc::new(vec![Felt::ONE]),
+            contract_address: Felt::from(0x456),
+            entry_point_selector: Felt::from(0x789),
+        },
+    });
+    let transaction_hash = Transaction::from(transaction.clone()).compute_hash(
+        chain_id,
+        StarknetVersion::V0_13_2,
+        /* is_query */ false,
+    );
+    let receipt = TxnReceipt::L1Handler(L1HandlerTxnReceipt {
+        message_hash: format!("0x{}", "11".repeat(32)),
+        common_receipt_properties: CommonReceiptProperties {
+            actual_fee: FeePayment { amount: Felt::ZERO, unit: PriceUnit::Wei },
+            events: vec![],
+            execution_resources: ExecutionResources { l1_gas: 100, l1_data_gas: 200, l2_gas: 0 },
+            finality_status: TxnFinalityStatus::L2,
+            messages_sent: vec![],
+            transaction_hash,
+            execution_status: ExecutionStatus::Successful,
+        },
+    });
+
+    let header = PendingHeader {
+        parent_block_hash: parent_hash,
+        sequencer_address: Felt::from(0x123),
+        block_timestamp: BlockTimestamp(1_700_000_000 + block_number),
+        protocol_version: StarknetVersion::V0_13_2,
+        l1_gas_price: GasPrices {
+            eth_l1_gas_price: 1,
+            strk_l1_gas_price: 2,
+            eth_l1_data_gas_price: 3,
+            strk_l1_data_gas_price: 4,
+            eth_l2_gas_price: 5,
+            strk_l2_gas_price: 6,
+        },
+        l1_da_mode: L1DataAvailabilityMode::Calldata,
+    };
+    let transactions = vec![TransactionWithReceipt {
+        transaction: transaction.clone().into(),
+        receipt: TransactionReceipt::try_from(receipt.clone()).unwrap(),
+    }];
+    let ctx = CommitmentComputationContext { protocol_version: header.protocol_version, chain_id };
+    let commitments = BlockCommitments::compute(&ctx, &transactions, &MadaraStateDiff::default(), &[]);
+    let block_hash = header
+        .to_closed_header(commitments, /* global_state_root */ Felt::ZERO, block_number)
+        .compute_hash(chain_id, /* pre_v0_13_2_override */ true);
+
+    let block = BlockWithReceipts {
+        transactions: vec![TransactionAndReceipt { receipt, transaction }],
+        status: BlockStatus::AcceptedOnL2,
+        block_header: BlockHeader {
+            block_hash,
+            block_number,
+            l1_da_mode: L1DaMode::Calldata,
+            l1_gas_price: ResourcePrice { price_in_wei: Felt::from(1), price_in_fri: Felt::from(2) },
+            l1_data_gas_price: ResourcePrice { price_in_wei: Felt::from(3), price_in_fri: Felt::from(4) },
+            l2_gas_price: ResourcePrice { price_in_wei: Felt::from(5), price_in_fri: Felt::from(6) },
+            new_root: Felt::ZERO,
+            parent_hash,
+            sequencer_address: Felt::from(0x123),
+            starknet_version: "0.13.2".into(),
+            timestamp: 1_700_000_000 + block_number,
+        },
+    };
+    let state_update = StateUpdate {
+        block_hash,
+        new_root: Felt::ZERO,
+        old_root: Felt::ZERO,
+        state_diff: StateDiff {
+            declared_classes: vec![],
+            deployed_contracts: vec![],
+            deprecated_declared_classes: vec![],
+            nonces: vec![],
+            replaced_classes: vec![],
+            storage_diffs: vec![],
+        },
+    };
+    (block, state_update)
+}
+
+async fn sync(backend: &Arc<MadaraBackend>, mock: &RpcMock) -> anyhow::Result<()> {
+    let importer = Arc::new(BlockImporter::new(backend.clone(), BlockValidationConfig::default()));
+    let mut sync = crate::rpc::forward_sync(
+        backend.clone(),
+        importer,
+        mock.client.clone(),
+        SyncControllerConfig::default().stop_on_sync(true),
+        ForwardSyncConfig::default(),
+    );
+    sync.run(ServiceContext::default()).await
+}
+
+#[tokio::test]
+/// The header commitments are not served by the node, they are computed and the block hashes are checked.
+async fn test_rpc_sync_verifies_blocks() {
+    let backend = MadaraBackend::open_for_testing(Arc::new(ChainConfig::madara_test()));
+    let chain_id = backend.chain_config().chain_id.to_felt();
+    let mock = RpcMock::start().await;
+    let block_0 = rpc_block(chain_id, 0, Felt::ZERO);
+    let block_1 = rpc_block(chain_id, 1, block_0.0.block_header.block_hash);
+    mock.add_block(block_0.clone());
+    mock.add_block(block_1.clone());
+
+    sync(&backend, &mock).await.unwrap();
+
+    assert_eq!(backend.head_status().latest_full_block_n(), Some(1));
+    for (block, _) in [block_0, block_1] {
+        let block_n = block.block_header.block_number;
+        let info = backend.get_block_info(&DbBlockId::Number(block_n)).unwrap().unwrap();
+        let info = info.as_closed().unwrap();
+        assert_eq!(info.block_hash, block.block_header.block_hash);
+        assert_eq!(info.header.transaction_count, 1);
+        assert!(info.header.receipt_commitment.is_some());
+        assert_eq!(info.header.l1_gas_price.strk_l2_gas_price, 6);
+
+        let receipts = backend.get_block_inner(&DbBlockId::Number(block_n)).unwrap().unwrap().receipts;
+        assert_eq!(receipts[0].total_gas_consumed(), &L1Gas { l1_gas: 100, l1_data_gas: 200 });
+    }
+}
+
+#[tokio::test]
+/// A block which does not match its block hash is rejected.
+async fn test_rpc_sync_wrong_block_hash() {
+    let backend = MadaraBackend::open_for_testing(Arc::new(ChainConfig::madara_test()));
+    let chain_id = backend.chain_config().chain_id.to_felt();
+    let mock = RpcMock::start().await;
+    let (mut block, mut state_update) = rpc_block(chain_id, 0, Felt::ZERO);
+    block.block_header.block_hash = Felt::from(0xdead);
+    state_update.block_hash = Felt::from(0xdead);
+    mock.add_block((block, state_update));
+
+    let err = sync(&backend, &mock).await.unwrap_err();
+    assert!(format!("{err:#}").contains("Block hash mismatch"), "{err:#}");
+    assert_eq!(backend.head_status().latest_full_block_n(), None);
+}
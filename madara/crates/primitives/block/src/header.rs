@@ -74,7 +74,6 @@ pub struct PendingHeader {
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 /// Starknet header definition.
-// TODO: add L2 gas
 pub struct Header {
     /// The hash of this block’s parent.
     pub parent_block_hash: Felt,
@@ -114,6 +113,9 @@ pub struct GasPrices {
     pub strk_l1_gas_price: u128,
     pub eth_l1_data_gas_price: u128,
     pub strk_l1_data_gas_price: u128,
+    /// Zero for blocks produced before L2 gas was priced.
+    pub eth_l2_gas_price: u128,
+    pub strk_l2_gas_price: u128,
 }
 
 // Starknet API can't have null gas prices, so the default null gas prices are set to 1.
@@ -125,15 +127,16 @@ impl From<&GasPrices> for starknet_api::block::GasPrices {
                     .unwrap_or_default(),
                 l1_data_gas_price: starknet_api::block::NonzeroGasPrice::new(gas_prices.eth_l1_data_gas_price.into())
                     .unwrap_or_default(),
-                // TODO: L2 gas price is not used in the current implementation, but it should be set to 1
-                l2_gas_price: Default::default(), // 1
+                l2_gas_price: starknet_api::block::NonzeroGasPrice::new(gas_prices.eth_l2_gas_price.into())
+                    .unwrap_or_default(),
             },
             strk_gas_prices: starknet_api::block::GasPriceVector {
                 l1_gas_price: starknet_api::block::NonzeroGasPrice::new(gas_prices.strk_l1_gas_price.into())
                     .unwrap_or_default(),
                 l1_data_gas_price: starknet_api::block::NonzeroGasPrice::new(gas_prices.strk_l1_data_gas_price.into())
                     .unwrap_or_default(),
-                l2_gas_price: Default::default(), // 1
+                l2_gas_price: starknet_api::block::NonzeroGasPrice::new(gas_prices.strk_l2_gas_price.into())
+                    .unwrap_or_default(),
             },
         }
     }
@@ -153,6 +156,14 @@ impl GasPrices {
             price_in_wei: self.eth_l1_data_gas_price.into(),
         }
     }
+
+    /// Blocks produced before L2 gas was priced were executed with the default L2 gas price of 1.
+    pub fn l2_gas_price(&self) -> mp_rpc::ResourcePrice {
+        mp_rpc::ResourcePrice {
+            price_in_fri: self.strk_l2_gas_price.max(1).into(),
+            price_in_wei: self.eth_l2_gas_price.max(1).into(),
+        }
+    }
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -227,7 +238,7 @@ impl Header {
                 Felt::ZERO, // reserved: extra data
                 self.parent_block_hash,
             ])
-        } else {
+        } else if hash_version < StarknetVersion::V0_13_4 {
             // Based off https://github.com/starkware-libs/sequencer/blob/78ceca6aa230a63ca31f29f746fbb26d312fe381/crates/starknet_api/src/block_hash/block_hash_calculator.rs#L67
             Poseidon::hash_array(&[
                 Felt::from_bytes_be_slice(b"STARKNET_BLOCK_HASH0"),
@@ -253,6 +264,37 @@ impl Header {
                 Felt::ZERO,
                 self.parent_block_hash,
             ])
+        } else {
+            // From v0.13.4, the gas prices are hashed together and include the L2 gas prices.
+            Poseidon::hash_array(&[
+                Felt::from_bytes_be_slice(b"STARKNET_BLOCK_HASH1"),
+                Felt::from(self.block_number),
+                self.global_state_root,
+                self.sequencer_address,
+                Felt::from(self.block_timestamp.0),
+                concat_counts(
+                    self.transaction_count,
+                    self.event_count,
+                    self.state_diff_length.unwrap_or(0),
+                    self.l1_da_mode,
+                ),
+                self.state_diff_commitment.unwrap_or(Felt::ZERO),
+                self.transaction_commitment,
+                self.event_commitment,
+                self.receipt_commitment.unwrap_or(Felt::ZERO),
+                Poseidon::hash_array(&[
+                    Felt::from_bytes_be_slice(b"STARKNET_GAS_PRICES0"),
+                    self.l1_gas_price.eth_l1_gas_price.into(),
+                    self.l1_gas_price.strk_l1_gas_price.into(),
+                    self.l1_gas_price.eth_l1_data_gas_price.into(),
+                    self.l1_gas_price.strk_l1_data_gas_price.into(),
+                    self.l1_gas_price.eth_l2_gas_price.into(),
+                    self.l1_gas_price.strk_l2_gas_price.into(),
+                ]),
+                Felt::from_bytes_be_slice(self.protocol_version.to_string().as_bytes()),
+                Felt::ZERO,
+                self.parent_block_hash,
+            ])
         }
     }
 
@@ -330,6 +372,8 @@ mod tests {
                 strk_l1_gas_price: 15,
                 eth_l1_data_gas_price: 16,
                 strk_l1_data_gas_price: 17,
+                eth_l2_gas_price: 18,
+                strk_l2_gas_price: 19,
             },
             L1DataAvailabilityMode::Blob,
         );
@@ -346,6 +390,46 @@ mod tests {
         assert_eq!(hash, expected_hash);
     }
 
+    #[test]
+    fn test_header_hash_v0_13_4() {
+        // Based off the v0.13.4 case of `test_block_hash_regression` in starknet_api 0.15.0-rc.1. The commitments are
+        // the ones of its transaction, receipt and state diff.
+        let header = Header {
+            parent_block_hash: Felt::from(11),
+            block_number: 1,
+            global_state_root: Felt::from(2),
+            sequencer_address: Felt::from(3),
+            block_timestamp: BlockTimestamp(4),
+            transaction_count: 1,
+            transaction_commitment: Felt::from_hex_unchecked(
+                "0x72f432efa51e2a34f68404ac5e77514301e26eb53ec89badd8173f4e8561b95",
+            ),
+            event_count: 0,
+            event_commitment: Felt::ZERO,
+            state_diff_length: Some(10),
+            state_diff_commitment: Some(Felt::from_hex_unchecked(
+                "0x281f5966e49ad7dad9323826d53d1d27c0c4e6ebe5525e2e2fbca549bfa0a67",
+            )),
+            receipt_commitment: Some(Felt::from_hex_unchecked(
+                "0x8e7dfb2772c2ac26e712fb97404355d66db0ba9555f0f64f30d61a56df9c76",
+            )),
+            protocol_version: StarknetVersion::V0_13_4,
+            l1_gas_price: GasPrices {
+                eth_l1_gas_price: 7,
+                strk_l1_gas_price: 6,
+                eth_l1_data_gas_price: 9,
+                strk_l1_data_gas_price: 10,
+                eth_l2_gas_price: 12,
+                strk_l2_gas_price: 11,
+            },
+            l1_da_mode: L1DataAvailabilityMode::Blob,
+        };
+        let hash = header.compute_hash(Felt::from_bytes_be_slice(b"CHAIN_ID"), false);
+        let expected_hash =
+            Felt::from_hex_unchecked("0x3d6174623c812f5dc03fa3faa07c42c06fd90ad425629ee5f39e149df65c3ca");
+        assert_eq!(hash, expected_hash);
+    }
+
     #[test]
     fn test_header_hash_v0_11_1() {
         let header = dummy_header(StarknetVersion::V0_11_1);
@@ -384,6 +468,8 @@ mod tests {
                 strk_l1_gas_price: 15,
                 eth_l1_data_gas_price: 16,
                 strk_l1_data_gas_price: 17,
+                eth_l2_gas_price: 18,
+                strk_l2_gas_price: 19,
             },
             l1_da_mode: L1DataAvailabilityMode::Blob,
         }
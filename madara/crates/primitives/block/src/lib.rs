@@ -151,7 +151,7 @@ impl From<MadaraBlockInfo> for mp_rpc::BlockHeader {
             block_hash,
             ..
         } = info;
-        let GasPrices { eth_l1_gas_price, strk_l1_gas_price, eth_l1_data_gas_price, strk_l1_data_gas_price } =
+        let GasPrices { eth_l1_gas_price, strk_l1_gas_price, eth_l1_data_gas_price, strk_l1_data_gas_price, .. } =
             l1_gas_price;
 
         Self {
@@ -4,7 +4,7 @@
 //! the user needing to clone the repo.
 //! Only use `fs` for constants when writing tests.
 
-use crate::{L1DataAvailabilityMode, StarknetVersion};
+use crate::{L1DataAvailabilityMode, L2GasPriceConfig, StarknetVersion, TransactionPolicyConfig};
 use anyhow::{bail, Context, Result};
 use blockifier::blockifier::config::ConcurrencyConfig;
 use blockifier::blockifier_versioned_constants::{RawVersionedConstants, VersionedConstants};
@@ -148,6 +148,20 @@ pub struct ChainConfig {
     /// The bouncer is in charge of limiting block sizes. This is where the max number of step per block, gas etc are.
     pub bouncer_config: BouncerConfig,
 
+    /// Only used for block production.
+    /// How the L2 gas price of produced blocks is set. Transactions with an L2 gas max price per unit under the
+    /// current price are rejected.
+    /// Default: fixed price of 1 fri.
+    #[serde(default)]
+    pub l2_gas_price: L2GasPriceConfig,
+
+    /// Only used for block production.
+    /// Which transactions are accepted by the sequencer: sender and contract allow/deny lists, declarable classes,
+    /// per-account rate limits and private deploy mode. These can be changed at runtime using the admin RPC.
+    /// Default: everything is allowed.
+    #[serde(default)]
+    pub transaction_policies: TransactionPolicyConfig,
+
     /// Only used for block production.
     pub sequencer_address: ContractAddress,
 
@@ -167,6 +181,12 @@ pub struct ChainConfig {
     #[serde(skip)]
     pub private_key: ZeroingPrivateKey,
 
+    /// Public key of the sequencer signing the blocks served through the feeder gateway. When set, full nodes
+    /// fetch the signature of every block they sync and reject blocks which are not signed by this key, unless they
+    /// are started with `--no-block-signature-verification`.
+    #[serde(default)]
+    pub sequencer_public_key: Option<Felt>,
+
     #[serde(default)]
     pub mempool_mode: MempoolMode,
     /// Minimum tip increase when replacing a transaction with the same (contract_address, nonce) pair in the mempool, as a ratio.
@@ -226,6 +246,8 @@ impl ChainConfig {
         if self.pending_block_update_time.is_some_and(|t| t.is_zero()) {
             bail!("Pending block update time cannot be zero for block production.")
         }
+        self.l2_gas_price.check()?;
+        self.transaction_policies.check()?;
         Ok(())
     }
 
@@ -277,6 +299,8 @@ impl ChainConfig {
                     n_txs: usize::MAX,
                 },
             },
+            l2_gas_price: L2GasPriceConfig::default(),
+            transaction_policies: TransactionPolicyConfig::default(),
             // We are not producing blocks for these chains.
             sequencer_address: ContractAddress(
                 PatriciaKey::try_from(Felt::from_hex_unchecked(
@@ -286,6 +310,7 @@ impl ChainConfig {
             ),
 
             private_key: ZeroingPrivateKey::default(),
+            sequencer_public_key: Some(Felt::from_hex_unchecked(public_key::MAINNET)),
 
             mempool_mode: MempoolMode::Timestamp,
             mempool_max_transactions: 10_000,
@@ -309,6 +334,7 @@ impl ChainConfig {
             eth_gps_statement_verifier: eth_gps_statement_verifier::SEPOLIA_TESTNET
                 .parse()
                 .expect("parsing a constant"),
+            sequencer_public_key: Some(Felt::from_hex_unchecked(public_key::SEPOLIA_TESTNET)),
             ..Self::starknet_mainnet()
         }
     }
@@ -325,6 +351,7 @@ impl ChainConfig {
             eth_gps_statement_verifier: eth_gps_statement_verifier::SEPOLIA_INTEGRATION
                 .parse()
                 .expect("parsing a constant"),
+            sequencer_public_key: Some(Felt::from_hex_unchecked(public_key::SEPOLIA_INTEGRATION)),
             ..Self::starknet_mainnet()
         }
     }
@@ -336,6 +363,7 @@ impl ChainConfig {
             feeder_gateway_url: Url::parse("http://localhost:8080/feeder_gateway/").unwrap(),
             gateway_url: Url::parse("http://localhost:8080/gateway/").unwrap(),
             sequencer_address: Felt::from_hex_unchecked("0x123").try_into().unwrap(),
+            sequencer_public_key: None,
             ..ChainConfig::starknet_sepolia()
         }
     }
@@ -352,6 +380,7 @@ impl ChainConfig {
             )
             .try_into()
             .unwrap(),
+            sequencer_public_key: None,
             ..ChainConfig::starknet_sepolia()
         }
     }
@@ -0,0 +1,194 @@
+use blockifier::bouncer::BouncerWeights;
+use serde::{Deserialize, Serialize};
+
+/// Block fullness is measured in parts per million.
+const FULL_BLOCK_PPM: u128 = 1_000_000;
+
+/// How the block producer prices L2 gas. Prices are in fri (STRK), the wei price is derived from the L1 gas prices
+/// ratio when the block is opened.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum L2GasPriceConfig {
+    /// Every block uses the same L2 gas price.
+    Fixed { price: u128 },
+    /// The L2 gas price is a base fee which goes up when the previous block was fuller than the target, and down when
+    /// it was emptier. Block fullness is the fullest of the bouncer weights, relative to the block max capacity.
+    Eip1559 {
+        /// Price used for the first block produced by the chain.
+        initial_price: u128,
+        /// The price never goes below this value.
+        #[serde(default = "default_min_price")]
+        min_price: u128,
+        /// Block fullness at which the price stays the same, in percent.
+        #[serde(default = "default_target_fullness_percent")]
+        target_fullness_percent: u8,
+        /// Bounds the price change between two blocks: a full block with a 50% target increases the price by
+        /// 1/max_change_denominator.
+        #[serde(default = "default_max_change_denominator")]
+        max_change_denominator: u128,
+    },
+}
+
+fn default_min_price() -> u128 {
+    1
+}
+fn default_target_fullness_percent() -> u8 {
+    50
+}
+fn default_max_change_denominator() -> u128 {
+    8
+}
+
+impl Default for L2GasPriceConfig {
+    fn default() -> Self {
+        Self::Fixed { price: 1 }
+    }
+}
+
+impl L2GasPriceConfig {
+    pub fn check(&self) -> anyhow::Result<()> {
+        if let Self::Eip1559 { initial_price, min_price, target_fullness_percent, max_change_denominator } = self {
+            if !(1..=100).contains(target_fullness_percent) {
+                anyhow::bail!("L2 gas price target fullness must be between 1 and 100 percent.")
+            }
+            if *max_change_denominator == 0 {
+                anyhow::bail!("L2 gas price max change denominator cannot be zero.")
+            }
+            if initial_price < min_price {
+                anyhow::bail!("L2 gas price initial price cannot be lower than the min price.")
+            }
+        }
+        Ok(())
+    }
+
+    /// Price of the first block produced by this node, given the L2 gas price of the latest block on chain.
+    pub fn initial_price(&self, latest_block_price: Option<u128>) -> u128 {
+        match self {
+            Self::Fixed { price } => *price,
+            Self::Eip1559 { initial_price, min_price, .. } => {
+                latest_block_price.filter(|price| *price != 0).unwrap_or(*initial_price).max(*min_price)
+            }
+        }
+    }
+
+    /// Price of the next block, given the price and the bouncer weights of the block that was just closed.
+    pub fn next_price(&self, previous_price: u128, used: &BouncerWeights, capacity: &BouncerWeights) -> u128 {
+        let Self::Eip1559 { min_price, target_fullness_percent, max_change_denominator, .. } = self else {
+            return self.initial_price(None);
+        };
+
+        let target = u128::from(*target_fullness_percent) * (FULL_BLOCK_PPM / 100);
+        let fullness = block_fullness_ppm(used, capacity);
+        let change = |diff: u128| previous_price.saturating_mul(diff) / target / (*max_change_denominator).max(1);
+
+        let price = if fullness > target {
+            // Always move up, otherwise small prices could never increase.
+            previous_price.saturating_add(change(fullness - target).max(1))
+        } else {
+            previous_price.saturating_sub(change(target - fullness))
+        };
+        price.max(*min_price)
+    }
+}
+
+/// Fullest bouncer weight relative to the block capacity, in parts per million.
+fn block_fullness_ppm(used: &BouncerWeights, capacity: &BouncerWeights) -> u128 {
+    let ratio = |used: u128, capacity: u128| {
+        if capacity == 0 {
+            return 0;
+        }
+        (used.saturating_mul(FULL_BLOCK_PPM) / capacity).min(FULL_BLOCK_PPM)
+    };
+    [
+        ratio(used.l1_gas as u128, capacity.l1_gas as u128),
+        ratio(used.message_segment_length as u128, capacity.message_segment_length as u128),
+        ratio(used.n_events as u128, capacity.n_events as u128),
+        ratio(used.state_diff_size as u128, capacity.state_diff_size as u128),
+        ratio(used.sierra_gas.0.into(), capacity.sierra_gas.0.into()),
+        ratio(used.n_txs as u128, capacity.n_txs as u128),
+    ]
+    .into_iter()
+    .max()
+    .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use starknet_api::execution_resources::GasAmount;
+
+    fn capacity() -> BouncerWeights {
+        BouncerWeights {
+            l1_gas: 1000,
+            message_segment_length: usize::MAX,
+            n_events: usize::MAX,
+            state_diff_size: 1000,
+            sierra_gas: GasAmount(1000),
+            n_txs: usize::MAX,
+        }
+    }
+
+    fn used_sierra_gas(gas: u64) -> BouncerWeights {
+        BouncerWeights { sierra_gas: GasAmount(gas), ..BouncerWeights::empty() }
+    }
+
+    fn eip1559() -> L2GasPriceConfig {
+        L2GasPriceConfig::Eip1559 {
+            initial_price: 1000,
+            min_price: 100,
+            target_fullness_percent: 50,
+            max_change_denominator: 8,
+        }
+    }
+
+    #[test]
+    fn test_fixed_price() {
+        let config = L2GasPriceConfig::Fixed { price: 42 };
+        assert_eq!(config.initial_price(Some(1000)), 42);
+        assert_eq!(config.next_price(42, &used_sierra_gas(1000), &capacity()), 42);
+    }
+
+    #[test]
+    fn test_eip1559_price() {
+        let config = eip1559();
+        assert_eq!(config.initial_price(None), 1000);
+        assert_eq!(config.initial_price(Some(0)), 1000);
+        assert_eq!(config.initial_price(Some(2000)), 2000);
+        assert_eq!(config.initial_price(Some(10)), 100);
+
+        // Full block: +1/8, empty block: -1/8, at target: unchanged.
+        assert_eq!(config.next_price(1000, &used_sierra_gas(1000), &capacity()), 1125);
+        assert_eq!(config.next_price(1000, &BouncerWeights::empty(), &capacity()), 875);
+        assert_eq!(config.next_price(1000, &used_sierra_gas(500), &capacity()), 1000);
+        // The fullest weight is used.
+        let used = BouncerWeights { state_diff_size: 1000, ..used_sierra_gas(100) };
+        assert_eq!(config.next_price(1000, &used, &capacity()), 1125);
+
+        // Small prices still increase, and the price never goes under the minimum.
+        assert_eq!(config.next_price(100, &used_sierra_gas(501), &capacity()), 101);
+        assert_eq!(config.next_price(100, &BouncerWeights::empty(), &capacity()), 100);
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let config: L2GasPriceConfig = serde_yaml::from_str("type: eip1559\ninitial_price: 1000").unwrap();
+        assert_eq!(
+            config,
+            L2GasPriceConfig::Eip1559 {
+                initial_price: 1000,
+                min_price: 1,
+                target_fullness_percent: 50,
+                max_change_denominator: 8
+            }
+        );
+        config.check().unwrap();
+        assert!(L2GasPriceConfig::Eip1559 {
+            initial_price: 1000,
+            min_price: 1,
+            target_fullness_percent: 0,
+            max_change_denominator: 8
+        }
+        .check()
+        .is_err());
+    }
+}
@@ -0,0 +1,92 @@
+use mp_utils::serde::{deserialize_duration, serialize_duration};
+use serde::{Deserialize, Serialize};
+use starknet_types_core::felt::Felt;
+use std::time::Duration;
+
+/// Rules deciding which transactions the sequencer accepts. Everything is allowed by default.
+///
+/// Contract rules look at the calls of invoke transactions, decoded from the standard account `__execute__` calldata:
+/// `[n_calls, (to, selector, calldata_len, ...calldata)*]`. When a contract or deploy rule is set, invoke transactions
+/// whose calldata does not follow this layout are rejected.
+///
+/// Only the calls made by the account are checked: calls made by the called contracts, and contracts deployed by them
+/// with the `deploy` syscall, are not covered by the contract and private deploy rules.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TransactionPolicyConfig {
+    /// When set, only these accounts can send transactions.
+    pub allowed_senders: Option<Vec<Felt>>,
+    /// Transactions sent by these accounts are rejected.
+    pub denied_senders: Vec<Felt>,
+    /// When set, invoke transactions can only call these contracts.
+    pub allowed_contracts: Option<Vec<Felt>>,
+    /// Invoke transactions calling any of these contracts are rejected.
+    pub denied_contracts: Vec<Felt>,
+    /// When set, only these class hashes can be declared.
+    pub allowed_declared_classes: Option<Vec<Felt>>,
+    /// Limits how many transactions a single account can submit.
+    pub rate_limit: Option<RateLimitConfig>,
+    /// Private deploy mode: when set, only these accounts can deploy contracts through the universal deployer, and
+    /// only these addresses can be deployed with a deploy account transaction.
+    pub private_deploy: Option<Vec<Felt>>,
+    /// Addresses of the universal deployers checked in private deploy mode. Defaults to the legacy and Cairo 1
+    /// universal deployers of OpenZeppelin, see [`TransactionPolicyConfig::DEFAULT_UNIVERSAL_DEPLOYERS`].
+    pub universal_deployers: Option<Vec<Felt>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RateLimitConfig {
+    /// Max number of transactions an account can submit during a period.
+    pub max_transactions: u32,
+    #[serde(deserialize_with = "deserialize_duration", serialize_with = "serialize_duration")]
+    pub period: Duration,
+}
+
+impl TransactionPolicyConfig {
+    pub const DEFAULT_UNIVERSAL_DEPLOYERS: [Felt; 2] = [
+        Felt::from_hex_unchecked("0x041a78e741e5af2fec34b695679bc6891742439f7afb8484ecd7766661ad02bf"),
+        Felt::from_hex_unchecked("0x02ceed65a4bd731034c01113685c831b01c15d7d432f71afb1cf1634b53a2125"),
+    ];
+
+    pub fn universal_deployers(&self) -> Vec<Felt> {
+        self.universal_deployers.clone().unwrap_or_else(|| Self::DEFAULT_UNIVERSAL_DEPLOYERS.to_vec())
+    }
+
+    pub fn check(&self) -> anyhow::Result<()> {
+        if let Some(rate_limit) = &self.rate_limit {
+            if rate_limit.period.is_zero() {
+                anyhow::bail!("Transaction rate limit period cannot be zero.")
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize() {
+        let config: TransactionPolicyConfig = serde_yaml::from_str(
+            "denied_senders: [\"0x1\"]\nrate_limit:\n  max_transactions: 10\n  period: 1min\nprivate_deploy: []",
+        )
+        .unwrap();
+        assert_eq!(
+            config,
+            TransactionPolicyConfig {
+                denied_senders: vec![Felt::ONE],
+                rate_limit: Some(RateLimitConfig { max_transactions: 10, period: Duration::from_secs(60) }),
+                private_deploy: Some(vec![]),
+                ..Default::default()
+            }
+        );
+        config.check().unwrap();
+
+        let roundtrip: TransactionPolicyConfig =
+            serde_yaml::from_str(&serde_yaml::to_string(&config).unwrap()).unwrap();
+        assert_eq!(roundtrip, config);
+
+        assert!(serde_yaml::from_str::<TransactionPolicyConfig>("unknown_rule: true").is_err());
+    }
+}
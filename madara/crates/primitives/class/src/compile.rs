@@ -28,6 +28,9 @@ pub enum ClassCompilationError {
     #[cfg(feature = "cairo_native")]
     #[error("Failed to extract sierra program")]
     ExtractSierraProgramFailed(String), // use String due to original error type Felt252SerdeError not being available publicly
+    #[cfg(feature = "cairo_native")]
+    #[error("The class is already being compiled to cairo native")]
+    NativeCompilationInProgress,
 }
 
 impl CompressedLegacyContractClass {
@@ -136,13 +139,7 @@ impl FlattenedSierraClass {
 
     #[cfg(feature = "cairo_native")]
     pub fn compile_to_native(&self) -> Result<AotContractExecutor, ClassCompilationError> {
-        let sierra_version = parse_sierra_version(&self.sierra_program)?;
-        let sierra_version = casm_classes_v2::compiler_version::VersionId {
-            major: sierra_version.0 as _,
-            minor: sierra_version.1 as _,
-            patch: sierra_version.2 as _,
-        };
-        let sierra = v2::to_cairo_lang(self);
+        let (sierra, sierra_version) = self.to_native_sierra()?;
         let program = sierra
             .extract_sierra_program()
             .map_err(|e| ClassCompilationError::ExtractSierraProgramFailed(e.to_string()))?;
@@ -158,6 +155,45 @@ impl FlattenedSierraClass {
         Ok(executor)
     }
 
+    /// Compiles the class to a native shared library at `path`, which can later be loaded back with
+    /// [`AotContractExecutor::from_path`] instead of compiling the class again.
+    ///
+    /// Returns [`ClassCompilationError::NativeCompilationInProgress`] if another compilation is already writing to
+    /// `path`.
+    #[cfg(feature = "cairo_native")]
+    pub fn compile_to_native_into(&self, path: &std::path::Path) -> Result<AotContractExecutor, ClassCompilationError> {
+        let (sierra, sierra_version) = self.to_native_sierra()?;
+        let program = sierra
+            .extract_sierra_program()
+            .map_err(|e| ClassCompilationError::ExtractSierraProgramFailed(e.to_string()))?;
+
+        AotContractExecutor::new_into(
+            &program,
+            &sierra.entry_points_by_type,
+            sierra_version,
+            path,
+            cairo_native::OptLevel::Default,
+        )
+        .map_err(ClassCompilationError::NativeCompilationFailed)?
+        .ok_or(ClassCompilationError::NativeCompilationInProgress)
+    }
+
+    #[cfg(feature = "cairo_native")]
+    fn to_native_sierra(
+        &self,
+    ) -> Result<
+        (casm_classes_v2::contract_class::ContractClass, casm_classes_v2::compiler_version::VersionId),
+        ClassCompilationError,
+    > {
+        let sierra_version = parse_sierra_version(&self.sierra_program)?;
+        let sierra_version = casm_classes_v2::compiler_version::VersionId {
+            major: sierra_version.0 as _,
+            minor: sierra_version.1 as _,
+            patch: sierra_version.2 as _,
+        };
+        Ok((v2::to_cairo_lang(self), sierra_version))
+    }
+
     pub fn sierra_version(&self) -> Result<starknet_api::contract_class::SierraVersion, SierraVersionError> {
         let version = parse_sierra_version(&self.sierra_program)?;
         Ok(starknet_api::contract_class::SierraVersion::new(version.0, version.1, version.2))
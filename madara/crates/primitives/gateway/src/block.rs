@@ -114,6 +114,10 @@ pub struct ProviderBlock {
     pub l1_da_mode: L1DataAvailabilityMode,
     pub l1_gas_price: ResourcePrice,
     pub l1_data_gas_price: ResourcePrice,
+    /// Not present on blocks produced before L2 gas was priced.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub l2_gas_price: Option<ResourcePrice>,
     pub transactions: Vec<Transaction>,
     pub transaction_receipts: Vec<ConfirmedReceipt>,
     #[serde(default)]
@@ -174,6 +178,7 @@ impl ProviderBlock {
                 price_in_wei: block.info.header.l1_gas_price.eth_l1_data_gas_price,
                 price_in_fri: block.info.header.l1_gas_price.strk_l1_data_gas_price,
             },
+            l2_gas_price: l2_gas_price(&block.info.header.l1_gas_price),
             transactions,
             transaction_receipts,
             starknet_version,
@@ -201,6 +206,8 @@ impl ProviderBlock {
                 strk_l1_gas_price: self.l1_gas_price.price_in_fri,
                 eth_l1_data_gas_price: self.l1_data_gas_price.price_in_wei,
                 strk_l1_data_gas_price: self.l1_data_gas_price.price_in_fri,
+                eth_l2_gas_price: self.l2_gas_price.as_ref().map(|price| price.price_in_wei).unwrap_or_default(),
+                strk_l2_gas_price: self.l2_gas_price.as_ref().map(|price| price.price_in_fri).unwrap_or_default(),
             },
             l1_da_mode: self.l1_da_mode,
             block_number: self.block_number,
@@ -225,6 +232,10 @@ pub struct ProviderBlockPending {
     pub l1_da_mode: L1DataAvailabilityMode,
     pub l1_gas_price: ResourcePrice,
     pub l1_data_gas_price: ResourcePrice,
+    /// Not present on blocks produced before L2 gas was priced.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub l2_gas_price: Option<ResourcePrice>,
     pub transactions: Vec<Transaction>,
     pub timestamp: u64,
     #[serde(default)]
@@ -263,6 +274,7 @@ impl ProviderBlockPending {
                 price_in_wei: block.info.header.l1_gas_price.eth_l1_data_gas_price,
                 price_in_fri: block.info.header.l1_gas_price.strk_l1_data_gas_price,
             },
+            l2_gas_price: l2_gas_price(&block.info.header.l1_gas_price),
             transactions,
             timestamp: block.info.header.block_timestamp.0,
             sequencer_address: block.info.header.sequencer_address,
@@ -282,6 +294,8 @@ impl ProviderBlockPending {
                 strk_l1_gas_price: self.l1_gas_price.price_in_fri,
                 eth_l1_data_gas_price: self.l1_data_gas_price.price_in_wei,
                 strk_l1_data_gas_price: self.l1_data_gas_price.price_in_fri,
+                eth_l2_gas_price: self.l2_gas_price.as_ref().map(|price| price.price_in_wei).unwrap_or_default(),
+                strk_l2_gas_price: self.l2_gas_price.as_ref().map(|price| price.price_in_fri).unwrap_or_default(),
             },
             l1_da_mode: self.l1_da_mode,
         })
@@ -325,6 +339,13 @@ pub enum BlockStatus {
     AcceptedOnL1,
 }
 
+fn l2_gas_price(gas_prices: &mp_block::header::GasPrices) -> Option<ResourcePrice> {
+    if gas_prices.eth_l2_gas_price == 0 && gas_prices.strk_l2_gas_price == 0 {
+        return None;
+    }
+    Some(ResourcePrice { price_in_wei: gas_prices.eth_l2_gas_price, price_in_fri: gas_prices.strk_l2_gas_price })
+}
+
 fn starknet_version(version: StarknetVersion) -> Option<String> {
     match version {
         version if version < StarknetVersion::V0_9_1 => None,
@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+use starknet_types_core::felt::Felt;
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum FeeUnit {
+    Wei,
+    Fri,
+}
+
+impl From<mp_rpc::PriceUnit> for FeeUnit {
+    fn from(unit: mp_rpc::PriceUnit) -> Self {
+        match unit {
+            mp_rpc::PriceUnit::Wei => Self::Wei,
+            mp_rpc::PriceUnit::Fri => Self::Fri,
+        }
+    }
+}
+
+/// Response of `estimate_fee`, and fee estimation of `simulate_transaction`. The amounts are plain numbers, not hex
+/// strings.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct ProviderFeeEstimate {
+    pub overall_fee: u128,
+    pub unit: FeeUnit,
+    pub gas_price: u128,
+    pub gas_usage: u128,
+    pub data_gas_price: u128,
+    pub data_gas_consumed: u128,
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Fee estimate value {0:#x} does not fit in a u128")]
+pub struct FeeEstimateOverflow(pub Felt);
+
+impl TryFrom<mp_rpc::FeeEstimate> for ProviderFeeEstimate {
+    type Error = FeeEstimateOverflow;
+
+    fn try_from(estimate: mp_rpc::FeeEstimate) -> Result<Self, Self::Error> {
+        let to_u128 = |value: Felt| u128::try_from(value).map_err(|_| FeeEstimateOverflow(value));
+        Ok(Self {
+            overall_fee: to_u128(estimate.overall_fee)?,
+            unit: estimate.unit.into(),
+            gas_price: to_u128(estimate.gas_price)?,
+            gas_usage: to_u128(estimate.gas_consumed)?,
+            data_gas_price: to_u128(estimate.data_gas_price)?,
+            data_gas_consumed: to_u128(estimate.data_gas_consumed)?,
+        })
+    }
+}
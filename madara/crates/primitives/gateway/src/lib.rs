@@ -1,6 +1,8 @@
 pub mod block;
 pub mod error;
+pub mod fee;
 pub mod receipt;
 pub mod state_update;
 pub mod transaction;
+pub mod transaction_status;
 pub mod user_transaction;
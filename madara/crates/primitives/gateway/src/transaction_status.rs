@@ -0,0 +1,99 @@
+use crate::receipt::{ConfirmedReceipt, ExecutionStatus};
+use crate::transaction::Transaction;
+use serde::{Deserialize, Serialize};
+use starknet_types_core::felt::Felt;
+
+/// The status of a transaction, as found in the `tx_status` and `status` fields.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TransactionStatus {
+    NotReceived,
+    Received,
+    Rejected,
+    Reverted,
+    AcceptedOnL2,
+    AcceptedOnL1,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TransactionFinalityStatus {
+    NotReceived,
+    Received,
+    AcceptedOnL2,
+    AcceptedOnL1,
+}
+
+impl TransactionStatus {
+    pub fn new(finality_status: &TransactionFinalityStatus, execution_status: Option<&ExecutionStatus>) -> Self {
+        match (finality_status, execution_status) {
+            (TransactionFinalityStatus::NotReceived, _) => Self::NotReceived,
+            (TransactionFinalityStatus::Received, _) => Self::Received,
+            (_, Some(ExecutionStatus::Reverted)) => Self::Reverted,
+            (TransactionFinalityStatus::AcceptedOnL2, _) => Self::AcceptedOnL2,
+            (TransactionFinalityStatus::AcceptedOnL1, _) => Self::AcceptedOnL1,
+        }
+    }
+}
+
+/// Response of `get_transaction_status`.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct ProviderTransactionStatus {
+    pub tx_status: TransactionStatus,
+    pub finality_status: TransactionFinalityStatus,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub execution_status: Option<ExecutionStatus>,
+    /// Absent for transactions in the pending block.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_hash: Option<Felt>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tx_revert_reason: Option<String>,
+}
+
+/// Response of `get_transaction`.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct ProviderTransaction {
+    pub status: TransactionStatus,
+    pub finality_status: TransactionFinalityStatus,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub execution_status: Option<ExecutionStatus>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_hash: Option<Felt>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_number: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transaction_index: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transaction: Option<Transaction>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub revert_error: Option<String>,
+}
+
+impl ProviderTransaction {
+    pub fn not_received() -> Self {
+        Self {
+            status: TransactionStatus::NotReceived,
+            finality_status: TransactionFinalityStatus::NotReceived,
+            execution_status: None,
+            block_hash: None,
+            block_number: None,
+            transaction_index: None,
+            transaction: None,
+            revert_error: None,
+        }
+    }
+}
+
+/// Response of `get_transaction_receipt`.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct ProviderTransactionReceipt {
+    pub status: TransactionStatus,
+    pub finality_status: TransactionFinalityStatus,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_hash: Option<Felt>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_number: Option<u64>,
+    /// Absent when the transaction has not been executed yet.
+    #[serde(flatten, default, skip_serializing_if = "Option::is_none")]
+    pub receipt: Option<ConfirmedReceipt>,
+}
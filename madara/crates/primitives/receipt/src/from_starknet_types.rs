@@ -0,0 +1,275 @@
+use crate::{
+    DeclareTransactionReceipt, DeployAccountTransactionReceipt, DeployTransactionReceipt, Event, ExecutionResources,
+    ExecutionResult, FeePayment, Hash256, InvokeTransactionReceipt, L1Gas, L1HandlerTransactionReceipt, MsgToL1,
+    PriceUnit, TransactionReceipt,
+};
+
+impl TryFrom<mp_rpc::TxnReceipt> for TransactionReceipt {
+    type Error = anyhow::Error;
+
+    fn try_from(receipt: mp_rpc::TxnReceipt) -> Result<Self, Self::Error> {
+        Ok(match receipt {
+            mp_rpc::TxnReceipt::Invoke(receipt) => Self::Invoke(receipt.into()),
+            mp_rpc::TxnReceipt::L1Handler(receipt) => Self::L1Handler(receipt.try_into()?),
+            mp_rpc::TxnReceipt::Declare(receipt) => Self::Declare(receipt.into()),
+            mp_rpc::TxnReceipt::Deploy(receipt) => Self::Deploy(receipt.into()),
+            mp_rpc::TxnReceipt::DeployAccount(receipt) => Self::DeployAccount(receipt.into()),
+        })
+    }
+}
+
+impl From<mp_rpc::InvokeTxnReceipt> for InvokeTransactionReceipt {
+    fn from(receipt: mp_rpc::InvokeTxnReceipt) -> Self {
+        let common = receipt.common_receipt_properties;
+        Self {
+            transaction_hash: common.transaction_hash,
+            actual_fee: common.actual_fee.into(),
+            messages_sent: common.messages_sent.into_iter().map(MsgToL1::from).collect(),
+            events: common.events.into_iter().map(Event::from).collect(),
+            execution_resources: common.execution_resources.into(),
+            execution_result: common.execution_status.into(),
+        }
+    }
+}
+
+impl TryFrom<mp_rpc::L1HandlerTxnReceipt> for L1HandlerTransactionReceipt {
+    type Error = anyhow::Error;
+
+    fn try_from(receipt: mp_rpc::L1HandlerTxnReceipt) -> Result<Self, Self::Error> {
+        let message_hash = Hash256::from_hex(&receipt.message_hash)
+            .map_err(|err| anyhow::anyhow!("Invalid message hash {:?}: {err:?}", receipt.message_hash))?;
+        let common = receipt.common_receipt_properties;
+        Ok(Self {
+            message_hash,
+            transaction_hash: common.transaction_hash,
+            actual_fee: common.actual_fee.into(),
+            messages_sent: common.messages_sent.into_iter().map(MsgToL1::from).collect(),
+            events: common.events.into_iter().map(Event::from).collect(),
+            execution_resources: common.execution_resources.into(),
+            execution_result: common.execution_status.into(),
+        })
+    }
+}
+
+impl From<mp_rpc::DeclareTxnReceipt> for DeclareTransactionReceipt {
+    fn from(receipt: mp_rpc::DeclareTxnReceipt) -> Self {
+        let common = receipt.common_receipt_properties;
+        Self {
+            transaction_hash: common.transaction_hash,
+            actual_fee: common.actual_fee.into(),
+            messages_sent: common.messages_sent.into_iter().map(MsgToL1::from).collect(),
+            events: common.events.into_iter().map(Event::from).collect(),
+            execution_resources: common.execution_resources.into(),
+            execution_result: common.execution_status.into(),
+        }
+    }
+}
+
+impl From<mp_rpc::DeployTxnReceipt> for DeployTransactionReceipt {
+    fn from(receipt: mp_rpc::DeployTxnReceipt) -> Self {
+        let common = receipt.common_receipt_properties;
+        Self {
+            transaction_hash: common.transaction_hash,
+            actual_fee: common.actual_fee.into(),
+            messages_sent: common.messages_sent.into_iter().map(MsgToL1::from).collect(),
+            events: common.events.into_iter().map(Event::from).collect(),
+            execution_resources: common.execution_resources.into(),
+            execution_result: common.execution_status.into(),
+            contract_address: receipt.contract_address,
+        }
+    }
+}
+
+impl From<mp_rpc::DeployAccountTxnReceipt> for DeployAccountTransactionReceipt {
+    fn from(receipt: mp_rpc::DeployAccountTxnReceipt) -> Self {
+        let common = receipt.common_receipt_properties;
+        Self {
+            transaction_hash: common.transaction_hash,
+            actual_fee: common.actual_fee.into(),
+            messages_sent: common.messages_sent.into_iter().map(MsgToL1::from).collect(),
+            events: common.events.into_iter().map(Event::from).collect(),
+            execution_resources: common.execution_resources.into(),
+            execution_result: common.execution_status.into(),
+            contract_address: receipt.contract_address,
+        }
+    }
+}
+
+impl TryFrom<mp_rpc::v0_9_0::TxnReceipt> for TransactionReceipt {
+    type Error = anyhow::Error;
+
+    fn try_from(receipt: mp_rpc::v0_9_0::TxnReceipt) -> Result<Self, Self::Error> {
+        Ok(match receipt {
+            mp_rpc::v0_9_0::TxnReceipt::Invoke(receipt) => Self::Invoke(receipt.into()),
+            mp_rpc::v0_9_0::TxnReceipt::L1Handler(receipt) => Self::L1Handler(receipt.try_into()?),
+            mp_rpc::v0_9_0::TxnReceipt::Declare(receipt) => Self::Declare(receipt.into()),
+            mp_rpc::v0_9_0::TxnReceipt::Deploy(receipt) => Self::Deploy(receipt.into()),
+            mp_rpc::v0_9_0::TxnReceipt::DeployAccount(receipt) => Self::DeployAccount(receipt.into()),
+        })
+    }
+}
+
+impl From<mp_rpc::v0_9_0::InvokeTxnReceipt> for InvokeTransactionReceipt {
+    fn from(receipt: mp_rpc::v0_9_0::InvokeTxnReceipt) -> Self {
+        let common = receipt.common_receipt_properties;
+        Self {
+            transaction_hash: common.transaction_hash,
+            actual_fee: common.actual_fee.into(),
+            messages_sent: common.messages_sent.into_iter().map(MsgToL1::from).collect(),
+            events: common.events.into_iter().map(Event::from).collect(),
+            execution_resources: common.execution_resources.into(),
+            execution_result: common.execution_status.into(),
+        }
+    }
+}
+
+impl TryFrom<mp_rpc::v0_9_0::L1HandlerTxnReceipt> for L1HandlerTransactionReceipt {
+    type Error = anyhow::Error;
+
+    fn try_from(receipt: mp_rpc::v0_9_0::L1HandlerTxnReceipt) -> Result<Self, Self::Error> {
+        let message_hash = Hash256::from_hex(&receipt.message_hash)
+            .map_err(|err| anyhow::anyhow!("Invalid message hash {:?}: {err:?}", receipt.message_hash))?;
+        let common = receipt.common_receipt_properties;
+        Ok(Self {
+            message_hash,
+            transaction_hash: common.transaction_hash,
+            actual_fee: common.actual_fee.into(),
+            messages_sent: common.messages_sent.into_iter().map(MsgToL1::from).collect(),
+            events: common.events.into_iter().map(Event::from).collect(),
+            execution_resources: common.execution_resources.into(),
+            execution_result: common.execution_status.into(),
+        })
+    }
+}
+
+impl From<mp_rpc::v0_9_0::DeclareTxnReceipt> for DeclareTransactionReceipt {
+    fn from(receipt: mp_rpc::v0_9_0::DeclareTxnReceipt) -> Self {
+        let common = receipt.common_receipt_properties;
+        Self {
+            transaction_hash: common.transaction_hash,
+            actual_fee: common.actual_fee.into(),
+            messages_sent: common.messages_sent.into_iter().map(MsgToL1::from).collect(),
+            events: common.events.into_iter().map(Event::from).collect(),
+            execution_resources: common.execution_resources.into(),
+            execution_result: common.execution_status.into(),
+        }
+    }
+}
+
+impl From<mp_rpc::v0_9_0::DeployTxnReceipt> for DeployTransactionReceipt {
+    fn from(receipt: mp_rpc::v0_9_0::DeployTxnReceipt) -> Self {
+        let common = receipt.common_receipt_properties;
+        Self {
+            transaction_hash: common.transaction_hash,
+            actual_fee: common.actual_fee.into(),
+            messages_sent: common.messages_sent.into_iter().map(MsgToL1::from).collect(),
+            events: common.events.into_iter().map(Event::from).collect(),
+            execution_resources: common.execution_resources.into(),
+            execution_result: common.execution_status.into(),
+            contract_address: receipt.contract_address,
+        }
+    }
+}
+
+impl From<mp_rpc::v0_9_0::DeployAccountTxnReceipt> for DeployAccountTransactionReceipt {
+    fn from(receipt: mp_rpc::v0_9_0::DeployAccountTxnReceipt) -> Self {
+        let common = receipt.common_receipt_properties;
+        Self {
+            transaction_hash: common.transaction_hash,
+            actual_fee: common.actual_fee.into(),
+            messages_sent: common.messages_sent.into_iter().map(MsgToL1::from).collect(),
+            events: common.events.into_iter().map(Event::from).collect(),
+            execution_resources: common.execution_resources.into(),
+            execution_result: common.execution_status.into(),
+            contract_address: receipt.contract_address,
+        }
+    }
+}
+
+impl From<mp_rpc::FeePayment> for FeePayment {
+    fn from(fee: mp_rpc::FeePayment) -> Self {
+        Self { amount: fee.amount, unit: fee.unit.into() }
+    }
+}
+
+impl From<mp_rpc::PriceUnit> for PriceUnit {
+    fn from(unit: mp_rpc::PriceUnit) -> Self {
+        match unit {
+            mp_rpc::PriceUnit::Wei => PriceUnit::Wei,
+            mp_rpc::PriceUnit::Fri => PriceUnit::Fri,
+        }
+    }
+}
+
+impl From<mp_rpc::MsgToL1> for MsgToL1 {
+    fn from(msg: mp_rpc::MsgToL1) -> Self {
+        Self { from_address: msg.from_address, to_address: msg.to_address, payload: msg.payload }
+    }
+}
+
+impl From<mp_rpc::Event> for Event {
+    fn from(event: mp_rpc::Event) -> Self {
+        Self { from_address: event.from_address, keys: event.event_content.keys, data: event.event_content.data }
+    }
+}
+
+// The v0.7.1 RPC receipts do not include the total gas consumed by the transaction, it is left at zero.
+impl From<mp_rpc::ExecutionResources> for ExecutionResources {
+    fn from(resources: mp_rpc::ExecutionResources) -> Self {
+        Self {
+            steps: resources.steps,
+            memory_holes: resources.memory_holes.unwrap_or_default(),
+            range_check_builtin_applications: resources.range_check_builtin_applications.unwrap_or_default(),
+            pedersen_builtin_applications: resources.pedersen_builtin_applications.unwrap_or_default(),
+            poseidon_builtin_applications: resources.poseidon_builtin_applications.unwrap_or_default(),
+            ec_op_builtin_applications: resources.ec_op_builtin_applications.unwrap_or_default(),
+            ecdsa_builtin_applications: resources.ecdsa_builtin_applications.unwrap_or_default(),
+            bitwise_builtin_applications: resources.bitwise_builtin_applications.unwrap_or_default(),
+            keccak_builtin_applications: resources.keccak_builtin_applications.unwrap_or_default(),
+            segment_arena_builtin: resources.segment_arena_builtin.unwrap_or_default(),
+            data_availability: resources.data_availability.into(),
+            total_gas_consumed: L1Gas::default(),
+        }
+    }
+}
+
+// The v0.9.0 RPC receipts only have the total gas consumed by the transaction, the other resources are left at zero.
+impl From<mp_rpc::v0_9_0::ExecutionResources> for ExecutionResources {
+    fn from(resources: mp_rpc::v0_9_0::ExecutionResources) -> Self {
+        Self {
+            total_gas_consumed: L1Gas { l1_gas: resources.l1_gas, l1_data_gas: resources.l1_data_gas },
+            ..Default::default()
+        }
+    }
+}
+
+impl From<mp_rpc::DataAvailability> for L1Gas {
+    fn from(resources: mp_rpc::DataAvailability) -> Self {
+        Self { l1_gas: resources.l1_gas, l1_data_gas: resources.l1_data_gas }
+    }
+}
+
+impl From<mp_rpc::ExecutionStatus> for ExecutionResult {
+    fn from(status: mp_rpc::ExecutionStatus) -> Self {
+        match status {
+            mp_rpc::ExecutionStatus::Successful => ExecutionResult::Succeeded,
+            mp_rpc::ExecutionStatus::Reverted(reason) => ExecutionResult::Reverted { reason },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{L1HandlerTransactionReceipt, TransactionReceipt};
+    use starknet_core::types::Hash256;
+
+    #[test]
+    fn test_l1_handler_receipt_roundtrip() {
+        let receipt = TransactionReceipt::L1Handler(L1HandlerTransactionReceipt {
+            message_hash: Hash256::from_bytes([u8::MAX; 32]),
+            ..Default::default()
+        });
+        let rpc_receipt = receipt.clone().to_starknet_types(mp_rpc::TxnFinalityStatus::L1);
+        assert_eq!(TransactionReceipt::try_from(rpc_receipt).unwrap(), receipt);
+    }
+}
@@ -9,6 +9,7 @@ use starknet_types_core::{
 
 pub mod from_blockifier;
 
+mod from_starknet_types;
 mod to_starknet_types;
 
 pub use from_blockifier::from_blockifier_execution_info;
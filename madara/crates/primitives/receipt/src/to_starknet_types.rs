@@ -114,6 +114,46 @@ impl DeployAccountTransactionReceipt {
     }
 }
 
+impl TransactionReceipt {
+    pub fn to_rpc_v0_9_0(self, finality_status: mp_rpc::v0_9_0::TxnFinalityStatus) -> mp_rpc::v0_9_0::TxnReceipt {
+        let common_receipt_properties = mp_rpc::v0_9_0::CommonReceiptProperties {
+            actual_fee: self.actual_fee().clone().into(),
+            events: self.events().iter().cloned().map(mp_rpc::Event::from).collect(),
+            execution_resources: self.execution_resources().clone().into(),
+            finality_status,
+            messages_sent: self.messages_sent().iter().cloned().map(mp_rpc::MsgToL1::from).collect(),
+            transaction_hash: self.transaction_hash(),
+            execution_status: self.execution_result().into(),
+        };
+        match self {
+            TransactionReceipt::Invoke(_) => {
+                mp_rpc::v0_9_0::TxnReceipt::Invoke(mp_rpc::v0_9_0::InvokeTxnReceipt { common_receipt_properties })
+            }
+            TransactionReceipt::L1Handler(receipt) => {
+                mp_rpc::v0_9_0::TxnReceipt::L1Handler(mp_rpc::v0_9_0::L1HandlerTxnReceipt {
+                    message_hash: format!("{}", receipt.message_hash),
+                    common_receipt_properties,
+                })
+            }
+            TransactionReceipt::Declare(_) => {
+                mp_rpc::v0_9_0::TxnReceipt::Declare(mp_rpc::v0_9_0::DeclareTxnReceipt { common_receipt_properties })
+            }
+            TransactionReceipt::Deploy(receipt) => {
+                mp_rpc::v0_9_0::TxnReceipt::Deploy(mp_rpc::v0_9_0::DeployTxnReceipt {
+                    contract_address: receipt.contract_address,
+                    common_receipt_properties,
+                })
+            }
+            TransactionReceipt::DeployAccount(receipt) => {
+                mp_rpc::v0_9_0::TxnReceipt::DeployAccount(mp_rpc::v0_9_0::DeployAccountTxnReceipt {
+                    contract_address: receipt.contract_address,
+                    common_receipt_properties,
+                })
+            }
+        }
+    }
+}
+
 impl From<FeePayment> for mp_rpc::FeePayment {
     fn from(fee: FeePayment) -> Self {
         Self { amount: fee.amount, unit: fee.unit.into() }
@@ -162,6 +202,17 @@ impl From<ExecutionResources> for mp_rpc::ExecutionResources {
     }
 }
 
+// L2 gas is not tracked in the receipts yet, it is reported as zero.
+impl From<ExecutionResources> for mp_rpc::v0_9_0::ExecutionResources {
+    fn from(resources: ExecutionResources) -> Self {
+        Self {
+            l1_gas: resources.total_gas_consumed.l1_gas,
+            l1_data_gas: resources.total_gas_consumed.l1_data_gas,
+            l2_gas: 0,
+        }
+    }
+}
+
 fn nullify_zero(u: u64) -> Option<u64> {
     match u {
         0 => None,
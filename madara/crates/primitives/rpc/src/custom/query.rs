@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     BroadcastedDeclareTxnV1, BroadcastedDeclareTxnV2, BroadcastedDeclareTxnV3, DeployAccountTxnV1, DeployAccountTxnV3,
-    InvokeTxnV0, InvokeTxnV1, InvokeTxnV3,
+    InvokeTxnV0, InvokeTxnV1, InvokeTxnV3, ResourceBoundsMapping,
 };
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
@@ -35,6 +35,14 @@ impl BroadcastedDeclareTxn {
             BroadcastedDeclareTxn::V1(_) | BroadcastedDeclareTxn::V2(_) | BroadcastedDeclareTxn::V3(_) => false,
         }
     }
+
+    /// Only v3 transactions have resource bounds.
+    pub fn resource_bounds(&self) -> Option<&ResourceBoundsMapping> {
+        match self {
+            BroadcastedDeclareTxn::V3(tx) | BroadcastedDeclareTxn::QueryV3(tx) => Some(&tx.resource_bounds),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
@@ -60,6 +68,14 @@ impl BroadcastedDeployAccountTxn {
             BroadcastedDeployAccountTxn::V1(_) | BroadcastedDeployAccountTxn::V3(_) => false,
         }
     }
+
+    /// Only v3 transactions have resource bounds.
+    pub fn resource_bounds(&self) -> Option<&ResourceBoundsMapping> {
+        match self {
+            BroadcastedDeployAccountTxn::V3(tx) | BroadcastedDeployAccountTxn::QueryV3(tx) => Some(&tx.resource_bounds),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
@@ -92,4 +108,12 @@ impl BroadcastedInvokeTxn {
             BroadcastedInvokeTxn::V0(_) | BroadcastedInvokeTxn::V1(_) | BroadcastedInvokeTxn::V3(_) => false,
         }
     }
+
+    /// Only v3 transactions have resource bounds.
+    pub fn resource_bounds(&self) -> Option<&ResourceBoundsMapping> {
+        match self {
+            BroadcastedInvokeTxn::V3(tx) | BroadcastedInvokeTxn::QueryV3(tx) => Some(&tx.resource_bounds),
+            _ => None,
+        }
+    }
 }
@@ -4,5 +4,6 @@ mod custom_serde;
 pub mod admin;
 pub mod v0_7_1;
 pub mod v0_8_1;
+pub mod v0_9_0;
 
 pub use self::v0_7_1::*;
@@ -171,6 +171,14 @@ impl BroadcastedTxn {
             BroadcastedTxn::DeployAccount(txn) => txn.is_query(),
         }
     }
+
+    pub fn resource_bounds(&self) -> Option<&ResourceBoundsMapping> {
+        match self {
+            BroadcastedTxn::Invoke(txn) => txn.resource_bounds(),
+            BroadcastedTxn::Declare(txn) => txn.resource_bounds(),
+            BroadcastedTxn::DeployAccount(txn) => txn.resource_bounds(),
+        }
+    }
 }
 
 /// StarkNet chain id, given in hex representation.
@@ -0,0 +1,99 @@
+use serde::{Deserialize, Deserializer, Serialize};
+
+use super::{BlockHash, BlockNumber, BlockTag};
+
+/// A block hash, number or tag.
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub enum BlockId {
+    /// The tag of the block.
+    Tag(BlockTag),
+    /// The hash of the block.
+    Hash(BlockHash),
+    /// The height of the block.
+    Number(BlockNumber),
+}
+
+#[derive(Serialize, Deserialize)]
+struct BlockHashHelper {
+    block_hash: BlockHash,
+}
+
+#[derive(Serialize, Deserialize)]
+struct BlockNumberHelper {
+    block_number: BlockNumber,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum BlockIdHelper {
+    Tag(BlockTag),
+    Hash(BlockHashHelper),
+    Number(BlockNumberHelper),
+}
+
+impl serde::Serialize for BlockId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            BlockId::Tag(tag) => tag.serialize(serializer),
+            BlockId::Hash(block_hash) => {
+                let helper = BlockHashHelper { block_hash: *block_hash };
+                helper.serialize(serializer)
+            }
+            BlockId::Number(block_number) => {
+                let helper = BlockNumberHelper { block_number: *block_number };
+                helper.serialize(serializer)
+            }
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for BlockId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let helper = BlockIdHelper::deserialize(deserializer)?;
+        match helper {
+            BlockIdHelper::Tag(tag) => Ok(BlockId::Tag(tag)),
+            BlockIdHelper::Hash(helper) => Ok(BlockId::Hash(helper.block_hash)),
+            BlockIdHelper::Number(helper) => Ok(BlockId::Number(helper.block_number)),
+        }
+    }
+}
+
+#[test]
+fn block_id_from_pre_confirmed() {
+    let s = "\"pre_confirmed\"";
+    let block_id: BlockId = serde_json::from_str(s).unwrap();
+    assert_eq!(block_id, BlockId::Tag(BlockTag::PreConfirmed));
+}
+
+#[test]
+fn block_id_from_l1_accepted() {
+    let s = "\"l1_accepted\"";
+    let block_id: BlockId = serde_json::from_str(s).unwrap();
+    assert_eq!(block_id, BlockId::Tag(BlockTag::L1Accepted));
+}
+
+#[test]
+fn block_id_from_pending_is_rejected() {
+    let s = "\"pending\"";
+    assert!(serde_json::from_str::<BlockId>(s).is_err());
+}
+
+#[test]
+fn block_id_to_pre_confirmed() {
+    let block_id = BlockId::Tag(BlockTag::PreConfirmed);
+    let s = serde_json::to_string(&block_id).unwrap();
+    assert_eq!(s, "\"pre_confirmed\"");
+}
+
+#[test]
+fn block_id_to_number() {
+    let block_id = BlockId::Number(123);
+    let s = serde_json::to_string(&block_id).unwrap();
+    assert_eq!(s, "{\"block_number\":123}");
+}
@@ -0,0 +1,72 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+
+/// The hash of an L1 transaction, as a 0x-prefixed hex string of at most 64 digits.
+///
+/// This can't be a [`Felt`](starknet_types_core::felt::Felt), as Ethereum transaction hashes span the full 256 bits.
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, PartialEq)]
+pub struct L1TxnHash(pub [u8; 32]);
+
+impl fmt::Display for L1TxnHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "0x")?;
+        self.0.iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+    }
+}
+
+impl Serialize for L1TxnHash {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for L1TxnHash {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        let digits =
+            s.strip_prefix("0x").ok_or_else(|| serde::de::Error::custom("expected a 0x-prefixed hex string"))?;
+        if digits.is_empty() || digits.len() > 64 || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(serde::de::Error::custom("expected between 1 and 64 hex digits"));
+        }
+
+        // Left-pad to a full 32 bytes.
+        let padded = format!("{digits:0>64}");
+        let mut bytes = [0u8; 32];
+        for (byte, chunk) in bytes.iter_mut().zip(padded.as_bytes().chunks(2)) {
+            let chunk = std::str::from_utf8(chunk).expect("Checked to be ascii");
+            *byte = u8::from_str_radix(chunk, 16).expect("Checked to be hex digits");
+        }
+        Ok(Self(bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn l1_txn_hash_round_trip() {
+        let mut bytes = [0xffu8; 32];
+        bytes[0] = 0x0a;
+        let hash = L1TxnHash(bytes);
+        let json = serde_json::to_string(&hash).unwrap();
+        assert_eq!(json, format!("\"0x0a{}\"", "ff".repeat(31)));
+        assert_eq!(serde_json::from_str::<L1TxnHash>(&json).unwrap(), hash);
+    }
+
+    #[test]
+    fn l1_txn_hash_short() {
+        let mut bytes = [0u8; 32];
+        bytes[30] = 0x01;
+        bytes[31] = 0x23;
+        assert_eq!(serde_json::from_str::<L1TxnHash>("\"0x123\"").unwrap(), L1TxnHash(bytes));
+    }
+
+    #[test]
+    fn l1_txn_hash_invalid() {
+        assert!(serde_json::from_str::<L1TxnHash>("\"123\"").is_err());
+        assert!(serde_json::from_str::<L1TxnHash>("\"0x\"").is_err());
+        assert!(serde_json::from_str::<L1TxnHash>("\"0xzz\"").is_err());
+        assert!(serde_json::from_str::<L1TxnHash>(&format!("\"0x{}\"", "1".repeat(65))).is_err());
+    }
+}
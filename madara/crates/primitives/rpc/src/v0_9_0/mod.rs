@@ -0,0 +1,15 @@
+//! v0.9.0 of the API.
+//!
+//! Only the types whose shape changed since v0.7.1 are defined here, the others are re-exported.
+pub use crate::v0_7_1::{
+    Address, BlockHash, BlockHashAndNumber, BlockNumber, Event, ExecutionStatus, FeePayment, L1DaMode, MsgToL1,
+    ResourcePrice, Txn, TxnExecutionStatus, TxnHash, TxnWithHash,
+};
+
+mod block_id;
+mod l1_txn_hash;
+mod starknet_api_openrpc;
+
+pub use self::block_id::*;
+pub use self::l1_txn_hash::*;
+pub use self::starknet_api_openrpc::*;
@@ -0,0 +1,361 @@
+use serde::{Deserialize, Serialize};
+use starknet_types_core::felt::Felt;
+
+use super::{
+    Address, BlockHash, BlockId, BlockNumber, Event, ExecutionStatus, FeePayment, L1DaMode, MsgToL1, ResourcePrice,
+    Txn, TxnExecutionStatus, TxnHash, TxnWithHash,
+};
+
+/// A tag specifying a dynamic reference to a block
+#[derive(Eq, Hash, PartialEq, Serialize, Deserialize, Clone, Debug)]
+pub enum BlockTag {
+    /// The latest block accepted on L2
+    #[serde(rename = "latest")]
+    Latest,
+    /// The block currently being built by the sequencer, its transactions are executed but not final
+    #[serde(rename = "pre_confirmed")]
+    PreConfirmed,
+    /// The latest block whose state has been settled on L1
+    #[serde(rename = "l1_accepted")]
+    L1Accepted,
+}
+
+/// The status of the block
+#[derive(Eq, Hash, PartialEq, Serialize, Deserialize, Clone, Debug)]
+pub enum BlockStatus {
+    #[serde(rename = "PRE_CONFIRMED")]
+    PreConfirmed,
+    #[serde(rename = "ACCEPTED_ON_L2")]
+    AcceptedOnL2,
+    #[serde(rename = "ACCEPTED_ON_L1")]
+    AcceptedOnL1,
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct BlockHeader {
+    pub block_hash: BlockHash,
+    /// The block number (its height)
+    pub block_number: BlockNumber,
+    /// specifies whether the data of this block is published via blob data or calldata
+    pub l1_da_mode: L1DaMode,
+    /// The price of l1 data gas in the block
+    pub l1_data_gas_price: ResourcePrice,
+    /// The price of l1 gas in the block
+    pub l1_gas_price: ResourcePrice,
+    /// The price of l2 gas in the block
+    pub l2_gas_price: ResourcePrice,
+    /// The new global state root
+    pub new_root: Felt,
+    /// The hash of this block's parent
+    pub parent_hash: BlockHash,
+    /// The StarkNet identity of the sequencer submitting this block
+    pub sequencer_address: Felt,
+    /// Semver of the current Starknet protocol
+    pub starknet_version: String,
+    /// The time in which the block was created, encoded in Unix time
+    pub timestamp: u64,
+}
+
+/// The header of the block being built by the sequencer. Its hash and state root are not known yet.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct PreConfirmedBlockHeader {
+    /// The block number the block will have once closed
+    pub block_number: BlockNumber,
+    /// specifies whether the data of this block is published via blob data or calldata
+    pub l1_da_mode: L1DaMode,
+    /// The price of l1 data gas in the block
+    pub l1_data_gas_price: ResourcePrice,
+    /// The price of l1 gas in the block
+    pub l1_gas_price: ResourcePrice,
+    /// The price of l2 gas in the block
+    pub l2_gas_price: ResourcePrice,
+    /// The StarkNet identity of the sequencer submitting this block
+    pub sequencer_address: Felt,
+    /// Semver of the current Starknet protocol
+    pub starknet_version: String,
+    /// The time in which the block was created, encoded in Unix time
+    pub timestamp: u64,
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct TransactionAndReceipt {
+    pub receipt: TxnReceipt,
+    pub transaction: Txn,
+}
+
+/// The block object
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct BlockWithReceipts {
+    /// The transactions in this block
+    pub transactions: Vec<TransactionAndReceipt>,
+    pub status: BlockStatus,
+    #[serde(flatten)]
+    pub block_header: BlockHeader,
+}
+
+/// The block object
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct BlockWithTxs {
+    /// The transactions in this block
+    pub transactions: Vec<TxnWithHash>,
+    pub status: BlockStatus,
+    #[serde(flatten)]
+    pub block_header: BlockHeader,
+}
+
+/// The block object
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct BlockWithTxHashes {
+    /// The hashes of the transactions included in this block
+    pub transactions: Vec<TxnHash>,
+    pub status: BlockStatus,
+    #[serde(flatten)]
+    pub block_header: BlockHeader,
+}
+
+/// The block being built by the sequencer
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct PreConfirmedBlockWithReceipts {
+    /// The transactions in this block
+    pub transactions: Vec<TransactionAndReceipt>,
+    #[serde(flatten)]
+    pub pre_confirmed_block_header: PreConfirmedBlockHeader,
+}
+
+/// The block being built by the sequencer
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct PreConfirmedBlockWithTxs {
+    /// The transactions in this block
+    pub transactions: Vec<TxnWithHash>,
+    #[serde(flatten)]
+    pub pre_confirmed_block_header: PreConfirmedBlockHeader,
+}
+
+/// The block being built by the sequencer
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct PreConfirmedBlockWithTxHashes {
+    /// The hashes of the transactions included in this block
+    pub transactions: Vec<TxnHash>,
+    #[serde(flatten)]
+    pub pre_confirmed_block_header: PreConfirmedBlockHeader,
+}
+
+#[derive(Eq, Hash, PartialEq, Serialize, Deserialize, Clone, Debug)]
+#[serde(untagged)]
+pub enum MaybePreConfirmedBlockWithReceipts {
+    Block(BlockWithReceipts),
+    PreConfirmed(PreConfirmedBlockWithReceipts),
+}
+
+#[derive(Eq, Hash, PartialEq, Serialize, Deserialize, Clone, Debug)]
+#[serde(untagged)]
+pub enum MaybePreConfirmedBlockWithTxHashes {
+    Block(BlockWithTxHashes),
+    PreConfirmed(PreConfirmedBlockWithTxHashes),
+}
+
+#[derive(Eq, Hash, PartialEq, Serialize, Deserialize, Clone, Debug)]
+#[serde(untagged)]
+pub enum MaybePreConfirmedBlockWithTxs {
+    Block(BlockWithTxs),
+    PreConfirmed(PreConfirmedBlockWithTxs),
+}
+
+/// The resources consumed by the transaction
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct ExecutionResources {
+    /// l1 gas consumed by this transaction, used for l2-->l1 messages and state updates if blobs are not used
+    pub l1_gas: u128,
+    /// data gas consumed by this transaction, 0 if blobs are not used
+    pub l1_data_gas: u128,
+    /// l2 gas consumed by this transaction, used for computation and calldata
+    pub l2_gas: u128,
+}
+
+/// The finality status of the transaction
+#[derive(Eq, Hash, PartialEq, Serialize, Deserialize, Clone, Debug)]
+pub enum TxnFinalityStatus {
+    #[serde(rename = "PRE_CONFIRMED")]
+    PreConfirmed,
+    #[serde(rename = "ACCEPTED_ON_L2")]
+    L2,
+    #[serde(rename = "ACCEPTED_ON_L1")]
+    L1,
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct CommonReceiptProperties {
+    /// The fee that was charged by the sequencer
+    pub actual_fee: FeePayment,
+    /// The events emitted as part of this transaction
+    pub events: Vec<Event>,
+    /// The resources consumed by the transaction
+    pub execution_resources: ExecutionResources,
+    /// finality status of the tx
+    pub finality_status: TxnFinalityStatus,
+    pub messages_sent: Vec<MsgToL1>,
+    /// The hash identifying the transaction
+    pub transaction_hash: TxnHash,
+    #[serde(flatten)]
+    pub execution_status: ExecutionStatus,
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct InvokeTxnReceipt {
+    #[serde(flatten)]
+    pub common_receipt_properties: CommonReceiptProperties,
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct L1HandlerTxnReceipt {
+    /// The message hash as it appears on the L1 core contract
+    pub message_hash: String,
+    #[serde(flatten)]
+    pub common_receipt_properties: CommonReceiptProperties,
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct DeclareTxnReceipt {
+    #[serde(flatten)]
+    pub common_receipt_properties: CommonReceiptProperties,
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct DeployTxnReceipt {
+    /// The address of the deployed contract
+    pub contract_address: Felt,
+    #[serde(flatten)]
+    pub common_receipt_properties: CommonReceiptProperties,
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct DeployAccountTxnReceipt {
+    /// The address of the deployed contract
+    pub contract_address: Felt,
+    #[serde(flatten)]
+    pub common_receipt_properties: CommonReceiptProperties,
+}
+
+#[derive(Eq, Hash, PartialEq, Serialize, Deserialize, Clone, Debug)]
+#[serde(tag = "type")]
+pub enum TxnReceipt {
+    #[serde(rename = "INVOKE")]
+    Invoke(InvokeTxnReceipt),
+    #[serde(rename = "L1_HANDLER")]
+    L1Handler(L1HandlerTxnReceipt),
+    #[serde(rename = "DECLARE")]
+    Declare(DeclareTxnReceipt),
+    #[serde(rename = "DEPLOY")]
+    Deploy(DeployTxnReceipt),
+    #[serde(rename = "DEPLOY_ACCOUNT")]
+    DeployAccount(DeployAccountTxnReceipt),
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct TxnReceiptWithBlockInfo {
+    #[serde(flatten)]
+    pub transaction_receipt: TxnReceipt,
+    /// If this field is missing, it means the receipt belongs to the pre-confirmed block
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_hash: Option<BlockHash>,
+    /// The number of the block the transaction is included in, this is also set for the pre-confirmed block
+    pub block_number: BlockNumber,
+}
+
+/// The finality status of the transaction, including the case the txn is still in the mempool or is a candidate
+/// for the pre-confirmed block
+#[derive(Eq, Hash, PartialEq, Serialize, Deserialize, Clone, Debug)]
+pub enum TxnStatus {
+    #[serde(rename = "RECEIVED")]
+    Received,
+    #[serde(rename = "CANDIDATE")]
+    Candidate,
+    #[serde(rename = "PRE_CONFIRMED")]
+    PreConfirmed,
+    #[serde(rename = "ACCEPTED_ON_L2")]
+    AcceptedOnL2,
+    #[serde(rename = "ACCEPTED_ON_L1")]
+    AcceptedOnL1,
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct TxnFinalityAndExecutionStatus {
+    pub finality_status: TxnStatus,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub execution_status: Option<TxnExecutionStatus>,
+    /// The failure reason, only present when the transaction is reverted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub failure_reason: Option<String>,
+}
+
+/// The status of an L1 handler transaction sent by an L1 transaction
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct MessageStatus {
+    /// The hash of the L1 handler transaction
+    pub transaction_hash: TxnHash,
+    pub finality_status: TxnStatus,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub execution_status: Option<TxnExecutionStatus>,
+    /// The failure reason, only present when the transaction is reverted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub failure_reason: Option<String>,
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct EventFilterWithPageRequest {
+    #[serde(default)]
+    pub address: Option<Address>,
+    #[serde(default)]
+    pub from_block: Option<BlockId>,
+    /// The values used to filter the events
+    #[serde(default)]
+    pub keys: Option<Vec<Vec<Felt>>>,
+    #[serde(default)]
+    pub to_block: Option<BlockId>,
+    pub chunk_size: u64,
+    /// The token returned from the previous query. If no token is provided the first page is returned.
+    #[serde(default)]
+    pub continuation_token: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pre_confirmed_receipt_has_no_block_hash() {
+        let receipt = TxnReceiptWithBlockInfo {
+            transaction_receipt: TxnReceipt::Invoke(InvokeTxnReceipt {
+                common_receipt_properties: CommonReceiptProperties {
+                    actual_fee: FeePayment { amount: Felt::ONE, unit: crate::v0_7_1::PriceUnit::Fri },
+                    events: vec![],
+                    execution_resources: ExecutionResources { l1_gas: 1, l1_data_gas: 2, l2_gas: 3 },
+                    finality_status: TxnFinalityStatus::PreConfirmed,
+                    messages_sent: vec![],
+                    transaction_hash: Felt::TWO,
+                    execution_status: ExecutionStatus::Successful,
+                },
+            }),
+            block_hash: None,
+            block_number: 5,
+        };
+
+        let value = serde_json::to_value(&receipt).unwrap();
+        assert_eq!(value["type"], "INVOKE");
+        assert_eq!(value["finality_status"], "PRE_CONFIRMED");
+        assert_eq!(value["execution_status"], "SUCCEEDED");
+        assert_eq!(value["block_number"], 5);
+        assert!(value.get("block_hash").is_none());
+        assert_eq!(serde_json::from_value::<TxnReceiptWithBlockInfo>(value).unwrap(), receipt);
+    }
+
+    #[test]
+    fn txn_status_without_execution_status() {
+        let status = TxnFinalityAndExecutionStatus {
+            finality_status: TxnStatus::Candidate,
+            execution_status: None,
+            failure_reason: None,
+        };
+        assert_eq!(serde_json::to_string(&status).unwrap(), "{\"finality_status\":\"CANDIDATE\"}");
+    }
+}
@@ -215,6 +215,15 @@ impl Transaction {
         }
     }
 
+    pub fn resource_bounds(&self) -> Option<&ResourceBoundsMapping> {
+        match self {
+            Transaction::Invoke(InvokeTransaction::V3(tx)) => Some(&tx.resource_bounds),
+            Transaction::Declare(DeclareTransaction::V3(tx)) => Some(&tx.resource_bounds),
+            Transaction::DeployAccount(DeployAccountTransaction::V3(tx)) => Some(&tx.resource_bounds),
+            _ => None,
+        }
+    }
+
     pub fn is_l1_handler(&self) -> bool {
         matches!(self, Transaction::L1Handler(_))
     }
@@ -638,6 +638,8 @@ pub struct ServiceContext {
     token_global: tokio_util::sync::CancellationToken,
     token_local: Option<tokio_util::sync::CancellationToken>,
     services: Arc<MadaraServiceMask>,
+    services_running: Arc<MadaraServiceMask>,
+    service_stopped: Arc<tokio::sync::Notify>,
     service_update_sender: Arc<tokio::sync::broadcast::Sender<ServiceTransport>>,
     service_update_receiver: Option<tokio::sync::broadcast::Receiver<ServiceTransport>>,
     id: PowerOfTwo,
@@ -649,6 +651,8 @@ impl Clone for ServiceContext {
             token_global: self.token_global.clone(),
             token_local: self.token_local.clone(),
             services: Arc::clone(&self.services),
+            services_running: Arc::clone(&self.services_running),
+            service_stopped: Arc::clone(&self.service_stopped),
             service_update_sender: Arc::clone(&self.service_update_sender),
             service_update_receiver: None,
             id: self.id,
@@ -662,6 +666,8 @@ impl Default for ServiceContext {
             token_global: tokio_util::sync::CancellationToken::new(),
             token_local: None,
             services: Arc::new(MadaraServiceMask::default()),
+            services_running: Arc::new(MadaraServiceMask::default()),
+            service_stopped: Arc::new(tokio::sync::Notify::new()),
             service_update_sender: Arc::new(tokio::sync::broadcast::channel(SERVICE_COUNT_MAX).0),
             service_update_receiver: None,
             id: MadaraServiceId::Monitor.svc_id(),
@@ -885,6 +891,25 @@ impl ServiceContext {
         res
     }
 
+    /// Waits until a [Service] has shut down.
+    ///
+    /// [ServiceContext::service_remove] only marks a service for removal, the
+    /// service then has up to [SERVICE_GRACE_PERIOD] to stop. Use this to make
+    /// sure a service is not running anymore before acting on what it owns.
+    /// This completes immediately for services that are not run by a
+    /// [ServiceMonitor].
+    pub async fn service_stopped(&self, id: impl ServiceId) {
+        let svc_id = id.svc_id();
+        loop {
+            // Registered before checking the status so that no notification is missed.
+            let notified = self.service_stopped.notified();
+            if self.services_running.status(svc_id) == MadaraServiceStatus::Off {
+                return;
+            }
+            notified.await;
+        }
+    }
+
     /// Opens up a new subscription which will complete once the status of a
     /// [Service] has been updated.
     ///
@@ -1191,6 +1216,7 @@ pub struct ServiceMonitor {
     join_set: JoinSet<anyhow::Result<PowerOfTwo>>,
     status_request: Arc<MadaraServiceMask>,
     status_actual: Arc<MadaraServiceMask>,
+    service_stopped: Arc<tokio::sync::Notify>,
 }
 
 impl Default for ServiceMonitor {
@@ -1200,6 +1226,7 @@ impl Default for ServiceMonitor {
             join_set: JoinSet::new(),
             status_request: Arc::default(),
             status_actual: Arc::default(),
+            service_stopped: Arc::default(),
         }
     }
 }
@@ -1245,7 +1272,11 @@ impl ServiceMonitor {
     /// are running (otherwise the node would shutdown).
     #[tracing::instrument(skip(self), fields(module = "Service"))]
     pub async fn start(mut self) -> anyhow::Result<()> {
-        let mut ctx = ServiceContext::new_with_services(Arc::clone(&self.status_request));
+        let mut ctx = ServiceContext {
+            services_running: Arc::clone(&self.status_actual),
+            service_stopped: Arc::clone(&self.service_stopped),
+            ..ServiceContext::new_with_services(Arc::clone(&self.status_request))
+        };
 
         // start only the initially active services
         for svc in self.services.iter_mut() {
@@ -1294,6 +1325,7 @@ impl ServiceMonitor {
                             tracing::debug!("Service {id} has shut down");
                             self.status_actual.deactivate(id);
                             self.status_request.deactivate(id);
+                            self.service_stopped.notify_waiters();
                         }
                         Err(panic_error) if panic_error.is_panic() => {
                             // bubble up panics too
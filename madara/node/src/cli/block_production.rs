@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
 
 /// Parameters used to config block production.
 #[derive(Clone, Debug, clap::Parser, Deserialize, Serialize)]
@@ -11,4 +12,9 @@ pub struct BlockProductionParams {
     /// Create this number of contracts in the genesis block for the devnet configuration.
     #[arg(env = "MADARA_DEVNET_CONTRACTS", long, default_value_t = 10)]
     pub devnet_contracts: u64,
+
+    /// Load the devnet chain from a state dump made with `devnet_dumpState`, instead of deploying a new genesis
+    /// block. This is only used when the database is empty.
+    #[arg(env = "MADARA_DEVNET_LOAD_STATE", long, value_name = "PATH")]
+    pub devnet_load_state: Option<PathBuf>,
 }
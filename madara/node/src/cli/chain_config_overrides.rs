@@ -6,10 +6,11 @@ use clap::Parser;
 use serde::{Deserialize, Serialize};
 use serde_yaml::Value;
 use starknet_api::core::{ChainId, ContractAddress};
+use starknet_core::types::Felt;
 
 use mp_chain_config::{
     deserialize_starknet_version, serialize_starknet_version, BlockProductionConfig, ChainConfig,
-    L1DataAvailabilityMode, MempoolMode, StarknetVersion,
+    L1DataAvailabilityMode, L2GasPriceConfig, MempoolMode, StarknetVersion, TransactionPolicyConfig,
 };
 use mp_utils::parsers::parse_key_value_yaml;
 use mp_utils::serde::{
@@ -57,6 +58,14 @@ pub struct ChainConfigOverrideParams {
     ///   * bouncer_config: execution limits per block. This has to be
     ///     yaml-encoded following the format in yaml chain config files.
     ///
+    ///   * l2_gas_price: how the L2 gas price of produced blocks is set, either
+    ///     a fixed price or an EIP-1559 style base fee. This has to be
+    ///     yaml-encoded following the format in yaml chain config files.
+    ///
+    ///   * transaction_policies: which transactions the sequencer accepts.
+    ///     This has to be yaml-encoded following the format in yaml chain
+    ///     config files.
+    ///
     ///   * sequencer_address: the address of this chain's sequencer.
     ///
     ///   * eth_core_contract_address: address of the core contract on the
@@ -68,6 +77,10 @@ pub struct ChainConfigOverrideParams {
     ///   * private_key: private key used by the node in sequencer mode to sign
     ///     the blocks it provides. This is zeroed.
     ///
+    ///   * sequencer_public_key: public key of the sequencer, used to verify the
+    ///     signatures of the blocks synced from the feeder gateway, unless
+    ///     `--no-block-signature-verification` is set.
+    ///
     ///   * mempool_max_transactions: max number of transactions allowed in the mempool
     ///     in sequencer mode.
     ///
@@ -96,10 +109,16 @@ pub struct ChainConfigOverridesInner {
     #[serde(deserialize_with = "deserialize_optional_duration", serialize_with = "serialize_optional_duration")]
     pub pending_block_update_time: Option<Duration>,
     pub bouncer_config: BouncerConfig,
+    #[serde(default)]
+    pub l2_gas_price: L2GasPriceConfig,
+    #[serde(default)]
+    pub transaction_policies: TransactionPolicyConfig,
     pub sequencer_address: ContractAddress,
     pub eth_core_contract_address: String,
     pub eth_gps_statement_verifier: String,
     #[serde(default)]
+    pub sequencer_public_key: Option<Felt>,
+    #[serde(default)]
     pub mempool_mode: MempoolMode,
     #[serde(default)]
     pub mempool_min_tip_bump: f64,
@@ -127,9 +146,12 @@ impl ChainConfigOverrideParams {
             block_time: chain_config.block_time,
             pending_block_update_time: chain_config.pending_block_update_time,
             bouncer_config: chain_config.bouncer_config,
+            l2_gas_price: chain_config.l2_gas_price,
+            transaction_policies: chain_config.transaction_policies,
             sequencer_address: chain_config.sequencer_address,
             eth_core_contract_address: chain_config.eth_core_contract_address,
             eth_gps_statement_verifier: chain_config.eth_gps_statement_verifier,
+            sequencer_public_key: chain_config.sequencer_public_key,
             mempool_mode: chain_config.mempool_mode,
             mempool_min_tip_bump: chain_config.mempool_min_tip_bump,
             mempool_max_transactions: chain_config.mempool_max_transactions,
@@ -184,11 +206,14 @@ impl ChainConfigOverrideParams {
             block_time: chain_config_overrides.block_time,
             pending_block_update_time: chain_config_overrides.pending_block_update_time,
             bouncer_config: chain_config_overrides.bouncer_config,
+            l2_gas_price: chain_config_overrides.l2_gas_price,
+            transaction_policies: chain_config_overrides.transaction_policies,
             sequencer_address: chain_config_overrides.sequencer_address,
             eth_core_contract_address: chain_config_overrides.eth_core_contract_address,
             versioned_constants,
             eth_gps_statement_verifier: chain_config_overrides.eth_gps_statement_verifier,
             private_key: chain_config.private_key,
+            sequencer_public_key: chain_config_overrides.sequencer_public_key,
             mempool_mode: chain_config.mempool_mode,
             mempool_min_tip_bump: chain_config.mempool_min_tip_bump,
             mempool_max_transactions: chain_config.mempool_max_transactions,
@@ -0,0 +1,50 @@
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Parameters used to config transaction execution.
+#[derive(Debug, Clone, Args, Serialize, Deserialize)]
+pub struct ExecParams {
+    /// Execute Sierra classes with Cairo Native instead of the Cairo VM. Classes are compiled to native code in the
+    /// background when they are declared or first executed, and run in the VM until their compilation is done.
+    /// This applies to block production, `call`, fee estimation and tracing. The node must be built with the
+    /// `cairo_native` feature.
+    #[arg(env = "MADARA_ENABLE_NATIVE_EXECUTION", long)]
+    pub enable_native_execution: bool,
+
+    /// Directory where the natively compiled classes are cached. Defaults to `native_classes` in the base path.
+    /// It must not be shared with another running node.
+    #[arg(env = "MADARA_NATIVE_CACHE_DIR", long, value_name = "PATH")]
+    pub native_cache_dir: Option<PathBuf>,
+
+    /// Maximum number of classes compiled to native code at the same time.
+    #[arg(
+        env = "MADARA_NATIVE_MAX_CONCURRENT_COMPILATIONS",
+        long,
+        default_value_t = 4,
+        value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..)
+    )]
+    pub native_max_concurrent_compilations: usize,
+}
+
+impl ExecParams {
+    /// Enables native execution for the whole node, when requested.
+    pub fn init_native_execution(&self, base_path: &std::path::Path) -> anyhow::Result<()> {
+        if !self.enable_native_execution {
+            return Ok(());
+        }
+
+        #[cfg(feature = "cairo_native")]
+        {
+            mc_exec::native::init_native_execution(mc_exec::native::NativeConfig {
+                cache_dir: self.native_cache_dir.clone().unwrap_or_else(|| base_path.join("native_classes")),
+                max_concurrent_compilations: self.native_max_concurrent_compilations,
+            })
+        }
+        #[cfg(not(feature = "cairo_native"))]
+        {
+            let _ = base_path;
+            anyhow::bail!("Native execution requires madara to be built with the `cairo_native` feature")
+        }
+    }
+}
@@ -2,6 +2,7 @@ use anyhow::Context;
 use http::HeaderName;
 use http::HeaderValue;
 use mc_gateway_client::GatewayProvider;
+use mc_sync::rpc::RpcProvider;
 use mp_chain_config::ChainConfig;
 use mp_utils::parsers::parse_url;
 use serde::{Deserialize, Serialize};
@@ -36,6 +37,18 @@ pub struct L2SyncParams {
     #[clap(env = "MADARA_GATEWAY_URL", long, value_parser = parse_url, value_name = "URL")]
     pub gateway_url: Option<Url>,
 
+    /// Sync blocks, state updates and classes from a Starknet JSON-RPC endpoint instead of the feeder gateway. The
+    /// endpoint must serve the v0.9.0 specification, for example `https://<provider>/rpc/v0_9`.
+    /// Block signatures are not served by this source, so they are not checked, and the pending block is not synced.
+    #[clap(
+        env = "MADARA_SYNC_RPC_URL",
+        long,
+        value_parser = parse_url,
+        value_name = "URL",
+        conflicts_with = "gateway_url"
+    )]
+    pub sync_rpc_url: Option<Url>,
+
     /// The port used for nodes to make rpc calls during a warp update.
     #[arg(env = "MADARA_WARP_UPDATE_PORT_RPC", long, value_name = "WARP UPDATE PORT RPC", default_value_t = RPC_DEFAULT_PORT_ADMIN)]
     pub warp_update_port_rpc: u16,
@@ -74,6 +87,11 @@ pub struct L2SyncParams {
     /// will mean that block hashes are trusted for these legacy blocks.
     #[clap(env = "MADARA_POST_V0_13_2_HASHES", long)]
     pub post_v0_13_2_hashes: bool,
+
+    /// Do not check the signatures of the synced blocks. By default, the signature of every synced block is fetched
+    /// from the feeder gateway and checked against the `sequencer_public_key` of the chain config, when it has one.
+    #[clap(env = "MADARA_NO_BLOCK_SIGNATURE_VERIFICATION", long)]
+    pub no_block_signature_verification: bool,
 }
 
 impl L2SyncParams {
@@ -102,4 +120,10 @@ impl L2SyncParams {
 
         Ok(Arc::new(client))
     }
+
+    /// Returns [`None`] when the node should sync from the feeder gateway.
+    pub fn create_rpc_client(&self) -> anyhow::Result<Option<Arc<RpcProvider>>> {
+        let Some(url) = &self.sync_rpc_url else { return Ok(None) };
+        Ok(Some(Arc::new(RpcProvider::new(url).context("Creating the sync rpc client")?)))
+    }
 }
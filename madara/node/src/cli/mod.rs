@@ -7,11 +7,13 @@ use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 use std::str::FromStr;
 use std::sync::Arc;
+use url::Url;
 
 pub mod analytics;
 pub mod block_production;
 pub mod chain_config_overrides;
 pub mod db;
+pub mod exec;
 pub mod gateway;
 pub mod l1;
 pub mod l2;
@@ -23,6 +25,7 @@ pub use analytics::*;
 pub use block_production::*;
 pub use chain_config_overrides::*;
 pub use db::*;
+pub use exec::*;
 pub use gateway::*;
 pub use l1::*;
 pub use rpc::*;
@@ -188,6 +191,10 @@ pub struct RunCmd {
     #[clap(flatten)]
     pub block_production_params: BlockProductionParams,
 
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub exec_params: ExecParams,
+
     /// The node will run as a sequencer and produce its own state.
     #[arg(env = "MADARA_SEQUENCER", long, group = "mode")]
     pub sequencer: bool,
@@ -204,6 +211,16 @@ pub struct RunCmd {
     #[arg(env = "MADARA_DEVNET_UNSAFE", long, requires = "devnet")]
     pub devnet_unsafe: bool,
 
+    /// Fork the state of a remote chain: state that is missing from the devnet database is read from this JSON-RPC
+    /// endpoint, and cached locally.
+    #[arg(env = "MADARA_FORK_URL", long, requires = "devnet", value_name = "URL")]
+    pub fork_url: Option<Url>,
+
+    /// The block of the remote chain to fork from. Defaults to the block the database was forked from, or to the
+    /// latest block of the remote chain for a new database.
+    #[arg(env = "MADARA_FORK_BLOCK", long, requires = "fork_url", value_name = "BLOCK NUMBER")]
+    pub fork_block: Option<u64>,
+
     /// The network chain configuration.
     #[clap(env = "MADARA_NETWORK", long, short, group = "full_mode_config")]
     pub network: Option<NetworkType>,
@@ -3,6 +3,7 @@ use mc_rpc::StorageProofConfig;
 use serde::{Deserialize, Serialize};
 use std::convert::Infallible;
 use std::net::{Ipv4Addr, SocketAddr};
+use std::path::PathBuf;
 use std::str::FromStr;
 
 /// The default port.
@@ -156,6 +157,11 @@ pub struct RpcParams {
     /// storage is queried count as one each.
     #[arg(env = "MADARA_RPC_STORAGE_PROOF_MAX_TRIES", long, default_value_t = 5)]
     pub rpc_storage_proof_max_tries: usize,
+
+    /// Directory where `devnet_dumpState` writes the devnet state dumps, and where `devnet_loadState` reads them.
+    /// These methods take a file name in this directory, and are disabled when it is not set.
+    #[arg(env = "MADARA_RPC_DEVNET_STATE_DIR", long, value_name = "PATH")]
+    pub rpc_devnet_state_dir: Option<PathBuf>,
 }
 
 impl RpcParams {
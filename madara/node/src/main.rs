@@ -16,7 +16,8 @@ use figment::{
 };
 use http::{HeaderName, HeaderValue};
 use mc_analytics::Analytics;
-use mc_db::DatabaseService;
+use mc_db::{fork_db::ForkedStateProvider, DatabaseService};
+use mc_devnet::JsonRpcForkedStateProvider;
 use mc_gateway_client::GatewayProvider;
 use mc_mempool::{GasPriceProvider, L1DataProvider, Mempool, MempoolConfig};
 use mc_settlement_client::gas_price::L1BlockMetrics;
@@ -143,6 +144,26 @@ async fn main() -> anyhow::Result<()> {
         .await
         .context("Initializing db service")?;
 
+    if let Some(fork_url) = run_cmd.fork_url.clone() {
+        // A database that was already forked keeps its fork block, the remote chain has moved on since.
+        let fork_block = match run_cmd.fork_block {
+            Some(fork_block) => Some(fork_block),
+            None => service_db.backend().saved_fork_block_n().context("Getting the saved fork block")?,
+        };
+        let provider = JsonRpcForkedStateProvider::new(fork_url.clone(), fork_block)
+            .await
+            .context("Initializing forked state provider")?;
+        tracing::info!("🍴 Forking {fork_url} at block #{}", provider.fork_block_n());
+        service_db.backend().set_forked_state_provider(Arc::new(provider)).context("Forking the devnet state")?;
+    }
+
+    // Execution
+
+    run_cmd
+        .exec_params
+        .init_native_execution(&run_cmd.db_params.base_path)
+        .context("Initializing Cairo Native execution")?;
+
     // L1 Sync
 
     let mut l1_gas_setter = GasPriceProvider::new();
@@ -278,11 +299,17 @@ async fn main() -> anyhow::Result<()> {
 
     // Add transaction provider
 
-    let mempool_tx_validator = Arc::new(TransactionValidator::new(
+    let mut mempool_tx_validator = TransactionValidator::new(
         Arc::clone(&mempool) as _,
         Arc::clone(service_db.backend()),
         run_cmd.validator_params.as_validator_config(),
-    ));
+    )
+    .with_transaction_policies(service_block_production.handle().transaction_policies().clone());
+    if run_cmd.is_devnet() {
+        mempool_tx_validator = mempool_tx_validator
+            .with_impersonated_accounts(service_block_production.handle().impersonated_accounts().clone());
+    }
+    let mempool_tx_validator = Arc::new(mempool_tx_validator);
 
     let gateway_submit_tx: Arc<dyn SubmitTransaction> =
         if run_cmd.validator_params.validate_then_forward_txs_to.is_some() {
@@ -312,6 +339,8 @@ async fn main() -> anyhow::Result<()> {
         Arc::clone(service_db.backend()),
         tx_submit.clone(),
         service_block_production.handle(),
+        Arc::clone(&mempool),
+        run_cmd.is_devnet(),
     );
 
     // Feeder gateway
@@ -2,16 +2,19 @@ use crate::cli::block_production::BlockProductionParams;
 use anyhow::Context;
 use mc_block_production::{metrics::BlockProductionMetrics, BlockProductionHandle, BlockProductionTask};
 use mc_db::{DatabaseService, MadaraBackend};
-use mc_devnet::{ChainGenesisDescription, DevnetKeys};
+use mc_devnet::{ChainGenesisDescription, DevnetKeys, DevnetStateDump};
 use mc_mempool::L1DataProvider;
 use mc_settlement_client::SettlementClient;
 use mp_utils::service::{MadaraServiceId, PowerOfTwo, Service, ServiceId, ServiceRunner};
-use std::{io::Write, sync::Arc};
+use std::{io::Write, path::PathBuf, sync::Arc};
 
 pub struct BlockProductionService {
     backend: Arc<MadaraBackend>,
-    task: Option<BlockProductionTask>,
+    /// The task is kept around so that the service can be restarted, see [`BlockProductionTask::run`].
+    task: Arc<tokio::sync::Mutex<BlockProductionTask>>,
+    handle: BlockProductionHandle,
     n_devnet_contracts: u64,
+    devnet_load_state: Option<PathBuf>,
     disabled: bool,
 }
 
@@ -26,15 +29,14 @@ impl BlockProductionService {
     ) -> anyhow::Result<Self> {
         let metrics = Arc::new(BlockProductionMetrics::register());
 
+        let task =
+            BlockProductionTask::new(db_service.backend().clone(), mempool, metrics, l1_data_provider, l1_client);
+
         Ok(Self {
-            task: Some(BlockProductionTask::new(
-                db_service.backend().clone(),
-                mempool,
-                metrics,
-                l1_data_provider,
-                l1_client,
-            )),
+            handle: task.handle(),
+            task: Arc::new(tokio::sync::Mutex::new(task)),
             n_devnet_contracts: config.devnet_contracts,
+            devnet_load_state: config.devnet_load_state.clone(),
             disabled: config.block_production_disabled,
             backend: db_service.backend().clone(),
         })
@@ -45,9 +47,9 @@ impl BlockProductionService {
 impl Service for BlockProductionService {
     #[tracing::instrument(skip(self, runner), fields(module = "BlockProductionService"))]
     async fn start<'a>(&mut self, runner: ServiceRunner<'a>) -> anyhow::Result<()> {
-        let block_production_task = self.task.take().context("Service already started")?;
         if !self.disabled {
-            runner.service_loop(move |ctx| block_production_task.run(ctx));
+            let block_production_task = Arc::clone(&self.task);
+            runner.service_loop(move |ctx| async move { block_production_task.lock().await.run(ctx).await });
         }
 
         Ok(())
@@ -68,9 +70,17 @@ impl BlockProductionService {
     /// called on node startup even if sequencer block production is not yet
     /// enabled. This happens during warp updates on a local sequencer.
     pub async fn setup_devnet(&self) -> anyhow::Result<()> {
-        let Self { backend, n_devnet_contracts, .. } = self;
+        let Self { backend, n_devnet_contracts, devnet_load_state, .. } = self;
+
+        let db_is_empty = backend.get_latest_block_n().context("Getting the latest block number in db")?.is_none();
+        let keys = if let (true, Some(path)) = (db_is_empty, devnet_load_state) {
+            tracing::info!("📥 Loading devnet state from {path:?}");
 
-        let keys = if backend.get_latest_block_n().context("Getting the latest block number in db")?.is_none() {
+            let dump = DevnetStateDump::read_from_file(path)?;
+            dump.load_into(backend).await.context("Loading devnet state dump")?;
+
+            DevnetKeys::from_db(backend).context("Getting the devnet predeployed contract keys and balances")?
+        } else if db_is_empty {
             // deploy devnet genesis
             tracing::info!("⛏️  Deploying devnet genesis block");
 
@@ -98,6 +108,6 @@ impl BlockProductionService {
     }
 
     pub fn handle(&self) -> BlockProductionHandle {
-        self.task.as_ref().expect("Service started").handle()
+        self.handle.clone()
     }
 }
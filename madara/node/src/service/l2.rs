@@ -66,11 +66,18 @@ impl Service for SyncService {
         if self.disabled {
             return Ok(());
         }
-        let this = self.start_args.take().expect("Service already started");
-        let importer = Arc::new(BlockImporter::new(
-            this.db_backend.clone(),
-            BlockValidationConfig::default().trust_parent_hash(this.params.unsafe_starting_block.is_some()),
-        ));
+        let start_args = self.start_args.as_mut().expect("Sync service is enabled");
+        let this = start_args.clone();
+        // The service can be restarted, for example after a chain revert. Warp update and the unsafe starting block
+        // only make sense the first time it is started, after that we always resume from the database.
+        start_args.warp_update = None;
+        start_args.params.unsafe_starting_block = None;
+
+        // The JSON-RPC does not serve the block signatures.
+        let validation = BlockValidationConfig::default()
+            .trust_parent_hash(this.params.unsafe_starting_block.is_some())
+            .trust_signatures(this.params.no_block_signature_verification || this.params.sync_rpc_url.is_some());
+        let importer = Arc::new(BlockImporter::new(this.db_backend.clone(), validation.clone()));
 
         let config = SyncControllerConfig::default()
             .l1_head_recv(this.l1_head_recv)
@@ -114,9 +121,12 @@ impl Service for SyncService {
                         .expect("Failed to parse warp update sender feeder gateway url. This should not fail in prod"),
                 ));
 
+                // The blocks of the warp update sender are signed with its own key, not the sequencer key.
+                let warp_importer =
+                    Arc::new(BlockImporter::new(this.db_backend.clone(), validation.trust_signatures(true)));
                 mc_sync::gateway::forward_sync(
                     this.db_backend.clone(),
-                    importer.clone(),
+                    warp_importer,
                     gateway,
                     SyncControllerConfig::default().stop_on_sync(true).no_pending_block(true),
                     mc_sync::gateway::ForwardSyncConfig::default()
@@ -147,18 +157,20 @@ impl Service for SyncService {
                 }
             }
 
+            let forward_sync_config = mc_sync::gateway::ForwardSyncConfig::default()
+                .disable_tries(this.params.disable_tries)
+                .keep_pre_v0_13_2_hashes(this.params.keep_pre_v0_13_2_hashes());
+
+            if let Some(client) = this.params.create_rpc_client()? {
+                return mc_sync::rpc::forward_sync(this.db_backend, importer, client, config, forward_sync_config)
+                    .run(ctx)
+                    .await;
+            }
+
             let gateway = this.params.create_feeder_client(this.db_backend.chain_config().clone())?;
-            mc_sync::gateway::forward_sync(
-                this.db_backend,
-                importer,
-                gateway,
-                config,
-                mc_sync::gateway::ForwardSyncConfig::default()
-                    .disable_tries(this.params.disable_tries)
-                    .keep_pre_v0_13_2_hashes(this.params.keep_pre_v0_13_2_hashes()),
-            )
-            .run(ctx)
-            .await
+            mc_sync::gateway::forward_sync(this.db_backend, importer, gateway, config, forward_sync_config)
+                .run(ctx)
+                .await
         });
 
         Ok(())
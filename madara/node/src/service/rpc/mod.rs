@@ -3,7 +3,8 @@ use crate::{cli::RpcParams, submit_tx::MakeSubmitTransactionSwitch};
 use jsonrpsee::server::ServerHandle;
 use mc_block_production::BlockProductionHandle;
 use mc_db::MadaraBackend;
-use mc_rpc::{rpc_api_admin, rpc_api_user, Starknet};
+use mc_mempool::Mempool;
+use mc_rpc::{rpc_api_admin, rpc_api_devnet, rpc_api_user, Starknet};
 use metrics::RpcMetrics;
 use mp_utils::service::{MadaraServiceId, PowerOfTwo, Service, ServiceId, ServiceRunner};
 use server::{start_server, ServerConfig};
@@ -26,6 +27,9 @@ pub struct RpcService {
     server_handle: Option<ServerHandle>,
     rpc_type: RpcType,
     block_prod_handle: Option<BlockProductionHandle>,
+    mempool: Option<Arc<Mempool>>,
+    /// Serve the `devnet_*` test helpers on the admin RPC.
+    devnet: bool,
 }
 
 impl RpcService {
@@ -41,6 +45,8 @@ impl RpcService {
             server_handle: None,
             rpc_type: RpcType::User,
             block_prod_handle: None,
+            mempool: None,
+            devnet: false,
         }
     }
 
@@ -49,6 +55,8 @@ impl RpcService {
         backend: Arc<MadaraBackend>,
         submit_tx_provider: MakeSubmitTransactionSwitch,
         block_prod_handle: BlockProductionHandle,
+        mempool: Arc<Mempool>,
+        devnet: bool,
     ) -> Self {
         Self {
             config,
@@ -57,6 +65,8 @@ impl RpcService {
             server_handle: None,
             rpc_type: RpcType::Admin,
             block_prod_handle: Some(block_prod_handle),
+            mempool: Some(mempool),
+            devnet,
         }
     }
 }
@@ -68,11 +78,13 @@ impl Service for RpcService {
         let backend = Arc::clone(&self.backend);
         let submit_tx_provider = self.submit_tx_provider.clone();
         let rpc_type = self.rpc_type.clone();
+        let devnet = self.devnet;
 
         let (stop_handle, server_handle) = jsonrpsee::server::stop_channel();
 
         self.server_handle = Some(server_handle);
         let block_prod_handle = self.block_prod_handle.clone();
+        let mempool = self.mempool.clone();
 
         runner.service_loop(move |ctx| async move {
             let submit_tx = Arc::new(submit_tx_provider.make(ctx.clone()));
@@ -83,7 +95,9 @@ impl Service for RpcService {
                 config.storage_proof_config(),
                 block_prod_handle,
                 ctx.clone(),
-            );
+            )
+            .with_mempool(mempool)
+            .with_devnet_state_dir(config.rpc_devnet_state_dir.clone());
             let metrics = RpcMetrics::register()?;
 
             let server_config = {
@@ -94,12 +108,18 @@ impl Service for RpcService {
                         rpc_api_user(&starknet)?,
                         mp_chain_config::RpcVersion::RPC_VERSION_LATEST,
                     ),
-                    RpcType::Admin => (
-                        "JSON-RPC (Admin)".to_string(),
-                        config.addr_admin(),
-                        rpc_api_admin(&starknet)?,
-                        mp_chain_config::RpcVersion::RPC_VERSION_LATEST_ADMIN,
-                    ),
+                    RpcType::Admin => {
+                        let mut api_rpc = rpc_api_admin(&starknet)?;
+                        if devnet {
+                            api_rpc.merge(rpc_api_devnet(&starknet)?)?;
+                        }
+                        (
+                            "JSON-RPC (Admin)".to_string(),
+                            config.addr_admin(),
+                            api_rpc,
+                            mp_chain_config::RpcVersion::RPC_VERSION_LATEST_ADMIN,
+                        )
+                    }
                 };
                 let methods = rpc_api_build("rpc", api_rpc).into();
 
@@ -13,6 +13,16 @@ pub enum DaVerificationStatus {
     Rejected(String),
 }
 
+/// The format of the data expected by [`DaClient::publish_state_diff`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DaDataFormat {
+    /// EIP-4844 blobs of exactly `max_bytes_per_blob` bytes, holding the FFT of the state diff felts.
+    Blobs,
+    /// The state diff felts, 32 bytes big endian each, in chunks of at most `max_bytes_per_blob` bytes. The last chunk
+    /// is not padded.
+    Felts,
+}
+
 /// Trait for every new DaClient to implement
 #[automock]
 #[async_trait]
@@ -26,4 +36,6 @@ pub trait DaClient: Send + Sync {
     async fn max_blob_per_txn(&self) -> u64;
     /// Should return the max bytes per blob
     async fn max_bytes_per_blob(&self) -> u64;
+    /// Should return the format of the data to publish
+    async fn data_format(&self) -> DaDataFormat;
 }
@@ -11,7 +11,7 @@ use async_trait::async_trait;
 use color_eyre::Result;
 use mockall::automock;
 use mockall::predicate::*;
-use orchestrator_da_client_interface::{DaClient, DaVerificationStatus};
+use orchestrator_da_client_interface::{DaClient, DaDataFormat, DaVerificationStatus};
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use url::Url;
@@ -57,4 +57,8 @@ impl DaClient for EthereumDaClient {
     async fn max_bytes_per_blob(&self) -> u64 {
         131072
     }
+
+    async fn data_format(&self) -> DaDataFormat {
+        DaDataFormat::Blobs
+    }
 }
@@ -1,61 +1,245 @@
 #![allow(missing_docs)]
 #![allow(clippy::missing_docs_in_private_items)]
 
-use std::str::FromStr;
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use color_eyre::eyre::{eyre, Context};
 use color_eyre::Result;
 use mockall::predicate::*;
-use orchestrator_da_client_interface::{DaClient, DaVerificationStatus};
+use orchestrator_da_client_interface::{DaClient, DaDataFormat, DaVerificationStatus};
 use serde::{Deserialize, Serialize};
+use starknet::accounts::{Account, ConnectedAccount, ExecutionEncoding, SingleOwnerAccount};
+use starknet::core::types::{BlockId, BlockTag, Call, Felt, StarknetError, TransactionExecutionStatus};
+use starknet::core::utils::get_selector_from_name;
 use starknet::providers::jsonrpc::HttpTransport;
-use starknet::providers::JsonRpcClient;
+use starknet::providers::{JsonRpcClient, Provider, ProviderError};
+use starknet::signers::{LocalWallet, SigningKey};
 use url::Url;
 
+/// Number of bytes of a felt in the published data.
+const BYTES_PER_FELT: u64 = 32;
+
+/// Calldata felts of an invoke transaction that are not data: the number of calls, the contract address, the
+/// selector and the calldata length of the call, and the length of the data span.
+const CALLDATA_OVERHEAD_FELTS: u64 = 5;
+
+/// Separator of the transaction hashes in the external id.
+const EXTERNAL_ID_SEPARATOR: char = ',';
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct StarknetDaValidatedArgs {
     pub starknet_da_rpc_url: Url,
+    pub starknet_da_private_key: String,
+    pub starknet_da_account_address: String,
+    pub starknet_da_contract_address: String,
+    /// Maximum number of calldata felts accepted by the sequencer for a transaction.
+    pub starknet_da_max_calldata_felts: u64,
+    /// Maximum number of transactions sent to publish the state diff of a block.
+    pub starknet_da_max_txs_per_state_diff: u64,
 }
 
+pub type LocalWalletSignerMiddleware = Arc<SingleOwnerAccount<Arc<JsonRpcClient<HttpTransport>>, LocalWallet>>;
+
+// Assumed the DA contract exposes `fn publish_data(ref self: ContractState, data: Span<felt252>)`. The data itself is
+// available in the calldata of the transactions, their inclusion is checked with their receipts.
 pub struct StarknetDaClient {
-    #[allow(dead_code)]
-    provider: Arc<JsonRpcClient<HttpTransport>>,
+    account: LocalWalletSignerMiddleware,
+    da_contract_address: Felt,
+    publish_data_selector: Felt,
+    max_calldata_felts: u64,
+    max_txs_per_state_diff: u64,
 }
 
 impl StarknetDaClient {
-    pub async fn new_with_args(starknet_da_params: &StarknetDaValidatedArgs) -> Self {
-        let client = JsonRpcClient::new(HttpTransport::new(
-            Url::from_str(starknet_da_params.starknet_da_rpc_url.as_str())
-                .inspect_err(|e| {
-                    tracing::error!("Failed to parse Starknet DA RPC URL: {}", e);
-                })
-                .expect("invalid url provided"),
-        ));
-        Self { provider: Arc::new(client) }
+    pub async fn new_with_args(starknet_da_params: &StarknetDaValidatedArgs) -> Result<Self> {
+        if starknet_da_params.starknet_da_max_calldata_felts <= CALLDATA_OVERHEAD_FELTS {
+            return Err(eyre!("The max calldata size must be greater than {} felts", CALLDATA_OVERHEAD_FELTS));
+        }
+
+        let provider = Arc::new(JsonRpcClient::new(HttpTransport::new(starknet_da_params.starknet_da_rpc_url.clone())));
+
+        let signer_address =
+            Felt::from_hex(&starknet_da_params.starknet_da_account_address).wrap_err("Invalid DA account address")?;
+        let private_key =
+            Felt::from_hex(&starknet_da_params.starknet_da_private_key).wrap_err("Invalid DA private key")?;
+        let signer = LocalWallet::from(SigningKey::from_secret_scalar(private_key));
+        let da_contract_address =
+            Felt::from_hex(&starknet_da_params.starknet_da_contract_address).wrap_err("Invalid DA contract address")?;
+
+        let chain_id = provider.chain_id().await.wrap_err("Failed to get the chain id of the DA layer")?;
+        let mut account = SingleOwnerAccount::new(provider, signer, signer_address, chain_id, ExecutionEncoding::New);
+        account.set_block_id(BlockId::Tag(BlockTag::Pending));
+
+        Ok(Self {
+            account: Arc::new(account),
+            da_contract_address,
+            publish_data_selector: get_selector_from_name("publish_data")?,
+            max_calldata_felts: starknet_da_params.starknet_da_max_calldata_felts,
+            max_txs_per_state_diff: starknet_da_params.starknet_da_max_txs_per_state_diff,
+        })
+    }
+
+    /// Returns the inclusion status of a DA transaction, from its receipt.
+    async fn tx_status(&self, tx_hash: Felt) -> Result<DaVerificationStatus> {
+        let tx_receipt = match self.account.provider().get_transaction_receipt(tx_hash).await {
+            Ok(tx_receipt) => tx_receipt,
+            // The transaction has not been received by the node yet
+            Err(ProviderError::StarknetError(StarknetError::TransactionHashNotFound)) => {
+                return Ok(DaVerificationStatus::Pending)
+            }
+            Err(e) => return Err(e).wrap_err(format!("Failed to get the receipt of DA transaction {:#x}", tx_hash)),
+        };
+        let execution_result = tx_receipt.receipt.execution_result();
+        Ok(match execution_result.status() {
+            TransactionExecutionStatus::Reverted => DaVerificationStatus::Rejected(format!(
+                "DA transaction {:#x} has been reverted: {}",
+                tx_hash,
+                execution_result.revert_reason().unwrap_or_default()
+            )),
+            TransactionExecutionStatus::Succeeded if tx_receipt.block.is_pending() => DaVerificationStatus::Pending,
+            TransactionExecutionStatus::Succeeded => DaVerificationStatus::Verified,
+        })
     }
 }
 
+/// Reads the felts of a chunk of [`DaDataFormat::Felts`] data.
+fn bytes_to_felts(bytes: &[u8]) -> Result<Vec<Felt>> {
+    if bytes.len() % BYTES_PER_FELT as usize != 0 {
+        return Err(eyre!("DA data of {} bytes is not made of {} bytes felts", bytes.len(), BYTES_PER_FELT));
+    }
+    bytes
+        .chunks(BYTES_PER_FELT as usize)
+        .map(|chunk| {
+            let felt = Felt::from_bytes_be_slice(chunk);
+            // Values out of the field would be reduced, and published modified
+            if felt.to_bytes_be().as_slice() != chunk {
+                return Err(eyre!("DA data contains a value out of the field: 0x{}", hex_string(chunk)));
+            }
+            Ok(felt)
+        })
+        .collect()
+}
+
+fn hex_string(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// The external id of a state diff lists the hashes of the transactions publishing its chunks.
+fn external_id(tx_hashes: &[Felt]) -> String {
+    tx_hashes.iter().map(|tx_hash| tx_hash.to_hex_string()).collect::<Vec<_>>().join(&EXTERNAL_ID_SEPARATOR.to_string())
+}
+
+fn parse_external_id(external_id: &str) -> Result<Vec<Felt>> {
+    external_id
+        .split(EXTERNAL_ID_SEPARATOR)
+        .map(|tx_hash| Felt::from_hex(tx_hash).wrap_err(format!("Invalid DA transaction hash: {}", tx_hash)))
+        .collect()
+}
+
 #[cfg_attr(test, mockall::automock)]
 #[async_trait]
 impl DaClient for StarknetDaClient {
-    async fn publish_state_diff(&self, _state_diff: Vec<Vec<u8>>, _to: &[u8; 32]) -> Result<String> {
-        // Here in case of starknet we are not publishing the state diff because we are doing it all
-        // together in proving and update_state job. So we don't need to send anything here.
-        Ok("NA".to_string())
+    /// Publishes each chunk of the state diff in the calldata of a transaction to the DA contract. The external id
+    /// lists the hashes of these transactions, see [`external_id`].
+    async fn publish_state_diff(&self, state_diff: Vec<Vec<u8>>, _to: &[u8; 32]) -> Result<String> {
+        if state_diff.len() as u64 > self.max_txs_per_state_diff {
+            return Err(eyre!(
+                "State diff needs {} transactions, more than the maximum of {}",
+                state_diff.len(),
+                self.max_txs_per_state_diff
+            ));
+        }
+
+        // The nonces are set explicitly so that the transactions can be sent without waiting for each other.
+        let nonce = self.account.get_nonce().await.wrap_err("Failed to get the nonce of the DA account")?;
+        let mut tx_hashes = Vec::with_capacity(state_diff.len());
+        for (i, blob) in state_diff.iter().enumerate() {
+            let data = bytes_to_felts(blob)?;
+            let mut calldata = Vec::with_capacity(data.len() + 1);
+            calldata.push(Felt::from(data.len()));
+            calldata.extend(data);
+
+            let call = Call { to: self.da_contract_address, selector: self.publish_data_selector, calldata };
+            let result = self
+                .account
+                .execute_v3(vec![call])
+                .nonce(nonce + Felt::from(i))
+                .send()
+                .await
+                .map_err(|e| eyre!("Failed to send DA transaction {}/{}: {:?}", i + 1, state_diff.len(), e))?;
+            tracing::debug!(tx_hash = %result.transaction_hash.to_hex_string(), "Sent DA transaction");
+            tx_hashes.push(result.transaction_hash);
+        }
+
+        Ok(external_id(&tx_hashes))
     }
 
-    async fn verify_inclusion(&self, _external_id: &str) -> Result<DaVerificationStatus> {
-        Ok(DaVerificationStatus::Verified)
+    /// The state diff is verified once all its transactions have succeeded in an accepted block. It is rejected if one
+    /// of them has been reverted.
+    async fn verify_inclusion(&self, external_id: &str) -> Result<DaVerificationStatus> {
+        let mut status = DaVerificationStatus::Verified;
+        for tx_hash in parse_external_id(external_id)? {
+            match self.tx_status(tx_hash).await? {
+                DaVerificationStatus::Verified => {}
+                DaVerificationStatus::Pending => status = DaVerificationStatus::Pending,
+                rejected @ DaVerificationStatus::Rejected(_) => return Ok(rejected),
+            }
+        }
+        Ok(status)
     }
 
+    // max_blob_per_txn - In Starknet, each chunk is published in its own transaction. This returns the maximum number
+    // of transactions used to publish the state diff of a block.
     async fn max_blob_per_txn(&self) -> u64 {
-        6
+        self.max_txs_per_state_diff
     }
 
-    // max_bytes_per_blob - return's the maximum size of a blob in Starknet
-    // Maximum size of a blob in Starknet is 128KB (131072 bytes)
+    // max_bytes_per_blob - return's the number of bytes fitting in the calldata of a transaction
     async fn max_bytes_per_blob(&self) -> u64 {
-        131072
+        (self.max_calldata_felts - CALLDATA_OVERHEAD_FELTS) * BYTES_PER_FELT
+    }
+
+    async fn data_format(&self) -> DaDataFormat {
+        DaDataFormat::Felts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(vec![], vec![])]
+    #[case(Felt::from(0x0102).to_bytes_be().to_vec(), vec![Felt::from(0x0102)])]
+    // Trailing zero felts are kept
+    #[case([Felt::MAX.to_bytes_be(), [0; 32]].concat(), vec![Felt::MAX, Felt::ZERO])]
+    fn test_bytes_to_felts(#[case] bytes: Vec<u8>, #[case] expected: Vec<Felt>) {
+        assert_eq!(bytes_to_felts(&bytes).unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case(vec![1, 2])]
+    #[case(vec![0xff; 32])]
+    fn test_bytes_to_felts_invalid(#[case] bytes: Vec<u8>) {
+        assert!(bytes_to_felts(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_full_chunk_fits_in_calldata() {
+        let max_calldata_felts = 4000;
+        let chunk = vec![0; ((max_calldata_felts - CALLDATA_OVERHEAD_FELTS) * BYTES_PER_FELT) as usize];
+        // The data and its length fit in the calldata of the call
+        assert_eq!(bytes_to_felts(&chunk).unwrap().len() as u64 + CALLDATA_OVERHEAD_FELTS, max_calldata_felts);
+    }
+
+    #[test]
+    fn test_external_id_roundtrip() {
+        let tx_hashes = vec![Felt::from(0xa), Felt::from(0xb)];
+        let external_id = external_id(&tx_hashes);
+        assert_eq!(external_id, "0xa,0xb");
+        assert_eq!(parse_external_id(&external_id).unwrap(), tx_hashes);
     }
 }
@@ -1,10 +1,12 @@
 use crate::constants::ATLANTIC_PROOF_URL;
 use crate::error::AtlanticError;
 use crate::types::{
-    AtlanticAddJobResponse, AtlanticCairoVersion, AtlanticCairoVm, AtlanticGetStatusResponse, AtlanticQueryStep,
+    AtlanticAddJobResponse, AtlanticBucketResponse, AtlanticCairoVersion, AtlanticCairoVm, AtlanticGetBucketResponse,
+    AtlanticGetStatusResponse, AtlanticQueryStep,
 };
 use crate::AtlanticValidatedArgs;
 use cairo_vm::types::layout_name::LayoutName;
+use orchestrator_prover_client_interface::BucketTask;
 use orchestrator_utils::http_client::{HttpClient, RequestBuilder};
 use reqwest::Method;
 use std::path::Path;
@@ -69,6 +71,7 @@ impl AtlanticClient {
         atlantic_api_key: impl AsRef<str>,
         n_steps: Option<usize>,
         atlantic_network: impl AsRef<str>,
+        bucket: Option<BucketTask>,
     ) -> Result<AtlanticAddJobResponse, AtlanticError> {
         let proof_layout = match proof_layout {
             LayoutName::dynamic => "dynamic",
@@ -82,18 +85,25 @@ impl AtlanticClient {
             atlantic_network.as_ref()
         );
 
-        let api = self.proving_layer.customize_request(
-            self.client
-                .request()
-                .method(Method::POST)
-                .path("atlantic-query")
-                .query_param("apiKey", atlantic_api_key.as_ref())
-                .form_text("declaredJobSize", self.n_steps_to_job_size(n_steps))
-                .form_text("layout", proof_layout)
-                .form_text("cairoVersion", &AtlanticCairoVersion::Cairo0.as_str())
-                .form_text("cairoVm", &AtlanticCairoVm::Rust.as_str())
-                .form_file("pieFile", pie_file, "pie.zip", Some("application/zip"))?,
-        );
+        let request = self
+            .client
+            .request()
+            .method(Method::POST)
+            .path("atlantic-query")
+            .query_param("apiKey", atlantic_api_key.as_ref())
+            .form_text("declaredJobSize", self.n_steps_to_job_size(n_steps))
+            .form_text("layout", proof_layout)
+            .form_text("cairoVersion", &AtlanticCairoVersion::Cairo0.as_str())
+            .form_text("cairoVm", &AtlanticCairoVm::Rust.as_str())
+            .form_file("pieFile", pie_file, "pie.zip", Some("application/zip"))?;
+        // The queries of a bucket are only proven once aggregated
+        let api = match bucket {
+            Some(bucket) => request
+                .form_text("bucketId", &bucket.bucket_id)
+                .form_text("bucketJobIndex", &bucket.index.to_string())
+                .form_text("result", &AtlanticQueryStep::ProofGeneration.to_string()),
+            None => self.proving_layer.customize_request(request),
+        };
         debug!("Triggering the debug Request for: {:?}", api);
         let response = api.send().await.map_err(AtlanticError::AddJobFailure)?;
 
@@ -174,6 +184,70 @@ impl AtlanticClient {
         }
     }
 
+    /// create_bucket - Creates a bucket aggregating the queries submitted to it
+    pub async fn create_bucket(
+        &self,
+        atlantic_api_key: impl AsRef<str>,
+    ) -> Result<AtlanticBucketResponse, AtlanticError> {
+        let response = self
+            .client
+            .request()
+            .method(Method::POST)
+            .path("buckets")
+            .path("create")
+            .query_param("apiKey", atlantic_api_key.as_ref())
+            .send()
+            .await
+            .map_err(AtlanticError::BucketFailure)?;
+
+        match response.status().is_success() {
+            true => response.json().await.map_err(AtlanticError::BucketFailure),
+            false => Err(AtlanticError::AtlanticService(response.status())),
+        }
+    }
+
+    /// close_bucket - Closes the bucket, Atlantic then submits the aggregation of its queries
+    pub async fn close_bucket(
+        &self,
+        bucket_id: &str,
+        atlantic_api_key: impl AsRef<str>,
+    ) -> Result<AtlanticBucketResponse, AtlanticError> {
+        let response = self
+            .client
+            .request()
+            .method(Method::POST)
+            .path("buckets")
+            .path("close")
+            .query_param("bucketId", bucket_id)
+            .query_param("apiKey", atlantic_api_key.as_ref())
+            .send()
+            .await
+            .map_err(AtlanticError::BucketFailure)?;
+
+        match response.status().is_success() {
+            true => response.json().await.map_err(AtlanticError::BucketFailure),
+            false => Err(AtlanticError::AtlanticService(response.status())),
+        }
+    }
+
+    /// get_bucket - Returns the bucket and its queries
+    pub async fn get_bucket(&self, bucket_id: &str) -> Result<AtlanticGetBucketResponse, AtlanticError> {
+        let response = self
+            .client
+            .request()
+            .method(Method::GET)
+            .path("buckets")
+            .path(bucket_id)
+            .send()
+            .await
+            .map_err(AtlanticError::BucketFailure)?;
+
+        match response.status().is_success() {
+            true => response.json().await.map_err(AtlanticError::BucketFailure),
+            false => Err(AtlanticError::AtlanticService(response.status())),
+        }
+    }
+
     // https://docs.herodotus.cloud/atlantic/sending-query#sending-query
     fn n_steps_to_job_size(&self, n_steps: Option<usize>) -> &'static str {
         let n_steps = n_steps.unwrap_or(40_000_000) / 1_000_000;
@@ -13,6 +13,9 @@ pub enum AtlanticError {
     #[error("Failed to to get result of a Atlantic job: {0}")]
     GetJobResultFailure(#[source] reqwest::Error),
 
+    #[error("Failed to call the bucket endpoint: {0}")]
+    BucketFailure(#[source] reqwest::Error),
+
     #[error("Failed to submit L2 query: {0}")]
     SubmitL2QueryFailure(#[source] reqwest::Error),
 
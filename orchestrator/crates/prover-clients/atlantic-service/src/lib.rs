@@ -8,7 +8,7 @@ use alloy::primitives::B256;
 use async_trait::async_trait;
 use cairo_vm::types::layout_name::LayoutName;
 use orchestrator_gps_fact_checker::FactChecker;
-use orchestrator_prover_client_interface::{ProverClient, ProverClientError, Task, TaskStatus};
+use orchestrator_prover_client_interface::{BucketTask, ProverClient, ProverClientError, Task, TaskStatus};
 use std::str::FromStr;
 use swiftness_proof_parser::{parse, StarkProof};
 use tempfile::NamedTempFile;
@@ -46,6 +46,7 @@ impl ProverClient for AtlanticProverService {
         task: Task,
         proof_layout: LayoutName,
         n_steps: Option<usize>,
+        bucket: Option<BucketTask>,
     ) -> Result<String, ProverClientError> {
         tracing::info!(
             log_type = "starting",
@@ -70,6 +71,7 @@ impl ProverClient for AtlanticProverService {
                         self.atlantic_api_key.clone(),
                         n_steps,
                         self.atlantic_network.clone(),
+                        bucket,
                     )
                     .await?;
 
@@ -178,6 +180,23 @@ impl ProverClient for AtlanticProverService {
 
         Ok(atlantic_job_response.atlantic_query_id)
     }
+
+    async fn create_bucket(&self) -> Result<String, ProverClientError> {
+        let response = self.atlantic_client.create_bucket(&self.atlantic_api_key).await?;
+        tracing::debug!(bucket_id = %response.atlantic_bucket.id, "Created Atlantic bucket");
+        Ok(response.atlantic_bucket.id)
+    }
+
+    async fn close_bucket(&self, bucket_id: &str) -> Result<(), ProverClientError> {
+        let response = self.atlantic_client.close_bucket(bucket_id, &self.atlantic_api_key).await?;
+        tracing::debug!(bucket_id = %bucket_id, status = ?response.atlantic_bucket.status, "Closed Atlantic bucket");
+        Ok(())
+    }
+
+    async fn get_aggregation_task_id(&self, bucket_id: &str) -> Result<Option<String>, ProverClientError> {
+        let response = self.atlantic_client.get_bucket(bucket_id).await?;
+        Ok(response.queries.into_iter().find(|query| query.bucket_job_index.is_none()).map(|query| query.id))
+    }
 }
 
 impl AtlanticProverService {
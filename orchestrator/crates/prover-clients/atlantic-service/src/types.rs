@@ -46,6 +46,44 @@ pub struct AtlanticQuery {
     pub client: AtlanticClient,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AtlanticBucketResponse {
+    pub atlantic_bucket: AtlanticBucket,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AtlanticGetBucketResponse {
+    pub bucket: AtlanticBucket,
+    pub queries: Vec<AtlanticBucketQuery>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AtlanticBucket {
+    pub id: String,
+    pub status: AtlanticBucketStatus,
+}
+
+/// A query of a bucket, the aggregation query is the only one without a job index
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AtlanticBucketQuery {
+    pub id: String,
+    pub status: AtlanticQueryStatus,
+    pub bucket_job_index: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AtlanticBucketStatus {
+    Open,
+    InProgress,
+    Done,
+    Failed,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AtlanticClient {
@@ -45,12 +45,62 @@ async fn atlantic_client_submit_task_when_mock_works() {
 
     // We don't need to send the steps because it's a mock fact hash.
     let task_result =
-        atlantic_service.submit_task(Task::CairoPie(Box::new(cairo_pie)), LayoutName::dynamic, None).await;
+        atlantic_service.submit_task(Task::CairoPie(Box::new(cairo_pie)), LayoutName::dynamic, None, None).await;
 
     assert!(task_result.is_ok());
     submit_mock.assert();
 }
 
+#[tokio::test]
+async fn atlantic_client_bucket_aggregation_task_when_mock_works() {
+    let _ = env_logger::try_init();
+    dotenvy::from_filename_override("../.env.test").expect("Failed to load the .env file");
+    let atlantic_params = AtlanticValidatedArgs {
+        atlantic_api_key: get_env_var_or_panic("MADARA_ORCHESTRATOR_ATLANTIC_API_KEY"),
+        atlantic_service_url: Url::parse(&get_env_var_or_panic("MADARA_ORCHESTRATOR_ATLANTIC_SERVICE_URL")).unwrap(),
+        atlantic_rpc_node_url: Url::parse(&get_env_var_or_panic("MADARA_ORCHESTRATOR_ATLANTIC_RPC_NODE_URL")).unwrap(),
+        atlantic_mock_fact_hash: get_env_var_or_panic("MADARA_ORCHESTRATOR_ATLANTIC_MOCK_FACT_HASH"),
+        atlantic_prover_type: get_env_var_or_panic("MADARA_ORCHESTRATOR_ATLANTIC_PROVER_TYPE"),
+        atlantic_settlement_layer: get_env_var_or_panic("MADARA_ORCHESTRATOR_ATLANTIC_SETTLEMENT_LAYER"),
+        atlantic_verifier_contract_address: get_env_var_or_panic(
+            "MADARA_ORCHESTRATOR_ATLANTIC_VERIFIER_CONTRACT_ADDRESS",
+        ),
+        atlantic_network: get_env_var_or_panic("MADARA_ORCHESTRATOR_ATLANTIC_NETWORK"),
+        cairo_verifier_program_hash: None,
+    };
+    let mock_server = MockServer::start();
+
+    let create_bucket_mock = mock_server.mock(|when, then| {
+        when.method("POST").path("/buckets/create");
+        then.status(200).header("content-type", "application/json").json_body(serde_json::json!({
+            "atlanticBucket": { "id": "mock_bucket_id", "status": "OPEN" }
+        }));
+    });
+    let get_bucket_mock = mock_server.mock(|when, then| {
+        when.method("GET").path("/buckets/mock_bucket_id");
+        then.status(200).header("content-type", "application/json").json_body(serde_json::json!({
+            "bucket": { "id": "mock_bucket_id", "status": "IN_PROGRESS" },
+            "queries": [
+                { "id": "block_query_id", "status": "DONE", "bucketJobIndex": 0 },
+                { "id": "aggregation_query_id", "status": "IN_PROGRESS", "bucketJobIndex": null }
+            ]
+        }));
+    });
+
+    let atlantic_service = AtlanticProverService::with_test_params(mock_server.port(), &atlantic_params);
+
+    let bucket_id = atlantic_service.create_bucket().await.expect("Failed to create the bucket");
+    assert_eq!(bucket_id, "mock_bucket_id");
+
+    // The aggregation query is the query of the bucket without a job index
+    let aggregation_task_id =
+        atlantic_service.get_aggregation_task_id(&bucket_id).await.expect("Failed to get the bucket");
+    assert_eq!(aggregation_task_id, Some("aggregation_query_id".to_string()));
+
+    create_bucket_mock.assert();
+    get_bucket_mock.assert();
+}
+
 #[tokio::test]
 async fn atlantic_client_get_task_status_works() {
     let _ = env_logger::try_init();
@@ -105,7 +155,7 @@ async fn atlantic_client_submit_task_and_get_job_status_with_mock_fact_hash() {
     // Submit the task to the actual Atlantic service
     let task_result = atlantic_service
         // We don't need to send the steps because it's a mock fact hash.
-        .submit_task(Task::CairoPie(Box::new(cairo_pie)), LayoutName::dynamic, None)
+        .submit_task(Task::CairoPie(Box::new(cairo_pie)), LayoutName::dynamic, None, None)
         .await
         .expect("Failed to submit task to Atlantic service");
 
@@ -1,3 +1,5 @@
+pub mod local;
+
 use alloy::providers::{ProviderBuilder, RootProvider};
 use alloy::sol;
 use alloy::transports::http::{Client, Http};
@@ -16,6 +18,21 @@ sol!(
 pub enum FactCheckerError {
     #[error("Fact registry call failed: {0}")]
     InvalidFact(#[source] alloy::contract::Error),
+
+    #[error("Failed to parse proof: {0}")]
+    ProofParse(String),
+
+    #[error("Proof verification failed: {0}")]
+    ProofVerification(String),
+
+    #[error("Layout {0} is not supported by the local verifier")]
+    UnsupportedLayout(String),
+
+    #[error("Invalid bootloader output: {0}")]
+    InvalidBootloaderOutput(String),
+
+    #[error("Proof does not prove fact {expected}, it proves fact {proven}")]
+    FactMismatch { expected: B256, proven: B256 },
 }
 
 #[derive(Debug, Clone, PartialEq)]
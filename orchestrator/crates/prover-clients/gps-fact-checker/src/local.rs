@@ -0,0 +1,231 @@
+//! Offline verification of STARK proofs with the Rust Cairo verifier, without the GPS statement verifier contract.
+//!
+//! The verifier supports the [`SUPPORTED_LAYOUTS`], selected at runtime from the layout the proof was made with. The
+//! commitment hasher and the stone prover version are compile time choices of the verifier: proofs must be committed
+//! with `keccak_160_lsb` by the stone prover version 5.
+
+use alloy_primitives::{keccak256, B256};
+use cairo_vm::types::layout_name::LayoutName;
+use starknet::core::crypto::pedersen_hash;
+use starknet::core::types::Felt;
+use swiftness::TransformTo;
+use swiftness_air::layout as layouts;
+use swiftness_air::public_memory::PublicInput;
+use swiftness_stark::types::StarkProof;
+
+use crate::FactCheckerError;
+
+/// Layouts of the proofs verified by the Rust Cairo verifier.
+pub const SUPPORTED_LAYOUTS: [LayoutName; 7] = [
+    LayoutName::dex,
+    LayoutName::dynamic,
+    LayoutName::recursive,
+    LayoutName::recursive_with_poseidon,
+    LayoutName::small,
+    LayoutName::starknet,
+    LayoutName::starknet_with_keccak,
+];
+
+/// Index of the output segment in the public input, the same for all the supported layouts.
+const OUTPUT_SEGMENT: usize = layouts::small::segments::OUTPUT;
+
+/// Statement of a verified proof: the execution of the program with this hash produced this output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerifiedProof {
+    pub program_hash: Felt,
+    pub program_output: Vec<Felt>,
+}
+
+/// A task run by the bootloader, read from the output of the bootloader.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BootloadedTask {
+    pub program_hash: Felt,
+    pub program_output: Vec<Felt>,
+}
+
+/// Verifies a proof in the stone prover JSON format, proven with the `layout` layout.
+pub fn verify_proof(proof: &str, layout: LayoutName) -> Result<VerifiedProof, FactCheckerError> {
+    if !SUPPORTED_LAYOUTS.contains(&layout) {
+        return Err(FactCheckerError::UnsupportedLayout(layout.to_str().to_string()));
+    }
+
+    let stark_proof: StarkProof = swiftness_proof_parser::parse(proof.to_string())
+        .map_err(|e| FactCheckerError::ProofParse(e.to_string()))?
+        .transform_to();
+    let security_bits = stark_proof.config.security_bits();
+
+    let (program_hash, output_hash) = match layout {
+        LayoutName::dex => stark_proof.verify::<layouts::dex::Layout>(security_bits),
+        LayoutName::dynamic => stark_proof.verify::<layouts::dynamic::Layout>(security_bits),
+        LayoutName::recursive => stark_proof.verify::<layouts::recursive::Layout>(security_bits),
+        LayoutName::recursive_with_poseidon => {
+            stark_proof.verify::<layouts::recursive_with_poseidon::Layout>(security_bits)
+        }
+        LayoutName::small => stark_proof.verify::<layouts::small::Layout>(security_bits),
+        LayoutName::starknet => stark_proof.verify::<layouts::starknet::Layout>(security_bits),
+        LayoutName::starknet_with_keccak => stark_proof.verify::<layouts::starknet_with_keccak::Layout>(security_bits),
+        _ => return Err(FactCheckerError::UnsupportedLayout(layout.to_str().to_string())),
+    }
+    .map_err(|e| FactCheckerError::ProofVerification(e.to_string()))?;
+
+    let program_output = program_output(&stark_proof.public_input)?;
+    if output_hash_of(&program_output) != Felt::from_bytes_be(&output_hash.to_bytes_be()) {
+        return Err(FactCheckerError::ProofVerification("Output does not match the proven output hash".to_string()));
+    }
+
+    Ok(VerifiedProof { program_hash: Felt::from_bytes_be(&program_hash.to_bytes_be()), program_output })
+}
+
+/// Output of the proven program, read like the verifier does from the last cells of the main page.
+fn program_output(public_input: &PublicInput) -> Result<Vec<Felt>, FactCheckerError> {
+    let output_segment = public_input
+        .segments
+        .get(OUTPUT_SEGMENT)
+        .ok_or_else(|| FactCheckerError::ProofVerification("Missing output segment".to_string()))?;
+    let output_len: usize = (output_segment.stop_ptr - output_segment.begin_addr)
+        .to_biguint()
+        .try_into()
+        .map_err(|_| FactCheckerError::ProofVerification("Invalid output segment".to_string()))?;
+    let output_start = public_input
+        .main_page
+        .len()
+        .checked_sub(output_len)
+        .ok_or_else(|| FactCheckerError::ProofVerification("Output larger than the public memory".to_string()))?;
+
+    Ok(public_input.main_page[output_start..]
+        .iter()
+        .map(|cell| Felt::from_bytes_be(&cell.value.to_bytes_be()))
+        .collect())
+}
+
+/// Hash of the program output computed by the verifier.
+fn output_hash_of(program_output: &[Felt]) -> Felt {
+    let hash = program_output.iter().fold(Felt::ZERO, |hash, felt| pedersen_hash(&hash, felt));
+    pedersen_hash(&hash, &Felt::from(program_output.len()))
+}
+
+/// Fact of the execution of a program whose output is a single page, as registered by the GPS statement verifier.
+pub fn fact(program_hash: Felt, program_output: &[Felt]) -> B256 {
+    let output: Vec<u8> = program_output.iter().flat_map(|felt| felt.to_bytes_be()).collect();
+    keccak256([program_hash.to_bytes_be().as_slice(), keccak256(output).as_slice()].concat())
+}
+
+impl VerifiedProof {
+    pub fn fact(&self) -> B256 {
+        fact(self.program_hash, &self.program_output)
+    }
+
+    /// Reads the tasks from the output of the bootloader: `[n_tasks, (output_size, program_hash, ...output)*]`, the
+    /// output size of a task including its size and program hash.
+    pub fn bootloaded_tasks(&self) -> Result<Vec<BootloadedTask>, FactCheckerError> {
+        let mut output = self.program_output.iter().copied();
+        let n_tasks = next_usize(&mut output)?;
+        (0..n_tasks)
+            .map(|_| {
+                let output_size = next_usize(&mut output)?.checked_sub(2).ok_or_else(|| {
+                    FactCheckerError::InvalidBootloaderOutput("Task output size is smaller than 2".to_string())
+                })?;
+                let program_hash = output.next().ok_or_else(|| {
+                    FactCheckerError::InvalidBootloaderOutput("Missing task program hash".to_string())
+                })?;
+                let program_output: Vec<Felt> = output.by_ref().take(output_size).collect();
+                if program_output.len() != output_size {
+                    return Err(FactCheckerError::InvalidBootloaderOutput("Truncated task output".to_string()));
+                }
+                Ok(BootloadedTask { program_hash, program_output })
+            })
+            .collect()
+    }
+
+    /// Checks that the proof proves the execution of the program, on its own or as a task of the bootloader.
+    pub fn ensure_proves(&self, program_hash: Felt, program_output: &[Felt]) -> Result<(), FactCheckerError> {
+        let proves = |task_program_hash: Felt, task_program_output: &[Felt]| {
+            task_program_hash == program_hash && task_program_output == program_output
+        };
+        if proves(self.program_hash, &self.program_output)
+            || self
+                .bootloaded_tasks()
+                .unwrap_or_default()
+                .iter()
+                .any(|task| proves(task.program_hash, &task.program_output))
+        {
+            return Ok(());
+        }
+        Err(FactCheckerError::FactMismatch { expected: fact(program_hash, program_output), proven: self.fact() })
+    }
+}
+
+fn next_usize(output: &mut impl Iterator<Item = Felt>) -> Result<usize, FactCheckerError> {
+    let felt =
+        output.next().ok_or_else(|| FactCheckerError::InvalidBootloaderOutput("Output too short".to_string()))?;
+    felt.to_biguint().try_into().map_err(|_| FactCheckerError::InvalidBootloaderOutput(format!("Invalid size {felt}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn felts(values: &[u64]) -> Vec<Felt> {
+        values.iter().copied().map(Felt::from).collect()
+    }
+
+    fn bootloader_proof() -> VerifiedProof {
+        // Two tasks: program 7 outputting [1, 2], then program 8 outputting [3]
+        VerifiedProof { program_hash: Felt::from(42), program_output: felts(&[2, 4, 7, 1, 2, 3, 8, 3]) }
+    }
+
+    #[test]
+    fn test_bootloaded_tasks() {
+        assert_eq!(
+            bootloader_proof().bootloaded_tasks().unwrap(),
+            vec![
+                BootloadedTask { program_hash: Felt::from(7), program_output: felts(&[1, 2]) },
+                BootloadedTask { program_hash: Felt::from(8), program_output: felts(&[3]) },
+            ]
+        );
+
+        let truncated = VerifiedProof { program_hash: Felt::from(42), program_output: felts(&[1, 4, 7, 1]) };
+        assert!(matches!(truncated.bootloaded_tasks(), Err(FactCheckerError::InvalidBootloaderOutput(_))));
+    }
+
+    #[test]
+    fn test_ensure_proves() {
+        let proof = bootloader_proof();
+        assert!(proof.ensure_proves(Felt::from(8), &felts(&[3])).is_ok());
+        assert!(proof.ensure_proves(Felt::from(42), &proof.program_output.clone()).is_ok());
+
+        match proof.ensure_proves(Felt::from(8), &felts(&[4])) {
+            Err(FactCheckerError::FactMismatch { expected, proven }) => {
+                assert_eq!(expected, fact(Felt::from(8), &felts(&[4])));
+                assert_eq!(proven, proof.fact());
+            }
+            result => panic!("Unexpected result {result:?}"),
+        }
+    }
+
+    #[test]
+    fn test_verify_invalid_proof() {
+        assert!(matches!(verify_proof("not a proof", LayoutName::dynamic), Err(FactCheckerError::ProofParse(_))));
+    }
+
+    #[test]
+    fn test_verify_unsupported_layout() {
+        assert!(matches!(
+            verify_proof("not a proof", LayoutName::all_cairo),
+            Err(FactCheckerError::UnsupportedLayout(_))
+        ));
+    }
+
+    /// Stone proof of the fibonacci example of the stone prover with the `small` layout, outputting the 10th fibonacci
+    /// number.
+    #[test]
+    fn test_verify_stone_proof() {
+        let proof = include_str!("../tests/artifacts/fibonacci_proof.json");
+        assert_eq!(verify_proof(proof, LayoutName::small).unwrap().program_output, felts(&[10, 144]));
+
+        // The proof doesn't prove another output
+        let forged_proof = proof.replace(r#""value" : "0x90""#, r#""value" : "0x91""#);
+        assert_ne!(forged_proof, proof);
+        assert!(matches!(verify_proof(&forged_proof, LayoutName::small), Err(FactCheckerError::ProofVerification(_))));
+    }
+}
@@ -0,0 +1,25 @@
+use orchestrator_prover_client_interface::ProverClientError;
+
+#[derive(Debug, thiserror::Error)]
+pub enum LocalProverError {
+    #[error("Failed to access the task files: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("{program} failed: {stderr}")]
+    CommandFailed { program: String, stderr: String },
+
+    #[error("Failed to write the Cairo PIE: {0}")]
+    PieWrite(String),
+
+    #[error("Unknown task {0}")]
+    UnknownTask(String),
+
+    #[error("L2 queries are not supported by the local prover")]
+    L2QueryNotSupported,
+}
+
+impl From<LocalProverError> for ProverClientError {
+    fn from(value: LocalProverError) -> Self {
+        Self::Internal(Box::new(value))
+    }
+}
@@ -0,0 +1,248 @@
+pub mod error;
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use cairo_vm::types::layout_name::LayoutName;
+use orchestrator_prover_client_interface::{BucketTask, ProverClient, ProverClientError, Task, TaskStatus};
+use tokio::process::Command;
+use uuid::Uuid;
+
+use crate::error::LocalProverError;
+
+const CAIRO_PIE_FILE_NAME: &str = "cairo_pie.zip";
+const TRACE_FILE_NAME: &str = "trace.bin";
+const MEMORY_FILE_NAME: &str = "memory.bin";
+const PUBLIC_INPUT_FILE_NAME: &str = "public_input.json";
+const PRIVATE_INPUT_FILE_NAME: &str = "private_input.json";
+/// The prover writes the proof in a temporary file, which is renamed once the proof is complete.
+const PARTIAL_PROOF_FILE_NAME: &str = "proof.json.partial";
+const PROOF_FILE_NAME: &str = "proof.json";
+const ERROR_FILE_NAME: &str = "error.txt";
+
+#[derive(Debug, Clone)]
+pub struct LocalProverValidatedArgs {
+    pub local_prover_mode: LocalProverMode,
+}
+
+#[derive(Debug, Clone)]
+pub enum LocalProverMode {
+    /// Proves the tasks with a local stone prover. The proofs are not submitted to a verifier, so the settlement layer
+    /// must use a dev core contract as well.
+    Stone(StoneProverArgs),
+    /// Reports every task as proven right away, without proving it. The facts of the tasks are never registered,
+    /// so the settlement layer must use a dev core contract which does not check them.
+    Dummy,
+}
+
+#[derive(Debug, Clone)]
+pub struct StoneProverArgs {
+    /// Runs the bootloader on a Cairo PIE in proof mode. It is called with the `cairo-vm` CLI arguments:
+    /// `--cairo_pie_input <pie> --layout <layout> --proof_mode --trace_file <trace> --memory_file <memory>
+    /// --air_public_input <public input> --air_private_input <private input>`.
+    ///
+    /// `scripts/stone_cairo_runner.sh` runs the simple bootloader of cairo-lang with `cairo-run`.
+    pub cairo_runner_path: PathBuf,
+    /// The stone prover binary, `cpu_air_prover`.
+    pub cpu_air_prover_path: PathBuf,
+    pub prover_config_path: PathBuf,
+    pub prover_parameters_path: PathBuf,
+    /// Directory in which the tasks are proven, each task in its own sub directory.
+    pub work_dir: PathBuf,
+}
+
+/// Proves the tasks on the machine running the orchestrator, for devnets and tests.
+pub struct LocalProverService {
+    pub local_prover_mode: LocalProverMode,
+    /// Stone tasks whose prover is running.
+    running_tasks: Arc<Mutex<HashSet<String>>>,
+}
+
+#[async_trait]
+impl ProverClient for LocalProverService {
+    #[tracing::instrument(skip(self, task))]
+    async fn submit_task(
+        &self,
+        task: Task,
+        proof_layout: LayoutName,
+        _n_steps: Option<usize>,
+        bucket: Option<BucketTask>,
+    ) -> Result<String, ProverClientError> {
+        let stone_args = match &self.local_prover_mode {
+            LocalProverMode::Dummy => return Ok(Uuid::new_v4().to_string()),
+            LocalProverMode::Stone(stone_args) => stone_args,
+        };
+        if bucket.is_some() {
+            return Err(ProverClientError::AggregationNotSupported);
+        }
+
+        match task {
+            Task::CairoPie(cairo_pie) => {
+                let task_id = Uuid::new_v4().to_string();
+                let task_dir = stone_args.work_dir.join(&task_id);
+                std::fs::create_dir_all(&task_dir).map_err(LocalProverError::from)?;
+                cairo_pie
+                    .write_zip_file(&task_dir.join(CAIRO_PIE_FILE_NAME), true)
+                    .map_err(|e| LocalProverError::PieWrite(e.to_string()))?;
+
+                self.running_tasks.lock().expect("Poisoned lock").insert(task_id.clone());
+                let stone_args = stone_args.clone();
+                let running_tasks = self.running_tasks.clone();
+                let id = task_id.clone();
+                tokio::spawn(async move {
+                    if let Err(e) = prove(&stone_args, &task_dir, proof_layout).await {
+                        tracing::error!(task_id = %id, error = %e, "Local proving failed");
+                        if let Err(e) = std::fs::write(task_dir.join(ERROR_FILE_NAME), e.to_string()) {
+                            tracing::error!(task_id = %id, error = %e, "Failed to store the proving error");
+                        }
+                    }
+                    running_tasks.lock().expect("Poisoned lock").remove(&id);
+                });
+
+                tracing::debug!(task_id = %task_id, "Started local stone prover");
+                Ok(task_id)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_task_status(
+        &self,
+        task_id: &str,
+        _fact: Option<String>,
+        cross_verify: bool,
+    ) -> Result<TaskStatus, ProverClientError> {
+        let stone_args = match &self.local_prover_mode {
+            LocalProverMode::Dummy => {
+                tracing::debug!("Dummy prover, considering the task proven and its fact registered");
+                return Ok(TaskStatus::Succeeded);
+            }
+            LocalProverMode::Stone(stone_args) => stone_args,
+        };
+
+        let task_dir = stone_args.work_dir.join(task_id);
+        if task_dir.join(PROOF_FILE_NAME).exists() {
+            if cross_verify {
+                tracing::debug!("Local proofs are not registered on chain, skipping cross-verification");
+            }
+            return Ok(TaskStatus::Succeeded);
+        }
+        if let Ok(error) = std::fs::read_to_string(task_dir.join(ERROR_FILE_NAME)) {
+            return Ok(TaskStatus::Failed(error));
+        }
+        if self.running_tasks.lock().expect("Poisoned lock").contains(task_id) {
+            return Ok(TaskStatus::Processing);
+        }
+        if task_dir.exists() {
+            // The orchestrator was restarted while the task was being proven
+            return Ok(TaskStatus::Failed("Local proving was interrupted".to_string()));
+        }
+        Err(LocalProverError::UnknownTask(task_id.to_string()).into())
+    }
+
+    /// The files of a stone task are removed once its proof has been read, the proof can only be fetched once.
+    async fn get_proof(&self, task_id: &str) -> Result<String, ProverClientError> {
+        match &self.local_prover_mode {
+            LocalProverMode::Dummy => Ok(format!("{{\"dummy_proof\":\"{task_id}\"}}")),
+            LocalProverMode::Stone(stone_args) => {
+                let task_dir = stone_args.work_dir.join(task_id);
+                let proof = std::fs::read_to_string(task_dir.join(PROOF_FILE_NAME)).map_err(LocalProverError::from)?;
+                if let Err(e) = std::fs::remove_dir_all(&task_dir) {
+                    tracing::warn!(task_id = %task_id, error = %e, "Failed to remove the files of the proven task");
+                }
+                Ok(proof)
+            }
+        }
+    }
+
+    async fn submit_l2_query(
+        &self,
+        _task_id: &str,
+        _proof: &str,
+        _n_steps: Option<usize>,
+    ) -> Result<String, ProverClientError> {
+        // The proofs are not registered on the settlement layer, so the local prover is rejected for L3s
+        Err(LocalProverError::L2QueryNotSupported.into())
+    }
+
+    async fn create_bucket(&self) -> Result<String, ProverClientError> {
+        match &self.local_prover_mode {
+            LocalProverMode::Dummy => Ok(Uuid::new_v4().to_string()),
+            LocalProverMode::Stone(_) => Err(ProverClientError::AggregationNotSupported),
+        }
+    }
+
+    async fn close_bucket(&self, _bucket_id: &str) -> Result<(), ProverClientError> {
+        match &self.local_prover_mode {
+            LocalProverMode::Dummy => Ok(()),
+            LocalProverMode::Stone(_) => Err(ProverClientError::AggregationNotSupported),
+        }
+    }
+
+    async fn get_aggregation_task_id(&self, _bucket_id: &str) -> Result<Option<String>, ProverClientError> {
+        match &self.local_prover_mode {
+            LocalProverMode::Dummy => Ok(Some(Uuid::new_v4().to_string())),
+            LocalProverMode::Stone(_) => Err(ProverClientError::AggregationNotSupported),
+        }
+    }
+}
+
+impl LocalProverService {
+    pub fn new_with_args(local_prover_params: &LocalProverValidatedArgs) -> Self {
+        Self {
+            local_prover_mode: local_prover_params.local_prover_mode.clone(),
+            running_tasks: Arc::new(Mutex::new(HashSet::new())),
+        }
+    }
+}
+
+/// Runs the bootloader on the Cairo PIE of the task, then proves its execution with the stone prover.
+async fn prove(
+    stone_args: &StoneProverArgs,
+    task_dir: &Path,
+    proof_layout: LayoutName,
+) -> Result<(), LocalProverError> {
+    run(Command::new(&stone_args.cairo_runner_path)
+        .arg("--cairo_pie_input")
+        .arg(task_dir.join(CAIRO_PIE_FILE_NAME))
+        .args(["--layout", proof_layout.to_str(), "--proof_mode"])
+        .arg("--trace_file")
+        .arg(task_dir.join(TRACE_FILE_NAME))
+        .arg("--memory_file")
+        .arg(task_dir.join(MEMORY_FILE_NAME))
+        .arg("--air_public_input")
+        .arg(task_dir.join(PUBLIC_INPUT_FILE_NAME))
+        .arg("--air_private_input")
+        .arg(task_dir.join(PRIVATE_INPUT_FILE_NAME)))
+    .await?;
+
+    run(Command::new(&stone_args.cpu_air_prover_path)
+        .arg("--out_file")
+        .arg(task_dir.join(PARTIAL_PROOF_FILE_NAME))
+        .arg("--public_input_file")
+        .arg(task_dir.join(PUBLIC_INPUT_FILE_NAME))
+        .arg("--private_input_file")
+        .arg(task_dir.join(PRIVATE_INPUT_FILE_NAME))
+        .arg("--prover_config_file")
+        .arg(&stone_args.prover_config_path)
+        .arg("--parameter_file")
+        .arg(&stone_args.prover_parameters_path)
+        .arg("--generate_annotations"))
+    .await?;
+
+    std::fs::rename(task_dir.join(PARTIAL_PROOF_FILE_NAME), task_dir.join(PROOF_FILE_NAME))?;
+    Ok(())
+}
+
+async fn run(command: &mut Command) -> Result<(), LocalProverError> {
+    let output = command.output().await?;
+    if !output.status.success() {
+        return Err(LocalProverError::CommandFailed {
+            program: command.as_std().get_program().to_string_lossy().into_owned(),
+            stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+        });
+    }
+    Ok(())
+}
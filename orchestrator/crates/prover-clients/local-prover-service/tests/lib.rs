@@ -0,0 +1,126 @@
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use cairo_vm::types::layout_name::LayoutName;
+use cairo_vm::vm::runners::cairo_pie::CairoPie;
+use orchestrator_local_prover_service::{
+    LocalProverMode, LocalProverService, LocalProverValidatedArgs, StoneProverArgs,
+};
+use orchestrator_prover_client_interface::{ProverClient, Task, TaskStatus};
+
+const CAIRO_PIE_PATH: &str = "/tests/artifacts/fibonacci.zip";
+const MAX_RETRIES: u8 = 50;
+const RETRY_DELAY: Duration = Duration::from_millis(100);
+
+fn cairo_pie_task() -> Task {
+    let cairo_pie_path = env!("CARGO_MANIFEST_DIR").to_string() + CAIRO_PIE_PATH;
+    Task::CairoPie(Box::new(CairoPie::read_zip_file(cairo_pie_path.as_ref()).expect("failed to read cairo pie zip")))
+}
+
+/// Writes an executable shell script standing in for a binary of the stone toolchain.
+fn write_script(dir: &Path, name: &str, body: &str) -> PathBuf {
+    let path = dir.join(name);
+    std::fs::write(&path, format!("#!/bin/sh\n{body}\n")).unwrap();
+    std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+    path
+}
+
+fn stone_prover(dir: &Path, cpu_air_prover_body: &str) -> LocalProverService {
+    let stone_args = StoneProverArgs {
+        cairo_runner_path: write_script(dir, "cairo-runner", "exit 0"),
+        cpu_air_prover_path: write_script(dir, "cpu_air_prover", cpu_air_prover_body),
+        prover_config_path: dir.join("cpu_air_prover_config.json"),
+        prover_parameters_path: dir.join("cpu_air_params.json"),
+        work_dir: dir.join("tasks"),
+    };
+    LocalProverService::new_with_args(&LocalProverValidatedArgs {
+        local_prover_mode: LocalProverMode::Stone(stone_args),
+    })
+}
+
+async fn wait_for_task(prover: &LocalProverService, task_id: &str, retry_delay: Duration) -> TaskStatus {
+    for _ in 0..MAX_RETRIES {
+        let status = prover.get_task_status(task_id, None, false).await.expect("Failed to get task status");
+        if status != TaskStatus::Processing {
+            return status;
+        }
+        tokio::time::sleep(retry_delay).await;
+    }
+    panic!("Task {task_id} is still processing");
+}
+
+#[tokio::test]
+async fn local_prover_stone_proves_task() {
+    let dir = tempfile::tempdir().unwrap();
+    // Writes the proof to the file following `--out_file`
+    let prover =
+        stone_prover(dir.path(), r#"while [ "$1" != "--out_file" ]; do shift; done; echo '{"proof": "stone"}' > "$2""#);
+
+    let task_id = prover.submit_task(cairo_pie_task(), LayoutName::dynamic, None, None).await.unwrap();
+    assert!(dir.path().join("tasks").join(&task_id).join("cairo_pie.zip").exists());
+
+    assert_eq!(wait_for_task(&prover, &task_id, RETRY_DELAY).await, TaskStatus::Succeeded);
+    assert_eq!(prover.get_proof(&task_id).await.unwrap().trim(), r#"{"proof": "stone"}"#);
+    assert!(!dir.path().join("tasks").join(&task_id).exists());
+}
+
+#[tokio::test]
+async fn local_prover_stone_reports_failure() {
+    let dir = tempfile::tempdir().unwrap();
+    let prover = stone_prover(dir.path(), "echo 'out of memory' >&2; exit 1");
+
+    let task_id = prover.submit_task(cairo_pie_task(), LayoutName::dynamic, None, None).await.unwrap();
+
+    match wait_for_task(&prover, &task_id, RETRY_DELAY).await {
+        TaskStatus::Failed(error) => assert!(error.contains("out of memory")),
+        status => panic!("Unexpected task status {status:?}"),
+    }
+    assert!(prover.get_task_status("unknown", None, false).await.is_err());
+}
+
+#[tokio::test]
+async fn local_prover_dummy_succeeds() {
+    let prover =
+        LocalProverService::new_with_args(&LocalProverValidatedArgs { local_prover_mode: LocalProverMode::Dummy });
+
+    let task_id = prover.submit_task(cairo_pie_task(), LayoutName::dynamic, None, None).await.unwrap();
+    let status = prover.get_task_status(&task_id, Some(format!("0x{}", "ab".repeat(32))), true).await.unwrap();
+    assert_eq!(status, TaskStatus::Succeeded);
+    assert!(prover.get_proof(&task_id).await.is_ok());
+    assert!(prover.submit_l2_query(&task_id, "{}", None).await.is_err());
+}
+
+/// Proves the Cairo PIE with the stone toolchain, whose paths are read from the same environment variables as the
+/// orchestrator. The Cairo runner defaults to `scripts/stone_cairo_runner.sh`, and the prover parameters must be
+/// those of the `recursive_with_poseidon` layout.
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn local_prover_stone_proves_task_with_stone_binaries() {
+    let env_path = |name: &str| {
+        PathBuf::from(std::env::var(name).unwrap_or_else(|_| panic!("{name} is required to run the test")))
+    };
+    let dir = tempfile::tempdir().unwrap();
+    let stone_args = StoneProverArgs {
+        cairo_runner_path: std::env::var("MADARA_ORCHESTRATOR_LOCAL_PROVER_CAIRO_RUNNER_PATH")
+            .map(PathBuf::from)
+            .unwrap_or_else(|_| Path::new(env!("CARGO_MANIFEST_DIR")).join("../../../scripts/stone_cairo_runner.sh")),
+        cpu_air_prover_path: env_path("MADARA_ORCHESTRATOR_LOCAL_PROVER_CPU_AIR_PROVER_PATH"),
+        prover_config_path: env_path("MADARA_ORCHESTRATOR_LOCAL_PROVER_CONFIG_PATH"),
+        prover_parameters_path: env_path("MADARA_ORCHESTRATOR_LOCAL_PROVER_PARAMETERS_PATH"),
+        work_dir: dir.path().join("tasks"),
+    };
+    let prover = LocalProverService::new_with_args(&LocalProverValidatedArgs {
+        local_prover_mode: LocalProverMode::Stone(stone_args),
+    });
+
+    let task_id = prover.submit_task(cairo_pie_task(), LayoutName::recursive_with_poseidon, None, None).await.unwrap();
+    match wait_for_task(&prover, &task_id, Duration::from_secs(10)).await {
+        TaskStatus::Succeeded => {}
+        status => panic!("Unexpected task status {status:?}"),
+    }
+
+    let proof: serde_json::Value = serde_json::from_str(&prover.get_proof(&task_id).await.unwrap()).unwrap();
+    assert!(proof["proof_hex"].is_string());
+    assert_eq!(proof["public_input"]["layout"], "recursive_with_poseidon");
+}
@@ -20,6 +20,7 @@ pub trait ProverClient: Send + Sync {
         task: Task,
         proof_layout: LayoutName,
         n_steps: Option<usize>,
+        bucket: Option<BucketTask>,
     ) -> Result<String, ProverClientError>;
     async fn get_task_status(
         &self,
@@ -34,12 +35,28 @@ pub trait ProverClient: Send + Sync {
         fact: &str,
         n_steps: Option<usize>,
     ) -> Result<String, ProverClientError>;
+    /// Creates a bucket. The tasks submitted to a bucket are aggregated in a single proof with
+    /// applicative recursion once the bucket is closed.
+    async fn create_bucket(&self) -> Result<String, ProverClientError>;
+    /// Closes the bucket, which starts the aggregation of its tasks.
+    async fn close_bucket(&self, bucket_id: &str) -> Result<(), ProverClientError>;
+    /// Returns the id of the task aggregating the tasks of a closed bucket, `None` until the
+    /// aggregation task is created.
+    async fn get_aggregation_task_id(&self, bucket_id: &str) -> Result<Option<String>, ProverClientError>;
 }
 
 pub enum Task {
     CairoPie(Box<CairoPie>),
 }
 
+/// Position of a task in a bucket
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BucketTask {
+    pub bucket_id: String,
+    /// Index of the task in the bucket, the tasks are aggregated in this order
+    pub index: u64,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TaskStatus {
     Processing,
@@ -71,4 +88,6 @@ pub enum ProverClientError {
     InvalidProofFormat(String),
     #[error("Missing Cairo verifier program hash")]
     MissingCairoVerifierProgramHash,
+    #[error("Aggregation of tasks is not supported by the prover")]
+    AggregationNotSupported,
 }
@@ -8,7 +8,7 @@ use alloy_primitives::B256;
 use async_trait::async_trait;
 use cairo_vm::types::layout_name::LayoutName;
 use orchestrator_gps_fact_checker::FactChecker;
-use orchestrator_prover_client_interface::{ProverClient, ProverClientError, Task, TaskStatus};
+use orchestrator_prover_client_interface::{BucketTask, ProverClient, ProverClientError, Task, TaskStatus};
 use starknet_os::sharp::CairoJobStatus;
 use uuid::Uuid;
 
@@ -45,7 +45,12 @@ impl ProverClient for SharpProverService {
         task: Task,
         proof_layout: LayoutName,
         _n_steps: Option<usize>,
+        bucket: Option<BucketTask>,
     ) -> Result<String, ProverClientError> {
+        // SHARP aggregates the tasks it receives on its own
+        if bucket.is_some() {
+            return Err(ProverClientError::AggregationNotSupported);
+        }
         tracing::info!(
             log_type = "starting",
             category = "submit_task",
@@ -175,6 +180,18 @@ impl ProverClient for SharpProverService {
     ) -> Result<String, ProverClientError> {
         todo!()
     }
+
+    async fn create_bucket(&self) -> Result<String, ProverClientError> {
+        Err(ProverClientError::AggregationNotSupported)
+    }
+
+    async fn close_bucket(&self, _bucket_id: &str) -> Result<(), ProverClientError> {
+        Err(ProverClientError::AggregationNotSupported)
+    }
+
+    async fn get_aggregation_task_id(&self, _bucket_id: &str) -> Result<Option<String>, ProverClientError> {
+        Err(ProverClientError::AggregationNotSupported)
+    }
 }
 
 impl SharpProverService {
@@ -49,7 +49,7 @@ async fn prover_client_submit_task_works() {
     });
 
     let cairo_pie = Box::new(cairo_pie);
-    assert!(sharp_service.submit_task(Task::CairoPie(cairo_pie), LayoutName::all_cairo, None).await.is_ok());
+    assert!(sharp_service.submit_task(Task::CairoPie(cairo_pie), LayoutName::all_cairo, None, None).await.is_ok());
 
     sharp_add_job_call.assert();
 }
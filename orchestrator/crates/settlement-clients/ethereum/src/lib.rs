@@ -14,14 +14,16 @@ use alloy::hex;
 use alloy::network::EthereumWallet;
 use alloy::primitives::{Address, Bytes, B256, U256};
 use alloy::providers::{Provider, ProviderBuilder};
-use alloy::rpc::types::TransactionReceipt;
+use alloy::rpc::types::{TransactionReceipt, TransactionRequest};
 use alloy::signers::local::PrivateKeySigner;
 use async_trait::async_trait;
 use c_kzg::{Blob, Bytes32, KzgCommitment, KzgProof, KzgSettings};
 use color_eyre::eyre::{bail, Ok};
 use color_eyre::Result;
 use conversion::{get_input_data_for_eip_4844, prepare_sidecar};
-use orchestrator_settlement_client_interface::{SettlementClient, SettlementVerificationStatus};
+use orchestrator_settlement_client_interface::{
+    SettlementClient, SettlementTx, SettlementVerificationStatus, TxReplacement,
+};
 #[cfg(feature = "testing")]
 use orchestrator_utils::env_utils::get_env_var_or_panic;
 use url::Url;
@@ -29,9 +31,13 @@ use url::Url;
 use crate::clients::interfaces::validity_interface::StarknetValidityContractTrait;
 use crate::clients::StarknetValidityContractClient;
 use crate::conversion::{slice_u8_to_u256, vec_u8_32_to_vec_u256};
+use crate::signer::EthereumSignerConfig;
+use crate::tx_manager::{GasEscalationPolicy, NetworkFees};
 pub mod clients;
 pub mod conversion;
+pub mod signer;
 pub mod tests;
+pub mod tx_manager;
 pub mod types;
 use alloy::providers::RootProvider;
 use alloy::transports::http::Http;
@@ -65,11 +71,13 @@ lazy_static! {
 pub struct EthereumSettlementValidatedArgs {
     pub ethereum_rpc_url: Url,
 
-    pub ethereum_private_key: String,
+    pub ethereum_signer: EthereumSignerConfig,
 
     pub l1_core_contract_address: Address,
 
     pub starknet_operator_address: Address,
+
+    pub gas_escalation_policy: GasEscalationPolicy,
 }
 
 #[allow(dead_code)]
@@ -79,14 +87,13 @@ pub struct EthereumSettlementClient {
     wallet_address: Address,
     provider: Arc<RootProvider<Http<Client>>>,
     impersonate_account: Option<Address>,
+    gas_escalation_policy: GasEscalationPolicy,
 }
 
 impl EthereumSettlementClient {
     pub fn new_with_args(settlement_cfg: &EthereumSettlementValidatedArgs) -> Self {
-        let private_key = settlement_cfg.ethereum_private_key.clone();
-        let signer: PrivateKeySigner = private_key.parse().expect("Failed to parse private key");
-        let wallet_address = signer.address();
-        let wallet = EthereumWallet::from(signer);
+        let wallet = settlement_cfg.ethereum_signer.wallet().expect("Failed to load the settlement wallet");
+        let wallet_address = wallet.default_signer().address();
 
         // provider without wallet
         let provider = Arc::new(ProviderBuilder::new().on_http(settlement_cfg.ethereum_rpc_url.clone()));
@@ -102,7 +109,14 @@ impl EthereumSettlementClient {
         let core_contract_client =
             StarknetValidityContractClient::new(settlement_cfg.l1_core_contract_address, filler_provider);
 
-        EthereumSettlementClient { provider, core_contract_client, wallet, wallet_address, impersonate_account: None }
+        EthereumSettlementClient {
+            provider,
+            core_contract_client,
+            wallet,
+            wallet_address,
+            impersonate_account: None,
+            gas_escalation_policy: settlement_cfg.gas_escalation_policy.clone(),
+        }
     }
 
     #[cfg(feature = "testing")]
@@ -128,9 +142,16 @@ impl EthereumSettlementClient {
             wallet,
             wallet_address,
             impersonate_account,
+            gas_escalation_policy: GasEscalationPolicy::default(),
         }
     }
 
+    #[cfg(feature = "testing")]
+    pub fn with_gas_escalation_policy(mut self, gas_escalation_policy: GasEscalationPolicy) -> Self {
+        self.gas_escalation_policy = gas_escalation_policy;
+        self
+    }
+
     /// Build kzg proof for the x_0 point evaluation
     pub fn build_proof(
         blob_data: Vec<Vec<u8>>,
@@ -169,6 +190,121 @@ impl EthereumSettlementClient {
         }
         Ok(kzg_proof)
     }
+
+    /// Account sending the settlement transactions
+    fn sender(&self) -> Address {
+        self.impersonate_account.unwrap_or(self.wallet_address)
+    }
+
+    async fn network_fees(&self) -> Result<NetworkFees> {
+        let eip1559_est = self.provider.estimate_eip1559_fees(None).await?;
+        Ok(NetworkFees {
+            max_fee_per_gas: eip1559_est.max_fee_per_gas.to_string().parse()?,
+            max_priority_fee_per_gas: eip1559_est.max_priority_fee_per_gas.to_string().parse()?,
+            max_fee_per_blob_gas: self.provider.get_blob_base_fee().await?.to_string().parse()?,
+        })
+    }
+
+    /// Estimates the gas limit of a blob transaction, with a safety margin.
+    async fn estimate_gas(&self, tx: &TxEip4844) -> Result<u64> {
+        let request = TransactionRequest {
+            from: Some(self.sender()),
+            to: Some(tx.to.into()),
+            input: tx.input.clone().into(),
+            max_fee_per_gas: Some(tx.max_fee_per_gas),
+            max_priority_fee_per_gas: Some(tx.max_priority_fee_per_gas),
+            max_fee_per_blob_gas: Some(tx.max_fee_per_blob_gas),
+            blob_versioned_hashes: Some(tx.blob_versioned_hashes.clone()),
+            ..Default::default()
+        };
+        let gas = self.provider.estimate_gas(&request).await.wrap_err("Failed to estimate the settlement gas")?;
+        Ok(add_safety_margin(gas.into()).try_into()?)
+    }
+
+    /// Builds the blob transaction settling `program_output` with the nonce `nonce`, without its gas limit and fees.
+    async fn build_blob_tx(
+        &self,
+        program_output: Vec<[u8; 32]>,
+        state_diff: Vec<Vec<u8>>,
+        nonce: u64,
+    ) -> Result<(TxEip4844, BlobTransactionSidecar)> {
+        let (sidecar_blobs, sidecar_commitments, sidecar_proofs) = prepare_sidecar(&state_diff, &KZG_SETTINGS).await?;
+        let sidecar = BlobTransactionSidecar::new(sidecar_blobs, sidecar_commitments, sidecar_proofs);
+
+        let chain_id: u64 = self.provider.get_chain_id().await?.to_string().parse()?;
+
+        // calculating y_0 point
+        let y_0 = Bytes32::from(
+            convert_stark_bigint_to_u256(
+                bytes_be_to_u128(&program_output[Y_LOW_POINT_OFFSET]),
+                bytes_be_to_u128(&program_output[Y_HIGH_POINT_OFFSET]),
+            )
+            .to_be_bytes(),
+        );
+
+        // x_0_value : program_output[10]
+        // Updated with starknet 0.13.2 spec
+        let x_0_point = Bytes32::from_bytes(program_output[X_0_POINT_OFFSET].as_slice())
+            .wrap_err("Failed to get x_0 point params")?;
+
+        let kzg_proof = Self::build_proof(state_diff, x_0_point, y_0).wrap_err("Failed to build KZG proof")?.to_owned();
+
+        let input_bytes = get_input_data_for_eip_4844(program_output, kzg_proof)?;
+
+        let tx = TxEip4844 {
+            chain_id,
+            nonce,
+            gas_limit: 0,
+            max_fee_per_gas: 0,
+            max_priority_fee_per_gas: 0,
+            to: self.core_contract_client.contract_address(),
+            value: U256::from(0),
+            access_list: AccessList(vec![]),
+            blob_versioned_hashes: sidecar.versioned_hashes().collect(),
+            max_fee_per_blob_gas: 0,
+            input: Bytes::from(hex::decode(input_bytes)?),
+        };
+        Ok((tx, sidecar))
+    }
+
+    /// Signs and broadcasts a blob transaction, returning its hash.
+    async fn send_blob_tx(&self, tx: TxEip4844, sidecar: BlobTransactionSidecar) -> Result<B256> {
+        let mut variant = TxEip4844Variant::from(TxEip4844WithSidecar { tx, sidecar });
+        let signature = self.wallet.default_signer().sign_transaction(&mut variant).await?;
+        let tx_envelope: TxEnvelope = variant.into_signed(signature).into();
+
+        #[cfg(feature = "testing")]
+        let pending_transaction = {
+            let txn_request = test_config::configure_transaction(tx_envelope, self.impersonate_account);
+            self.provider.send_transaction(txn_request).await?
+        };
+
+        #[cfg(not(feature = "testing"))]
+        let pending_transaction = {
+            let encoded = tx_envelope.encoded_2718();
+            self.provider.send_raw_transaction(encoded.as_slice()).await?
+        };
+
+        Ok(*pending_transaction.tx_hash())
+    }
+}
+
+/// Parameters of the blob transaction `tx`, to be kept by the caller.
+fn settlement_tx(tx: &TxEip4844, tx_hash: B256, sent_at_block: u64) -> Result<SettlementTx> {
+    Ok(SettlementTx {
+        tx_hash: tx_hash.to_string(),
+        nonce: tx.nonce,
+        gas_limit: tx.gas_limit,
+        max_fee_per_gas: tx.max_fee_per_gas.try_into()?,
+        max_priority_fee_per_gas: tx.max_priority_fee_per_gas.try_into()?,
+        max_fee_per_blob_gas: tx.max_fee_per_blob_gas.try_into()?,
+        sent_at_block,
+    })
+}
+
+/// Adds a safety margin to a fee or gas estimate to handle fluctuations.
+fn add_safety_margin(n: u128) -> u128 {
+    n + n / 5
 }
 
 #[automock]
@@ -211,109 +347,93 @@ impl SettlementClient for EthereumSettlementClient {
     }
 
     /// Should be used to update state on core contract when DA is in blobs/alt DA
+    ///
+    /// The transaction is sent with the given nonce and is not waited for: if it gets stuck, it can be re-broadcast
+    /// with higher fees using [`SettlementClient::replace_pending_tx`].
     async fn update_state_with_blobs(
         &self,
         program_output: Vec<[u8; 32]>,
         state_diff: Vec<Vec<u8>>,
-        _nonce: u64,
-    ) -> Result<String> {
+        nonce: u64,
+    ) -> Result<SettlementTx> {
         tracing::info!(
             log_type = "starting",
             category = "update_state",
             function_type = "blobs",
             "Updating state with blobs."
         );
-        let (sidecar_blobs, sidecar_commitments, sidecar_proofs) = prepare_sidecar(&state_diff, &KZG_SETTINGS).await?;
-        let sidecar = BlobTransactionSidecar::new(sidecar_blobs, sidecar_commitments, sidecar_proofs);
+        let (mut tx, sidecar) = self.build_blob_tx(program_output, state_diff, nonce).await?;
 
-        let eip1559_est = self.provider.estimate_eip1559_fees(None).await?;
-        let chain_id: u64 = self.provider.get_chain_id().await?.to_string().parse()?;
+        let network_fees = self.network_fees().await?;
+        tx.max_fee_per_gas = add_safety_margin(network_fees.max_fee_per_gas);
+        tx.max_priority_fee_per_gas = add_safety_margin(network_fees.max_priority_fee_per_gas);
+        tx.max_fee_per_blob_gas = add_safety_margin(network_fees.max_fee_per_blob_gas);
+        tx.gas_limit = self.estimate_gas(&tx).await?;
 
-        let max_fee_per_blob_gas: u128 = self.provider.get_blob_base_fee().await?.to_string().parse()?;
+        let sent_at_block = self.provider.get_block_number().await?;
+        let tx_hash = self.send_blob_tx(tx.clone(), sidecar).await?;
 
-        // calculating y_0 point
-        let y_0 = Bytes32::from(
-            convert_stark_bigint_to_u256(
-                bytes_be_to_u128(&program_output[Y_LOW_POINT_OFFSET]),
-                bytes_be_to_u128(&program_output[Y_HIGH_POINT_OFFSET]),
-            )
-            .to_be_bytes(),
+        tracing::info!(
+            log_type = "completed",
+            category = "update_state",
+            function_type = "blobs",
+            tx_hash = %tx_hash,
+            nonce = %nonce,
+            "State updated with blobs."
         );
+        settlement_tx(&tx, tx_hash, sent_at_block)
+    }
 
-        // x_0_value : program_output[10]
-        // Updated with starknet 0.13.2 spec
-        let x_0_point = Bytes32::from_bytes(program_output[X_0_POINT_OFFSET].as_slice())
-            .wrap_err("Failed to get x_0 point params")?;
-
-        let kzg_proof = Self::build_proof(state_diff, x_0_point, y_0).wrap_err("Failed to build KZG proof")?.to_owned();
-
-        let input_bytes = get_input_data_for_eip_4844(program_output, kzg_proof)?;
-
-        let nonce = self.provider.get_transaction_count(self.wallet_address).await?.to_string().parse()?;
-
-        // add a safety margin to the gas price to handle fluctuations
-        let add_safety_margin = |n: u128, div_factor: u128| n + n / div_factor;
-
-        let max_fee_per_gas: u128 = eip1559_est.max_fee_per_gas.to_string().parse()?;
-        let max_priority_fee_per_gas: u128 = eip1559_est.max_priority_fee_per_gas.to_string().parse()?;
-
-        let tx: TxEip4844 = TxEip4844 {
-            chain_id,
-            nonce,
-            // we noticed Starknet uses the same limit on mainnet
-            // https://etherscan.io/tx/0x8a58b936faaefb63ee1371991337ae3b99d74cb3504d73868615bf21fa2f25a1
-            gas_limit: 5_500_000,
-            max_fee_per_gas: add_safety_margin(max_fee_per_gas, 5),
-            max_priority_fee_per_gas: add_safety_margin(max_priority_fee_per_gas, 5),
-            to: self.core_contract_client.contract_address(),
-            value: U256::from(0),
-            access_list: AccessList(vec![]),
-            blob_versioned_hashes: sidecar.versioned_hashes().collect(),
-            max_fee_per_blob_gas: add_safety_margin(max_fee_per_blob_gas, 5),
-            input: Bytes::from(hex::decode(input_bytes)?),
-        };
+    /// Re-broadcasts the pending blob transaction with fees escalated according to the gas escalation policy, once it
+    /// has been pending for the number of blocks of the policy.
+    async fn replace_pending_tx(
+        &self,
+        pending_tx: &SettlementTx,
+        replacements: u64,
+        program_output: Vec<[u8; 32]>,
+        state_diff: Vec<Vec<u8>>,
+    ) -> Result<TxReplacement> {
+        let tx_hash = &pending_tx.tx_hash;
 
-        let tx_sidecar = TxEip4844WithSidecar { tx: tx.clone(), sidecar: sidecar.clone() };
+        // A transaction was already included for this nonce
+        let next_nonce: u64 = self.provider.get_transaction_count(self.sender()).await?.to_string().parse()?;
+        if pending_tx.nonce < next_nonce {
+            return Ok(TxReplacement::Unavailable);
+        }
 
-        let mut variant = TxEip4844Variant::from(tx_sidecar);
-        let signature = self.wallet.default_signer().sign_transaction(&mut variant).await?;
-        let tx_signed = variant.into_signed(signature);
-        let tx_envelope: TxEnvelope = tx_signed.into();
+        if replacements >= self.gas_escalation_policy.max_replacements {
+            tracing::warn!(tx_hash = %tx_hash, nonce = %pending_tx.nonce, "Max number of replacements reached.");
+            return Ok(TxReplacement::Unavailable);
+        }
+        let latest_block = self.provider.get_block_number().await?;
+        if !self.gas_escalation_policy.is_stuck(pending_tx.sent_at_block, latest_block) {
+            return Ok(TxReplacement::TooRecent);
+        }
 
-        #[cfg(feature = "testing")]
-        let pending_transaction = {
-            let txn_request = {
-                test_config::configure_transaction(self.provider.clone(), tx_envelope, self.impersonate_account).await
-            };
-            self.provider.send_transaction(txn_request).await?
-        };
+        let (mut tx, sidecar) = self.build_blob_tx(program_output, state_diff, pending_tx.nonce).await?;
+        tx.gas_limit = pending_tx.gas_limit;
+        tx.max_fee_per_gas = pending_tx.max_fee_per_gas.into();
+        tx.max_priority_fee_per_gas = pending_tx.max_priority_fee_per_gas.into();
+        tx.max_fee_per_blob_gas = pending_tx.max_fee_per_blob_gas.into();
 
-        #[cfg(not(feature = "testing"))]
-        let pending_transaction = {
-            let encoded = tx_envelope.encoded_2718();
-            self.provider.send_raw_transaction(encoded.as_slice()).await?
+        let network_fees = self.network_fees().await?;
+        let Some(tx) = self.gas_escalation_policy.escalate(&tx, network_fees) else {
+            tracing::warn!(tx_hash = %tx_hash, nonce = %pending_tx.nonce, "Fee caps reached, cannot replace the transaction.");
+            return Ok(TxReplacement::Unavailable);
         };
 
+        let replacement_hash = self.send_blob_tx(tx.clone(), sidecar).await?;
         tracing::info!(
-            log_type = "completed",
             category = "update_state",
             function_type = "blobs",
-            "State updated with blobs."
+            tx_hash = %tx_hash,
+            replacement_tx_hash = %replacement_hash,
+            nonce = %tx.nonce,
+            max_fee_per_gas = %tx.max_fee_per_gas,
+            max_fee_per_blob_gas = %tx.max_fee_per_blob_gas,
+            "Replaced pending transaction."
         );
-
-        log::warn!("⏳ Waiting for txn finality.......");
-
-        let res = self.wait_for_tx_finality(&pending_transaction.tx_hash().to_string()).await?;
-
-        match res {
-            Some(_) => {
-                log::info!("Txn hash : {:?} Finalized ✅", pending_transaction.tx_hash().to_string());
-            }
-            None => {
-                log::error!("Txn hash not finalised");
-            }
-        }
-        Ok(pending_transaction.tx_hash().to_string())
+        Ok(TxReplacement::Replaced(settlement_tx(&tx, replacement_hash, latest_block)?))
     }
 
     /// Should verify the inclusion of a tx in the settlement layer
@@ -410,11 +530,7 @@ mod test_config {
     use super::*;
 
     #[allow(dead_code)]
-    pub async fn configure_transaction(
-        provider: Arc<RootProvider<Http<Client>>>,
-        tx_envelope: TxEnvelope,
-        impersonate_account: Option<Address>,
-    ) -> TransactionRequest {
+    pub fn configure_transaction(tx_envelope: TxEnvelope, impersonate_account: Option<Address>) -> TransactionRequest {
         let mut txn_request: TransactionRequest = tx_envelope.into();
 
         // IMPORTANT to understand #[cfg(test)], #[cfg(not(test))] and SHOULD_IMPERSONATE_ACCOUNT
@@ -426,10 +542,8 @@ mod test_config {
         //      - if "1" then : Testing via impersonating `Starknet Operator Address`.
         // Note : changing between "0" and "1" is handled automatically by each test function, `no` manual
         // change in `env.test` is needed.
+        // The nonce of the transaction is the one of the impersonated account, given by the caller.
         if let Some(impersonate_account) = impersonate_account {
-            let nonce =
-                provider.get_transaction_count(impersonate_account).await.unwrap().to_string().parse::<u64>().unwrap();
-            txn_request.set_nonce(nonce);
             txn_request = txn_request.with_from(impersonate_account);
         }
 
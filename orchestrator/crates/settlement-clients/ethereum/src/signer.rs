@@ -0,0 +1,258 @@
+use std::path::PathBuf;
+
+use alloy::consensus::SignableTransaction;
+use alloy::hex;
+use alloy::network::{EthereumWallet, TxSigner};
+use alloy::primitives::{Address, Bytes, PrimitiveSignature};
+use alloy::signers::local::PrivateKeySigner;
+use async_trait::async_trait;
+use color_eyre::eyre::{eyre, WrapErr};
+use color_eyre::Result;
+use reqwest::Client;
+use serde::Serialize;
+use url::Url;
+
+/// Where the key signing the settlement transactions is held.
+#[derive(Clone, Debug)]
+pub enum EthereumSignerConfig {
+    /// Hex encoded private key.
+    PrivateKey(String),
+    /// Encrypted JSON keystore file.
+    Keystore { path: PathBuf, password: String },
+    /// Remote signer exposing the web3signer `eth1/sign` endpoint.
+    Remote { url: Url, address: Address },
+}
+
+impl EthereumSignerConfig {
+    pub fn wallet(&self) -> Result<EthereumWallet> {
+        Ok(match self {
+            Self::PrivateKey(private_key) => {
+                let signer: PrivateKeySigner = private_key.parse().wrap_err("Failed to parse private key")?;
+                signer.into()
+            }
+            Self::Keystore { path, password } => PrivateKeySigner::decrypt_keystore(path, password)
+                .wrap_err_with(|| format!("Failed to decrypt keystore {}", path.display()))?
+                .into(),
+            Self::Remote { url, address } => RemoteSigner::new(url.clone(), *address).into(),
+        })
+    }
+}
+
+#[derive(Serialize)]
+struct SignRequest {
+    data: Bytes,
+}
+
+/// Signs transactions with a web3signer compatible remote signer, through its `eth1/sign` endpoint which signs the
+/// keccak hash of the given data with the key of a public key.
+///
+/// The signing payload of the transaction is sent rather than the transaction itself, so that every transaction type
+/// is signed the same way, including the blob transactions of the state updates.
+#[derive(Clone)]
+pub struct RemoteSigner {
+    client: Client,
+    url: Url,
+    address: Address,
+}
+
+impl RemoteSigner {
+    pub fn new(url: Url, address: Address) -> Self {
+        Self { client: Client::new(), url, address }
+    }
+
+    fn endpoint(&self, path: &str) -> Result<Url> {
+        Ok(Url::parse(&format!("{}/api/v1/eth1/{path}", self.url.as_str().trim_end_matches('/')))?)
+    }
+
+    /// Looks up the public key of `address` among the keys of the remote signer, which identifies the signing key.
+    async fn public_key(&self) -> Result<String> {
+        let public_keys: Vec<String> =
+            self.client.get(self.endpoint("publicKeys")?).send().await?.error_for_status()?.json().await?;
+        public_keys
+            .into_iter()
+            .find(|public_key| public_key_address(public_key) == Some(self.address))
+            .ok_or_else(|| eyre!("The remote signer does not hold the key of {}", self.address))
+    }
+
+    async fn sign_payload(&self, payload: Vec<u8>) -> Result<PrimitiveSignature> {
+        let public_key = self.public_key().await?;
+        let signature = self
+            .client
+            .post(self.endpoint(&format!("sign/{public_key}"))?)
+            .json(&SignRequest { data: payload.into() })
+            .send()
+            .await?
+            .error_for_status()?
+            .text()
+            .await?;
+        let signature: Bytes = signature.trim().trim_matches('"').parse()?;
+        Ok(PrimitiveSignature::from_raw(&signature)?)
+    }
+}
+
+/// Address of a hex encoded uncompressed secp256k1 public key, with or without its `0x04` prefix.
+fn public_key_address(public_key: &str) -> Option<Address> {
+    let public_key = hex::decode(public_key).ok()?;
+    let public_key = match public_key.as_slice() {
+        [0x04, public_key @ ..] if public_key.len() == 64 => public_key,
+        public_key => public_key,
+    };
+    (public_key.len() == 64).then(|| Address::from_raw_public_key(public_key))
+}
+
+#[async_trait]
+impl TxSigner<PrimitiveSignature> for RemoteSigner {
+    fn address(&self) -> Address {
+        self.address
+    }
+
+    async fn sign_transaction(
+        &self,
+        tx: &mut dyn SignableTransaction<PrimitiveSignature>,
+    ) -> alloy::signers::Result<PrimitiveSignature> {
+        let mut payload = Vec::new();
+        tx.encode_for_signing(&mut payload);
+        let signature = self.sign_payload(payload).await.map_err(|e| alloy::signers::Error::other(e.to_string()))?;
+
+        // Makes sure the signer signed this exact transaction with the expected key.
+        if signature.recover_address_from_prehash(&tx.signature_hash())? != self.address {
+            return Err(alloy::signers::Error::other("Remote signer did not sign the requested transaction"));
+        }
+        Ok(signature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::consensus::{
+        BlobTransactionSidecar, TxEip1559, TxEip4844, TxEip4844Variant, TxEip4844WithSidecar, TxEnvelope, TxType,
+    };
+    use alloy::primitives::{TxKind, B256};
+    use alloy::signers::Signer;
+    use httpmock::prelude::*;
+    use rstest::*;
+    use serde_json::json;
+
+    use super::*;
+
+    /// Mocks the web3signer endpoints holding the key of `local_signer`, which signs `payload` with `signature`.
+    fn mock_remote_signer<'a>(
+        server: &'a MockServer,
+        local_signer: &PrivateKeySigner,
+        payload: &[u8],
+        signature: PrimitiveSignature,
+    ) -> httpmock::Mock<'a> {
+        let public_key =
+            hex::encode_prefixed(local_signer.credential().verifying_key().to_encoded_point(false).as_bytes());
+        server.mock(|when, then| {
+            when.method(GET).path("/api/v1/eth1/publicKeys");
+            then.status(200).json_body(json!([hex::encode_prefixed(B256::ZERO), public_key]));
+        });
+        server.mock(|when, then| {
+            when.method(POST)
+                .path(format!("/api/v1/eth1/sign/{public_key}"))
+                .json_body(json!({ "data": hex::encode_prefixed(payload) }));
+            then.status(200).body(hex::encode_prefixed(signature.as_bytes()));
+        })
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_remote_signer() {
+        let local_signer = PrivateKeySigner::random();
+        let mut tx = TxEip1559 {
+            chain_id: 1,
+            nonce: 3,
+            gas_limit: 21_000,
+            max_fee_per_gas: 20,
+            max_priority_fee_per_gas: 1,
+            to: TxKind::Call(Address::ZERO),
+            ..Default::default()
+        };
+        let signature = local_signer.sign_transaction(&mut tx).await.unwrap();
+
+        let server = MockServer::start();
+        let sign_mock = mock_remote_signer(&server, &local_signer, &tx.encoded_for_signing(), signature);
+
+        let remote_signer = RemoteSigner::new(server.base_url().parse().unwrap(), local_signer.address());
+        assert_eq!(remote_signer.sign_transaction(&mut tx).await.unwrap(), signature);
+        sign_mock.assert();
+    }
+
+    #[rstest]
+    #[tokio::test]
+    /// The blob transactions of the state updates are signed with their sidecar by the wallet of the settlement client.
+    async fn test_remote_signer_signs_blob_tx() {
+        let local_signer = PrivateKeySigner::random();
+        let tx = TxEip4844 {
+            chain_id: 1,
+            nonce: 3,
+            gas_limit: 100_000,
+            max_fee_per_gas: 20,
+            max_priority_fee_per_gas: 1,
+            max_fee_per_blob_gas: 5,
+            blob_versioned_hashes: vec![B256::with_last_byte(1)],
+            ..Default::default()
+        };
+        let signature = local_signer.sign_hash(&tx.signature_hash()).await.unwrap();
+
+        let server = MockServer::start();
+        let sign_mock = mock_remote_signer(&server, &local_signer, &tx.encoded_for_signing(), signature);
+
+        let wallet =
+            EthereumSignerConfig::Remote { url: server.base_url().parse().unwrap(), address: local_signer.address() }
+                .wallet()
+                .unwrap();
+        let mut variant =
+            TxEip4844Variant::from(TxEip4844WithSidecar { tx, sidecar: BlobTransactionSidecar::default() });
+        let remote_signature = wallet.default_signer().sign_transaction(&mut variant).await.unwrap();
+        assert_eq!(remote_signature, signature);
+        sign_mock.assert();
+
+        let signed_tx: TxEnvelope = variant.into_signed(remote_signature).into();
+        assert_eq!(signed_tx.tx_type(), TxType::Eip4844);
+        assert_eq!(signed_tx.recover_signer().unwrap(), local_signer.address());
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_remote_signer_rejects_wrong_signature() {
+        let local_signer = PrivateKeySigner::random();
+        let mut tx = TxEip1559 { chain_id: 1, nonce: 3, ..Default::default() };
+        // The remote signer signs another transaction
+        let other_tx = TxEip1559 { nonce: 4, ..tx.clone() };
+        let signature = local_signer.sign_hash(&other_tx.signature_hash()).await.unwrap();
+
+        let server = MockServer::start();
+        mock_remote_signer(&server, &local_signer, &tx.encoded_for_signing(), signature);
+
+        let remote_signer = RemoteSigner::new(server.base_url().parse().unwrap(), local_signer.address());
+        assert!(remote_signer.sign_transaction(&mut tx).await.is_err());
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_remote_signer_without_key() {
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET).path("/api/v1/eth1/publicKeys");
+            then.status(200).json_body(json!([]));
+        });
+
+        let remote_signer = RemoteSigner::new(server.base_url().parse().unwrap(), Address::ZERO);
+        assert!(remote_signer.sign_transaction(&mut TxEip1559::default()).await.is_err());
+    }
+
+    #[rstest]
+    fn test_keystore_signer() {
+        let dir = tempfile::tempdir().unwrap();
+        let (signer, file_name) =
+            PrivateKeySigner::new_keystore(dir.path(), &mut rand::thread_rng(), "password", None).unwrap();
+
+        let config = EthereumSignerConfig::Keystore { path: dir.path().join(file_name), password: "password".into() };
+        assert_eq!(config.wallet().unwrap().default_signer().address(), signer.address());
+
+        let config = EthereumSignerConfig::Keystore { path: dir.path().join("missing"), password: "password".into() };
+        assert!(config.wallet().is_err());
+    }
+}
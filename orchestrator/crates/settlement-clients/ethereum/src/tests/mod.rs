@@ -128,21 +128,26 @@ mod settlement_client_tests {
     use alloy::consensus::Transaction;
     use alloy::eips::eip4844::BYTES_PER_BLOB;
     use alloy::primitives::Address;
+    use alloy::providers::ext::AnvilApi;
     use alloy::providers::Provider;
     use alloy::sol_types::private::U256;
     use alloy_primitives::B256;
     use color_eyre::eyre::eyre;
-    use orchestrator_settlement_client_interface::{SettlementClient, SettlementVerificationStatus};
+    use orchestrator_settlement_client_interface::{
+        SettlementClient, SettlementTx, SettlementVerificationStatus, TxReplacement,
+    };
     use orchestrator_utils::env_utils::get_env_var_or_panic;
     use rstest::rstest;
     use tokio::time::sleep;
 
     use super::{BLOCK_TIME, ENV_FILE_PATH};
     use crate::conversion::to_padded_hex;
+    use crate::signer::EthereumSignerConfig;
     use crate::tests::{
         DummyCoreContract, EthereumTestBuilder, Pipe, CURRENT_PATH, MADARA_ORCHESTRATOR_STARKNET_OPERATOR_ADDRESS,
         STARKNET_CORE_CONTRACT, STARKNET_CORE_CONTRACT_ADDRESS,
     };
+    use crate::tx_manager::GasEscalationPolicy;
     use crate::types::{bytes_be_to_u128, convert_stark_bigint_to_u256};
     use crate::{EthereumSettlementClient, EthereumSettlementValidatedArgs, Y_HIGH_POINT_OFFSET, Y_LOW_POINT_OFFSET};
 
@@ -161,7 +166,9 @@ mod settlement_client_tests {
 
         let ethereum_settlement_params = EthereumSettlementValidatedArgs {
             ethereum_rpc_url: setup.rpc_url,
-            ethereum_private_key: get_env_var_or_panic("MADARA_ORCHESTRATOR_ETHEREUM_PRIVATE_KEY"),
+            ethereum_signer: EthereumSignerConfig::PrivateKey(get_env_var_or_panic(
+                "MADARA_ORCHESTRATOR_ETHEREUM_PRIVATE_KEY",
+            )),
             l1_core_contract_address: Address::from_str(&get_env_var_or_panic(
                 "MADARA_ORCHESTRATOR_L1_CORE_CONTRACT_ADDRESS",
             ))
@@ -170,6 +177,7 @@ mod settlement_client_tests {
                 "MADARA_ORCHESTRATOR_STARKNET_OPERATOR_ADDRESS",
             ))
             .expect("Invalid Starknet operator address"),
+            gas_escalation_policy: GasEscalationPolicy::default(),
         };
 
         // Deploying a dummy contract
@@ -193,7 +201,8 @@ mod settlement_client_tests {
         let update_state_result = ethereum_settlement_client
             .update_state_with_blobs(program_output, blob_data_vec, nonce)
             .await
-            .expect("Could not go through update_state_with_blobs.");
+            .expect("Could not go through update_state_with_blobs.")
+            .tx_hash;
 
         // Asserting, Expected to receive transaction hash.
         assert!(!update_state_result.is_empty(), "No transaction Hash received.");
@@ -222,6 +231,97 @@ mod settlement_client_tests {
         assert_eq!(verified_inclusion, SettlementVerificationStatus::Verified);
     }
 
+    #[rstest]
+    #[tokio::test]
+    /// Tests that a blob transaction stuck by a fee spike is replaced with escalated fees once it has been pending for
+    /// long enough, from the parameters it was sent with as after a restart, and that only the replacement is included.
+    async fn replace_pending_tx_works() {
+        dotenvy::from_filename_override(&*ENV_FILE_PATH).expect("Could not load .env.test file.");
+
+        let setup = EthereumTestBuilder::new().build().await;
+
+        let contract = DummyCoreContract::deploy(&setup.provider).await.expect("Unable to deploy address");
+        let gas_escalation_policy =
+            GasEscalationPolicy { max_replacements: 1, min_pending_blocks: 2, ..Default::default() };
+        let ethereum_settlement_client = EthereumSettlementClient::with_test_params(
+            setup.provider.clone(),
+            *contract.address(),
+            setup.rpc_url.clone(),
+            None,
+        )
+        .with_gas_escalation_policy(gas_escalation_policy.clone());
+
+        // Transactions stay pending until a block is mined manually
+        setup.provider.anvil_set_interval_mining(0).await.expect("Unable to stop mining");
+
+        let nonce = ethereum_settlement_client.get_nonce().await.expect("Unable to fetch nonce");
+        let tx = ethereum_settlement_client
+            .update_state_with_blobs(vec![[0; 32]; 16], vec![vec![0; BYTES_PER_BLOB]], nonce)
+            .await
+            .expect("Could not go through update_state_with_blobs.");
+
+        // Replacements are built from the same program output and state diff as the transaction
+        async fn replace(client: &EthereumSettlementClient, tx: &SettlementTx, replacements: u64) -> TxReplacement {
+            client
+                .replace_pending_tx(tx, replacements, vec![[0; 32]; 16], vec![vec![0; BYTES_PER_BLOB]])
+                .await
+                .expect("Could not replace the pending transaction.")
+        }
+        assert_eq!(replace(&ethereum_settlement_client, &tx, 0).await, TxReplacement::TooRecent);
+
+        // The fees spike above the fees of the transaction, which stays pending while blocks are mined
+        setup
+            .provider
+            .anvil_set_next_block_base_fee_per_gas(U256::from(tx.max_fee_per_gas) * U256::from(10))
+            .await
+            .expect("Unable to set the base fee");
+        setup.provider.anvil_mine(Some(U256::from(2)), None).await.expect("Unable to mine blocks");
+        assert_eq!(
+            ethereum_settlement_client.verify_tx_inclusion(&tx.tx_hash).await.unwrap(),
+            SettlementVerificationStatus::Pending
+        );
+
+        // A client which did not send the transaction replaces it, as after a restart
+        let restarted_client = EthereumSettlementClient::with_test_params(
+            setup.provider.clone(),
+            *contract.address(),
+            setup.rpc_url.clone(),
+            None,
+        )
+        .with_gas_escalation_policy(gas_escalation_policy);
+        let TxReplacement::Replaced(replacement) = replace(&restarted_client, &tx, 0).await else {
+            panic!("Pending transaction should be replaced.");
+        };
+        assert_ne!(replacement.tx_hash, tx.tx_hash);
+        assert_eq!(replacement.nonce, tx.nonce);
+        assert_eq!(replacement.gas_limit, tx.gas_limit);
+        assert!(replacement.max_fee_per_gas >= tx.max_fee_per_gas * 2);
+        assert!(replacement.max_fee_per_blob_gas >= tx.max_fee_per_blob_gas * 2);
+
+        let sent_replacement = setup
+            .provider
+            .get_transaction_by_hash(B256::from_str(&replacement.tx_hash).expect("Unable to convert txn"))
+            .await
+            .expect("did not get txn from hash")
+            .unwrap();
+        assert_eq!(sent_replacement.inner.max_fee_per_gas(), u128::from(replacement.max_fee_per_gas));
+
+        // Max number of replacements reached
+        assert_eq!(replace(&restarted_client, &replacement, 1).await, TxReplacement::Unavailable);
+
+        setup.provider.anvil_mine(Some(U256::from(1)), None).await.expect("Unable to mine a block");
+        assert_eq!(
+            restarted_client.verify_tx_inclusion(&replacement.tx_hash).await.unwrap(),
+            SettlementVerificationStatus::Verified
+        );
+        assert_eq!(
+            restarted_client.verify_tx_inclusion(&tx.tx_hash).await.unwrap(),
+            SettlementVerificationStatus::Pending
+        );
+        // The nonce is used, there is nothing to replace anymore
+        assert_eq!(replace(&restarted_client, &tx, 0).await, TxReplacement::Unavailable);
+    }
+
     #[rstest]
     #[tokio::test]
     #[case::basic(6806847)]
@@ -239,7 +339,9 @@ mod settlement_client_tests {
 
         let ethereum_settlement_params = EthereumSettlementValidatedArgs {
             ethereum_rpc_url: setup.rpc_url,
-            ethereum_private_key: get_env_var_or_panic("MADARA_ORCHESTRATOR_ETHEREUM_PRIVATE_KEY"),
+            ethereum_signer: EthereumSignerConfig::PrivateKey(get_env_var_or_panic(
+                "MADARA_ORCHESTRATOR_ETHEREUM_PRIVATE_KEY",
+            )),
             l1_core_contract_address: Address::from_str(&get_env_var_or_panic(
                 "MADARA_ORCHESTRATOR_L1_CORE_CONTRACT_ADDRESS",
             ))
@@ -248,6 +350,7 @@ mod settlement_client_tests {
                 "MADARA_ORCHESTRATOR_STARKNET_OPERATOR_ADDRESS",
             ))
             .expect("Invalid Starknet operator address"),
+            gas_escalation_policy: GasEscalationPolicy::default(),
         };
 
         let ethereum_settlement_client = EthereumSettlementClient::with_test_params(
@@ -278,7 +381,8 @@ mod settlement_client_tests {
         let update_state_result = ethereum_settlement_client
             .update_state_with_blobs(program_output.clone(), blob_data_vec, nonce)
             .await
-            .expect("Could not go through update_state_with_blobs.");
+            .expect("Could not go through update_state_with_blobs.")
+            .tx_hash;
 
         // Asserting, Expected to receive transaction hash.
         assert!(!update_state_result.is_empty(), "No transaction Hash received.");
@@ -311,7 +415,9 @@ mod settlement_client_tests {
 
         let ethereum_settlement_params = EthereumSettlementValidatedArgs {
             ethereum_rpc_url: setup.rpc_url,
-            ethereum_private_key: get_env_var_or_panic("MADARA_ORCHESTRATOR_ETHEREUM_PRIVATE_KEY"),
+            ethereum_signer: EthereumSignerConfig::PrivateKey(get_env_var_or_panic(
+                "MADARA_ORCHESTRATOR_ETHEREUM_PRIVATE_KEY",
+            )),
             l1_core_contract_address: Address::from_str(&get_env_var_or_panic(
                 "MADARA_ORCHESTRATOR_L1_CORE_CONTRACT_ADDRESS",
             ))
@@ -320,6 +426,7 @@ mod settlement_client_tests {
                 "MADARA_ORCHESTRATOR_STARKNET_OPERATOR_ADDRESS",
             ))
             .expect("Invalid Starknet operator address"),
+            gas_escalation_policy: GasEscalationPolicy::default(),
         };
 
         let ethereum_settlement_client = EthereumSettlementClient::with_test_params(
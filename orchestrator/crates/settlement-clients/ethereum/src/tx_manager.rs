@@ -0,0 +1,135 @@
+use alloy::consensus::TxEip4844;
+
+/// Geth only replaces a pending blob transaction when all its fees are at least doubled.
+pub const DEFAULT_FEE_BUMP_PERCENT: u64 = 100;
+pub const DEFAULT_MAX_TX_REPLACEMENTS: u64 = 5;
+/// About two minutes of Ethereum blocks, longer than the polling delay of the state update verification.
+pub const DEFAULT_MIN_PENDING_BLOCKS: u64 = 10;
+
+/// How the fees of a stuck settlement transaction are escalated when it is replaced.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GasEscalationPolicy {
+    /// Percentage by which all the fees of the pending transaction are bumped.
+    pub fee_bump_percent: u64,
+    /// Max `max_fee_per_gas` of a replacement transaction, in wei.
+    pub max_fee_per_gas_cap: Option<u128>,
+    /// Max `max_fee_per_blob_gas` of a replacement transaction, in wei.
+    pub max_fee_per_blob_gas_cap: Option<u128>,
+    /// Max number of replacements sent for a nonce.
+    pub max_replacements: u64,
+    /// Number of blocks a transaction stays pending before it is replaced.
+    pub min_pending_blocks: u64,
+}
+
+impl Default for GasEscalationPolicy {
+    fn default() -> Self {
+        Self {
+            fee_bump_percent: DEFAULT_FEE_BUMP_PERCENT,
+            max_fee_per_gas_cap: None,
+            max_fee_per_blob_gas_cap: None,
+            max_replacements: DEFAULT_MAX_TX_REPLACEMENTS,
+            min_pending_blocks: DEFAULT_MIN_PENDING_BLOCKS,
+        }
+    }
+}
+
+/// Fees currently asked by the network, the fees of a replacement transaction are never lower.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NetworkFees {
+    pub max_fee_per_gas: u128,
+    pub max_priority_fee_per_gas: u128,
+    pub max_fee_per_blob_gas: u128,
+}
+
+impl GasEscalationPolicy {
+    /// Whether a transaction sent at `sent_at_block` has been pending for long enough to be replaced.
+    pub fn is_stuck(&self, sent_at_block: u64, latest_block: u64) -> bool {
+        latest_block.saturating_sub(sent_at_block) >= self.min_pending_blocks
+    }
+
+    /// Returns `tx` with bumped fees, or `None` when the caps do not leave room for a replacement.
+    pub fn escalate(&self, tx: &TxEip4844, network_fees: NetworkFees) -> Option<TxEip4844> {
+        let max_fee_per_gas = self.bump(tx.max_fee_per_gas).max(network_fees.max_fee_per_gas);
+        let max_fee_per_blob_gas = self.bump(tx.max_fee_per_blob_gas).max(network_fees.max_fee_per_blob_gas);
+
+        // Nodes reject a replacement unless all its fees are bumped, so a capped fee can't be used if it is too low.
+        let max_fee_per_gas = self.max_fee_per_gas_cap.map_or(max_fee_per_gas, |cap| max_fee_per_gas.min(cap));
+        let max_fee_per_blob_gas =
+            self.max_fee_per_blob_gas_cap.map_or(max_fee_per_blob_gas, |cap| max_fee_per_blob_gas.min(cap));
+        if max_fee_per_gas < self.bump(tx.max_fee_per_gas) || max_fee_per_blob_gas < self.bump(tx.max_fee_per_blob_gas)
+        {
+            return None;
+        }
+
+        let max_priority_fee_per_gas =
+            self.bump(tx.max_priority_fee_per_gas).max(network_fees.max_priority_fee_per_gas).min(max_fee_per_gas);
+
+        Some(TxEip4844 { max_fee_per_gas, max_priority_fee_per_gas, max_fee_per_blob_gas, ..tx.clone() })
+    }
+
+    fn bump(&self, fee: u128) -> u128 {
+        if self.fee_bump_percent == 0 {
+            return fee;
+        }
+        fee.saturating_add((fee.saturating_mul(self.fee_bump_percent.into()) / 100).max(1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+
+    use super::*;
+
+    fn tx(max_fee_per_gas: u128, max_priority_fee_per_gas: u128, max_fee_per_blob_gas: u128) -> TxEip4844 {
+        TxEip4844 { max_fee_per_gas, max_priority_fee_per_gas, max_fee_per_blob_gas, ..Default::default() }
+    }
+
+    #[rstest]
+    #[case::doubled(GasEscalationPolicy::default(), NetworkFees::default(), Some((200, 20, 2)))]
+    #[case::network_fees_are_a_floor(
+        GasEscalationPolicy::default(),
+        NetworkFees { max_fee_per_gas: 500, max_priority_fee_per_gas: 50, max_fee_per_blob_gas: 8 },
+        Some((500, 50, 8))
+    )]
+    #[case::under_caps(
+        GasEscalationPolicy { max_fee_per_gas_cap: Some(300), max_fee_per_blob_gas_cap: Some(2), ..Default::default() },
+        NetworkFees { max_fee_per_gas: 500, ..Default::default() },
+        Some((300, 20, 2))
+    )]
+    #[case::gas_cap_reached(
+        GasEscalationPolicy { max_fee_per_gas_cap: Some(150), ..Default::default() },
+        NetworkFees::default(),
+        None
+    )]
+    #[case::blob_gas_cap_reached(
+        GasEscalationPolicy { max_fee_per_blob_gas_cap: Some(1), ..Default::default() },
+        NetworkFees::default(),
+        None
+    )]
+    #[case::small_bump(
+        GasEscalationPolicy { fee_bump_percent: 10, ..Default::default() },
+        NetworkFees::default(),
+        Some((110, 11, 2))
+    )]
+    fn test_escalate(
+        #[case] policy: GasEscalationPolicy,
+        #[case] network_fees: NetworkFees,
+        #[case] expected: Option<(u128, u128, u128)>,
+    ) {
+        let escalated = policy.escalate(&tx(100, 10, 1), network_fees);
+        assert_eq!(
+            escalated.map(|tx| (tx.max_fee_per_gas, tx.max_priority_fee_per_gas, tx.max_fee_per_blob_gas)),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case::just_sent(100, 100, false)]
+    #[case::too_recent(100, 109, false)]
+    #[case::stuck(100, 110, true)]
+    #[case::reorged(100, 90, false)]
+    fn test_is_stuck(#[case] sent_at_block: u64, #[case] latest_block: u64, #[case] expected: bool) {
+        assert_eq!(GasEscalationPolicy::default().is_stuck(sent_at_block, latest_block), expected);
+    }
+}
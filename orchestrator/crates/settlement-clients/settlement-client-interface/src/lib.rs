@@ -12,6 +12,33 @@ pub enum SettlementVerificationStatus {
     Rejected(String),
 }
 
+/// A settlement tx sent with blobs, with the parameters it was sent with. It is kept by the caller so that the tx can
+/// be replaced if it gets stuck, even after a restart.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SettlementTx {
+    pub tx_hash: String,
+    pub nonce: u64,
+    pub gas_limit: u64,
+    /// Fees of the tx, in wei
+    pub max_fee_per_gas: u64,
+    pub max_priority_fee_per_gas: u64,
+    pub max_fee_per_blob_gas: u64,
+    /// Latest block of the settlement layer when the tx was sent
+    pub sent_at_block: u64,
+}
+
+/// Result of an attempt to replace a pending settlement tx.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TxReplacement {
+    /// The tx was sent again with higher fees
+    Replaced(SettlementTx),
+    /// The tx has not been pending for long enough to be replaced
+    TooRecent,
+    /// The tx can't be replaced: a tx was already included for its nonce, the limits of the fee escalation are
+    /// reached, or the settlement layer does not support replacements
+    Unavailable,
+}
+
 /// Trait for every new Settlement Layer to implement
 #[automock]
 #[async_trait]
@@ -35,7 +62,18 @@ pub trait SettlementClient: Send + Sync {
         program_output: Vec<[u8; 32]>,
         state_diff: Vec<Vec<u8>>,
         nonce: u64,
-    ) -> Result<String>;
+    ) -> Result<SettlementTx>;
+
+    /// Should re-broadcast the pending tx with the same nonce and higher fees, so that it does not stay stuck when
+    /// fees spike. The tx is built again from the program output and state diff it was sent with, and
+    /// `replacements` is the number of times it was already replaced.
+    async fn replace_pending_tx(
+        &self,
+        pending_tx: &SettlementTx,
+        replacements: u64,
+        program_output: Vec<[u8; 32]>,
+        state_diff: Vec<Vec<u8>>,
+    ) -> Result<TxReplacement>;
 
     /// Should verify the inclusion of a tx in the settlement layer
     async fn verify_tx_inclusion(&self, tx_hash: &str) -> Result<SettlementVerificationStatus>;
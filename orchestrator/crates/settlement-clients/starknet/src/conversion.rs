@@ -1,5 +1,5 @@
 use color_eyre::Result;
-use starknet::core::types::Felt;
+use starknet::core::types::{Felt, U256};
 
 pub(crate) fn slice_slice_u8_to_vec_field(slices: &[[u8; 32]]) -> Vec<Felt> {
     slices.iter().map(slice_u8_to_field).collect()
@@ -9,6 +9,26 @@ pub(crate) fn slice_u8_to_field(slice: &[u8; 32]) -> Felt {
     Felt::from_bytes_be_slice(slice)
 }
 
+/// Serializes the arguments of the core contract `update_state` entrypoint:
+/// `update_state(snos_output: Array<felt252>, program_output: Span<felt252>, onchain_data_hash: felt252,
+/// onchain_data_size: u256)`.
+pub(crate) fn encode_update_state_calldata(
+    snos_output: &[Felt],
+    program_output: &[Felt],
+    onchain_data_hash: Felt,
+    onchain_data_size: U256,
+) -> Vec<Felt> {
+    let mut calldata = Vec::with_capacity(snos_output.len() + program_output.len() + 5);
+    calldata.push(Felt::from(snos_output.len()));
+    calldata.extend_from_slice(snos_output);
+    calldata.push(Felt::from(program_output.len()));
+    calldata.extend_from_slice(program_output);
+    calldata.push(onchain_data_hash);
+    calldata.push(Felt::from(onchain_data_size.low()));
+    calldata.push(Felt::from(onchain_data_size.high()));
+    calldata
+}
+
 pub(crate) fn u64_from_felt(number: Felt) -> Result<u64> {
     let bytes = number.to_bytes_be();
 
@@ -36,3 +56,18 @@ fn test_u64_from_from_felt_panic() {
         Err(e) => tracing::error!("Error getting nonce: {:?}", e),
     }
 }
+
+#[test]
+fn test_encode_update_state_calldata() {
+    let calldata = encode_update_state_calldata(
+        &[Felt::from(1), Felt::from(2)],
+        &[Felt::from(3)],
+        Felt::from(4),
+        U256::from_words(5, 6),
+    );
+    assert_eq!(
+        calldata,
+        [2, 1, 2, 1, 3, 4, 5, 6].into_iter().map(Felt::from).collect::<Vec<_>>(),
+        "Arrays are prefixed by their length and the u256 is split in low and high words"
+    );
+}
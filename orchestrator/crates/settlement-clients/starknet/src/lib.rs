@@ -1,34 +1,36 @@
 pub mod config;
 pub mod conversion;
+pub mod signer;
 #[cfg(test)]
 pub mod tests;
 
 use std::sync::Arc;
 
-use appchain_core_contract_client::clients::StarknetCoreContractClient;
-use appchain_core_contract_client::interfaces::core_contract::CoreContract;
 use async_trait::async_trait;
 use color_eyre::eyre::{eyre, Context};
 use color_eyre::Result;
 use lazy_static::lazy_static;
 use mockall::automock;
 use mockall::predicate::*;
-use orchestrator_settlement_client_interface::{SettlementClient, SettlementVerificationStatus};
-use starknet::accounts::{ConnectedAccount, ExecutionEncoding, SingleOwnerAccount};
-use starknet::core::types::{BlockId, BlockTag, Felt, FunctionCall, TransactionExecutionStatus, U256};
+use orchestrator_settlement_client_interface::{
+    SettlementClient, SettlementTx, SettlementVerificationStatus, TxReplacement,
+};
+use starknet::accounts::{Account, ConnectedAccount, ExecutionEncoding, SingleOwnerAccount};
+use starknet::core::types::{BlockId, BlockTag, Call, Felt, FunctionCall, TransactionExecutionStatus, U256};
 use starknet::core::utils::get_selector_from_name;
 use starknet::providers::jsonrpc::HttpTransport;
 use starknet::providers::{JsonRpcClient, Provider};
-use starknet::signers::{LocalWallet, SigningKey};
+use starknet::signers::LocalWallet;
 use tokio::time::{sleep, Duration};
 
-use crate::conversion::{slice_slice_u8_to_vec_field, slice_u8_to_field, u64_from_felt};
+use crate::conversion::{encode_update_state_calldata, slice_slice_u8_to_vec_field, slice_u8_to_field, u64_from_felt};
+use crate::signer::{StarknetSigner, StarknetSignerConfig};
 
 pub type LocalWalletSignerMiddleware = Arc<SingleOwnerAccount<Arc<JsonRpcClient<HttpTransport>>, LocalWallet>>;
+pub type StarknetSignerMiddleware = Arc<SingleOwnerAccount<Arc<JsonRpcClient<HttpTransport>>, StarknetSigner>>;
 
 pub struct StarknetSettlementClient {
-    pub account: LocalWalletSignerMiddleware,
-    pub starknet_core_contract_client: StarknetCoreContractClient,
+    pub account: StarknetSignerMiddleware,
     pub core_contract_address: Felt,
     pub tx_finality_retry_delay_in_seconds: u64,
 }
@@ -42,7 +44,7 @@ use url::Url;
 #[derive(Clone, Debug)]
 pub struct StarknetSettlementValidatedArgs {
     pub starknet_rpc_url: Url,
-    pub starknet_private_key: String,
+    pub starknet_signer: StarknetSignerConfig,
     pub starknet_account_address: String,
     pub starknet_cairo_core_contract_address: String,
     pub starknet_finality_retry_wait_in_secs: u64,
@@ -58,9 +60,7 @@ impl StarknetSettlementClient {
 
         let signer_address = Felt::from_hex(&settlement_cfg.starknet_account_address).expect("Invalid signer address");
 
-        let private_key = Felt::from_hex(&settlement_cfg.starknet_private_key).expect("Invalid private key");
-        let signing_key = SigningKey::from_secret_scalar(private_key);
-        let signer = LocalWallet::from(signing_key);
+        let signer = settlement_cfg.starknet_signer.signer().expect("Failed to load the settlement signer");
 
         let core_contract_address = Felt::from_hex(&settlement_cfg.starknet_cairo_core_contract_address)
             .expect("Invalid core contract address");
@@ -74,17 +74,53 @@ impl StarknetSettlementClient {
         account.set_block_id(BlockId::Tag(BlockTag::Pending));
         let account = Arc::new(account);
 
-        let starknet_core_contract_client = StarknetCoreContractClient::new(core_contract_address, account.clone());
-
         StarknetSettlementClient {
             account,
             core_contract_address,
-            starknet_core_contract_client,
             tx_finality_retry_delay_in_seconds: settlement_cfg.starknet_finality_retry_wait_in_secs,
         }
     }
 }
 
+/// Arguments of the core contract `update_state` entrypoint.
+struct UpdateStateArgs {
+    snos_output: Vec<Felt>,
+    program_output: Vec<Felt>,
+    onchain_data_hash: Felt,
+    onchain_data_size: U256,
+}
+
+impl UpdateStateArgs {
+    fn new(
+        snos_output: Vec<[u8; 32]>,
+        program_output: Vec<[u8; 32]>,
+        onchain_data_hash: [u8; 32],
+        onchain_data_size: [u8; 32],
+    ) -> Result<Self> {
+        let low = u128::from_be_bytes(onchain_data_size[16..32].try_into()?);
+        let high = u128::from_be_bytes(onchain_data_size[0..16].try_into()?);
+
+        Ok(Self {
+            snos_output: slice_slice_u8_to_vec_field(snos_output.as_slice()),
+            program_output: slice_slice_u8_to_vec_field(program_output.as_slice()),
+            onchain_data_hash: slice_u8_to_field(&onchain_data_hash),
+            onchain_data_size: U256::from_words(low, high),
+        })
+    }
+}
+
+impl StarknetSettlementClient {
+    /// Call to the core contract `update_state` entrypoint.
+    fn update_state_call(&self, args: UpdateStateArgs) -> Call {
+        let UpdateStateArgs { snos_output, program_output, onchain_data_hash, onchain_data_size: size } = args;
+        Call {
+            to: self.core_contract_address,
+            selector: *CONTRACT_WRITE_UPDATE_STATE_SELECTOR,
+            calldata: encode_update_state_calldata(&snos_output, &program_output, onchain_data_hash, size),
+        }
+    }
+}
+
 lazy_static! {
     pub static ref CONTRACT_WRITE_UPDATE_STATE_SELECTOR: Felt =
         get_selector_from_name("update_state").expect("Invalid update state selector");
@@ -126,17 +162,11 @@ impl SettlementClient for StarknetSettlementClient {
             function_type = "calldata",
             "Updating state with calldata."
         );
-        let snos_output = slice_slice_u8_to_vec_field(snos_output.as_slice());
-        let program_output = slice_slice_u8_to_vec_field(program_output.as_slice());
-        let onchain_data_hash = slice_u8_to_field(&onchain_data_hash);
-        let core_contract: &CoreContract = self.starknet_core_contract_client.as_ref();
-
-        let low = u128::from_be_bytes(onchain_data_size[16..32].try_into()?);
-        let high = u128::from_be_bytes(onchain_data_size[0..16].try_into()?);
-        let size = U256::from_words(low, high);
-
-        let invoke_result = core_contract
-            .update_state(snos_output, program_output, onchain_data_hash, size)
+        let args = UpdateStateArgs::new(snos_output, program_output, onchain_data_hash, onchain_data_size)?;
+        let invoke_result = self
+            .account
+            .execute_v3(vec![self.update_state_call(args)])
+            .send()
             .await
             .map_err(|e| eyre!("Failed to update state with calldata: {:?}", e))?;
         tracing::info!(
@@ -203,14 +233,27 @@ impl SettlementClient for StarknetSettlementClient {
     }
 
     /// Should be used to update state on core contract and publishing the blob simultaneously
-    #[allow(unused)]
+    ///
+    /// Starknet has no blobs: the state updates of an L3 are settled with
+    /// [`SettlementClient::update_state_calldata`], whatever the DA mode.
     async fn update_state_with_blobs(
         &self,
-        program_output: Vec<[u8; 32]>,
-        state_diff: Vec<Vec<u8>>,
-        nonce: u64,
-    ) -> Result<String> {
-        !unimplemented!("not implemented yet.")
+        _program_output: Vec<[u8; 32]>,
+        _state_diff: Vec<Vec<u8>>,
+        _nonce: u64,
+    ) -> Result<SettlementTx> {
+        Err(eyre!("Blobs are not supported on Starknet, the state is updated with calldata"))
+    }
+
+    /// Replacing pending transactions is not supported on Starknet.
+    async fn replace_pending_tx(
+        &self,
+        _pending_tx: &SettlementTx,
+        _replacements: u64,
+        _program_output: Vec<[u8; 32]>,
+        _state_diff: Vec<Vec<u8>>,
+    ) -> Result<TxReplacement> {
+        Ok(TxReplacement::Unavailable)
     }
 
     /// Wait for a pending tx to achieve finality
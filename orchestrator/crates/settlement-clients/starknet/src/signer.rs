@@ -0,0 +1,199 @@
+use std::path::PathBuf;
+
+use async_trait::async_trait;
+use color_eyre::eyre::Context;
+use color_eyre::Result;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use starknet::core::crypto::{ecdsa_verify, EcdsaSignError, Signature};
+use starknet::core::types::Felt;
+use starknet::signers::{Signer, SignerInteractivityContext, SigningKey, VerifyingKey};
+use url::Url;
+
+/// Where the key signing the settlement transactions is held.
+#[derive(Clone, Debug)]
+pub enum StarknetSignerConfig {
+    /// Hex encoded private key.
+    PrivateKey(String),
+    /// Encrypted JSON keystore file.
+    Keystore { path: PathBuf, password: String },
+    /// Remote signer exposing the `starknet_signHash` JSON-RPC method.
+    Remote { url: Url, public_key: Felt },
+}
+
+impl StarknetSignerConfig {
+    pub fn signer(&self) -> Result<StarknetSigner> {
+        Ok(match self {
+            Self::PrivateKey(private_key) => {
+                let private_key = Felt::from_hex(private_key).wrap_err("Invalid private key")?;
+                StarknetSigner::Local(SigningKey::from_secret_scalar(private_key))
+            }
+            Self::Keystore { path, password } => StarknetSigner::Local(
+                SigningKey::from_keystore(path, password)
+                    .wrap_err_with(|| format!("Failed to decrypt keystore {}", path.display()))?,
+            ),
+            Self::Remote { url, public_key } => StarknetSigner::Remote(RemoteSigner::new(url.clone(), *public_key)),
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum StarknetSignerError {
+    #[error("Failed to sign hash: {0}")]
+    Local(#[from] EcdsaSignError),
+    #[error("Remote signer request failed: {0}")]
+    Request(#[from] reqwest::Error),
+    #[error("Remote signer returned an error: {0}")]
+    Rpc(String),
+    #[error("Remote signer did not sign the requested hash")]
+    InvalidSignature,
+}
+
+/// Signer of the settlement account, holding its key locally or delegating the signing to a remote signer.
+#[derive(Clone, Debug)]
+pub enum StarknetSigner {
+    Local(SigningKey),
+    Remote(RemoteSigner),
+}
+
+#[async_trait]
+impl Signer for StarknetSigner {
+    type GetPublicKeyError = StarknetSignerError;
+    type SignError = StarknetSignerError;
+
+    async fn get_public_key(&self) -> Result<VerifyingKey, Self::GetPublicKeyError> {
+        Ok(match self {
+            Self::Local(signing_key) => signing_key.verifying_key(),
+            Self::Remote(remote_signer) => VerifyingKey::from_scalar(remote_signer.public_key),
+        })
+    }
+
+    async fn sign_hash(&self, hash: &Felt) -> Result<Signature, Self::SignError> {
+        match self {
+            Self::Local(signing_key) => Ok(signing_key.sign(hash)?),
+            Self::Remote(remote_signer) => remote_signer.sign_hash(hash).await,
+        }
+    }
+
+    fn is_interactive(&self, _context: SignerInteractivityContext<'_>) -> bool {
+        false
+    }
+}
+
+#[derive(Serialize)]
+struct JsonRpcRequest<'a> {
+    jsonrpc: &'static str,
+    id: u64,
+    method: &'static str,
+    params: (Felt, &'a Felt),
+}
+
+#[derive(Deserialize)]
+struct JsonRpcResponse {
+    result: Option<[Felt; 2]>,
+    error: Option<JsonRpcError>,
+}
+
+#[derive(Deserialize)]
+struct JsonRpcError {
+    message: String,
+}
+
+/// Signs hashes with a remote signer, through the `starknet_signHash` JSON-RPC method.
+///
+/// The method takes the public key of the signing key and the hash to sign, and returns the `[r, s]` signature.
+#[derive(Clone, Debug)]
+pub struct RemoteSigner {
+    client: Client,
+    url: Url,
+    public_key: Felt,
+}
+
+impl RemoteSigner {
+    pub fn new(url: Url, public_key: Felt) -> Self {
+        Self { client: Client::new(), url, public_key }
+    }
+
+    async fn sign_hash(&self, hash: &Felt) -> Result<Signature, StarknetSignerError> {
+        let request =
+            JsonRpcRequest { jsonrpc: "2.0", id: 1, method: "starknet_signHash", params: (self.public_key, hash) };
+        let response: JsonRpcResponse =
+            self.client.post(self.url.clone()).json(&request).send().await?.error_for_status()?.json().await?;
+        if let Some(error) = response.error {
+            return Err(StarknetSignerError::Rpc(error.message));
+        }
+        let [r, s] = response.result.ok_or_else(|| StarknetSignerError::Rpc("Missing signature".to_string()))?;
+
+        // Makes sure the signer signed this exact hash with the expected key.
+        if !matches!(ecdsa_verify(&self.public_key, hash, &Signature { r, s }), Ok(true)) {
+            return Err(StarknetSignerError::InvalidSignature);
+        }
+        Ok(Signature { r, s })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+    use rstest::*;
+    use serde_json::json;
+
+    use super::*;
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_remote_signer() {
+        let signing_key = SigningKey::from_random();
+        let hash = Felt::from(42);
+        let signature = signing_key.sign(&hash).unwrap();
+
+        let server = MockServer::start();
+        let sign_mock = server.mock(|when, then| {
+            when.method(POST).body_contains("starknet_signHash");
+            then.status(200).json_body(json!({ "jsonrpc": "2.0", "id": 1, "result": [signature.r, signature.s] }));
+        });
+
+        let config = StarknetSignerConfig::Remote {
+            url: server.base_url().parse().unwrap(),
+            public_key: signing_key.verifying_key().scalar(),
+        };
+        let signer = config.signer().unwrap();
+        assert_eq!(signer.get_public_key().await.unwrap().scalar(), signing_key.verifying_key().scalar());
+        let remote_signature = signer.sign_hash(&hash).await.unwrap();
+        assert_eq!((remote_signature.r, remote_signature.s), (signature.r, signature.s));
+        sign_mock.assert();
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_remote_signer_rejects_wrong_signature() {
+        let signing_key = SigningKey::from_random();
+        // The remote signer signs another hash
+        let signature = signing_key.sign(&Felt::from(43)).unwrap();
+
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(POST);
+            then.status(200).json_body(json!({ "jsonrpc": "2.0", "id": 1, "result": [signature.r, signature.s] }));
+        });
+
+        let remote_signer = RemoteSigner::new(server.base_url().parse().unwrap(), signing_key.verifying_key().scalar());
+        assert!(matches!(remote_signer.sign_hash(&Felt::from(42)).await, Err(StarknetSignerError::InvalidSignature)));
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_keystore_signer() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("keystore.json");
+        let signing_key = SigningKey::from_random();
+        signing_key.save_as_keystore(&path, "password").unwrap();
+
+        let config = StarknetSignerConfig::Keystore { path, password: "password".into() };
+        let signer = config.signer().unwrap();
+        assert_eq!(signer.get_public_key().await.unwrap().scalar(), signing_key.verifying_key().scalar());
+
+        let config = StarknetSignerConfig::Keystore { path: dir.path().join("missing"), password: "password".into() };
+        assert!(config.signer().is_err());
+    }
+}
@@ -20,6 +20,7 @@ use starknet::providers::{JsonRpcClient, Provider, ProviderError, Url};
 use starknet::signers::{LocalWallet, SigningKey};
 
 use super::setup::{wait_for_cond, MadaraCmd, MadaraCmdBuilder};
+use crate::signer::StarknetSignerConfig;
 use crate::{LocalWalletSignerMiddleware, StarknetSettlementClient, StarknetSettlementValidatedArgs};
 
 #[fixture]
@@ -74,7 +75,9 @@ async fn setup(#[future] spin_up_madara: MadaraCmd) -> (LocalWalletSignerMiddlew
 
     let starknet_settlement_params: StarknetSettlementValidatedArgs = StarknetSettlementValidatedArgs {
         starknet_rpc_url: Url::parse(madara_process.rpc_url.as_ref()).unwrap(),
-        starknet_private_key: get_env_var_or_panic("MADARA_ORCHESTRATOR_STARKNET_PRIVATE_KEY"),
+        starknet_signer: StarknetSignerConfig::PrivateKey(get_env_var_or_panic(
+            "MADARA_ORCHESTRATOR_STARKNET_PRIVATE_KEY",
+        )),
         starknet_account_address: get_env_var_or_panic("MADARA_ORCHESTRATOR_STARKNET_ACCOUNT_ADDRESS"),
         starknet_cairo_core_contract_address: get_env_var_or_panic(
             "MADARA_ORCHESTRATOR_STARKNET_CAIRO_CORE_CONTRACT_ADDRESS",
@@ -90,7 +93,7 @@ async fn setup(#[future] spin_up_madara: MadaraCmd) -> (LocalWalletSignerMiddlew
 
     let provider = Arc::new(JsonRpcClient::new(HttpTransport::new(rpc_url)));
     let signer = LocalWallet::from(SigningKey::from_secret_scalar(
-        Felt::from_hex(&starknet_settlement_params.starknet_private_key).expect("Invalid private key"),
+        Felt::from_hex(&get_env_var_or_panic("MADARA_ORCHESTRATOR_STARKNET_PRIVATE_KEY")).expect("Invalid private key"),
     ));
     let address = Felt::from_hex(&starknet_settlement_params.starknet_account_address.to_string()).unwrap();
 
@@ -103,28 +106,25 @@ async fn setup(#[future] spin_up_madara: MadaraCmd) -> (LocalWalletSignerMiddlew
     (Arc::new(account), madara_process)
 }
 
-#[rstest]
-#[tokio::test]
-#[ignore]
-async fn test_settle(#[future] setup: (LocalWalletSignerMiddleware, MadaraCmd)) {
-    dotenvy::from_filename_override(".env.test").expect("Failed to load the .env file");
-
-    let (account, madara_process) = setup.await;
-
-    let mut starknet_settlement_params: StarknetSettlementValidatedArgs = StarknetSettlementValidatedArgs {
+/// Builds the settlement client args to settle on the Madara node with the given core contract.
+fn settlement_params(madara_process: &MadaraCmd, core_contract_address: Felt) -> StarknetSettlementValidatedArgs {
+    StarknetSettlementValidatedArgs {
         starknet_rpc_url: madara_process.rpc_url.clone(),
-        starknet_private_key: get_env_var_or_panic("MADARA_ORCHESTRATOR_STARKNET_PRIVATE_KEY"),
+        starknet_signer: StarknetSignerConfig::PrivateKey(get_env_var_or_panic(
+            "MADARA_ORCHESTRATOR_STARKNET_PRIVATE_KEY",
+        )),
         starknet_account_address: get_env_var_or_panic("MADARA_ORCHESTRATOR_STARKNET_ACCOUNT_ADDRESS"),
-        starknet_cairo_core_contract_address: get_env_var_or_panic(
-            "MADARA_ORCHESTRATOR_STARKNET_CAIRO_CORE_CONTRACT_ADDRESS",
-        ),
+        starknet_cairo_core_contract_address: core_contract_address.to_hex_string(),
         starknet_finality_retry_wait_in_secs: get_env_var_or_panic(
             "MADARA_ORCHESTRATOR_STARKNET_FINALITY_RETRY_WAIT_IN_SECS",
         )
         .parse::<u64>()
         .unwrap(),
-    };
+    }
+}
 
+/// Declares and deploys the mock Piltover core contract, and returns its address.
+async fn deploy_mock_core_contract(account: &LocalWalletSignerMiddleware) -> Felt {
     let project_root = Path::new(env!("CARGO_MANIFEST_DIR")).ancestors().nth(3).unwrap();
     let contract_path = project_root.join("../build-artifacts/orchestrator_tests");
     let sierra_class: SierraClass = serde_json::from_reader(
@@ -146,21 +146,31 @@ async fn test_settle(#[future] setup: (LocalWalletSignerMiddleware, MadaraCmd))
         account.declare_v3(Arc::new(flattened_class.clone()), compiled_class_hash).send().await.unwrap();
     tracing::debug!("declare tx hash {:?}", declare_tx_hash);
 
-    let is_success = wait_for_tx(&account, declare_tx_hash, Duration::from_secs(2)).await;
+    let is_success = wait_for_tx(account, declare_tx_hash, Duration::from_secs(2)).await;
     assert!(is_success, "Declare transaction failed");
 
     let contract_factory = ContractFactory::new(flattened_class.class_hash(), account.clone());
     let deploy_v1 = contract_factory.deploy_v3(vec![], felt!("1122"), false);
     let deployed_address = deploy_v1.deployed_address();
 
-    // env::set_var("STARKNET_CAIRO_CORE_CONTRACT_ADDRESS", deployed_address.to_hex_string());
-    starknet_settlement_params.starknet_cairo_core_contract_address = deployed_address.to_hex_string();
-
     let InvokeTransactionResult { transaction_hash: deploy_tx_hash } =
         deploy_v1.send().await.expect("Unable to deploy contract");
 
-    let is_success = wait_for_tx(&account, deploy_tx_hash, Duration::from_secs(2)).await;
+    let is_success = wait_for_tx(account, deploy_tx_hash, Duration::from_secs(2)).await;
     assert!(is_success, "Deploy trasaction failed");
+    deployed_address
+}
+
+#[rstest]
+#[tokio::test]
+#[ignore]
+async fn test_settle(#[future] setup: (LocalWalletSignerMiddleware, MadaraCmd)) {
+    dotenvy::from_filename_override(".env.test").expect("Failed to load the .env file");
+
+    let (account, madara_process) = setup.await;
+
+    let deployed_address = deploy_mock_core_contract(&account).await;
+    let starknet_settlement_params = settlement_params(&madara_process, deployed_address);
 
     let settlement_client = StarknetSettlementClient::new_with_args(&starknet_settlement_params).await;
     let onchain_data_hash = [1; 32];
@@ -195,6 +205,24 @@ async fn test_settle(#[future] setup: (LocalWalletSignerMiddleware, MadaraCmd))
     assert!(call_result[0] == true.into(), "Should be updated");
 }
 
+#[rstest]
+#[tokio::test]
+#[ignore]
+async fn test_settle_with_blobs_fails(#[future] setup: (LocalWalletSignerMiddleware, MadaraCmd)) {
+    dotenvy::from_filename_override(".env.test").expect("Failed to load the .env file");
+
+    let (account, madara_process) = setup.await;
+
+    let deployed_address = deploy_mock_core_contract(&account).await;
+    let settlement_client =
+        StarknetSettlementClient::new_with_args(&settlement_params(&madara_process, deployed_address)).await;
+
+    let nonce = settlement_client.get_nonce().await.expect("Failed to get the nonce");
+    let result = settlement_client.update_state_with_blobs(vec![[1; 32]; 4], vec![vec![4; 32]], nonce).await;
+    assert!(result.is_err(), "Blobs should not be supported on Starknet");
+    assert_eq!(settlement_client.get_nonce().await.expect("Failed to get the nonce"), nonce);
+}
+
 #[rstest]
 #[tokio::test]
 async fn test_get_nonce_works(#[future] setup: (LocalWalletSignerMiddleware, MadaraCmd)) {
@@ -0,0 +1,47 @@
+#!/bin/sh
+# Cairo runner of the stone local prover (`MADARA_ORCHESTRATOR_LOCAL_PROVER_CAIRO_RUNNER_PATH`).
+#
+# Runs the simple bootloader of cairo-lang on the Cairo PIE of a task in proof mode, taking the `cairo-vm` CLI
+# arguments passed by the orchestrator:
+#   --cairo_pie_input <pie> --layout <layout> --proof_mode --trace_file <trace> --memory_file <memory>
+#   --air_public_input <public input> --air_private_input <private input>
+#
+# Requires `cairo-run` of cairo-lang 0.13.x, and the simple bootloader compiled in proof mode, given by
+# SIMPLE_BOOTLOADER_PROGRAM:
+#   cairo-compile --proof_mode --output simple_bootloader.json \
+#     starkware/cairo/bootloaders/simple_bootloader/simple_bootloader.cairo
+set -e
+
+while [ $# -gt 0 ]; do
+    case "$1" in
+        --cairo_pie_input) CAIRO_PIE="$2"; shift 2 ;;
+        --layout) LAYOUT="$2"; shift 2 ;;
+        --proof_mode) shift ;;
+        --trace_file) TRACE_FILE="$2"; shift 2 ;;
+        --memory_file) MEMORY_FILE="$2"; shift 2 ;;
+        --air_public_input) PUBLIC_INPUT="$2"; shift 2 ;;
+        --air_private_input) PRIVATE_INPUT="$2"; shift 2 ;;
+        *) echo "Unknown argument $1" >&2; exit 1 ;;
+    esac
+done
+
+if [ -z "$SIMPLE_BOOTLOADER_PROGRAM" ]; then
+    echo "SIMPLE_BOOTLOADER_PROGRAM is required" >&2
+    exit 1
+fi
+
+# The bootloader runs the PIE as its single task
+PROGRAM_INPUT="$(dirname "$CAIRO_PIE")/bootloader_input.json"
+cat > "$PROGRAM_INPUT" <<INPUT
+{"tasks": [{"type": "CairoPiePath", "path": "$CAIRO_PIE", "use_poseidon": true}], "single_page": true}
+INPUT
+
+exec cairo-run \
+    --program "$SIMPLE_BOOTLOADER_PROGRAM" \
+    --program_input "$PROGRAM_INPUT" \
+    --layout "$LAYOUT" \
+    --proof_mode \
+    --trace_file "$TRACE_FILE" \
+    --memory_file "$MEMORY_FILE" \
+    --air_public_input "$PUBLIC_INPUT" \
+    --air_private_input "$PRIVATE_INPUT"
@@ -5,7 +5,7 @@ use clap::Args;
 #[group()]
 pub struct AWSSNSCliArgs {
     /// Use the AWS SNS client
-    #[arg(long)]
+    #[arg(long, requires = "aws")]
     pub aws_sns: bool,
 
     /// The ARN / Name of the SNS topic. it can have either name or ARN string
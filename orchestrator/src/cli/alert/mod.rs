@@ -1 +1,2 @@
 pub mod aws_sns;
+pub mod webhook;
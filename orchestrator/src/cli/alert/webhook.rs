@@ -0,0 +1,15 @@
+use clap::Args;
+use url::Url;
+
+/// Parameters used to config the webhook alerts.
+#[derive(Debug, Clone, Args)]
+pub struct WebhookCliArgs {
+    /// Use the webhook alert client
+    #[arg(long)]
+    pub webhook_alert: bool,
+
+    /// The URL of the webhook the alerts are posted to.
+    #[arg(env = "MADARA_ORCHESTRATOR_WEBHOOK_ALERT_URL", long)]
+    #[arg(required_if_eq("webhook_alert", "true"))]
+    pub webhook_alert_url: Option<Url>,
+}
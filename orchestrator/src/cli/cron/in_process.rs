@@ -0,0 +1,41 @@
+use clap::Args;
+
+/// CLI arguments for the in-process cron.
+#[derive(Debug, Clone, Args)]
+pub struct InProcessCronCliArgs {
+    /// Trigger the workers from within the orchestrator, instead of relying on AWS Event Bridge
+    #[arg(long)]
+    pub in_process_cron: bool,
+
+    /// The interval in seconds between two triggers of the workers. Must be at least 1.
+    #[arg(
+        env = "MADARA_ORCHESTRATOR_IN_PROCESS_CRON_INTERVAL_SECONDS",
+        long,
+        default_value = "60",
+        value_parser = clap::value_parser!(u64).range(1..)
+    )]
+    pub in_process_cron_interval_seconds: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+    use rstest::rstest;
+
+    #[derive(Parser)]
+    struct Cli {
+        #[command(flatten)]
+        args: InProcessCronCliArgs,
+    }
+
+    #[rstest]
+    #[case("0", false)]
+    #[case("1", true)]
+    #[case("60", true)]
+    fn test_in_process_cron_interval_must_be_positive(#[case] interval: &str, #[case] valid: bool) {
+        let cli =
+            Cli::try_parse_from(["orchestrator", "--in-process-cron", "--in-process-cron-interval-seconds", interval]);
+        assert_eq!(cli.is_ok(), valid);
+    }
+}
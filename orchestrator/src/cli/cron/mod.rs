@@ -1 +1,2 @@
 pub mod event_bridge;
+pub mod in_process;
@@ -3,7 +3,7 @@ use url::Url;
 
 /// Parameters used to config Starknet.
 #[derive(Debug, Clone, Args)]
-#[group(requires_all = ["starknet_da_rpc_url"])]
+#[group(requires_all = ["starknet_da_rpc_url", "starknet_da_private_key", "starknet_da_account_address", "starknet_da_contract_address"])]
 pub struct StarknetDaCliArgs {
     /// Use the Starknet DA layer.
     #[arg(long)]
@@ -12,4 +12,24 @@ pub struct StarknetDaCliArgs {
     /// The RPC URL of the Starknet node.
     #[arg(env = "MADARA_ORCHESTRATOR_STARKNET_DA_RPC_URL", long)]
     pub starknet_da_rpc_url: Option<Url>,
+
+    /// The private key of the Starknet account sending the DA transactions.
+    #[arg(env = "MADARA_ORCHESTRATOR_STARKNET_DA_PRIVATE_KEY", long)]
+    pub starknet_da_private_key: Option<String>,
+
+    /// The address of the Starknet account sending the DA transactions.
+    #[arg(env = "MADARA_ORCHESTRATOR_STARKNET_DA_ACCOUNT_ADDRESS", long)]
+    pub starknet_da_account_address: Option<String>,
+
+    /// The address of the DA contract the state diffs are published to, exposing `publish_data`.
+    #[arg(env = "MADARA_ORCHESTRATOR_STARKNET_DA_CONTRACT_ADDRESS", long)]
+    pub starknet_da_contract_address: Option<String>,
+
+    /// The maximum number of calldata felts of a transaction accepted by the Starknet sequencer.
+    #[arg(env = "MADARA_ORCHESTRATOR_STARKNET_DA_MAX_CALLDATA_FELTS", long, default_value = "4000")]
+    pub starknet_da_max_calldata_felts: u64,
+
+    /// The maximum number of transactions sent to publish the state diff of a block.
+    #[arg(env = "MADARA_ORCHESTRATOR_STARKNET_DA_MAX_TXS_PER_STATE_DIFF", long, default_value = "16")]
+    pub starknet_da_max_txs_per_state_diff: u64,
 }
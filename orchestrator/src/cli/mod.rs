@@ -46,33 +46,29 @@ pub enum Commands {
     group(
         ArgGroup::new("provider")
             .args(&["aws"])
-            .required(true)
             .multiple(false)
     ),
     group(
         ArgGroup::new("storage")
-            .args(&["aws_s3"])
+            .args(&["aws_s3", "local_storage"])
             .required(true)
             .multiple(false)
-            .requires("provider")
     ),
     group(
       ArgGroup::new("queue")
-          .args(&["aws_sqs"])
+          .args(&["aws_sqs", "mongodb_queue", "rabbitmq_queue"])
           .required(true)
           .multiple(false)
-          .requires("provider")
     ),
     group(
       ArgGroup::new("alert")
-          .args(&["aws_sns"])
+          .args(&["aws_sns", "webhook_alert"])
           .required(true)
           .multiple(false)
-          .requires("provider")
     ),
     group(
         ArgGroup::new("prover")
-            .args(&["sharp", "atlantic"])
+            .args(&["sharp", "atlantic", "local_prover"])
             .required(true)
             .multiple(false)
     ),
@@ -98,10 +94,19 @@ pub struct RunCmd {
     #[clap(flatten)]
     pub aws_s3_args: storage::aws_s3::AWSS3CliArgs,
 
+    #[clap(flatten)]
+    pub local_storage_args: storage::local::LocalStorageCliArgs,
+
     // Queue
     #[clap(flatten)]
     pub aws_sqs_args: queue::aws_sqs::AWSSQSCliArgs,
 
+    #[clap(flatten)]
+    pub mongodb_queue_args: queue::mongodb::MongoDBQueueCliArgs,
+
+    #[clap(flatten)]
+    pub rabbitmq_args: queue::rabbitmq::RabbitMQCliArgs,
+
     // Server
     #[clap(flatten)]
     pub server_args: server::ServerCliArgs,
@@ -110,6 +115,13 @@ pub struct RunCmd {
     #[clap(flatten)]
     pub aws_sns_args: alert::aws_sns::AWSSNSCliArgs,
 
+    #[clap(flatten)]
+    pub webhook_args: alert::webhook::WebhookCliArgs,
+
+    // Cron
+    #[clap(flatten)]
+    pub in_process_cron_args: cron::in_process::InProcessCronCliArgs,
+
     // Database
     #[clap(flatten)]
     pub mongodb_args: database::mongodb::MongoDBCliArgs,
@@ -138,6 +150,9 @@ pub struct RunCmd {
     #[clap(flatten)]
     pub atlantic_args: prover::atlantic::AtlanticCliArgs,
 
+    #[clap(flatten)]
+    pub local_prover_args: prover::local::LocalProverCliArgs,
+
     // SNOS
     #[clap(flatten)]
     pub snos_args: snos::SNOSCliArgs,
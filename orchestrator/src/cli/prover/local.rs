@@ -0,0 +1,48 @@
+use clap::Args;
+use std::path::PathBuf;
+
+#[derive(Clone, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum LocalProverModeType {
+    /// Prove the tasks with a local stone prover, requires a dev core contract
+    Stone,
+    /// Report the tasks as proven without proving them, requires a dev core contract
+    Dummy,
+}
+
+/// Parameters used to config the local prover.
+#[derive(Debug, Clone, Args)]
+pub struct LocalProverCliArgs {
+    /// Use the local prover.
+    #[arg(long)]
+    pub local_prover: bool,
+
+    /// How the local prover proves the tasks (stone or dummy).
+    #[arg(env = "MADARA_ORCHESTRATOR_LOCAL_PROVER_MODE", long, value_enum)]
+    #[arg(required_if_eq("local_prover", "true"))]
+    pub local_prover_mode: Option<LocalProverModeType>,
+
+    /// The runner executing the bootloader on a Cairo PIE in proof mode, taking the `cairo-vm` CLI arguments.
+    #[arg(env = "MADARA_ORCHESTRATOR_LOCAL_PROVER_CAIRO_RUNNER_PATH", long)]
+    #[arg(required_if_eq("local_prover_mode", "stone"))]
+    pub local_prover_cairo_runner_path: Option<PathBuf>,
+
+    /// The path of the stone prover binary, `cpu_air_prover`.
+    #[arg(env = "MADARA_ORCHESTRATOR_LOCAL_PROVER_CPU_AIR_PROVER_PATH", long)]
+    #[arg(required_if_eq("local_prover_mode", "stone"))]
+    pub local_prover_cpu_air_prover_path: Option<PathBuf>,
+
+    /// The stone prover config file.
+    #[arg(env = "MADARA_ORCHESTRATOR_LOCAL_PROVER_CONFIG_PATH", long)]
+    #[arg(required_if_eq("local_prover_mode", "stone"))]
+    pub local_prover_config_path: Option<PathBuf>,
+
+    /// The stone prover parameters file.
+    #[arg(env = "MADARA_ORCHESTRATOR_LOCAL_PROVER_PARAMETERS_PATH", long)]
+    #[arg(required_if_eq("local_prover_mode", "stone"))]
+    pub local_prover_parameters_path: Option<PathBuf>,
+
+    /// The directory in which the tasks are proven.
+    #[arg(env = "MADARA_ORCHESTRATOR_LOCAL_PROVER_WORK_DIR", long)]
+    #[arg(required_if_eq("local_prover_mode", "stone"))]
+    pub local_prover_work_dir: Option<PathBuf>,
+}
@@ -1,2 +1,3 @@
 pub mod atlantic;
+pub mod local;
 pub mod sharp;
@@ -5,7 +5,7 @@ use clap::Args;
 #[group(requires_all = ["queue_identifier"])]
 pub struct AWSSQSCliArgs {
     /// Use the AWS sqs client
-    #[arg(long)]
+    #[arg(long, requires = "aws")]
     pub aws_sqs: bool,
 
     /// The ARN / Name of the queue.
@@ -1 +1,3 @@
 pub mod aws_sqs;
+pub mod mongodb;
+pub mod rabbitmq;
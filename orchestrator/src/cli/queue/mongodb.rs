@@ -0,0 +1,9 @@
+use clap::Args;
+
+/// Parameters used to config the MongoDB queue.
+#[derive(Debug, Clone, Args)]
+pub struct MongoDBQueueCliArgs {
+    /// Use the MongoDB queue, the messages are stored in the orchestrator database
+    #[arg(long)]
+    pub mongodb_queue: bool,
+}
@@ -25,4 +25,34 @@ pub struct ServiceCliArgs {
     /// Timeout in seconds for jobs stuck in LockedForProcessing status before self-healing recovery.
     #[arg(env = "MADARA_ORCHESTRATOR_JOB_PROCESSING_TIMEOUT_SECONDS", long, default_value = "1800")]
     pub job_processing_timeout_seconds: u64,
+
+    /// The maximum number of blocks in a batch. A batch is closed once it reaches this size.
+    #[arg(
+        env = "MADARA_ORCHESTRATOR_MAX_BATCH_SIZE",
+        long,
+        default_value = "100",
+        value_parser = clap::value_parser!(u64).range(1..)
+    )]
+    pub max_batch_size: u64,
+
+    /// The maximum age in seconds of a batch. A batch is closed once it is older, even if no new block is added.
+    #[arg(
+        env = "MADARA_ORCHESTRATOR_MAX_BATCH_TIME_SECONDS",
+        long,
+        default_value = "3600",
+        value_parser = clap::value_parser!(u64).range(1..)
+    )]
+    pub max_batch_time_seconds: u64,
+
+    /// Aggregate the proofs of the blocks of each batch and settle a batch with a single transaction.
+    /// Requires a prover supporting the aggregation of proofs.
+    #[arg(env = "MADARA_ORCHESTRATOR_AGGREGATE_BATCH_PROOFS", long, default_value = "false")]
+    pub aggregate_batch_proofs: bool,
+
+    /// Verify the proofs with the Rust Cairo verifier before their facts are checked on chain, instead of relying
+    /// on the GPS verifier contract only. Not supported with Sharp, which does not return the proofs.
+    /// The proofs must be committed with `keccak_160_lsb` by the stone prover version 5, with one of the layouts of
+    /// the verifier (dex, dynamic, recursive, recursive_with_poseidon, small, starknet or starknet_with_keccak).
+    #[arg(env = "MADARA_ORCHESTRATOR_VERIFY_PROOFS_LOCALLY", long, default_value = "false")]
+    pub verify_proofs_locally: bool,
 }
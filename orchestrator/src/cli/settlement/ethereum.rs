@@ -1,8 +1,13 @@
+use std::path::PathBuf;
+
 use clap::Args;
+use orchestrator_ethereum_settlement_client::tx_manager::{
+    DEFAULT_FEE_BUMP_PERCENT, DEFAULT_MAX_TX_REPLACEMENTS, DEFAULT_MIN_PENDING_BLOCKS,
+};
 use url::Url;
 
 #[derive(Debug, Clone, Args)]
-#[group(requires_all = ["ethereum_rpc_url", "ethereum_private_key", "l1_core_contract_address", "starknet_operator_address"])]
+#[group(requires_all = ["ethereum_rpc_url", "l1_core_contract_address", "starknet_operator_address"])]
 pub struct EthereumSettlementCliArgs {
     /// Use the Ethereum settlement layer.
     #[arg(long)]
@@ -16,6 +21,22 @@ pub struct EthereumSettlementCliArgs {
     #[arg(env = "MADARA_ORCHESTRATOR_ETHEREUM_PRIVATE_KEY", long)]
     pub ethereum_private_key: Option<String>,
 
+    /// Path of the encrypted JSON keystore of the Ethereum account, used instead of the private key.
+    #[arg(env = "MADARA_ORCHESTRATOR_ETHEREUM_KEYSTORE_PATH", long, requires = "ethereum_keystore_password")]
+    pub ethereum_keystore_path: Option<PathBuf>,
+
+    /// The password of the Ethereum keystore.
+    #[arg(env = "MADARA_ORCHESTRATOR_ETHEREUM_KEYSTORE_PASSWORD", long)]
+    pub ethereum_keystore_password: Option<String>,
+
+    /// The URL of a web3signer compatible remote signer holding the key of the Ethereum account.
+    #[arg(env = "MADARA_ORCHESTRATOR_ETHEREUM_REMOTE_SIGNER_URL", long, requires = "ethereum_remote_signer_address")]
+    pub ethereum_remote_signer_url: Option<Url>,
+
+    /// The address of the Ethereum account whose key is held by the remote signer.
+    #[arg(env = "MADARA_ORCHESTRATOR_ETHEREUM_REMOTE_SIGNER_ADDRESS", long)]
+    pub ethereum_remote_signer_address: Option<String>,
+
     /// The address of the L1 core contract.
     #[arg(env = "MADARA_ORCHESTRATOR_L1_CORE_CONTRACT_ADDRESS", long)]
     pub l1_core_contract_address: Option<String>,
@@ -23,4 +44,29 @@ pub struct EthereumSettlementCliArgs {
     /// The address of the Starknet operator.
     #[arg(env = "MADARA_ORCHESTRATOR_STARKNET_OPERATOR_ADDRESS", long)]
     pub starknet_operator_address: Option<String>,
+
+    /// Percentage by which all the fees of a stuck settlement transaction are bumped when it is replaced.
+    #[arg(env = "MADARA_ORCHESTRATOR_ETHEREUM_FEE_BUMP_PERCENT", long, default_value_t = DEFAULT_FEE_BUMP_PERCENT)]
+    pub ethereum_fee_bump_percent: u64,
+
+    /// Max `max_fee_per_gas` of a settlement transaction replacement, in wei.
+    #[arg(env = "MADARA_ORCHESTRATOR_ETHEREUM_MAX_FEE_PER_GAS_CAP", long)]
+    pub ethereum_max_fee_per_gas_cap: Option<u128>,
+
+    /// Max `max_fee_per_blob_gas` of a settlement transaction replacement, in wei.
+    #[arg(env = "MADARA_ORCHESTRATOR_ETHEREUM_MAX_FEE_PER_BLOB_GAS_CAP", long)]
+    pub ethereum_max_fee_per_blob_gas_cap: Option<u128>,
+
+    /// Max number of replacements of a stuck settlement transaction.
+    #[arg(env = "MADARA_ORCHESTRATOR_ETHEREUM_MAX_TX_REPLACEMENTS", long, default_value_t = DEFAULT_MAX_TX_REPLACEMENTS)]
+    pub ethereum_max_tx_replacements: u64,
+
+    /// Number of blocks a settlement transaction stays pending before it is replaced.
+    #[arg(env = "MADARA_ORCHESTRATOR_ETHEREUM_MIN_PENDING_BLOCKS", long, default_value_t = DEFAULT_MIN_PENDING_BLOCKS)]
+    pub ethereum_min_pending_blocks: u64,
+
+    /// Acknowledges that the core contract is a dev contract which does not check the facts of the state updates,
+    /// as required by the local prover whose proofs are not registered. The state updates are sent as usual.
+    #[arg(env = "MADARA_ORCHESTRATOR_ETHEREUM_DEV_CORE_CONTRACT", long)]
+    pub ethereum_dev_core_contract: bool,
 }
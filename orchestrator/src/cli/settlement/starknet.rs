@@ -1,8 +1,10 @@
+use std::path::PathBuf;
+
 use clap::Args;
 use url::Url;
 
 #[derive(Debug, Clone, Args)]
-#[group(requires_all = ["starknet_rpc_url", "starknet_private_key", "starknet_account_address", "starknet_cairo_core_contract_address", "starknet_finality_retry_wait_in_secs"])]
+#[group(requires_all = ["starknet_rpc_url", "starknet_account_address", "starknet_cairo_core_contract_address", "starknet_finality_retry_wait_in_secs"])]
 pub struct StarknetSettlementCliArgs {
     /// Use the Starknet settlement layer.
     #[arg(long)]
@@ -16,6 +18,26 @@ pub struct StarknetSettlementCliArgs {
     #[arg(env = "MADARA_ORCHESTRATOR_STARKNET_PRIVATE_KEY", long)]
     pub starknet_private_key: Option<String>,
 
+    /// Path of the encrypted JSON keystore of the Starknet account, used instead of the private key.
+    #[arg(env = "MADARA_ORCHESTRATOR_STARKNET_KEYSTORE_PATH", long, requires = "starknet_keystore_password")]
+    pub starknet_keystore_path: Option<PathBuf>,
+
+    /// The password of the Starknet keystore.
+    #[arg(env = "MADARA_ORCHESTRATOR_STARKNET_KEYSTORE_PASSWORD", long)]
+    pub starknet_keystore_password: Option<String>,
+
+    /// The URL of a remote signer holding the key of the Starknet account.
+    #[arg(
+        env = "MADARA_ORCHESTRATOR_STARKNET_REMOTE_SIGNER_URL",
+        long,
+        requires = "starknet_remote_signer_public_key"
+    )]
+    pub starknet_remote_signer_url: Option<Url>,
+
+    /// The public key of the Starknet account key held by the remote signer.
+    #[arg(env = "MADARA_ORCHESTRATOR_STARKNET_REMOTE_SIGNER_PUBLIC_KEY", long)]
+    pub starknet_remote_signer_public_key: Option<String>,
+
     /// The address of the Starknet account.
     #[arg(env = "MADARA_ORCHESTRATOR_STARKNET_ACCOUNT_ADDRESS", long)]
     pub starknet_account_address: Option<String>,
@@ -27,4 +49,9 @@ pub struct StarknetSettlementCliArgs {
     /// The number of seconds to wait for finality.
     #[arg(env = "MADARA_ORCHESTRATOR_STARKNET_FINALITY_RETRY_WAIT_IN_SECS", long)]
     pub starknet_finality_retry_wait_in_secs: Option<u64>,
+
+    /// Acknowledges that the core contract is a dev contract which does not check the facts of the state updates,
+    /// as required by the local prover whose proofs are not registered. The state updates are sent as usual.
+    #[arg(env = "MADARA_ORCHESTRATOR_STARKNET_DEV_CORE_CONTRACT", long)]
+    pub starknet_dev_core_contract: bool,
 }
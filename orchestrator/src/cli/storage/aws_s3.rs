@@ -5,7 +5,7 @@ use clap::Args;
 #[group()] // Note: we are not using bucket_name in requires_all because it has a default value.
 pub struct AWSS3CliArgs {
     /// Use the AWS s3 client
-    #[arg(long)]
+    #[arg(long, requires = "aws")]
     pub aws_s3: bool,
 
     /// The ARN / Name of the S3 bucket.
@@ -0,0 +1,15 @@
+use clap::Args;
+use std::path::PathBuf;
+
+/// Parameters used to config the local filesystem storage.
+#[derive(Debug, Clone, Args)]
+pub struct LocalStorageCliArgs {
+    /// Use the local filesystem storage
+    #[arg(long)]
+    pub local_storage: bool,
+
+    /// The directory in which the objects are stored.
+    #[arg(env = "MADARA_ORCHESTRATOR_LOCAL_STORAGE_PATH", long)]
+    #[arg(required_if_eq("local_storage", "true"))]
+    pub local_storage_path: Option<PathBuf>,
+}
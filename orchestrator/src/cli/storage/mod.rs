@@ -1 +1,2 @@
 pub mod aws_s3;
+pub mod local;
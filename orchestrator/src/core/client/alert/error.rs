@@ -25,4 +25,7 @@ pub enum AlertError {
 
     #[error("Failed to take lock: {0}")]
     LockError(String),
+
+    #[error("Failed to send alert to webhook: {0}")]
+    WebhookError(reqwest::Error),
 }
@@ -1,5 +1,6 @@
 pub mod error;
 pub(crate) mod sns;
+pub mod webhook;
 
 use async_trait::async_trait;
 
@@ -0,0 +1,52 @@
+use super::AlertError;
+use crate::core::client::alert::AlertClient;
+use async_trait::async_trait;
+use reqwest::Client;
+use serde_json::json;
+use url::Url;
+
+/// Webhook is an alert client posting the alerts to a generic HTTP webhook.
+///
+/// The message is sent as a JSON object `{"text": "<message>"}`, which is understood by most chat webhooks
+/// (Slack, Mattermost, Rocket.Chat...).
+#[derive(Clone, Debug)]
+pub struct Webhook {
+    client: Client,
+    url: Url,
+}
+
+impl Webhook {
+    /// Creates a new webhook alert client.
+    ///
+    /// # Arguments
+    /// * `url` - The URL of the webhook.
+    ///
+    /// # Returns
+    /// * `Self` - The webhook client.
+    pub fn new(url: Url) -> Self {
+        Self { client: Client::new(), url }
+    }
+}
+
+#[async_trait]
+impl AlertClient for Webhook {
+    /// send_message posts a message to the webhook.
+    ///
+    /// # Arguments
+    ///
+    /// * `message_body` - The message body to send.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), AlertError>` - The result of the send operation.
+    async fn send_message(&self, message_body: String) -> Result<(), AlertError> {
+        self.client
+            .post(self.url.clone())
+            .json(&json!({ "text": message_body }))
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(AlertError::WebhookError)?;
+        Ok(())
+    }
+}
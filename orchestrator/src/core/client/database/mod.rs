@@ -75,6 +75,10 @@ pub trait DatabaseClient: Send + Sync {
 
     /// get_latest_batch - Get the latest batch from DB. Returns `None` if the DB is empty
     async fn get_latest_batch(&self) -> Result<Option<Batch>, DatabaseError>;
+    /// get_batch_by_index - Get the batch with the given index. Returns `None` if the batch doesn't exist
+    async fn get_batch_by_index(&self, index: u64) -> Result<Option<Batch>, DatabaseError>;
+    /// get_batch_for_block - Get the batch containing the block. Returns `None` if the block isn't batched yet
+    async fn get_batch_for_block(&self, block_number: u64) -> Result<Option<Batch>, DatabaseError>;
     /// update_batch - Update the bath
     async fn update_batch(&self, batch: &Batch, update: BatchUpdates) -> Result<Batch, DatabaseError>;
     /// create_batch - Create a new batch
@@ -813,6 +813,31 @@ impl DatabaseClient for MongoDbClient {
         }
     }
 
+    async fn get_batch_by_index(&self, index: u64) -> Result<Option<Batch>, DatabaseError> {
+        let start = Instant::now();
+        let filter = doc! {
+            "index": index as i64,
+        };
+        let batch = self.get_batch_collection().find_one(filter, None).await?;
+        let attributes = [KeyValue::new("db_operation_name", "get_batch_by_index")];
+        let duration = start.elapsed();
+        ORCHESTRATOR_METRICS.db_calls_response_time.record(duration.as_secs_f64(), &attributes);
+        Ok(batch)
+    }
+
+    async fn get_batch_for_block(&self, block_number: u64) -> Result<Option<Batch>, DatabaseError> {
+        let start = Instant::now();
+        let filter = doc! {
+            "start_block": { "$lte": block_number as i64 },
+            "end_block": { "$gte": block_number as i64 },
+        };
+        let batch = self.get_batch_collection().find_one(filter, None).await?;
+        let attributes = [KeyValue::new("db_operation_name", "get_batch_for_block")];
+        let duration = start.elapsed();
+        ORCHESTRATOR_METRICS.db_calls_response_time.record(duration.as_secs_f64(), &attributes);
+        Ok(batch)
+    }
+
     async fn update_batch(&self, batch: &Batch, update: BatchUpdates) -> Result<Batch, DatabaseError> {
         let start = Instant::now();
         let filter = doc! {
@@ -26,4 +26,19 @@ pub enum QueueError {
 
     #[error("Failed to get queue attributes for queue name : {0}")]
     FailedToGetQueueArn(String),
+
+    #[error("No message available in queue : {0}")]
+    NoMessage(String),
+
+    #[error("Queue is not configured : {0}")]
+    QueueNotConfigured(String),
+
+    #[error("Invalid queue message : {0}")]
+    InvalidMessage(String),
+
+    #[error("MongoDB queue error: {0}")]
+    MongoDbError(#[from] mongodb::error::Error),
+
+    #[error("RabbitMQ queue error: {0}")]
+    RabbitMQError(#[from] lapin::Error),
 }
@@ -1,19 +1,73 @@
 pub mod error;
+pub mod mongodb;
+pub mod rabbitmq;
 pub mod sqs;
 
 use crate::types::queue::QueueType;
 use async_trait::async_trait;
 pub use error::QueueError;
-use omniqueue::backends::{SqsConsumer, SqsProducer};
-use omniqueue::Delivery;
+use serde::de::DeserializeOwned;
+use std::fmt;
 use std::time::Duration;
 
+/// Acker - Acknowledges a message on the queue backend it was received from
+#[async_trait]
+pub trait Acker: Send + Sync {
+    /// Marks the message as handled, it will not be delivered again.
+    async fn ack(self: Box<Self>) -> Result<(), QueueError>;
+    /// Marks the message as failed, it will be delivered again.
+    async fn nack(self: Box<Self>) -> Result<(), QueueError>;
+}
+
+/// Delivery - A message received from a queue, independent of the queue backend
+/// The message must be acknowledged once handled, or negatively acknowledged to be delivered again.
+pub struct Delivery {
+    payload: Option<Vec<u8>>,
+    acker: Box<dyn Acker>,
+}
+
+impl Delivery {
+    pub fn new(payload: Option<Vec<u8>>, acker: Box<dyn Acker>) -> Self {
+        Self { payload, acker }
+    }
+
+    /// borrow_payload - Returns the raw payload of the message
+    pub fn borrow_payload(&self) -> Option<&[u8]> {
+        self.payload.as_deref()
+    }
+
+    /// payload_serde_json - Deserializes the payload of the message from JSON
+    pub fn payload_serde_json<T: DeserializeOwned>(&self) -> Result<Option<T>, serde_json::Error> {
+        self.payload.as_deref().map(serde_json::from_slice).transpose()
+    }
+
+    pub async fn ack(self) -> Result<(), QueueError> {
+        self.acker.ack().await
+    }
+
+    pub async fn nack(self) -> Result<(), QueueError> {
+        self.acker.nack().await
+    }
+}
+
+impl fmt::Debug for Delivery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Delivery").field("payload", &self.payload.as_deref().map(String::from_utf8_lossy)).finish()
+    }
+}
+
+/// QueueConsumer - Receives messages from a single queue
+#[async_trait]
+pub trait QueueConsumer: Send {
+    /// Returns the next message of the queue, or `None` if there is no message available right now.
+    async fn receive(&mut self) -> Result<Option<Delivery>, QueueError>;
+}
+
 /// Trait defining queue operations
 #[cfg_attr(test, mockall::automock)]
 #[async_trait]
 pub trait QueueClient: Send + Sync {
-    async fn get_producer(&self, queue: QueueType) -> Result<SqsProducer, QueueError>;
-    async fn get_consumer(&self, queue: QueueType) -> Result<SqsConsumer, QueueError>;
+    async fn get_consumer(&self, queue: QueueType) -> Result<Box<dyn QueueConsumer>, QueueError>;
     async fn send_message(&self, queue: QueueType, payload: String, delay: Option<Duration>) -> Result<(), QueueError>;
     async fn consume_message_from_queue(&self, queue: QueueType) -> Result<Delivery, QueueError>;
 }
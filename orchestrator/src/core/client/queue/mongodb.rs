@@ -0,0 +1,169 @@
+use crate::core::client::queue::{Acker, Delivery, QueueClient, QueueConsumer, QueueError};
+use crate::types::params::database::DatabaseArgs;
+use crate::types::queue::QueueType;
+use crate::types::queue_control::QUEUES;
+use async_trait::async_trait;
+use chrono::Utc;
+use mongodb::bson::{self, doc, oid::ObjectId};
+use mongodb::options::{FindOneAndUpdateOptions, ReturnDocument};
+use mongodb::{Client, Collection, IndexModel};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Name of the collection holding the messages of all the queues.
+const QUEUE_COLLECTION: &str = "queue_messages";
+
+/// QueueDocument - A message stored in the queue collection
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct QueueDocument {
+    #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+    id: Option<ObjectId>,
+    queue: String,
+    payload: String,
+    /// The message is not delivered before this time. This is used for delayed messages and for the visibility
+    /// timeout of the messages that have been received but not acknowledged yet.
+    visible_at: bson::DateTime,
+    /// Number of times the message has been delivered, also used as a receipt when acknowledging the message.
+    receive_count: u32,
+}
+
+/// MongoDbQueue - A queue client storing the messages in MongoDB
+///
+/// It follows the SQS semantics used by the orchestrator: a received message is hidden for the visibility timeout of
+/// its queue and delivered again if it is not acknowledged in time, and a message delivered more than the
+/// `max_receive_count` of its queue is moved to the dead letter queue.
+#[derive(Clone, Debug)]
+pub struct MongoDbQueue {
+    collection: Collection<QueueDocument>,
+}
+
+impl MongoDbQueue {
+    pub async fn new(args: &DatabaseArgs) -> Result<Self, QueueError> {
+        let client = Client::with_uri_str(&args.connection_uri).await?;
+        let collection = client.database(&args.database_name).collection(QUEUE_COLLECTION);
+        collection.create_index(IndexModel::builder().keys(doc! { "queue": 1, "visible_at": 1 }).build(), None).await?;
+        Ok(Self { collection })
+    }
+
+    /// receive - Receive the next visible message of the queue
+    /// The message is hidden for the visibility timeout of the queue, and moved to the dead letter queue if it has
+    /// been delivered too many times.
+    async fn receive(&self, queue: &QueueType) -> Result<Option<Delivery>, QueueError> {
+        let queue_config = QUEUES.get(queue).ok_or_else(|| QueueError::QueueNotConfigured(queue.to_string()))?;
+
+        loop {
+            let now = Utc::now();
+            let visible_until = now + chrono::Duration::seconds(queue_config.visibility_timeout.into());
+            let options = FindOneAndUpdateOptions::builder()
+                .sort(doc! { "visible_at": 1 })
+                .return_document(ReturnDocument::After)
+                .build();
+            let Some(message) = self
+                .collection
+                .find_one_and_update(
+                    doc! { "queue": queue.to_string(), "visible_at": { "$lte": bson::DateTime::from_chrono(now) } },
+                    doc! {
+                        "$set": { "visible_at": bson::DateTime::from_chrono(visible_until) },
+                        "$inc": { "receive_count": 1 },
+                    },
+                    options,
+                )
+                .await?
+            else {
+                return Ok(None);
+            };
+            let id = message.id.ok_or_else(|| QueueError::InvalidMessage("Message without id".to_string()))?;
+
+            if let Some(dlq_config) = &queue_config.dlq_config {
+                if message.receive_count > dlq_config.max_receive_count {
+                    tracing::warn!(
+                        "Message {} of queue {} exceeded the max receive count, moving it to {}",
+                        id,
+                        queue,
+                        dlq_config.dlq_name
+                    );
+                    self.collection
+                        .update_one(
+                            doc! { "_id": id, "receive_count": message.receive_count },
+                            doc! { "$set": {
+                                "queue": dlq_config.dlq_name.to_string(),
+                                "visible_at": bson::DateTime::from_chrono(now),
+                                "receive_count": 0,
+                            } },
+                            None,
+                        )
+                        .await?;
+                    continue;
+                }
+            }
+
+            let acker = MongoDbAcker { collection: self.collection.clone(), id, receive_count: message.receive_count };
+            return Ok(Some(Delivery::new(Some(message.payload.into_bytes()), Box::new(acker))));
+        }
+    }
+}
+
+/// MongoDbAcker - Acknowledges a message of the MongoDB queue
+/// The receive count acts as a receipt: once the message has been delivered again after its visibility timeout,
+/// acknowledging it from the previous delivery does nothing.
+struct MongoDbAcker {
+    collection: Collection<QueueDocument>,
+    id: ObjectId,
+    receive_count: u32,
+}
+
+#[async_trait]
+impl Acker for MongoDbAcker {
+    async fn ack(self: Box<Self>) -> Result<(), QueueError> {
+        self.collection.delete_one(doc! { "_id": self.id, "receive_count": self.receive_count }, None).await?;
+        Ok(())
+    }
+
+    async fn nack(self: Box<Self>) -> Result<(), QueueError> {
+        self.collection
+            .update_one(
+                doc! { "_id": self.id, "receive_count": self.receive_count },
+                doc! { "$set": { "visible_at": bson::DateTime::from_chrono(Utc::now()) } },
+                None,
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+pub struct MongoDbConsumer {
+    queue: MongoDbQueue,
+    queue_type: QueueType,
+}
+
+#[async_trait]
+impl QueueConsumer for MongoDbConsumer {
+    async fn receive(&mut self) -> Result<Option<Delivery>, QueueError> {
+        self.queue.receive(&self.queue_type).await
+    }
+}
+
+#[async_trait]
+impl QueueClient for MongoDbQueue {
+    async fn get_consumer(&self, queue: QueueType) -> Result<Box<dyn QueueConsumer>, QueueError> {
+        Ok(Box::new(MongoDbConsumer { queue: self.clone(), queue_type: queue }))
+    }
+
+    async fn send_message(&self, queue: QueueType, payload: String, delay: Option<Duration>) -> Result<(), QueueError> {
+        let delay = chrono::Duration::from_std(delay.unwrap_or_default())
+            .map_err(|e| QueueError::InvalidMessage(format!("Invalid delay: {}", e)))?;
+        let message = QueueDocument {
+            id: None,
+            queue: queue.to_string(),
+            payload,
+            visible_at: bson::DateTime::from_chrono(Utc::now() + delay),
+            receive_count: 0,
+        };
+        self.collection.insert_one(message, None).await?;
+        Ok(())
+    }
+
+    async fn consume_message_from_queue(&self, queue: QueueType) -> Result<Delivery, QueueError> {
+        self.receive(&queue).await?.ok_or_else(|| QueueError::NoMessage(queue.to_string()))
+    }
+}
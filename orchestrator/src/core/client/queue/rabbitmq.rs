@@ -0,0 +1,209 @@
+use crate::core::client::queue::{Acker, Delivery, QueueClient, QueueConsumer, QueueError};
+use crate::types::queue::QueueType;
+use crate::types::queue_control::{QueueConfig, QUEUES};
+use async_trait::async_trait;
+use lapin::options::{
+    BasicAckOptions, BasicGetOptions, BasicNackOptions, BasicPublishOptions, ConfirmSelectOptions, QueueDeclareOptions,
+};
+use lapin::types::{AMQPValue, FieldTable, LongString, ShortString};
+use lapin::{acker, BasicProperties, Channel, Connection, ConnectionProperties};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use url::Url;
+
+/// RabbitMQ - A queue client using a RabbitMQ broker over AMQP
+///
+/// The queues are declared as quorum queues so that the broker counts the deliveries of each message: a message
+/// negatively acknowledged more than the `max_receive_count` of its queue is dead-lettered to the dead letter queue.
+/// RabbitMQ has no visibility timeout: a delivery which is not acknowledged within the visibility timeout of its queue
+/// is negatively acknowledged, and delivered again.
+///
+/// Delayed messages are published to a delay queue per queue and delay, whose messages expire after the delay and are
+/// then dead-lettered to the target queue.
+#[derive(Clone)]
+pub struct RabbitMQ {
+    // The channel is closed when the connection is dropped
+    _connection: Arc<Connection>,
+    channel: Channel,
+    /// Overrides the visibility timeout of the queues.
+    delivery_timeout: Option<Duration>,
+}
+
+impl RabbitMQ {
+    pub async fn new(url: &Url) -> Result<Self, QueueError> {
+        let connection = Connection::connect(url.as_str(), ConnectionProperties::default()).await?;
+        let channel = connection.create_channel().await?;
+        // Wait for the broker to confirm the published messages
+        channel.confirm_select(ConfirmSelectOptions::default()).await?;
+
+        let queue = Self { _connection: Arc::new(connection), channel, delivery_timeout: None };
+        for (queue_type, queue_config) in QUEUES.iter() {
+            queue.declare_queue(queue_type, queue_config).await?;
+        }
+        Ok(queue)
+    }
+
+    /// with_delivery_timeout - Uses the same delivery timeout for all the queues, instead of their visibility timeout
+    pub fn with_delivery_timeout(self, delivery_timeout: Duration) -> Self {
+        Self { delivery_timeout: Some(delivery_timeout), ..self }
+    }
+
+    async fn declare_queue(&self, queue: &QueueType, queue_config: &QueueConfig) -> Result<(), QueueError> {
+        let mut arguments = FieldTable::default();
+        arguments.insert(ShortString::from("x-queue-type"), AMQPValue::LongString(LongString::from("quorum")));
+        if let Some(dlq_config) = &queue_config.dlq_config {
+            arguments.insert(
+                ShortString::from("x-delivery-limit"),
+                AMQPValue::LongLongInt(dlq_config.max_receive_count.into()),
+            );
+            arguments.insert(ShortString::from("x-dead-letter-exchange"), AMQPValue::LongString(LongString::from("")));
+            arguments.insert(
+                ShortString::from("x-dead-letter-routing-key"),
+                AMQPValue::LongString(LongString::from(dlq_config.dlq_name.to_string())),
+            );
+        }
+        self.channel
+            .queue_declare(&queue.to_string(), QueueDeclareOptions { durable: true, ..Default::default() }, arguments)
+            .await?;
+        Ok(())
+    }
+
+    /// declare_delay_queue - Declares the queue holding the messages sent to `queue` with `delay`
+    /// Returns the name of the delay queue.
+    async fn declare_delay_queue(&self, queue: &QueueType, delay: Duration) -> Result<String, QueueError> {
+        let delay_ms = i64::try_from(delay.as_millis())
+            .map_err(|_| QueueError::InvalidMessage(format!("Invalid delay: {:?}", delay)))?;
+        let name = format!("{}_delay_{}ms", queue, delay_ms);
+
+        let mut arguments = FieldTable::default();
+        arguments.insert(ShortString::from("x-message-ttl"), AMQPValue::LongLongInt(delay_ms));
+        arguments.insert(ShortString::from("x-dead-letter-exchange"), AMQPValue::LongString(LongString::from("")));
+        arguments.insert(
+            ShortString::from("x-dead-letter-routing-key"),
+            AMQPValue::LongString(LongString::from(queue.to_string())),
+        );
+        self.channel
+            .queue_declare(&name, QueueDeclareOptions { durable: true, ..Default::default() }, arguments)
+            .await?;
+        Ok(name)
+    }
+
+    /// receive - Receive the next message of the queue
+    /// The message is negatively acknowledged if it is not acknowledged within the delivery timeout.
+    async fn receive(&self, queue: &QueueType) -> Result<Option<Delivery>, QueueError> {
+        let delivery_timeout = match self.delivery_timeout {
+            Some(delivery_timeout) => delivery_timeout,
+            None => {
+                let queue_config =
+                    QUEUES.get(queue).ok_or_else(|| QueueError::QueueNotConfigured(queue.to_string()))?;
+                Duration::from_secs(queue_config.visibility_timeout.into())
+            }
+        };
+        let Some(message) = self.channel.basic_get(&queue.to_string(), BasicGetOptions::default()).await? else {
+            return Ok(None);
+        };
+        let delivery = message.delivery;
+        let acker = RabbitMQAcker::new(delivery.acker, queue, delivery_timeout);
+        Ok(Some(Delivery::new(Some(delivery.data), Box::new(acker))))
+    }
+}
+
+/// RabbitMQAcker - Acknowledges a message of the RabbitMQ queue
+/// Once the delivery timed out and the message was requeued, acknowledging it does nothing, like for the SQS and
+/// MongoDB queues after the visibility timeout.
+struct RabbitMQAcker {
+    acker: Arc<Mutex<Option<acker::Acker>>>,
+    timeout: JoinHandle<()>,
+}
+
+impl RabbitMQAcker {
+    fn new(acker: acker::Acker, queue: &QueueType, delivery_timeout: Duration) -> Self {
+        let acker = Arc::new(Mutex::new(Some(acker)));
+        let timeout = tokio::spawn({
+            let acker = acker.clone();
+            let queue = queue.to_string();
+            async move {
+                tokio::time::sleep(delivery_timeout).await;
+                let Some(acker) = acker.lock().expect("Poisoned lock").take() else { return };
+                tracing::warn!(
+                    "Message of queue {} was not acknowledged within {:?}, requeuing it",
+                    queue,
+                    delivery_timeout
+                );
+                if let Err(e) = acker.nack(BasicNackOptions { requeue: true, ..Default::default() }).await {
+                    tracing::error!(error = %e, "Failed to requeue the message of queue {}", queue);
+                }
+            }
+        });
+        Self { acker, timeout }
+    }
+
+    /// take - Stops the delivery timeout, returns `None` if it has already expired
+    fn take(&self) -> Option<acker::Acker> {
+        let acker = self.acker.lock().expect("Poisoned lock").take();
+        // Once expired, the timeout task is requeuing the message and must not be aborted
+        if acker.is_some() {
+            self.timeout.abort();
+        }
+        acker
+    }
+}
+
+#[async_trait]
+impl Acker for RabbitMQAcker {
+    async fn ack(self: Box<Self>) -> Result<(), QueueError> {
+        if let Some(acker) = self.take() {
+            acker.ack(BasicAckOptions::default()).await?;
+        }
+        Ok(())
+    }
+
+    async fn nack(self: Box<Self>) -> Result<(), QueueError> {
+        if let Some(acker) = self.take() {
+            acker.nack(BasicNackOptions { requeue: true, ..Default::default() }).await?;
+        }
+        Ok(())
+    }
+}
+
+pub struct RabbitMQConsumer {
+    queue: RabbitMQ,
+    queue_type: QueueType,
+}
+
+#[async_trait]
+impl QueueConsumer for RabbitMQConsumer {
+    async fn receive(&mut self) -> Result<Option<Delivery>, QueueError> {
+        self.queue.receive(&self.queue_type).await
+    }
+}
+
+#[async_trait]
+impl QueueClient for RabbitMQ {
+    async fn get_consumer(&self, queue: QueueType) -> Result<Box<dyn QueueConsumer>, QueueError> {
+        Ok(Box::new(RabbitMQConsumer { queue: self.clone(), queue_type: queue }))
+    }
+
+    async fn send_message(&self, queue: QueueType, payload: String, delay: Option<Duration>) -> Result<(), QueueError> {
+        let routing_key = match delay {
+            Some(delay) if !delay.is_zero() => self.declare_delay_queue(&queue, delay).await?,
+            _ => queue.to_string(),
+        };
+        self.channel
+            .basic_publish(
+                "",
+                &routing_key,
+                BasicPublishOptions::default(),
+                payload.as_bytes(),
+                BasicProperties::default().with_delivery_mode(2),
+            )
+            .await?
+            .await?;
+        Ok(())
+    }
+
+    async fn consume_message_from_queue(&self, queue: QueueType) -> Result<Delivery, QueueError> {
+        self.receive(&queue).await?.ok_or_else(|| QueueError::NoMessage(queue.to_string()))
+    }
+}
@@ -1,4 +1,4 @@
-use crate::core::client::queue::QueueError;
+use crate::core::client::queue::{Acker, Delivery, QueueConsumer, QueueError};
 use crate::types::params::AWSResourceIdentifier;
 use crate::types::params::ARN;
 use crate::{
@@ -11,7 +11,7 @@ use aws_config::SdkConfig;
 use aws_sdk_sqs::types::QueueAttributeName;
 use aws_sdk_sqs::Client;
 use omniqueue::backends::{SqsBackend, SqsConfig, SqsConsumer, SqsProducer};
-use omniqueue::Delivery;
+use omniqueue::QueueError as OmniQueueError;
 use std::time::Duration;
 
 #[derive(Clone, Debug)]
@@ -162,27 +162,12 @@ impl SQS {
 
         Ok(InnerSQS::get_queue_name_from_type(template, queue_type))
     }
-}
-
-#[async_trait]
-impl QueueClient for SQS {
-    /// **send_message** - Send a message to the queue
-    /// This function sends a message to the queue.
-    /// It returns a Result<(), OrchestratorError> indicating whether the operation was successful or not
-    async fn send_message(&self, queue: QueueType, payload: String, delay: Option<Duration>) -> Result<(), QueueError> {
-        let producer = self.get_producer(queue).await?;
-        match delay {
-            Some(d) => producer.send_raw_scheduled(payload.as_str(), d).await?,
-            None => producer.send_raw(payload.as_str()).await?,
-        }
-        Ok(())
-    }
 
     /// TODO: if possible try to reuse the same producer which got created in the previous run
-    /// get_producer - Get the producer for the given queue
+    /// sqs_producer - Get the producer for the given queue
     /// This function returns the producer for the given queue.
     /// The producer is used to send messages to the queue.
-    async fn get_producer(&self, queue: QueueType) -> Result<SqsProducer, QueueError> {
+    pub async fn sqs_producer(&self, queue: QueueType) -> Result<SqsProducer, QueueError> {
         let queue_name = self.get_queue_name(&queue)?;
         let queue_url = self.inner.get_queue_url_from_client(queue_name.as_str()).await?;
         let producer =
@@ -190,10 +175,10 @@ impl QueueClient for SQS {
         Ok(producer)
     }
 
-    /// get_consumer - Get the consumer for the given queue
+    /// sqs_consumer - Get the consumer for the given queue
     /// This function returns the consumer for the given queue.
     /// The consumer is used to receive messages from the queue.
-    async fn get_consumer(&self, queue: QueueType) -> Result<SqsConsumer, QueueError> {
+    pub async fn sqs_consumer(&self, queue: QueueType) -> Result<SqsConsumer, QueueError> {
         let queue_name = self.get_queue_name(&queue)?;
         tracing::info!("Getting queue url for queue name {}", queue_name);
         let queue_url = self.inner.get_queue_url_from_client(queue_name.as_str()).await?;
@@ -202,9 +187,64 @@ impl QueueClient for SQS {
             SqsBackend::builder(SqsConfig { queue_dsn: queue_url, override_endpoint: true }).build_consumer().await?;
         Ok(consumer)
     }
+}
+
+/// SQSAcker - Acknowledges a message received through omniqueue
+struct SQSAcker(omniqueue::Delivery);
+
+#[async_trait]
+impl Acker for SQSAcker {
+    async fn ack(self: Box<Self>) -> Result<(), QueueError> {
+        self.0.ack().await.map_err(|(e, _)| e.into())
+    }
+
+    async fn nack(self: Box<Self>) -> Result<(), QueueError> {
+        self.0.nack().await.map_err(|(e, _)| e.into())
+    }
+}
+
+impl From<omniqueue::Delivery> for Delivery {
+    fn from(delivery: omniqueue::Delivery) -> Self {
+        let payload = delivery.borrow_payload().map(|payload| payload.to_vec());
+        Delivery::new(payload, Box::new(SQSAcker(delivery)))
+    }
+}
+
+pub struct SQSConsumer(SqsConsumer);
+
+#[async_trait]
+impl QueueConsumer for SQSConsumer {
+    async fn receive(&mut self) -> Result<Option<Delivery>, QueueError> {
+        match self.0.receive().await {
+            Ok(delivery) => Ok(Some(delivery.into())),
+            Err(OmniQueueError::NoData) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+#[async_trait]
+impl QueueClient for SQS {
+    /// **send_message** - Send a message to the queue
+    /// This function sends a message to the queue.
+    /// It returns a Result<(), OrchestratorError> indicating whether the operation was successful or not
+    async fn send_message(&self, queue: QueueType, payload: String, delay: Option<Duration>) -> Result<(), QueueError> {
+        let producer = self.sqs_producer(queue).await?;
+        match delay {
+            Some(d) => producer.send_raw_scheduled(payload.as_str(), d).await?,
+            None => producer.send_raw(payload.as_str()).await?,
+        }
+        Ok(())
+    }
+
+    /// get_consumer - Get the consumer for the given queue
+    async fn get_consumer(&self, queue: QueueType) -> Result<Box<dyn QueueConsumer>, QueueError> {
+        Ok(Box::new(SQSConsumer(self.sqs_consumer(queue).await?)))
+    }
+
     /// TODO: this should not be need remove this after reviewing the code access for usage
     async fn consume_message_from_queue(&self, queue: QueueType) -> Result<Delivery, QueueError> {
-        let mut consumer = self.get_consumer(queue).await?;
-        Ok(consumer.receive().await?)
+        let mut consumer = self.sqs_consumer(queue).await?;
+        Ok(consumer.receive().await?.into())
     }
 }
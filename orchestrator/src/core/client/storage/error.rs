@@ -27,4 +27,9 @@ pub enum StorageError {
     ObjectStreamError(String),
     #[error("Invalid Bucket Name is given: {0}")]
     InvalidBucketName(String),
+    /// Local storage error
+    #[error("Failed to access local storage: {0}")]
+    LocalStorageError(#[from] std::io::Error),
+    #[error("Invalid storage key: {0}")]
+    InvalidKey(String),
 }
@@ -0,0 +1,105 @@
+use crate::core::client::storage::{StorageClient, StorageError};
+use async_trait::async_trait;
+use bytes::Bytes;
+use std::path::{Component, Path, PathBuf};
+
+/// LocalStorage is a storage client writing the objects to the local filesystem.
+/// Each key is stored as a file relative to the root directory.
+#[derive(Clone, Debug)]
+pub struct LocalStorage {
+    root: PathBuf,
+}
+
+impl LocalStorage {
+    /// Creates a new instance of LocalStorage storing the objects under `root`.
+    /// # Arguments
+    /// * `root` - The root directory of the storage, created on the first write if it does not exist.
+    ///
+    /// # Returns
+    /// * `Self` - The new instance of LocalStorage.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Returns the path of the file for a key.
+    /// Keys are relative paths, they cannot point outside the root directory.
+    fn path_for_key(&self, key: &str) -> Result<PathBuf, StorageError> {
+        let relative = Path::new(key);
+        if key.is_empty() || !relative.components().all(|c| matches!(c, Component::Normal(_))) {
+            return Err(StorageError::InvalidKey(key.to_string()));
+        }
+        Ok(self.root.join(relative))
+    }
+}
+
+#[async_trait]
+impl StorageClient for LocalStorage {
+    /// Get the data stored with the specified key.
+    ///
+    /// # Arguments
+    /// * `key` - The key of the object to retrieve.
+    ///
+    /// # Returns
+    /// * `Result<Bytes, StorageError>` - The result of the get operation.
+    async fn get_data(&self, key: &str) -> Result<Bytes, StorageError> {
+        Ok(tokio::fs::read(self.path_for_key(key)?).await?.into())
+    }
+
+    /// Put the data with the specified key, creating the parent directories if needed.
+    ///
+    /// # Arguments
+    /// * `data` - The data to store.
+    /// * `key` - The key of the object to put.
+    /// # Returns
+    /// * `Result<(), StorageError>` - The result of the put operation.
+    async fn put_data(&self, data: Bytes, key: &str) -> Result<(), StorageError> {
+        let path = self.path_for_key(key)?;
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        // Write to a temporary file first so that readers never see a partially written object.
+        let mut tmp_path = path.clone().into_os_string();
+        tmp_path.push(".tmp");
+        tokio::fs::write(&tmp_path, &data).await?;
+        tokio::fs::rename(&tmp_path, &path).await?;
+        Ok(())
+    }
+
+    /// delete the data stored with the specified key.
+    ///
+    /// # Arguments
+    /// * `key` - The key of the object to delete.
+    /// # Returns
+    /// * `Result<(), StorageError>` - The result of the delete operation.
+    async fn delete_data(&self, key: &str) -> Result<(), StorageError> {
+        Ok(tokio::fs::remove_file(self.path_for_key(key)?).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[tokio::test]
+    async fn test_local_storage_put_get_delete() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = LocalStorage::new(dir.path());
+
+        storage.put_data(Bytes::from_static(b"data"), "1/snos_output.json").await.unwrap();
+        assert_eq!(storage.get_data("1/snos_output.json").await.unwrap(), Bytes::from_static(b"data"));
+
+        storage.delete_data("1/snos_output.json").await.unwrap();
+        assert!(storage.get_data("1/snos_output.json").await.is_err());
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("../outside")]
+    #[case("/etc/passwd")]
+    #[case("1/../../outside")]
+    fn test_local_storage_invalid_keys(#[case] key: &str) {
+        let storage = LocalStorage::new("/tmp/storage");
+        assert!(matches!(storage.path_for_key(key), Err(StorageError::InvalidKey(_))));
+    }
+}
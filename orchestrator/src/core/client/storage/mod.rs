@@ -1,4 +1,5 @@
 pub mod error;
+pub mod local;
 pub mod s3;
 
 use async_trait::async_trait;
@@ -2,11 +2,13 @@
 use alloy::providers::RootProvider;
 
 use anyhow::Context;
+use aws_config::SdkConfig;
 use cairo_vm::types::layout_name::LayoutName;
 use orchestrator_atlantic_service::AtlanticProverService;
 use orchestrator_da_client_interface::DaClient;
 use orchestrator_ethereum_da_client::EthereumDaClient;
 use orchestrator_ethereum_settlement_client::EthereumSettlementClient;
+use orchestrator_local_prover_service::{LocalProverMode, LocalProverService};
 use orchestrator_prover_client_interface::ProverClient;
 use orchestrator_settlement_client_interface::SettlementClient;
 use orchestrator_sharp_service::SharpProverService;
@@ -17,14 +19,15 @@ use starknet::providers::JsonRpcClient;
 use std::sync::Arc;
 use url::Url;
 
-use crate::core::error::OrchestratorCoreResult;
+use crate::core::error::{OrchestratorCoreError, OrchestratorCoreResult};
 use crate::types::params::database::DatabaseArgs;
 use crate::types::Layer;
 use crate::{
     cli::RunCmd,
     core::client::{
-        queue::QueueClient, storage::s3::AWSS3, storage::StorageClient, AlertClient, DatabaseClient, MongoDbClient,
-        SNS, SQS,
+        alert::webhook::Webhook, queue::mongodb::MongoDbQueue, queue::rabbitmq::RabbitMQ, queue::QueueClient,
+        storage::local::LocalStorage, storage::s3::AWSS3, storage::StorageClient, AlertClient, DatabaseClient,
+        MongoDbClient, SNS, SQS,
     },
     core::cloud::CloudProvider,
     types::params::da::DAConfig,
@@ -32,7 +35,7 @@ use crate::{
     types::params::service::{ServerParams, ServiceParams},
     types::params::settlement::SettlementConfig,
     types::params::snos::SNOSParams,
-    types::params::{AlertArgs, QueueArgs, StorageArgs},
+    types::params::{AlertBackend, QueueBackend, StorageBackend},
     OrchestratorError, OrchestratorResult,
 };
 
@@ -46,6 +49,8 @@ pub struct ConfigParam {
     pub snos_layout_name: LayoutName,
     /// Layout to use for proving
     pub prover_layout_name: LayoutName,
+    /// Whether the proofs of the blocks are stored, which is always the case on L3
+    pub store_proofs: bool,
 }
 
 /// The app config. It can be accessed from anywhere inside the service
@@ -103,18 +108,23 @@ impl Config {
 
     /// new - create config from the run command
     pub async fn from_run_cmd(run_cmd: &RunCmd) -> OrchestratorResult<Self> {
-        let cloud_provider =
-            CloudProvider::try_from(run_cmd.clone()).context("Failed to create cloud provider from run command")?;
-        let provider_config = Arc::new(cloud_provider);
+        // The cloud provider is only needed by the AWS clients
+        let provider_config = if run_cmd.aws_config_args.aws {
+            let cloud_provider =
+                CloudProvider::try_from(run_cmd.clone()).context("Failed to create cloud provider from run command")?;
+            Some(Arc::new(cloud_provider))
+        } else {
+            None
+        };
 
         let db: DatabaseArgs =
             DatabaseArgs::try_from(run_cmd.clone()).context("Failed to create database args from run command")?;
-        let storage_args: StorageArgs =
-            StorageArgs::try_from(run_cmd.clone()).context("Failed to create storage args from run command")?;
-        let alert_args: AlertArgs =
-            AlertArgs::try_from(run_cmd.clone()).context("Failed to create alert args from run command")?;
-        let queue_args: QueueArgs =
-            QueueArgs::try_from(run_cmd.clone()).context("Failed to create queue args from run command")?;
+        let storage_args: StorageBackend =
+            StorageBackend::try_from(run_cmd.clone()).context("Failed to create storage args from run command")?;
+        let alert_args: AlertBackend =
+            AlertBackend::try_from(run_cmd.clone()).context("Failed to create alert args from run command")?;
+        let queue_args: QueueBackend =
+            QueueBackend::try_from(run_cmd.clone()).context("Failed to create queue args from run command")?;
 
         let prover_config =
             ProverConfig::try_from(run_cmd.clone()).context("Failed to create prover config from run command")?;
@@ -133,6 +143,11 @@ impl Config {
                 .context("Failed to get SNOS layout name")?,
             prover_layout_name: Self::get_layout_name(run_cmd.proving_layout_args.prover_layout_name.clone().as_str())
                 .context("Failed to get prover layout name")?,
+            // The local stone proofs are not registered on chain, they are kept in the storage instead
+            store_proofs: matches!(
+                &prover_config,
+                ProverConfig::Local(args) if matches!(args.local_prover_mode, LocalProverMode::Stone(_))
+            ),
         };
         let rpc_client = JsonRpcClient::new(HttpTransport::new(params.madara_rpc_url.clone()));
 
@@ -143,7 +158,7 @@ impl Config {
 
         // External Clients Initialization
         let prover_client = Self::build_prover_service(&prover_config);
-        let da_client = Self::build_da_client(&da_config).await;
+        let da_client = Self::build_da_client(&da_config).await?;
         let settlement_client = Self::build_settlement_client(&settlement_config).await?;
 
         Ok(Self {
@@ -171,28 +186,44 @@ impl Config {
         Ok(Box::new(MongoDbClient::new(db_args).await?))
     }
 
+    /// aws_config - Returns the AWS config, required by the AWS clients
+    fn aws_config(provider_config: &Option<Arc<CloudProvider>>) -> OrchestratorCoreResult<&SdkConfig> {
+        provider_config.as_deref().map(CloudProvider::get_aws_client_or_panic).ok_or_else(|| {
+            OrchestratorCoreError::InvalidProvider("The AWS provider is required for AWS clients".to_string())
+        })
+    }
+
     pub(crate) async fn build_storage_client(
-        storage_config: &StorageArgs,
-        provider_config: Arc<CloudProvider>,
+        storage_config: &StorageBackend,
+        provider_config: Option<Arc<CloudProvider>>,
     ) -> OrchestratorCoreResult<Box<dyn StorageClient + Send + Sync>> {
-        let aws_config = provider_config.get_aws_client_or_panic();
-        Ok(Box::new(AWSS3::new(aws_config, storage_config)))
+        match storage_config {
+            StorageBackend::AWSS3(storage_args) => {
+                Ok(Box::new(AWSS3::new(Self::aws_config(&provider_config)?, storage_args)))
+            }
+            StorageBackend::Local(path) => Ok(Box::new(LocalStorage::new(path))),
+        }
     }
 
     pub(crate) async fn build_alert_client(
-        alert_config: &AlertArgs,
-        provider_config: Arc<CloudProvider>,
+        alert_config: &AlertBackend,
+        provider_config: Option<Arc<CloudProvider>>,
     ) -> OrchestratorCoreResult<Box<dyn AlertClient + Send + Sync>> {
-        let aws_config = provider_config.get_aws_client_or_panic();
-        Ok(Box::new(SNS::new(aws_config, alert_config)))
+        match alert_config {
+            AlertBackend::AWSSNS(alert_args) => Ok(Box::new(SNS::new(Self::aws_config(&provider_config)?, alert_args))),
+            AlertBackend::Webhook(url) => Ok(Box::new(Webhook::new(url.clone()))),
+        }
     }
 
     pub(crate) async fn build_queue_client(
-        queue_config: &QueueArgs,
-        provider_config: Arc<CloudProvider>,
+        queue_config: &QueueBackend,
+        provider_config: Option<Arc<CloudProvider>>,
     ) -> OrchestratorCoreResult<Box<dyn QueueClient + Send + Sync>> {
-        let aws_config = provider_config.get_aws_client_or_panic();
-        Ok(Box::new(SQS::new(aws_config, queue_config)))
+        match queue_config {
+            QueueBackend::AWSSQS(queue_args) => Ok(Box::new(SQS::new(Self::aws_config(&provider_config)?, queue_args))),
+            QueueBackend::MongoDB(db_args) => Ok(Box::new(MongoDbQueue::new(db_args).await?)),
+            QueueBackend::RabbitMQ(url) => Ok(Box::new(RabbitMQ::new(url).await?)),
+        }
     }
 
     /// build_prover_service - Build the proving service based on the config
@@ -206,17 +237,22 @@ impl Config {
         match prover_params {
             ProverConfig::Sharp(sharp_params) => Box::new(SharpProverService::new_with_args(sharp_params)),
             ProverConfig::Atlantic(atlantic_params) => Box::new(AtlanticProverService::new_with_args(atlantic_params)),
+            ProverConfig::Local(local_prover_params) => {
+                Box::new(LocalProverService::new_with_args(local_prover_params))
+            }
         }
     }
 
-    pub(crate) async fn build_da_client(da_params: &DAConfig) -> Box<dyn DaClient + Send + Sync> {
+    pub(crate) async fn build_da_client(da_params: &DAConfig) -> OrchestratorResult<Box<dyn DaClient + Send + Sync>> {
         match da_params {
             DAConfig::Ethereum(ethereum_da_params) => {
-                Box::new(EthereumDaClient::new_with_args(ethereum_da_params).await)
-            }
-            DAConfig::Starknet(starknet_da_params) => {
-                Box::new(StarknetDaClient::new_with_args(starknet_da_params).await)
+                Ok(Box::new(EthereumDaClient::new_with_args(ethereum_da_params).await))
             }
+            DAConfig::Starknet(starknet_da_params) => Ok(Box::new(
+                StarknetDaClient::new_with_args(starknet_da_params)
+                    .await
+                    .map_err(|e| OrchestratorError::ConfigError(format!("Starknet DA client: {:#}", e)))?,
+            )),
         }
     }
 
@@ -327,4 +363,9 @@ impl Config {
     pub fn prover_layout_name(&self) -> &LayoutName {
         &self.params.prover_layout_name
     }
+
+    /// Returns whether the proofs of the blocks are stored
+    pub fn store_proofs(&self) -> bool {
+        self.params.store_proofs || self.layer == Layer::L3
+    }
 }
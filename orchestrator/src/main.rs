@@ -4,9 +4,10 @@ use orchestrator::cli::{Cli, Commands, RunCmd, SetupCmd};
 use orchestrator::core::config::Config;
 use orchestrator::server::setup_server;
 use orchestrator::setup::setup;
-use orchestrator::types::params::OTELConfig;
+use orchestrator::types::params::{InProcessCronArgs, OTELConfig};
 use orchestrator::utils::instrument::OrchestratorInstrumentation;
 use orchestrator::utils::logging::init_logging;
+use orchestrator::worker::controller::cron::InProcessCron;
 use orchestrator::worker::initialize_worker;
 use orchestrator::OrchestratorResult;
 use std::sync::Arc;
@@ -71,6 +72,12 @@ async fn run_orchestrator(run_cmd: &RunCmd) -> OrchestratorResult<()> {
     setup_server(config.clone()).await?;
 
     debug!("Application router initialized");
+
+    // Trigger the workers from within the orchestrator when EventBridge is not used
+    if let Some(cron_args) = InProcessCronArgs::from_run_cmd(run_cmd) {
+        InProcessCron::new(config.clone(), cron_args.interval).spawn();
+    }
+
     initialize_worker(config.clone()).await?;
 
     tokio::signal::ctrl_c().await.expect("Failed to listen for ctrl+c");
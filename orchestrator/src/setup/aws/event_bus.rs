@@ -29,6 +29,7 @@ lazy_static! {
         WorkerTriggerType::DataSubmission,
         WorkerTriggerType::UpdateState,
         WorkerTriggerType::Batching,
+        WorkerTriggerType::Aggregator,
     ];
 }
 
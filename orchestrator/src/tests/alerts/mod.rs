@@ -1,12 +1,17 @@
+use crate::core::client::alert::webhook::Webhook;
+use crate::core::client::alert::{AlertClient, AlertError};
 use crate::core::client::SNS;
 use crate::tests::common::{get_sns_client, get_sqs_client};
 use crate::tests::config::{ConfigType, TestConfigBuilder};
 use crate::types::params::{AWSResourceIdentifier, AlertArgs};
 use aws_sdk_sqs::types::QueueAttributeName::QueueArn;
+use httpmock::prelude::*;
 use orchestrator_utils::env_utils::get_env_var_or_panic;
 use rstest::rstest;
+use serde_json::json;
 use std::time::Duration;
 use tokio::time::sleep;
+use url::Url;
 
 pub const SNS_ALERT_TEST_QUEUE: &str = "orchestrator_sns_alert_testing_queue";
 
@@ -61,3 +66,39 @@ async fn sns_alert_subscribe_to_topic_receive_alert_works() {
     assert_eq!(receive_message_result.len(), 1, "Alert message length assertion failed");
     assert!(receive_message_result[0].body.clone().unwrap().contains(message_to_send));
 }
+
+/// The webhook alert client posts the message as `{"text": <message>}`.
+#[rstest]
+#[tokio::test]
+async fn webhook_alert_posts_message() {
+    let server = MockServer::start_async().await;
+    let mock = server
+        .mock_async(|when, then| {
+            when.method(POST).path("/hooks/alerts").json_body(json!({ "text": "Hello World :)" }));
+            then.status(200);
+        })
+        .await;
+
+    let webhook = Webhook::new(Url::parse(&server.url("/hooks/alerts")).unwrap());
+    webhook.send_message("Hello World :)".to_string()).await.unwrap();
+
+    mock.assert_async().await;
+}
+
+/// An error status from the webhook is an error of the alert client.
+#[rstest]
+#[tokio::test]
+async fn webhook_alert_error_status_fails() {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(POST).path("/hooks/alerts");
+            then.status(500);
+        })
+        .await;
+
+    let webhook = Webhook::new(Url::parse(&server.url("/hooks/alerts")).unwrap());
+    let err = webhook.send_message("Hello World :)".to_string()).await.unwrap_err();
+
+    assert!(matches!(err, AlertError::WebhookError(_)), "{err:?}");
+}
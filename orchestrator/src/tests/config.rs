@@ -26,6 +26,8 @@ use cairo_vm::types::layout_name::LayoutName;
 use httpmock::MockServer;
 use orchestrator_da_client_interface::{DaClient, MockDaClient};
 use orchestrator_ethereum_da_client::EthereumDaValidatedArgs;
+use orchestrator_ethereum_settlement_client::signer::EthereumSignerConfig;
+use orchestrator_ethereum_settlement_client::tx_manager::GasEscalationPolicy;
 use orchestrator_ethereum_settlement_client::EthereumSettlementValidatedArgs;
 use orchestrator_prover_client_interface::{MockProverClient, ProverClient};
 use orchestrator_settlement_client_interface::{MockSettlementClient, SettlementClient};
@@ -113,6 +115,8 @@ pub struct TestConfigBuilder {
     storage_type: ConfigType,
     /// API Service
     api_server_type: ConfigType,
+    /// Changes to the service params read from the environment
+    service_params_update: Option<Box<dyn FnOnce(&mut ServiceParams) + Send>>,
 }
 
 impl Default for TestConfigBuilder {
@@ -142,6 +146,7 @@ impl TestConfigBuilder {
             storage_type: ConfigType::default(),
             alerts_type: ConfigType::default(),
             api_server_type: ConfigType::default(),
+            service_params_update: None,
         }
     }
 
@@ -194,10 +199,18 @@ impl TestConfigBuilder {
         self
     }
 
+    pub fn configure_service_params(
+        mut self,
+        update: impl FnOnce(&mut ServiceParams) + Send + 'static,
+    ) -> TestConfigBuilder {
+        self.service_params_update = Some(Box::new(update));
+        self
+    }
+
     pub async fn build(self) -> TestConfigBuilderReturns {
         dotenvy::from_filename_override("../.env.test").expect("Failed to load the .env.test file");
 
-        let params = get_env_params();
+        let mut params = get_env_params();
 
         let provider_config =
             Arc::new(CloudProvider::try_from(params.aws_params.clone()).expect("Failed to create provider config"));
@@ -213,8 +226,13 @@ impl TestConfigBuilder {
             queue_type,
             storage_type,
             api_server_type,
+            service_params_update,
         } = self;
 
+        if let Some(update) = service_params_update {
+            update(&mut params.orchestrator_params.service_config);
+        }
+
         let (_starknet_rpc_url, starknet_client, starknet_server) =
             implement_client::init_starknet_client(starknet_rpc_url_type, starknet_client_type).await;
 
@@ -311,7 +329,7 @@ pub mod implement_client {
     use crate::types::params::da::DAConfig;
     use crate::types::params::database::DatabaseArgs;
     use crate::types::params::settlement::SettlementConfig;
-    use crate::types::params::{AlertArgs, QueueArgs, StorageArgs};
+    use crate::types::params::{AlertArgs, AlertBackend, QueueArgs, QueueBackend, StorageArgs, StorageBackend};
     use crate::types::Layer;
 
     macro_rules! implement_mock_client_conversion {
@@ -339,7 +357,7 @@ pub mod implement_client {
     pub(crate) async fn init_da_client(service: ConfigType, da_params: &DAConfig) -> Box<dyn DaClient> {
         match service {
             ConfigType::Mock(client) => client.into(),
-            ConfigType::Actual => Config::build_da_client(da_params).await,
+            ConfigType::Actual => Config::build_da_client(da_params).await.expect("Failed to build the DA client"),
             ConfigType::Dummy => Box::new(MockDaClient::new()),
         }
     }
@@ -373,7 +391,9 @@ pub mod implement_client {
         match service {
             ConfigType::Mock(client) => client.into(),
             ConfigType::Actual => {
-                Config::build_alert_client(alert_params, provider_config).await.expect("error creating alert client")
+                Config::build_alert_client(&AlertBackend::AWSSNS(alert_params.clone()), Some(provider_config))
+                    .await
+                    .expect("error creating alert client")
             }
             ConfigType::Dummy => Box::new(MockAlertClient::new()),
         }
@@ -393,7 +413,9 @@ pub mod implement_client {
                 // First set up the storage
                 println!("Setting up the storage , {:?}", storage_cfg);
                 storage.setup(&Layer::L2, storage_cfg.clone()).await.unwrap();
-                Config::build_storage_client(storage_cfg, provider_config).await.expect("error creating storage client")
+                Config::build_storage_client(&StorageBackend::AWSS3(storage_cfg.clone()), Some(provider_config))
+                    .await
+                    .expect("error creating storage client")
             }
             ConfigType::Dummy => Box::new(MockStorageClient::new()),
         }
@@ -407,7 +429,9 @@ pub mod implement_client {
         match service {
             ConfigType::Mock(client) => client.into(),
             ConfigType::Actual => {
-                Config::build_queue_client(&queue_params, provider_config).await.expect("error creating queue client")
+                Config::build_queue_client(&QueueBackend::AWSSQS(queue_params), Some(provider_config))
+                    .await
+                    .expect("error creating queue client")
             }
             ConfigType::Dummy => Box::new(MockQueueClient::new()),
         }
@@ -515,7 +539,9 @@ pub(crate) fn get_env_params() -> EnvParams {
     let settlement_params = SettlementConfig::Ethereum(EthereumSettlementValidatedArgs {
         ethereum_rpc_url: Url::parse(&get_env_var_or_panic("MADARA_ORCHESTRATOR_ETHEREUM_SETTLEMENT_RPC_URL"))
             .expect("Failed to parse MADARA_ORCHESTRATOR_ETHEREUM_RPC_URL"),
-        ethereum_private_key: get_env_var_or_panic("MADARA_ORCHESTRATOR_ETHEREUM_PRIVATE_KEY"),
+        ethereum_signer: EthereumSignerConfig::PrivateKey(get_env_var_or_panic(
+            "MADARA_ORCHESTRATOR_ETHEREUM_PRIVATE_KEY",
+        )),
         l1_core_contract_address: Address::from_str(&get_env_var_or_panic(
             "MADARA_ORCHESTRATOR_L1_CORE_CONTRACT_ADDRESS",
         ))
@@ -524,6 +550,7 @@ pub(crate) fn get_env_params() -> EnvParams {
             "MADARA_ORCHESTRATOR_STARKNET_OPERATOR_ADDRESS",
         ))
         .expect("Invalid Starknet operator address"),
+        gas_escalation_policy: GasEscalationPolicy::default(),
     });
 
     let snos_config = SNOSParams {
@@ -559,6 +586,10 @@ pub(crate) fn get_env_params() -> EnvParams {
         max_concurrent_snos_jobs,
         max_concurrent_proving_jobs,
         job_processing_timeout_seconds: 3600,
+        max_batch_size: 100,
+        max_batch_time_seconds: 3600,
+        aggregate_batch_proofs: false,
+        verify_proofs_locally: false,
     };
 
     let server_config = ServerParams {
@@ -576,6 +607,7 @@ pub(crate) fn get_env_params() -> EnvParams {
         server_config,
         snos_layout_name: LayoutName::all_cairo,
         prover_layout_name: LayoutName::dynamic,
+        store_proofs: false,
     };
 
     let instrumentation_params = OTELConfig {
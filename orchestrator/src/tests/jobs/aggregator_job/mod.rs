@@ -0,0 +1,176 @@
+use std::fs;
+use std::path::Path;
+
+use bytes::Bytes;
+use mockall::predicate::eq;
+use orchestrator_prover_client_interface::{MockProverClient, TaskStatus};
+use rstest::*;
+use starknet::core::types::{ContractStorageDiffItem, Felt, StateDiff, StateUpdate, StorageEntry};
+
+use super::super::common::default_job_item;
+use crate::core::client::database::MockDatabaseClient;
+use crate::core::client::storage::MockStorageClient;
+use crate::tests::config::TestConfigBuilder;
+use crate::types::batch::Batch;
+use crate::types::jobs::job_item::JobItem;
+use crate::types::jobs::metadata::{AggregatorMetadata, CommonMetadata, JobMetadata, JobSpecificMetadata};
+use crate::types::jobs::status::JobVerificationStatus;
+use crate::types::jobs::types::{JobStatus, JobType};
+use crate::worker::event_handler::jobs::aggregator::AggregatorJobHandler;
+use crate::worker::event_handler::jobs::state_update::{calculate_output, vec_felt_to_vec_bytes32};
+use crate::worker::event_handler::jobs::JobHandlerTrait;
+
+fn aggregator_metadata() -> JobSpecificMetadata {
+    JobSpecificMetadata::Aggregator(AggregatorMetadata {
+        batch_num: 1,
+        bucket_id: "bucket_id".to_string(),
+        squashed_state_update_path: "state_update/batch/1.json".to_string(),
+        download_proof: None,
+        program_output_path: "batch/1/program_output.txt".to_string(),
+        blob_data_path: "batch/1/blob_data.txt".to_string(),
+    })
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_create_job() {
+    let metadata = JobMetadata { common: CommonMetadata::default(), specific: aggregator_metadata() };
+
+    let job = AggregatorJobHandler.create_job(String::from("1"), metadata).await.unwrap();
+
+    assert_eq!(job.job_type, JobType::Aggregator, "job_type should be Aggregator");
+    assert_eq!(job.status, JobStatus::Created, "status should be Created");
+    assert_eq!(job.internal_id, "1", "internal_id should be the batch index");
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_verify_job_pending_until_aggregation_task_created(#[from(default_job_item)] mut job_item: JobItem) {
+    let mut prover_client = MockProverClient::new();
+    prover_client.expect_get_aggregation_task_id().with(eq("bucket_id")).times(1).returning(|_| Ok(None));
+    prover_client.expect_get_task_status().never();
+
+    let services = TestConfigBuilder::new().configure_prover_client(prover_client.into()).build().await;
+    job_item.metadata.specific = aggregator_metadata();
+
+    assert_eq!(
+        AggregatorJobHandler.verify_job(services.config, &mut job_item).await.unwrap(),
+        JobVerificationStatus::Pending
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_verify_job_rejected_when_aggregation_fails(#[from(default_job_item)] mut job_item: JobItem) {
+    let mut prover_client = MockProverClient::new();
+    prover_client
+        .expect_get_aggregation_task_id()
+        .with(eq("bucket_id"))
+        .times(1)
+        .returning(|_| Ok(Some("aggregation_task_id".to_string())));
+    prover_client
+        .expect_get_task_status()
+        .times(1)
+        .returning(|_, _, _| Ok(TaskStatus::Failed("aggregation failed".to_string())));
+
+    let services = TestConfigBuilder::new().configure_prover_client(prover_client.into()).build().await;
+    job_item.metadata.specific = aggregator_metadata();
+
+    assert!(matches!(
+        AggregatorJobHandler.verify_job(services.config, &mut job_item).await.unwrap(),
+        JobVerificationStatus::Rejected(_)
+    ));
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_process_job(#[from(default_job_item)] mut job_item: JobItem) {
+    let mut database = MockDatabaseClient::new();
+    // The batch starts at the genesis block, so no alias is read from the madara client
+    database.expect_get_batch_by_index().with(eq(1)).times(1).returning(|index| {
+        Ok(Some(Batch::create(index, 0, "state_update/batch/1.json".to_string(), Some("bucket_id".to_string()))))
+    });
+
+    let state_update = StateUpdate {
+        block_hash: Felt::ONE,
+        new_root: Felt::TWO,
+        old_root: Felt::ZERO,
+        state_diff: StateDiff {
+            storage_diffs: vec![ContractStorageDiffItem {
+                address: Felt::from(0x1000),
+                storage_entries: vec![StorageEntry { key: Felt::from(0x10), value: Felt::ONE }],
+            }],
+            deprecated_declared_classes: vec![],
+            declared_classes: vec![],
+            deployed_contracts: vec![],
+            replaced_classes: vec![],
+            nonces: vec![],
+        },
+    };
+    let state_update_bytes = Bytes::from(serde_json::to_vec(&state_update).unwrap());
+    let mut storage = MockStorageClient::new();
+    storage
+        .expect_get_data()
+        .with(eq("state_update/batch/1.json"))
+        .times(1)
+        .returning(move |_| Ok(state_update_bytes.clone()));
+    storage
+        .expect_put_data()
+        .withf(|data, key| key == "batch/1/blob_data.txt" && !data.is_empty())
+        .times(1)
+        .returning(|_, _| Ok(()));
+
+    let mut prover_client = MockProverClient::new();
+    prover_client.expect_close_bucket().with(eq("bucket_id")).times(1).returning(|_| Ok(()));
+
+    let services = TestConfigBuilder::new()
+        .configure_database(database.into())
+        .configure_storage_client(storage.into())
+        .configure_prover_client(prover_client.into())
+        .build()
+        .await;
+    job_item.metadata.specific = aggregator_metadata();
+
+    assert_eq!(AggregatorJobHandler.process_job(services.config, &mut job_item).await.unwrap(), "bucket_id");
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_verify_job_stores_aggregated_program_output(#[from(default_job_item)] mut job_item: JobItem) {
+    let proof = fs::read_to_string(Path::new(&format!(
+        "{}/crates/prover-clients/gps-fact-checker/tests/artifacts/fibonacci_proof.json",
+        env!("CARGO_MANIFEST_DIR")
+    )))
+    .unwrap();
+    let program_output =
+        vec_felt_to_vec_bytes32(calculate_output(swiftness_proof_parser::parse(proof.clone()).unwrap()));
+    let encoded_program_output = Bytes::from(bincode::serialize(&program_output).unwrap());
+
+    let mut prover_client = MockProverClient::new();
+    prover_client
+        .expect_get_aggregation_task_id()
+        .with(eq("bucket_id"))
+        .times(1)
+        .returning(|_| Ok(Some("aggregation_task_id".to_string())));
+    prover_client.expect_get_task_status().times(1).returning(|_, _, _| Ok(TaskStatus::Succeeded));
+    prover_client.expect_get_proof().with(eq("aggregation_task_id")).times(1).returning(move |_| Ok(proof.clone()));
+
+    let mut storage = MockStorageClient::new();
+    storage
+        .expect_put_data()
+        .with(eq(encoded_program_output), eq("batch/1/program_output.txt"))
+        .times(1)
+        .returning(|_, _| Ok(()));
+
+    let services = TestConfigBuilder::new()
+        .configure_prover_client(prover_client.into())
+        .configure_storage_client(storage.into())
+        .build()
+        .await;
+    job_item.metadata.specific = aggregator_metadata();
+
+    assert_eq!(
+        AggregatorJobHandler.verify_job(services.config, &mut job_item).await.unwrap(),
+        JobVerificationStatus::Verified
+    );
+}
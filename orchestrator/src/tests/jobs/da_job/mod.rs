@@ -2,7 +2,7 @@ use assert_matches::assert_matches;
 use chrono::{SubsecRound, Utc};
 use color_eyre::eyre::eyre;
 use mockall::predicate::always;
-use orchestrator_da_client_interface::MockDaClient;
+use orchestrator_da_client_interface::{DaDataFormat, MockDaClient};
 use rstest::rstest;
 use serde_json::json;
 use starknet::core::types::{Felt, MaybePendingStateUpdate, PendingStateUpdate, StateDiff};
@@ -43,6 +43,7 @@ async fn test_da_job_process_job_failure_on_small_blob_size(
     // dummy state will have more than 1200 bytes
     da_client.expect_max_blob_per_txn().with().returning(|| 1);
     da_client.expect_max_bytes_per_blob().with().returning(|| 1200);
+    da_client.expect_data_format().with().returning(|| DaDataFormat::Blobs);
     let services = TestConfigBuilder::new()
         .configure_starknet_client(ConfigType::Actual)
         .configure_storage_client(ConfigType::Actual)
@@ -210,6 +211,7 @@ async fn test_da_job_process_job_success(
     da_client.expect_publish_state_diff().with(always(), always()).returning(|_, _| Ok("Done".to_string()));
     da_client.expect_max_blob_per_txn().with().returning(|| 6);
     da_client.expect_max_bytes_per_blob().with().returning(|| 131072);
+    da_client.expect_data_format().with().returning(|| DaDataFormat::Blobs);
 
     let services = TestConfigBuilder::new()
         .configure_starknet_client(ConfigType::Actual)
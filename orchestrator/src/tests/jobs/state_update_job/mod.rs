@@ -9,7 +9,9 @@ use httpmock::prelude::*;
 use lazy_static::lazy_static;
 use mockall::predicate::{always, eq};
 use num_bigint::BigUint;
-use orchestrator_settlement_client_interface::MockSettlementClient;
+use orchestrator_settlement_client_interface::{
+    MockSettlementClient, SettlementTx, SettlementVerificationStatus, TxReplacement,
+};
 use rstest::*;
 use starknet::providers::jsonrpc::HttpTransport;
 use starknet::providers::JsonRpcClient;
@@ -21,7 +23,10 @@ use crate::error::job::JobError;
 use crate::tests::common::default_job_item;
 use crate::tests::config::{ConfigType, TestConfigBuilder};
 use crate::types::constant::{BLOB_DATA_FILE_NAME, PROGRAM_OUTPUT_FILE_NAME, SNOS_OUTPUT_FILE_NAME};
-use crate::types::jobs::metadata::{CommonMetadata, JobMetadata, JobSpecificMetadata, StateUpdateMetadata};
+use crate::types::jobs::metadata::{
+    CommonMetadata, InFlightSettlementTx, JobMetadata, JobSpecificMetadata, StateUpdateMetadata,
+};
+use crate::types::jobs::status::JobVerificationStatus;
 use crate::types::jobs::types::{JobStatus, JobType};
 use crate::worker::event_handler::jobs::state_update::StateUpdateJobHandler;
 use crate::worker::event_handler::jobs::JobHandlerTrait;
@@ -48,8 +53,10 @@ async fn test_process_job_attempt_not_present_fails() {
         snos_output_paths: vec![],
         program_output_paths: vec![],
         blob_data_paths: vec![],
+        batch_num: None,
         last_failed_block_no: None,
         tx_hashes: vec![],
+        in_flight_txs: Vec::new(),
     });
 
     let res = StateUpdateJobHandler.process_job(services.config, &mut job).await.unwrap_err();
@@ -102,7 +109,7 @@ async fn test_process_job_works(
             .expect_update_state_with_blobs()
             .with(eq(program_output_data_vec), eq(blob_data_vec), always())
             .times(1)
-            .returning(|_, _, _| Ok("0xbeef".to_string()));
+            .returning(|_, _, nonce| Ok(SettlementTx { tx_hash: "0xbeef".to_string(), nonce, ..Default::default() }));
     }
     settlement_client.expect_get_last_settled_block().with().returning(move || Ok(Some(651052)));
     // Setting random nonce
@@ -168,8 +175,10 @@ async fn test_process_job_works(
             snos_output_paths,
             program_output_paths,
             blob_data_paths,
+            batch_num: None,
             last_failed_block_no: failed_block_number,
             tx_hashes: Vec::new(), // Start with empty tx_hashes, they'll be populated during processing
+            in_flight_txs: Vec::new(),
         }),
     };
 
@@ -198,8 +207,10 @@ async fn create_job_works() {
             snos_output_paths: vec![format!("1/{}", SNOS_OUTPUT_FILE_NAME)],
             program_output_paths: vec![format!("1/{}", PROGRAM_OUTPUT_FILE_NAME)],
             blob_data_paths: vec![format!("1/{}", BLOB_DATA_FILE_NAME)],
+            batch_num: None,
             last_failed_block_no: None,
             tx_hashes: vec![],
+            in_flight_txs: Vec::new(),
         }),
     };
 
@@ -283,7 +294,13 @@ async fn process_job_works_unit_test() {
         settlement_client
             .expect_update_state_with_blobs()
             .with(eq(deserialized_program_output), eq(vec![blob_data_vec_clone]), always())
-            .returning(|_, _, _| Ok(String::from("0x5d17fac98d9454030426606019364f6e68d915b91f6210ef1e2628cd6987442")));
+            .returning(|_, _, nonce| {
+                Ok(SettlementTx {
+                    tx_hash: String::from("0x5d17fac98d9454030426606019364f6e68d915b91f6210ef1e2628cd6987442"),
+                    nonce,
+                    ..Default::default()
+                })
+            });
     }
 
     let services = TestConfigBuilder::new()
@@ -306,8 +323,10 @@ async fn process_job_works_unit_test() {
                 .map(|block| format!("{}/{}", block, PROGRAM_OUTPUT_FILE_NAME))
                 .collect(),
             blob_data_paths: block_numbers.iter().map(|block| format!("{}/{}", block, BLOB_DATA_FILE_NAME)).collect(),
+            batch_num: None,
             last_failed_block_no: None,
             tx_hashes: vec![],
+            in_flight_txs: Vec::new(),
         }),
     };
 
@@ -353,8 +372,10 @@ async fn process_job_invalid_inputs_errors(#[case] block_numbers: Vec<u64>, #[ca
             snos_output_paths,
             program_output_paths,
             blob_data_paths,
+            batch_num: None,
             last_failed_block_no: None,
             tx_hashes: vec![],
+            in_flight_txs: Vec::new(),
         }),
     };
 
@@ -410,8 +431,10 @@ async fn process_job_invalid_input_gap_panics() {
                 format!("{}/{}", 7, BLOB_DATA_FILE_NAME),
                 format!("{}/{}", 8, BLOB_DATA_FILE_NAME),
             ],
+            batch_num: None,
             last_failed_block_no: None,
             tx_hashes: vec![],
+            in_flight_txs: Vec::new(),
         }),
     };
 
@@ -429,6 +452,142 @@ async fn process_job_invalid_input_gap_panics() {
 
 // ==================== Utility functions ===========================
 
+pub const IN_FLIGHT_PROGRAM_OUTPUT_PATH: &str = "651053/program_output.txt";
+pub const IN_FLIGHT_BLOB_DATA_PATH: &str = "651053/blob_data.txt";
+
+#[rstest]
+#[tokio::test]
+async fn verify_job_replaces_pending_tx() {
+    let program_output = vec![[1; 32]; 16];
+    let blob_data = vec![2; 32];
+
+    let mut storage_client = MockStorageClient::new();
+    let stored_program_output = bincode::serialize(&program_output).unwrap();
+    storage_client
+        .expect_get_data()
+        .with(eq(IN_FLIGHT_PROGRAM_OUTPUT_PATH))
+        .returning(move |_| Ok(Bytes::from(stored_program_output.clone())));
+    let stored_blob_data = blob_data.clone();
+    storage_client
+        .expect_get_data()
+        .with(eq(IN_FLIGHT_BLOB_DATA_PATH))
+        .returning(move |_| Ok(Bytes::from(stored_blob_data.clone())));
+
+    let mut settlement_client = MockSettlementClient::new();
+    settlement_client
+        .expect_verify_tx_inclusion()
+        .withf(|tx_hash| tx_hash == "0x1")
+        .times(1)
+        .returning(|_| Ok(SettlementVerificationStatus::Pending));
+    // The replacement is built from the persisted tx and the stored program output and blob data
+    settlement_client
+        .expect_replace_pending_tx()
+        .with(eq(settlement_tx("0x1", 100, 10)), eq(0), eq(program_output), eq(vec![blob_data]))
+        .times(1)
+        .returning(|_, _, _, _| Ok(TxReplacement::Replaced(settlement_tx("0x2", 200, 20))));
+    settlement_client.expect_wait_for_tx_finality().never();
+
+    let services = TestConfigBuilder::new()
+        .configure_storage_client(storage_client.into())
+        .configure_settlement_client(settlement_client.into())
+        .build()
+        .await;
+
+    let mut job = default_job_item();
+    job.metadata.specific = settlement_tx_metadata(&["0x1"], &settlement_tx("0x1", 100, 10));
+
+    let status = StateUpdateJobHandler.verify_job(services.config, &mut job).await.unwrap();
+    assert_eq!(status, JobVerificationStatus::Pending);
+    assert_eq!(job.metadata.specific, settlement_tx_metadata(&["0x1", "0x2"], &settlement_tx("0x2", 200, 20)));
+}
+
+#[rstest]
+#[tokio::test]
+async fn verify_job_does_not_replace_recent_pending_tx() {
+    let mut storage_client = MockStorageClient::new();
+    storage_client.expect_get_data().returning(|_| Ok(Bytes::from(bincode::serialize(&vec![[1u8; 32]; 16]).unwrap())));
+
+    let mut settlement_client = MockSettlementClient::new();
+    settlement_client.expect_verify_tx_inclusion().returning(|_| Ok(SettlementVerificationStatus::Pending));
+    settlement_client.expect_replace_pending_tx().times(1).returning(|_, _, _, _| Ok(TxReplacement::TooRecent));
+    // The job stays pending without waiting for the tx, so that it is replaced at a later verification if needed
+    settlement_client.expect_wait_for_tx_finality().never();
+
+    let services = TestConfigBuilder::new()
+        .configure_storage_client(storage_client.into())
+        .configure_settlement_client(settlement_client.into())
+        .build()
+        .await;
+
+    let mut job = default_job_item();
+    job.metadata.specific = settlement_tx_metadata(&["0x1"], &settlement_tx("0x1", 100, 10));
+
+    let status = StateUpdateJobHandler.verify_job(services.config, &mut job).await.unwrap();
+    assert_eq!(status, JobVerificationStatus::Pending);
+    assert_eq!(job.metadata.specific, settlement_tx_metadata(&["0x1"], &settlement_tx("0x1", 100, 10)));
+}
+
+#[rstest]
+#[tokio::test]
+async fn verify_job_reconciles_included_tx() {
+    let mut settlement_client = MockSettlementClient::new();
+    settlement_client
+        .expect_verify_tx_inclusion()
+        .withf(|tx_hash| tx_hash == "0x2")
+        .returning(|_| Ok(SettlementVerificationStatus::Pending));
+    // The original tx was included before its replacement
+    settlement_client
+        .expect_verify_tx_inclusion()
+        .withf(|tx_hash| tx_hash == "0x1")
+        .returning(|_| Ok(SettlementVerificationStatus::Verified));
+    settlement_client.expect_replace_pending_tx().never();
+    settlement_client.expect_get_last_settled_block().returning(|| Ok(Some(651053)));
+
+    let services = TestConfigBuilder::new().configure_settlement_client(settlement_client.into()).build().await;
+
+    let mut job = default_job_item();
+    job.metadata.specific = settlement_tx_metadata(&["0x1", "0x2"], &settlement_tx("0x2", 200, 20));
+
+    let status = StateUpdateJobHandler.verify_job(services.config, &mut job).await.unwrap();
+    assert_eq!(status, JobVerificationStatus::Verified);
+    let JobSpecificMetadata::StateUpdate(state_metadata) = job.metadata.specific else {
+        panic!("Metadata should be state update metadata");
+    };
+    assert_eq!(state_metadata.tx_hashes, vec!["0x1".to_string()]);
+}
+
+fn settlement_tx(tx_hash: &str, max_fee_per_gas: u64, sent_at_block: u64) -> SettlementTx {
+    SettlementTx {
+        tx_hash: tx_hash.to_string(),
+        nonce: 1,
+        gas_limit: 100_000,
+        max_fee_per_gas,
+        max_priority_fee_per_gas: max_fee_per_gas / 10,
+        max_fee_per_blob_gas: max_fee_per_gas,
+        sent_at_block,
+    }
+}
+
+/// Metadata of a job which sent the txs `in_flight_tx_hashes` with the same nonce, the latest one being `latest_tx`.
+fn settlement_tx_metadata(in_flight_tx_hashes: &[&str], latest_tx: &SettlementTx) -> JobSpecificMetadata {
+    JobSpecificMetadata::StateUpdate(StateUpdateMetadata {
+        blocks_to_settle: vec![651053],
+        tx_hashes: vec![latest_tx.tx_hash.clone()],
+        in_flight_txs: vec![InFlightSettlementTx {
+            nonce: latest_tx.nonce,
+            tx_hashes: in_flight_tx_hashes.iter().map(|hash| hash.to_string()).collect(),
+            gas_limit: latest_tx.gas_limit,
+            max_fee_per_gas: latest_tx.max_fee_per_gas,
+            max_priority_fee_per_gas: latest_tx.max_priority_fee_per_gas,
+            max_fee_per_blob_gas: latest_tx.max_fee_per_blob_gas,
+            sent_at_block: latest_tx.sent_at_block,
+            program_output_path: Some(IN_FLIGHT_PROGRAM_OUTPUT_PATH.to_string()),
+            blob_data_path: Some(IN_FLIGHT_BLOB_DATA_PATH.to_string()),
+        }],
+        ..Default::default()
+    })
+}
+
 async fn load_state_diff_file(block_no: u64) -> Vec<u8> {
     let file_path = format!("src/tests/jobs/state_update_job/test_data/{}/{}", block_no, BLOB_DATA_FILE_NAME);
     let file_data = fs::read_to_string(file_path).expect("Unable to read blob_data.txt").replace("0x", "");
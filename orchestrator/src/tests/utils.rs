@@ -31,8 +31,10 @@ pub fn build_job_item(job_type: JobType, job_status: JobStatus, internal_id: u64
                 snos_output_paths: vec![format!("{}/{}", internal_id, SNOS_OUTPUT_FILE_NAME)],
                 program_output_paths: vec![format!("{}/{}", internal_id, PROGRAM_OUTPUT_FILE_NAME)],
                 blob_data_paths: vec![format!("{}/{}", internal_id, BLOB_DATA_FILE_NAME)],
+                batch_num: None,
                 last_failed_block_no: None,
                 tx_hashes: Vec::new(),
+                in_flight_txs: Vec::new(),
             }),
         },
         JobType::SnosRun => JobMetadata {
@@ -91,6 +93,7 @@ pub fn build_batch(
         end_block,
         is_batch_ready: false,
         squashed_state_updates_path: String::from("path/to/file.json"),
+        bucket_id: None,
         created_at: Utc::now().round_subsecs(0),
         updated_at: Utc::now().round_subsecs(0),
     }
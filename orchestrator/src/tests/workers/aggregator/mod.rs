@@ -0,0 +1,70 @@
+use std::sync::Arc;
+
+use mockall::predicate::eq;
+use rstest::*;
+
+use crate::tests::config::{ConfigType, TestConfigBuilder};
+use crate::tests::workers::utils::get_job_by_mock_id_vector;
+use crate::types::batch::{Batch, BatchUpdates};
+use crate::types::jobs::job_updates::JobItemUpdates;
+use crate::types::jobs::metadata::{AggregatorMetadata, StateUpdateMetadata};
+use crate::types::jobs::types::{JobStatus, JobType};
+use crate::worker::event_handler::factory::mock_factory::get_job_handler_context;
+use crate::worker::event_handler::jobs::aggregator::AggregatorJobHandler;
+use crate::worker::event_handler::jobs::state_update::StateUpdateJobHandler;
+use crate::worker::event_handler::triggers::aggregator::AggregatorJobTrigger;
+use crate::worker::event_handler::triggers::data_submission_worker::DataSubmissionJobTrigger;
+use crate::worker::event_handler::triggers::update_state::UpdateStateJobTrigger;
+use crate::worker::event_handler::triggers::JobTrigger;
+
+/// A closed batch whose blocks are proven is aggregated, then settled at once, without a DA job per block
+#[rstest]
+#[tokio::test]
+async fn aggregated_batch_is_settled_without_block_da_jobs() {
+    let services = TestConfigBuilder::new()
+        .configure_database(ConfigType::Actual)
+        .configure_queue_client(ConfigType::Actual)
+        .configure_service_params(|params| params.aggregate_batch_proofs = true)
+        .build()
+        .await;
+    let database = services.config.database();
+
+    // Batch 1 holds the blocks 0 and 1, whose proofs are completed
+    let batch = Batch::create(1, 0, "state_update/batch/1.json".to_string(), Some("bucket_id".to_string()));
+    let batch = database.create_batch(batch).await.unwrap();
+    database.update_batch(&batch, BatchUpdates { end_block: 1, is_batch_ready: true }).await.unwrap();
+    for job in get_job_by_mock_id_vector(JobType::ProofCreation, JobStatus::Completed, 2, 0) {
+        database.create_job(job).await.unwrap();
+    }
+
+    let ctx = get_job_handler_context();
+    ctx.expect().with(eq(JobType::Aggregator)).returning(move |_| Arc::new(Box::new(AggregatorJobHandler)));
+    ctx.expect().with(eq(JobType::StateTransition)).returning(move |_| Arc::new(Box::new(StateUpdateJobHandler)));
+
+    AggregatorJobTrigger.run_worker(services.config.clone()).await.unwrap();
+    let aggregator_job = database.get_job_by_internal_id_and_type("1", &JobType::Aggregator).await.unwrap().unwrap();
+    let aggregator_metadata: AggregatorMetadata = aggregator_job.metadata.specific.clone().try_into().unwrap();
+    assert_eq!(aggregator_metadata.bucket_id, "bucket_id");
+
+    // The data of the blocks is not published by block
+    DataSubmissionJobTrigger.run_worker(services.config.clone()).await.unwrap();
+    assert!(database.get_latest_job_by_type(JobType::DataSubmission).await.unwrap().is_none());
+
+    // Nothing is settled before the proofs are aggregated
+    UpdateStateJobTrigger.run_worker(services.config.clone()).await.unwrap();
+    assert!(database.get_latest_job_by_type(JobType::StateTransition).await.unwrap().is_none());
+
+    database
+        .update_job(&aggregator_job, JobItemUpdates::new().update_status(JobStatus::Completed).build())
+        .await
+        .unwrap();
+    UpdateStateJobTrigger.run_worker(services.config.clone()).await.unwrap();
+
+    let state_transition_job = database.get_latest_job_by_type(JobType::StateTransition).await.unwrap().unwrap();
+    assert_eq!(state_transition_job.internal_id, "0");
+    let state_metadata: StateUpdateMetadata = state_transition_job.metadata.specific.try_into().unwrap();
+    assert_eq!(state_metadata.blocks_to_settle, vec![0, 1]);
+    assert_eq!(state_metadata.batch_num, Some(1));
+    assert_eq!(state_metadata.program_output_paths, vec![aggregator_metadata.program_output_path]);
+    assert_eq!(state_metadata.blob_data_paths, vec![aggregator_metadata.blob_data_path]);
+}
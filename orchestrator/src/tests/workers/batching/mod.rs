@@ -0,0 +1,105 @@
+use std::error::Error;
+
+use chrono::{Duration, Utc};
+use httpmock::MockServer;
+use orchestrator_da_client_interface::MockDaClient;
+use rstest::rstest;
+use serde_json::json;
+
+use crate::tests::config::{ConfigType, TestConfigBuilder};
+use crate::types::batch::Batch;
+use crate::worker::event_handler::triggers::batching::BatchingTrigger;
+use crate::worker::event_handler::triggers::JobTrigger;
+
+/// Mocks a sequencer at `latest_block`, with the same small state update for every block and no alias allocated
+fn mock_sequencer(server: &MockServer, latest_block: u64) {
+    server.mock(|when, then| {
+        when.path("/").body_includes("starknet_blockNumber");
+        then.status(200).json_body(json!({ "id": 1, "jsonrpc": "2.0", "result": latest_block }));
+    });
+    server.mock(|when, then| {
+        when.path("/").body_includes("starknet_getStateUpdate");
+        then.status(200).json_body(json!({
+            "id": 1,
+            "jsonrpc": "2.0",
+            "result": {
+                "block_hash": "0x1",
+                "new_root": "0x2",
+                "old_root": "0x3",
+                "state_diff": {
+                    "storage_diffs": [{ "address": "0x300", "storage_entries": [{ "key": "0x1000", "value": "0x1" }] }],
+                    "deprecated_declared_classes": [],
+                    "declared_classes": [],
+                    "deployed_contracts": [],
+                    "replaced_classes": [],
+                    "nonces": []
+                }
+            }
+        }));
+    });
+    server.mock(|when, then| {
+        when.path("/").body_includes("starknet_getStorageAt");
+        then.status(200).json_body(json!({ "id": 1, "jsonrpc": "2.0", "result": "0x0" }));
+    });
+}
+
+/// A DA client with room for a lot more than the state diffs of the test
+fn da_client() -> MockDaClient {
+    let mut da_client = MockDaClient::new();
+    da_client.expect_max_blob_per_txn().returning(|| 6);
+    da_client.expect_max_bytes_per_blob().returning(|| 131072);
+    da_client
+}
+
+#[rstest]
+#[tokio::test]
+async fn batching_worker_closes_full_batches() -> Result<(), Box<dyn Error>> {
+    let services = TestConfigBuilder::new()
+        .configure_database(ConfigType::Actual)
+        .configure_storage_client(ConfigType::Actual)
+        .configure_da_client(da_client().into())
+        .configure_service_params(|params| params.max_batch_size = 2)
+        .build()
+        .await;
+    mock_sequencer(services.starknet_server.as_ref().unwrap(), 4);
+
+    BatchingTrigger.run_worker(services.config.clone()).await?;
+
+    let database = services.config.database();
+    let batches = [
+        database.get_batch_by_index(1).await?.unwrap(),
+        database.get_batch_by_index(2).await?.unwrap(),
+        database.get_batch_by_index(3).await?.unwrap(),
+    ];
+    let ranges: Vec<_> =
+        batches.iter().map(|batch| (batch.start_block, batch.end_block, batch.is_batch_ready)).collect();
+    assert_eq!(ranges, vec![(0, 1, true), (2, 3, true), (4, 4, false)]);
+    assert_eq!(database.get_batch_by_index(4).await?, None);
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn batching_worker_closes_old_batches() -> Result<(), Box<dyn Error>> {
+    let services = TestConfigBuilder::new()
+        .configure_database(ConfigType::Actual)
+        .configure_storage_client(ConfigType::Actual)
+        .configure_da_client(da_client().into())
+        .configure_service_params(|params| params.max_batch_time_seconds = 60)
+        .build()
+        .await;
+    // No block was produced since the batch was created
+    mock_sequencer(services.starknet_server.as_ref().unwrap(), 0);
+
+    let database = services.config.database();
+    let mut batch = Batch::create(1, 0, "state_update/batch/1.json".to_string(), None);
+    batch.created_at = Utc::now() - Duration::seconds(120);
+    database.create_batch(batch).await?;
+
+    BatchingTrigger.run_worker(services.config.clone()).await?;
+
+    let batch = database.get_batch_by_index(1).await?.unwrap();
+    assert_eq!((batch.start_block, batch.end_block, batch.is_batch_ready), (0, 0, true));
+    assert_eq!(database.get_batch_by_index(2).await?, None);
+    Ok(())
+}
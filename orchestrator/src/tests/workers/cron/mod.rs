@@ -0,0 +1,36 @@
+use crate::core::client::queue::MockQueueClient;
+use crate::setup::aws::event_bus::WORKER_TRIGGERS;
+use crate::tests::config::TestConfigBuilder;
+use crate::types::jobs::WorkerTriggerType;
+use crate::types::queue::QueueType;
+use crate::types::Layer;
+use crate::worker::controller::cron::InProcessCron;
+use mockall::predicate::{always, eq};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// The first tick is immediate: every worker is triggered once, through the worker trigger queue.
+#[tokio::test]
+async fn test_in_process_cron_triggers_workers() {
+    let sent = Arc::new(Mutex::new(Vec::new()));
+    let mut queue = MockQueueClient::new();
+    let sent_clone = sent.clone();
+    queue.expect_send_message().with(eq(QueueType::WorkerTrigger), always(), eq(None)).returning(
+        move |_, payload, _| {
+            sent_clone.lock().unwrap().push(payload);
+            Ok(())
+        },
+    );
+    let services = TestConfigBuilder::new().configure_queue_client(queue.into()).build().await;
+
+    let handle = InProcessCron::new(services.config.clone(), Duration::from_secs(3600)).spawn();
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    handle.abort();
+
+    let expected: Vec<String> = WORKER_TRIGGERS
+        .iter()
+        .filter(|trigger| **trigger != WorkerTriggerType::ProofRegistration || *services.config.layer() == Layer::L3)
+        .map(|trigger| trigger.to_string())
+        .collect();
+    assert_eq!(*sent.lock().unwrap(), expected);
+}
@@ -1,4 +1,10 @@
 #[cfg(test)]
+pub mod aggregator;
+#[cfg(test)]
+pub mod batching;
+#[cfg(test)]
+pub mod cron;
+#[cfg(test)]
 pub mod proving;
 #[cfg(test)]
 pub mod snos;
@@ -158,8 +158,10 @@ async fn update_state_worker_continues_from_previous_state_update() {
             format!("{}/{}", 3, BLOB_DATA_FILE_NAME),
             format!("{}/{}", 4, BLOB_DATA_FILE_NAME),
         ],
+        batch_num: None,
         last_failed_block_no: None,
         tx_hashes: Vec::new(),
+        in_flight_txs: Vec::new(),
     };
 
     job_item.metadata =
@@ -226,8 +228,10 @@ async fn update_state_worker_next_block_missing() {
             format!("{}/{}", 3, BLOB_DATA_FILE_NAME),
             format!("{}/{}", 4, BLOB_DATA_FILE_NAME),
         ],
+        batch_num: None,
         last_failed_block_no: None,
         tx_hashes: Vec::new(),
+        in_flight_txs: Vec::new(),
     };
 
     job_item.metadata =
@@ -124,8 +124,10 @@ fn create_metadata_for_job_type(job_type: JobType, block_number: u64) -> JobMeta
                 snos_output_paths: vec![format!("{}/{}", block_number, SNOS_OUTPUT_FILE_NAME)],
                 program_output_paths: vec![format!("{}/{}", block_number, PROGRAM_OUTPUT_FILE_NAME)],
                 blob_data_paths: vec![format!("{}/{}", block_number, BLOB_DATA_FILE_NAME)],
+                batch_num: None,
                 last_failed_block_no: None,
                 tx_hashes: Vec::new(),
+                in_flight_txs: Vec::new(),
             }),
         },
         // For any other job types, use a default metadata structure
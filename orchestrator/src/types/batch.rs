@@ -23,11 +23,16 @@ pub struct Batch {
     pub start_block: u64,
     pub end_block: u64,
     /// Whether the batch is ready to be processed,
-    /// This will happen when adding a new block takes the size of the felt array beyond 6 * 4096
+    /// This will happen when adding a new block takes the size of the compressed state diff beyond the capacity of a
+    /// DA transaction, or when the batch reaches its maximum number of blocks or its maximum age
     pub is_batch_ready: bool,
     /// Path to the squashed state updates file,
     /// This is done for optimization so we don't have to create a new squashed state update from scratch
     pub squashed_state_updates_path: String,
+    /// Bucket of the prover aggregating the proofs of the blocks of the batch, `None` if the proofs are not
+    /// aggregated
+    #[serde(default)]
+    pub bucket_id: Option<String>,
     /// timestamp when the batch was created
     #[cfg_attr(feature = "with_mongodb", serde(with = "chrono_datetime_as_bson_datetime"))]
     pub created_at: DateTime<Utc>,
@@ -37,7 +42,12 @@ pub struct Batch {
 }
 
 impl Batch {
-    pub fn create(index: u64, start_block: u64, squashed_state_updates_path: String) -> Self {
+    pub fn create(
+        index: u64,
+        start_block: u64,
+        squashed_state_updates_path: String,
+        bucket_id: Option<String>,
+    ) -> Self {
         Self {
             id: Uuid::new_v4(),
             index,
@@ -46,6 +56,7 @@ impl Batch {
             end_block: start_block,
             is_batch_ready: false,
             squashed_state_updates_path,
+            bucket_id,
             created_at: Utc::now().round_subsecs(0),
             updated_at: Utc::now().round_subsecs(0),
         }
@@ -3,8 +3,7 @@ pub const SNOS_OUTPUT_FILE_NAME: &str = "snos_output.json";
 pub const PROGRAM_OUTPUT_FILE_NAME: &str = "program_output.txt";
 pub const CAIRO_PIE_FILE_NAME: &str = "cairo_pie.zip";
 pub const STORAGE_STATE_UPDATE_DIR: &str = "state_update";
-// TODO: Remove this constant when `assign_batch_to_block` method is updated
-pub const MAX_BATCH_SIZE: u64 = 50;
+pub const STORAGE_BATCH_DIR: &str = "batch";
 pub const ON_CHAIN_DATA_FILE_NAME: &str = "onchain_data.json";
 pub const PROOF_FILE_NAME: &str = "proof.json";
 pub const PROOF_PART2_FILE_NAME: &str = "proof_part2.json";
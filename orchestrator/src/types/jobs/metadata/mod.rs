@@ -84,6 +84,13 @@ pub struct ProvingMetadata {
     pub download_proof: Option<String>,
     /// Number of steps taken by SNOS to generate the proof
     pub n_steps: Option<usize>,
+    /// Bucket of the prover aggregating the proof with the proofs of the other blocks of its batch, `None` if the
+    /// proofs are not aggregated
+    #[serde(default)]
+    pub bucket_id: Option<String>,
+    /// Index of the proof in the bucket
+    #[serde(default)]
+    pub bucket_job_index: Option<u64>,
 }
 
 /// Metadata specific to SNOS (Starknet OS) jobs.
@@ -118,7 +125,7 @@ pub struct SnosMetadata {
 ///
 /// # Field Management
 /// - Worker-initialized fields: blocks and paths configurations
-/// - Job-populated fields: last_failed_block_no and tx_hashes (during processing)
+/// - Job-populated fields: last_failed_block_no, tx_hashes and in_flight_txs (during processing and verification)
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
 pub struct StateUpdateMetadata {
     // Worker-initialized fields
@@ -131,11 +138,67 @@ pub struct StateUpdateMetadata {
     /// Paths to blob data files for each block
     pub blob_data_paths: Vec<String>,
 
+    /// Batch settled with a single transaction using the aggregated program output of its blocks, `None` if the
+    /// blocks are settled one by one
+    #[serde(default)]
+    pub batch_num: Option<u64>,
+
     // Job-populated fields
     /// Last block number that failed processing
     pub last_failed_block_no: Option<u64>,
     /// Transaction hashes for processed blocks
     pub tx_hashes: Vec<String>,
+    /// Transactions sent for each hash of `tx_hashes`, the original one and its fee bumped replacements
+    #[serde(default)]
+    pub in_flight_txs: Vec<InFlightSettlementTx>,
+}
+
+/// Settlement transactions sent with the same nonce, at most one of them can be included.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub struct InFlightSettlementTx {
+    /// Nonce of the transactions
+    pub nonce: u64,
+    /// Hashes of the original transaction and of its replacements, from the oldest to the latest
+    pub tx_hashes: Vec<String>,
+    /// Gas limit of the transactions
+    #[serde(default)]
+    pub gas_limit: u64,
+    /// Fees of the latest transaction, in wei
+    #[serde(default)]
+    pub max_fee_per_gas: u64,
+    #[serde(default)]
+    pub max_priority_fee_per_gas: u64,
+    #[serde(default)]
+    pub max_fee_per_blob_gas: u64,
+    /// Settlement layer block at which the latest transaction was sent
+    #[serde(default)]
+    pub sent_at_block: u64,
+    /// Path of the program output the transactions are built from, only set for the transactions sent with blobs
+    #[serde(default)]
+    pub program_output_path: Option<String>,
+    /// Path of the blob data of the transactions, only set for the transactions sent with blobs
+    #[serde(default)]
+    pub blob_data_path: Option<String>,
+}
+
+/// Metadata specific to aggregator jobs.
+///
+/// # Field Management
+/// All fields are initialized by the worker during job creation.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub struct AggregatorMetadata {
+    /// Index of the batch whose proofs are aggregated
+    pub batch_num: u64,
+    /// Bucket of the prover holding the proofs of the blocks of the batch
+    pub bucket_id: String,
+    /// Path to the squashed state update of the batch
+    pub squashed_state_update_path: String,
+    /// Path where the aggregated proof is downloaded
+    pub download_proof: Option<String>,
+    /// Path where the aggregated program output is stored
+    pub program_output_path: String,
+    /// Path where the blob data of the batch is stored
+    pub blob_data_path: String,
 }
 
 /// Enum containing all possible job-specific metadata types.
@@ -153,6 +216,8 @@ pub enum JobSpecificMetadata {
     Proving(ProvingMetadata),
     /// Data availability job metadata
     Da(DaMetadata),
+    /// Aggregator job metadata
+    Aggregator(AggregatorMetadata),
 }
 
 /// Macro to implement TryInto for JobSpecificMetadata variants
@@ -179,6 +244,7 @@ impl_try_into_metadata!(Snos, SnosMetadata);
 impl_try_into_metadata!(Proving, ProvingMetadata);
 impl_try_into_metadata!(Da, DaMetadata);
 impl_try_into_metadata!(StateUpdate, StateUpdateMetadata);
+impl_try_into_metadata!(Aggregator, AggregatorMetadata);
 
 /// Complete job metadata containing both common and job-specific fields.
 ///
@@ -25,12 +25,25 @@ impl TryFrom<RunCmd> for DAConfig {
                     .ethereum_da_rpc_url
                     .ok_or_else(|| OrchestratorError::SetupCommandError("Ethereum RPC URL is missing".to_string()))?,
             })),
-            (false, true) => Ok(DAConfig::Starknet(StarknetDaValidatedArgs {
-                starknet_da_rpc_url: run_cmd
-                    .starknet_da_args
-                    .starknet_da_rpc_url
-                    .ok_or_else(|| OrchestratorError::SetupCommandError("Starknet RPC url is missing".to_string()))?,
-            })),
+            (false, true) => {
+                let args = run_cmd.starknet_da_args;
+                Ok(DAConfig::Starknet(StarknetDaValidatedArgs {
+                    starknet_da_rpc_url: args.starknet_da_rpc_url.ok_or_else(|| {
+                        OrchestratorError::SetupCommandError("Starknet RPC url is missing".to_string())
+                    })?,
+                    starknet_da_private_key: args.starknet_da_private_key.ok_or_else(|| {
+                        OrchestratorError::SetupCommandError("Starknet DA private key is missing".to_string())
+                    })?,
+                    starknet_da_account_address: args.starknet_da_account_address.ok_or_else(|| {
+                        OrchestratorError::SetupCommandError("Starknet DA account address is missing".to_string())
+                    })?,
+                    starknet_da_contract_address: args.starknet_da_contract_address.ok_or_else(|| {
+                        OrchestratorError::SetupCommandError("Starknet DA contract address is missing".to_string())
+                    })?,
+                    starknet_da_max_calldata_felts: args.starknet_da_max_calldata_felts,
+                    starknet_da_max_txs_per_state_diff: args.starknet_da_max_txs_per_state_diff,
+                }))
+            }
         }
     }
 }
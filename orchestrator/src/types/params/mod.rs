@@ -10,9 +10,13 @@ pub mod snos;
 use crate::cli::cron::event_bridge::EventBridgeType;
 use crate::cli::{RunCmd, SetupCmd};
 use crate::core::client::queue::sqs::InnerSQS;
+use crate::types::params::database::DatabaseArgs;
 use crate::types::queue::QueueType;
 use crate::OrchestratorError;
 pub use otel::OTELConfig;
+use std::path::PathBuf;
+use std::time::Duration;
+use url::Url;
 
 #[derive(Debug, Clone)]
 pub struct ARN {
@@ -134,6 +138,32 @@ impl AlertArgs {
     }
 }
 
+/// StorageBackend - The storage client selected to run the orchestrator
+#[derive(Debug, Clone)]
+pub enum StorageBackend {
+    AWSS3(StorageArgs),
+    /// Objects are stored as files under this directory
+    Local(PathBuf),
+}
+
+/// QueueBackend - The queue client selected to run the orchestrator
+#[derive(Debug, Clone)]
+pub enum QueueBackend {
+    AWSSQS(QueueArgs),
+    /// Messages are stored in the orchestrator MongoDB database
+    MongoDB(DatabaseArgs),
+    /// AMQP URI of the RabbitMQ broker
+    RabbitMQ(Url),
+}
+
+/// AlertBackend - The alert client selected to run the orchestrator
+#[derive(Debug, Clone)]
+pub enum AlertBackend {
+    AWSSNS(AlertArgs),
+    /// Alerts are posted to this webhook
+    Webhook(Url),
+}
+
 /// CronArgs - Arguments used to setup cron resources
 #[derive(Debug, Clone)]
 pub struct CronArgs {
@@ -303,6 +333,72 @@ impl TryFrom<RunCmd> for QueueArgs {
     }
 }
 
+impl TryFrom<RunCmd> for StorageBackend {
+    type Error = OrchestratorError;
+    fn try_from(run_cmd: RunCmd) -> Result<Self, Self::Error> {
+        match (run_cmd.aws_s3_args.aws_s3, run_cmd.local_storage_args.local_storage) {
+            (true, false) => Ok(Self::AWSS3(StorageArgs::try_from(run_cmd)?)),
+            (false, true) => Ok(Self::Local(
+                run_cmd
+                    .local_storage_args
+                    .local_storage_path
+                    .ok_or_else(|| OrchestratorError::RunCommandError("Local storage path is required".to_string()))?,
+            )),
+            _ => Err(OrchestratorError::RunCommandError("Must use exactly one storage client".to_string())),
+        }
+    }
+}
+
+impl TryFrom<RunCmd> for QueueBackend {
+    type Error = OrchestratorError;
+    fn try_from(run_cmd: RunCmd) -> Result<Self, Self::Error> {
+        match (
+            run_cmd.aws_sqs_args.aws_sqs,
+            run_cmd.mongodb_queue_args.mongodb_queue,
+            run_cmd.rabbitmq_args.rabbitmq_queue,
+        ) {
+            (true, false, false) => Ok(Self::AWSSQS(QueueArgs::try_from(run_cmd)?)),
+            (false, true, false) => Ok(Self::MongoDB(DatabaseArgs::try_from(run_cmd)?)),
+            (false, false, true) => Ok(Self::RabbitMQ(
+                run_cmd
+                    .rabbitmq_args
+                    .rabbitmq_url
+                    .ok_or_else(|| OrchestratorError::RunCommandError("RabbitMQ URL is required".to_string()))?,
+            )),
+            _ => Err(OrchestratorError::RunCommandError("Must use exactly one queue client".to_string())),
+        }
+    }
+}
+
+impl TryFrom<RunCmd> for AlertBackend {
+    type Error = OrchestratorError;
+    fn try_from(run_cmd: RunCmd) -> Result<Self, Self::Error> {
+        match (run_cmd.aws_sns_args.aws_sns, run_cmd.webhook_args.webhook_alert) {
+            (true, false) => Ok(Self::AWSSNS(AlertArgs::try_from(run_cmd)?)),
+            (false, true) => Ok(Self::Webhook(
+                run_cmd
+                    .webhook_args
+                    .webhook_alert_url
+                    .ok_or_else(|| OrchestratorError::RunCommandError("Webhook alert URL is required".to_string()))?,
+            )),
+            _ => Err(OrchestratorError::RunCommandError("Must use exactly one alert client".to_string())),
+        }
+    }
+}
+
+/// InProcessCronArgs - Arguments of the cron triggering the workers from within the orchestrator
+#[derive(Debug, Clone)]
+pub struct InProcessCronArgs {
+    pub interval: Duration,
+}
+
+impl InProcessCronArgs {
+    pub fn from_run_cmd(run_cmd: &RunCmd) -> Option<Self> {
+        let args = &run_cmd.in_process_cron_args;
+        args.in_process_cron.then(|| Self { interval: Duration::from_secs(args.in_process_cron_interval_seconds) })
+    }
+}
+
 impl TryFrom<SetupCmd> for CronArgs {
     type Error = OrchestratorError;
     fn try_from(setup_cmd: SetupCmd) -> Result<Self, Self::Error> {
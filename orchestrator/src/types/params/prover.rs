@@ -1,6 +1,9 @@
+use crate::cli::prover::local::LocalProverModeType;
 use crate::cli::RunCmd;
 use crate::OrchestratorError;
 use orchestrator_atlantic_service::AtlanticValidatedArgs;
+use orchestrator_gps_fact_checker::local as local_verifier;
+use orchestrator_local_prover_service::{LocalProverMode, LocalProverValidatedArgs, StoneProverArgs};
 use orchestrator_sharp_service::SharpValidatedArgs;
 use orchestrator_utils::layer::Layer;
 
@@ -8,19 +11,32 @@ use orchestrator_utils::layer::Layer;
 pub enum ProverConfig {
     Sharp(SharpValidatedArgs),
     Atlantic(AtlanticValidatedArgs),
+    Local(LocalProverValidatedArgs),
 }
 
 impl TryFrom<RunCmd> for ProverConfig {
     type Error = OrchestratorError;
     fn try_from(run_cmd: RunCmd) -> Result<Self, Self::Error> {
-        match (run_cmd.sharp_args.sharp, run_cmd.atlantic_args.atlantic) {
-            (true, true) => {
-                Err(OrchestratorError::RunCommandError("Cannot use both Sharp and Atlantic provers".to_string()))
-            }
-            (false, false) => {
-                Err(OrchestratorError::RunCommandError("Must use either Sharp or Atlantic prover".to_string()))
-            }
-            (true, false) => {
+        // NOTE: The Rust Cairo verifier only supports some of the layouts
+        let prover_layout_name = &run_cmd.proving_layout_args.prover_layout_name;
+        if run_cmd.service_args.verify_proofs_locally
+            && !local_verifier::SUPPORTED_LAYOUTS.iter().any(|layout| layout.to_str() == prover_layout_name)
+        {
+            return Err(OrchestratorError::RunCommandError(format!(
+                "Verifying the proofs locally is not supported with the {prover_layout_name} prover layout"
+            )));
+        }
+        match (run_cmd.sharp_args.sharp, run_cmd.atlantic_args.atlantic, run_cmd.local_prover_args.local_prover) {
+            (false, false, false) => Err(OrchestratorError::RunCommandError(
+                "Must use either Sharp, Atlantic or the local prover".to_string(),
+            )),
+            (true, false, false) => {
+                // NOTE: Sharp does not return the proofs, they can't be verified locally
+                if run_cmd.service_args.verify_proofs_locally {
+                    return Err(OrchestratorError::RunCommandError(
+                        "Verifying the proofs locally is not supported with Sharp".to_string(),
+                    ));
+                }
                 let sharp_args = run_cmd.sharp_args;
                 Ok(Self::Sharp(SharpValidatedArgs {
                     sharp_customer_id: sharp_args.sharp_customer_id.ok_or_else(|| {
@@ -52,7 +68,7 @@ impl TryFrom<RunCmd> for ProverConfig {
                     })?,
                 }))
             }
-            (false, true) => {
+            (false, true, false) => {
                 let atlantic_args = run_cmd.atlantic_args;
                 // NOTE: Just making sure Cairo Verifier Program Hash is there for L3
                 if run_cmd.layer == Layer::L3 && atlantic_args.cairo_verifier_program_hash.is_none() {
@@ -92,6 +108,54 @@ impl TryFrom<RunCmd> for ProverConfig {
                     cairo_verifier_program_hash: atlantic_args.cairo_verifier_program_hash,
                 }))
             }
+            (false, false, true) => {
+                let local_prover_args = run_cmd.local_prover_args;
+                // NOTE: L3s register their proofs through L2 queries, which the local prover can't serve
+                if run_cmd.layer == Layer::L3 {
+                    return Err(OrchestratorError::RunCommandError(
+                        "The local prover is not supported for L3".to_string(),
+                    ));
+                }
+                // Neither the dummy nor the stone proofs get their facts registered on the settlement layer, only a dev
+                // core contract accepts their state updates
+                let dev_core_contract = if run_cmd.ethereum_settlement_args.settle_on_ethereum {
+                    run_cmd.ethereum_settlement_args.ethereum_dev_core_contract
+                } else {
+                    run_cmd.starknet_settlement_args.starknet_dev_core_contract
+                };
+                if !dev_core_contract {
+                    return Err(OrchestratorError::RunCommandError(
+                        "The local prover requires a dev core contract on the settlement layer".to_string(),
+                    ));
+                }
+                let local_prover_mode = match local_prover_args.local_prover_mode {
+                    Some(LocalProverModeType::Dummy) => LocalProverMode::Dummy,
+                    Some(LocalProverModeType::Stone) => LocalProverMode::Stone(StoneProverArgs {
+                        cairo_runner_path: local_prover_args.local_prover_cairo_runner_path.ok_or_else(|| {
+                            OrchestratorError::RunCommandError("Local prover Cairo runner path is required".to_string())
+                        })?,
+                        cpu_air_prover_path: local_prover_args.local_prover_cpu_air_prover_path.ok_or_else(|| {
+                            OrchestratorError::RunCommandError(
+                                "Local prover cpu_air_prover path is required".to_string(),
+                            )
+                        })?,
+                        prover_config_path: local_prover_args.local_prover_config_path.ok_or_else(|| {
+                            OrchestratorError::RunCommandError("Local prover config path is required".to_string())
+                        })?,
+                        prover_parameters_path: local_prover_args.local_prover_parameters_path.ok_or_else(|| {
+                            OrchestratorError::RunCommandError("Local prover parameters path is required".to_string())
+                        })?,
+                        work_dir: local_prover_args.local_prover_work_dir.ok_or_else(|| {
+                            OrchestratorError::RunCommandError("Local prover work directory is required".to_string())
+                        })?,
+                    }),
+                    None => {
+                        return Err(OrchestratorError::RunCommandError("Local prover mode is required".to_string()))
+                    }
+                };
+                Ok(Self::Local(LocalProverValidatedArgs { local_prover_mode }))
+            }
+            _ => Err(OrchestratorError::RunCommandError("Cannot use more than one prover".to_string())),
         }
     }
 }
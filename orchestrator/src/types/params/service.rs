@@ -9,6 +9,10 @@ pub struct ServiceParams {
     pub max_concurrent_snos_jobs: Option<usize>,
     pub max_concurrent_proving_jobs: Option<usize>,
     pub job_processing_timeout_seconds: u64,
+    pub max_batch_size: u64,
+    pub max_batch_time_seconds: u64,
+    pub aggregate_batch_proofs: bool,
+    pub verify_proofs_locally: bool,
 }
 
 impl From<ServiceCliArgs> for ServiceParams {
@@ -20,6 +24,10 @@ impl From<ServiceCliArgs> for ServiceParams {
             max_concurrent_snos_jobs: args.max_concurrent_snos_jobs,
             max_concurrent_proving_jobs: args.max_concurrent_proving_jobs,
             job_processing_timeout_seconds: args.job_processing_timeout_seconds,
+            max_batch_size: args.max_batch_size,
+            max_batch_time_seconds: args.max_batch_time_seconds,
+            aggregate_batch_proofs: args.aggregate_batch_proofs,
+            verify_proofs_locally: args.verify_proofs_locally,
         }
     }
 }
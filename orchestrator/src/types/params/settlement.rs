@@ -1,8 +1,14 @@
+use crate::cli::settlement::ethereum::EthereumSettlementCliArgs;
+use crate::cli::settlement::starknet::StarknetSettlementCliArgs;
 use crate::cli::RunCmd;
 use crate::OrchestratorError;
 use alloy::primitives::Address;
+use orchestrator_ethereum_settlement_client::signer::EthereumSignerConfig;
+use orchestrator_ethereum_settlement_client::tx_manager::GasEscalationPolicy;
 use orchestrator_ethereum_settlement_client::EthereumSettlementValidatedArgs;
+use orchestrator_starknet_settlement_client::signer::StarknetSignerConfig;
 use orchestrator_starknet_settlement_client::StarknetSettlementValidatedArgs;
+use starknet::core::types::Felt;
 use std::str::FromStr as _;
 
 #[derive(Clone, Debug)]
@@ -50,11 +56,16 @@ impl TryFrom<RunCmd> for SettlementConfig {
                     ethereum_rpc_url: run_cmd.ethereum_settlement_args.ethereum_rpc_url.clone().ok_or_else(|| {
                         OrchestratorError::SetupCommandError("Ethereum RPC URL is required".to_string())
                     })?,
-                    ethereum_private_key: run_cmd.ethereum_settlement_args.ethereum_private_key.clone().ok_or_else(
-                        || OrchestratorError::SetupCommandError("Ethereum private key is required".to_string()),
-                    )?,
+                    ethereum_signer: ethereum_signer(&run_cmd.ethereum_settlement_args)?,
                     l1_core_contract_address,
                     starknet_operator_address: ethereum_operator_address,
+                    gas_escalation_policy: GasEscalationPolicy {
+                        fee_bump_percent: run_cmd.ethereum_settlement_args.ethereum_fee_bump_percent,
+                        max_fee_per_gas_cap: run_cmd.ethereum_settlement_args.ethereum_max_fee_per_gas_cap,
+                        max_fee_per_blob_gas_cap: run_cmd.ethereum_settlement_args.ethereum_max_fee_per_blob_gas_cap,
+                        max_replacements: run_cmd.ethereum_settlement_args.ethereum_max_tx_replacements,
+                        min_pending_blocks: run_cmd.ethereum_settlement_args.ethereum_min_pending_blocks,
+                    },
                 };
                 Ok(Self::Ethereum(ethereum_params))
             }
@@ -63,9 +74,7 @@ impl TryFrom<RunCmd> for SettlementConfig {
                     starknet_rpc_url: run_cmd.starknet_settlement_args.starknet_rpc_url.clone().ok_or_else(|| {
                         OrchestratorError::SetupCommandError("Starknet RPC URL is required".to_string())
                     })?,
-                    starknet_private_key: run_cmd.starknet_settlement_args.starknet_private_key.clone().ok_or_else(
-                        || OrchestratorError::SetupCommandError("Starknet private key is required".to_string()),
-                    )?,
+                    starknet_signer: starknet_signer(&run_cmd.starknet_settlement_args)?,
                     starknet_account_address: run_cmd
                         .starknet_settlement_args
                         .starknet_account_address
@@ -92,3 +101,53 @@ impl TryFrom<RunCmd> for SettlementConfig {
         }
     }
 }
+
+/// The Ethereum account key must come from exactly one of the private key, the keystore or the remote signer.
+fn ethereum_signer(args: &EthereumSettlementCliArgs) -> Result<EthereumSignerConfig, OrchestratorError> {
+    match (&args.ethereum_private_key, &args.ethereum_keystore_path, &args.ethereum_remote_signer_url) {
+        (Some(private_key), None, None) => Ok(EthereumSignerConfig::PrivateKey(private_key.clone())),
+        (None, Some(path), None) => Ok(EthereumSignerConfig::Keystore {
+            path: path.clone(),
+            password: args.ethereum_keystore_password.clone().ok_or_else(|| {
+                OrchestratorError::SetupCommandError("Ethereum keystore password is required".to_string())
+            })?,
+        }),
+        (None, None, Some(url)) => Ok(EthereumSignerConfig::Remote {
+            url: url.clone(),
+            address: Address::from_str(args.ethereum_remote_signer_address.as_deref().ok_or_else(|| {
+                OrchestratorError::SetupCommandError("Ethereum remote signer address is required".to_string())
+            })?)?,
+        }),
+        _ => Err(OrchestratorError::SetupCommandError(
+            "Exactly one of the Ethereum private key, keystore or remote signer is required".to_string(),
+        )),
+    }
+}
+
+/// The Starknet account key must come from exactly one of the private key, the keystore or the remote signer.
+fn starknet_signer(args: &StarknetSettlementCliArgs) -> Result<StarknetSignerConfig, OrchestratorError> {
+    match (&args.starknet_private_key, &args.starknet_keystore_path, &args.starknet_remote_signer_url) {
+        (Some(private_key), None, None) => Ok(StarknetSignerConfig::PrivateKey(private_key.clone())),
+        (None, Some(path), None) => Ok(StarknetSignerConfig::Keystore {
+            path: path.clone(),
+            password: args.starknet_keystore_password.clone().ok_or_else(|| {
+                OrchestratorError::SetupCommandError("Starknet keystore password is required".to_string())
+            })?,
+        }),
+        (None, None, Some(url)) => Ok(StarknetSignerConfig::Remote {
+            url: url.clone(),
+            public_key: args
+                .starknet_remote_signer_public_key
+                .as_deref()
+                .and_then(|public_key| Felt::from_hex(public_key).ok())
+                .ok_or_else(|| {
+                    OrchestratorError::SetupCommandError(
+                        "A valid Starknet remote signer public key is required".to_string(),
+                    )
+                })?,
+        }),
+        _ => Err(OrchestratorError::SetupCommandError(
+            "Exactly one of the Starknet private key, keystore or remote signer is required".to_string(),
+        )),
+    }
+}
@@ -7,7 +7,6 @@ use crate::types::Layer;
 #[derive(Clone)]
 pub struct DlqConfig {
     pub max_receive_count: u32,
-    #[allow(dead_code)]
     pub dlq_name: QueueType,
 }
 
@@ -149,5 +148,23 @@ pub static QUEUES: LazyLock<HashMap<QueueType, QueueConfig>> = LazyLock::new(||
             supported_layers: vec![Layer::L2, Layer::L3],
         },
     );
+    map.insert(
+        QueueType::AggregatorJobProcessing,
+        QueueConfig {
+            visibility_timeout: 300,
+            dlq_config: Some(DlqConfig { max_receive_count: 5, dlq_name: QueueType::JobHandleFailure }),
+            queue_control: QueueControlConfig::new(10),
+            supported_layers: vec![Layer::L2, Layer::L3],
+        },
+    );
+    map.insert(
+        QueueType::AggregatorJobVerification,
+        QueueConfig {
+            visibility_timeout: 300,
+            dlq_config: Some(DlqConfig { max_receive_count: 5, dlq_name: QueueType::JobHandleFailure }),
+            queue_control: QueueControlConfig::new(10),
+            supported_layers: vec![Layer::L2, Layer::L3],
+        },
+    );
     map
 });
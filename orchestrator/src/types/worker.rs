@@ -1,4 +1,4 @@
-use omniqueue::Delivery;
+use crate::core::client::queue::Delivery;
 
 #[derive(Debug)]
 pub enum MessageType {
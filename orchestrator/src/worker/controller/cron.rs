@@ -0,0 +1,49 @@
+use crate::core::config::Config;
+use crate::setup::aws::event_bus::WORKER_TRIGGERS;
+use crate::types::jobs::WorkerTriggerType;
+use crate::types::queue::QueueType;
+use crate::types::Layer;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+
+/// InProcessCron - Triggers the workers periodically from within the orchestrator
+///
+/// This replaces the EventBridge rules for deployments without AWS: on every tick, a message is sent to the worker
+/// trigger queue for each of the workers, in the same format as the EventBridge rules.
+pub struct InProcessCron {
+    config: Arc<Config>,
+    interval: Duration,
+}
+
+impl InProcessCron {
+    pub fn new(config: Arc<Config>, interval: Duration) -> Self {
+        Self { config, interval }
+    }
+
+    /// spawn - Starts the cron in a background task
+    pub fn spawn(self) -> JoinHandle<()> {
+        tokio::spawn(async move { self.run().await })
+    }
+
+    async fn run(self) {
+        tracing::info!("Starting in-process cron with an interval of {:?}", self.interval);
+        let mut interval = tokio::time::interval(self.interval);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+        loop {
+            interval.tick().await;
+            for trigger in WORKER_TRIGGERS.iter() {
+                // Proof registration is only required in L3
+                if *trigger == WorkerTriggerType::ProofRegistration && *self.config.layer() != Layer::L3 {
+                    continue;
+                }
+                if let Err(e) =
+                    self.config.queue().send_message(QueueType::WorkerTrigger, trigger.to_string(), None).await
+                {
+                    tracing::error!(error = %e, trigger = %trigger, "Failed to send worker trigger");
+                }
+            }
+        }
+    }
+}
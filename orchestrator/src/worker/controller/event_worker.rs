@@ -1,3 +1,4 @@
+use crate::core::client::queue::{Delivery, QueueConsumer};
 use crate::core::config::Config;
 use crate::error::other::OtherError;
 use crate::error::{event::EventSystemResult, ConsumptionError};
@@ -7,8 +8,6 @@ use crate::worker::event_handler::service::JobHandlerService;
 use crate::worker::parser::{job_queue_message::JobQueueMessage, worker_trigger_message::WorkerTriggerMessage};
 use crate::worker::traits::message::{MessageParser, ParsedMessage};
 use color_eyre::eyre::eyre;
-use omniqueue::backends::SqsConsumer;
-use omniqueue::{Delivery, QueueError};
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::task::JoinSet;
@@ -41,8 +40,13 @@ impl EventWorker {
         Ok(Self { queue_type, config, queue_control })
     }
 
-    async fn consumer(&self) -> EventSystemResult<SqsConsumer> {
-        Ok(self.config.clone().queue().get_consumer(self.queue_type.clone()).await.expect("error"))
+    async fn consumer(&self) -> EventSystemResult<Box<dyn QueueConsumer>> {
+        Ok(self
+            .config
+            .queue()
+            .get_consumer(self.queue_type.clone())
+            .await
+            .map_err(|e| ConsumptionError::FailedToConsumeFromQueue { error_msg: e.to_string() })?)
     }
 
     /// get_message - Get the next message from the queue with proper async waiting
@@ -53,8 +57,8 @@ impl EventWorker {
         loop {
             debug!("Long polling for message from queue {:?}", self.queue_type);
             match consumer.receive().await {
-                Ok(delivery) => return Ok(delivery),
-                Err(QueueError::NoData) => {
+                Ok(Some(delivery)) => return Ok(delivery),
+                Ok(None) => {
                     tokio::time::sleep(Duration::from_millis(100)).await;
                     continue;
                 }
@@ -225,7 +229,7 @@ impl EventWorker {
                 }
             };
 
-            message.nack().await.map_err(|e| ConsumptionError::FailedToAcknowledgeMessage(e.0.to_string()))?;
+            message.nack().await.map_err(|e| ConsumptionError::FailedToAcknowledgeMessage(e.to_string()))?;
 
             // TODO: Since we are using SNS, we need to send the error message to the DLQ in future
             // self.config.alerts().send_message(error_context).await?;
@@ -233,7 +237,7 @@ impl EventWorker {
             return Err(consumption_error.into());
         }
 
-        message.ack().await.map_err(|e| ConsumptionError::FailedToAcknowledgeMessage(e.0.to_string()))?;
+        message.ack().await.map_err(|e| ConsumptionError::FailedToAcknowledgeMessage(e.to_string()))?;
         Ok(())
     }
 
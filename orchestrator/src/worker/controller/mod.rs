@@ -1,2 +1,3 @@
+pub mod cron;
 pub mod event_worker;
 pub mod worker_controller;
@@ -73,10 +73,12 @@ impl WorkerController {
             QueueType::ProvingJobProcessing,
             QueueType::DataSubmissionJobProcessing,
             QueueType::UpdateStateJobProcessing,
+            QueueType::AggregatorJobProcessing,
             QueueType::SnosJobVerification,
             QueueType::ProvingJobVerification,
             QueueType::DataSubmissionJobVerification,
             QueueType::UpdateStateJobVerification,
+            QueueType::AggregatorJobVerification,
             QueueType::WorkerTrigger,
             QueueType::JobHandleFailure,
         ]
@@ -93,11 +95,13 @@ impl WorkerController {
             QueueType::ProofRegistrationJobProcessing,
             QueueType::DataSubmissionJobProcessing,
             QueueType::UpdateStateJobProcessing,
+            QueueType::AggregatorJobProcessing,
             QueueType::SnosJobVerification,
             QueueType::ProvingJobVerification,
             QueueType::ProofRegistrationJobVerification,
             QueueType::DataSubmissionJobVerification,
             QueueType::UpdateStateJobVerification,
+            QueueType::AggregatorJobVerification,
             QueueType::WorkerTrigger,
             QueueType::JobHandleFailure,
         ]
@@ -0,0 +1,137 @@
+use crate::core::config::Config;
+use crate::error::job::JobError;
+use crate::error::other::OtherError;
+use crate::types::jobs::job_item::JobItem;
+use crate::types::jobs::metadata::{AggregatorMetadata, JobMetadata};
+use crate::types::jobs::status::JobVerificationStatus;
+use crate::types::jobs::types::{JobStatus, JobType};
+use crate::worker::event_handler::jobs::da::{DAJobHandler, BLOB_LEN};
+use crate::worker::event_handler::jobs::state_update::{calculate_output, vec_felt_to_vec_bytes32};
+use crate::worker::event_handler::jobs::JobHandlerTrait;
+use crate::worker::utils::biguint_vec_to_u8_vec;
+use crate::worker::utils::stateful_compression::{compress_batch_state_diff, AliasReader};
+use async_trait::async_trait;
+use bytes::Bytes;
+use color_eyre::eyre::eyre;
+use orchestrator_prover_client_interface::TaskStatus;
+use starknet::core::types::StateUpdate;
+use std::sync::Arc;
+use swiftness_proof_parser::{parse, StarkProof};
+
+/// AggregatorJobHandler - Aggregates the proofs of the blocks of a batch
+///
+/// The proofs of the blocks are submitted to the bucket of the batch by the proving jobs. Processing the job closes
+/// the bucket, which makes the prover aggregate the proofs with applicative recursion, and stores the blob data of
+/// the batch. Once the aggregation succeeds, the aggregated program output is stored for the state transition job,
+/// which settles the whole batch with a single transaction.
+pub struct AggregatorJobHandler;
+
+#[async_trait]
+impl JobHandlerTrait for AggregatorJobHandler {
+    #[tracing::instrument(fields(category = "aggregator"), skip(self, metadata), ret, err)]
+    async fn create_job(&self, internal_id: String, metadata: JobMetadata) -> Result<JobItem, JobError> {
+        tracing::info!(log_type = "starting", category = "aggregator", function_type = "create_job", batch_no = %internal_id, "Aggregator job creation started.");
+        let job_item = JobItem::create(internal_id.clone(), JobType::Aggregator, JobStatus::Created, metadata);
+        tracing::info!(log_type = "completed", category = "aggregator", function_type = "create_job", batch_no = %internal_id, "Aggregator job created.");
+        Ok(job_item)
+    }
+
+    #[tracing::instrument(fields(category = "aggregator"), skip(self, config), ret, err)]
+    async fn process_job(&self, config: Arc<Config>, job: &mut JobItem) -> Result<String, JobError> {
+        tracing::info!(log_type = "starting", category = "aggregator", function_type = "process_job", job_id = ?job.id, batch_no = %job.internal_id, "Aggregator job processing started.");
+
+        let metadata: AggregatorMetadata = job.metadata.specific.clone().try_into()?;
+
+        // The blob data is computed before closing the bucket, so that a failure doesn't leave the bucket closed
+        // without its data
+        self.store_blob_data(&metadata, config.clone()).await?;
+
+        config.prover_client().close_bucket(&metadata.bucket_id).await.inspect_err(|e| {
+            tracing::error!(job_id = %job.internal_id, error = %e, "Failed to close the bucket of the batch");
+        })?;
+
+        tracing::info!(log_type = "completed", category = "aggregator", function_type = "process_job", job_id = ?job.id, batch_no = %job.internal_id, bucket_id = %metadata.bucket_id, "Aggregator job processed successfully.");
+        Ok(metadata.bucket_id)
+    }
+
+    #[tracing::instrument(fields(category = "aggregator"), skip(self, config), ret, err)]
+    async fn verify_job(&self, config: Arc<Config>, job: &mut JobItem) -> Result<JobVerificationStatus, JobError> {
+        tracing::info!(log_type = "starting", category = "aggregator", function_type = "verify_job", job_id = ?job.id, batch_no = %job.internal_id, "Aggregator job verification started.");
+
+        let metadata: AggregatorMetadata = job.metadata.specific.clone().try_into()?;
+
+        let Some(task_id) = config.prover_client().get_aggregation_task_id(&metadata.bucket_id).await? else {
+            tracing::info!(log_type = "pending", category = "aggregator", function_type = "verify_job", job_id = ?job.id, batch_no = %job.internal_id, "Aggregation task not created yet.");
+            return Ok(JobVerificationStatus::Pending);
+        };
+
+        match config.prover_client().get_task_status(&task_id, None, false).await? {
+            TaskStatus::Processing => {
+                tracing::info!(log_type = "pending", category = "aggregator", function_type = "verify_job", job_id = ?job.id, batch_no = %job.internal_id, "Aggregator job verification pending.");
+                Ok(JobVerificationStatus::Pending)
+            }
+            TaskStatus::Succeeded => {
+                let proof = config.prover_client().get_proof(&task_id).await?;
+                let parsed_proof: StarkProof =
+                    parse(proof.clone()).map_err(|e| JobError::Other(OtherError(eyre!("{}", e))))?;
+
+                // The aggregated program output is the output of the proven program
+                let program_output = vec_felt_to_vec_bytes32(calculate_output(parsed_proof));
+                let encoded_program_output = bincode::serialize(&program_output).map_err(|e| {
+                    JobError::Other(OtherError(eyre!("Failed to serialize the aggregated program output: {}", e)))
+                })?;
+                config.storage().put_data(encoded_program_output.into(), &metadata.program_output_path).await?;
+
+                if let Some(download_path) = &metadata.download_proof {
+                    config.storage().put_data(Bytes::from(proof.into_bytes()), download_path).await?;
+                }
+
+                tracing::info!(log_type = "completed", category = "aggregator", function_type = "verify_job", job_id = ?job.id, batch_no = %job.internal_id, "Aggregator job verification completed.");
+                Ok(JobVerificationStatus::Verified)
+            }
+            TaskStatus::Failed(err) => {
+                tracing::info!(log_type = "failed", category = "aggregator", function_type = "verify_job", job_id = ?job.id, batch_no = %job.internal_id, "Aggregator job verification failed.");
+                Ok(JobVerificationStatus::Rejected(format!(
+                    "Aggregator job #{} failed with error: {}",
+                    job.internal_id, err
+                )))
+            }
+        }
+    }
+
+    fn max_process_attempts(&self) -> u64 {
+        1
+    }
+
+    fn max_verification_attempts(&self) -> u64 {
+        300
+    }
+
+    fn verification_polling_delay_seconds(&self) -> u64 {
+        30
+    }
+}
+
+impl AggregatorJobHandler {
+    /// Stores the blobs of the compressed state diff of the batch, each blob in its evaluation form.
+    async fn store_blob_data(&self, metadata: &AggregatorMetadata, config: Arc<Config>) -> Result<(), JobError> {
+        let batch = config
+            .database()
+            .get_batch_by_index(metadata.batch_num)
+            .await?
+            .ok_or_else(|| JobError::Other(OtherError(eyre!("Batch {} not found", metadata.batch_num))))?;
+
+        let state_update_bytes = config.storage().get_data(&metadata.squashed_state_update_path).await?;
+        let state_update: StateUpdate = serde_json::from_slice(&state_update_bytes)?;
+
+        let mut aliases = AliasReader::new(batch.start_block);
+        let compressed_state_diff = compress_batch_state_diff(&state_update.state_diff, &mut aliases, &config).await?;
+
+        let mut blob_data = Vec::new();
+        for blob in compressed_state_diff.chunks(*BLOB_LEN) {
+            blob_data.extend(DAJobHandler::fft_transformation(DAJobHandler::convert_to_biguint(blob.to_vec()))?);
+        }
+        config.storage().put_data(biguint_vec_to_u8_vec(&blob_data).into(), &metadata.blob_data_path).await?;
+        Ok(())
+    }
+}
@@ -13,6 +13,7 @@ use color_eyre::eyre::{eyre, Context};
 use lazy_static::lazy_static;
 use num_bigint::{BigUint, ToBigUint};
 use num_traits::{Num, Zero};
+use orchestrator_da_client_interface::DaDataFormat;
 use starknet::providers::Provider;
 use starknet_core::types::{
     BlockId, ContractStorageDiffItem, DeclaredClassItem, Felt, MaybePendingStateUpdate, StateDiff, StateUpdate,
@@ -65,7 +66,7 @@ impl DAJobHandler {
     /// DA word encoding:
     /// |---padding---|---class flag---|---new nonce---|---num changes---|
     ///     127 bits        1 bit           64 bits          64 bits
-    fn da_word(class_flag: bool, nonce_change: Option<Felt>, num_changes: u64) -> Result<Felt, JobError> {
+    pub(crate) fn da_word(class_flag: bool, nonce_change: Option<Felt>, num_changes: u64) -> Result<Felt, JobError> {
         // padding of 127 bits
         let mut binary_string = "0".repeat(127);
 
@@ -240,6 +241,20 @@ impl DAJobHandler {
         Ok(blobs)
     }
 
+    /// Splits the state diff felts in chunks of at most `chunk_size` bytes, for the [`DaDataFormat::Felts`] DA clients.
+    /// The felts are 32 bytes big endian each, and the last chunk is not padded.
+    fn felts_to_chunks(chunk_size: u64, block_data: &[Felt]) -> Result<Vec<Vec<u8>>, JobError> {
+        if chunk_size < 32 {
+            Err(DaError::InsufficientBlobSize { blob_size: chunk_size })?
+        }
+
+        let felts_per_chunk = (chunk_size / 32) as usize;
+        Ok(block_data
+            .chunks(felts_per_chunk)
+            .map(|felts| felts.iter().flat_map(|felt| felt.to_bytes_be()).collect())
+            .collect())
+    }
+
     /// To store the blob data using the storage client with path <block_number>/blob_data.txt
     async fn store_blob_data(
         blob_data: Vec<BigUint>,
@@ -334,7 +349,10 @@ impl JobHandlerTrait for DAJobHandler {
             "Retrieved DA client configuration"
         );
 
-        let blob_array = Self::data_to_blobs(max_bytes_per_blob, transformed_data)?;
+        let blob_array = match config.da_client().data_format().await {
+            DaDataFormat::Blobs => Self::data_to_blobs(max_bytes_per_blob, transformed_data)?,
+            DaDataFormat::Felts => Self::felts_to_chunks(max_bytes_per_blob, &blob_data)?,
+        };
         let current_blob_length: u64 = blob_array
             .len()
             .try_into()
@@ -545,6 +563,21 @@ pub mod test {
         assert_eq!(blob_data_biguint, recovered_blob_data);
     }
 
+    /// Tests that the felts are split in chunks of whole felts, without padding: the trailing zero felts of the state
+    /// diff are kept, and no zero felt is added.
+    #[rstest]
+    #[case(64, vec![], 0)]
+    #[case(64, vec![Felt::ONE, Felt::ZERO, Felt::ZERO], 2)]
+    #[case(100, vec![Felt::ONE; 7], 3)]
+    fn test_felts_to_chunks(#[case] chunk_size: u64, #[case] felts: Vec<Felt>, #[case] expected_chunks: usize) {
+        let chunks = DAJobHandler::felts_to_chunks(chunk_size, &felts).unwrap();
+        assert_eq!(chunks.len(), expected_chunks);
+        assert!(chunks.iter().all(|chunk| chunk.len() as u64 <= chunk_size && chunk.len() % 32 == 0));
+
+        let recovered: Vec<Felt> = chunks.concat().chunks(32).map(Felt::from_bytes_be_slice).collect();
+        assert_eq!(recovered, felts);
+    }
+
     /// Tests the `fft_transformation` function with various test blob files.
     /// Verifies the correctness of FFT and IFFT transformations by ensuring round-trip consistency.
     /// Parses the original blob data, recovers it using IFFT, and re-applies FFT.
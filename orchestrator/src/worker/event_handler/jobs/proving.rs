@@ -7,10 +7,14 @@ use crate::types::jobs::metadata::{JobMetadata, ProvingInputType, ProvingMetadat
 use crate::types::jobs::status::JobVerificationStatus;
 use crate::types::jobs::types::{JobStatus, JobType};
 use crate::worker::event_handler::jobs::JobHandlerTrait;
+use crate::worker::utils::fact_info::{get_program_output, BOOTLOADER_VERSION};
 use async_trait::async_trait;
+use cairo_vm::program_hash::compute_program_hash_chain;
 use cairo_vm::vm::runners::cairo_pie::CairoPie;
 use color_eyre::eyre::eyre;
-use orchestrator_prover_client_interface::{Task, TaskStatus};
+use orchestrator_gps_fact_checker::local::verify_proof;
+use orchestrator_prover_client_interface::{BucketTask, ProverClientError, Task, TaskStatus};
+use starknet::core::types::Felt;
 use std::sync::Arc;
 
 pub struct ProvingJobHandler;
@@ -59,9 +63,14 @@ impl JobHandlerTrait for ProvingJobHandler {
 
         tracing::debug!(job_id = %job.internal_id, "Submitting task to prover client");
 
+        let bucket = match (proving_metadata.bucket_id, proving_metadata.bucket_job_index) {
+            (Some(bucket_id), Some(index)) => Some(BucketTask { bucket_id, index }),
+            _ => None,
+        };
+
         let external_id = config
             .prover_client()
-            .submit_task(Task::CairoPie(cairo_pie), *config.prover_layout_name(), proving_metadata.n_steps)
+            .submit_task(Task::CairoPie(cairo_pie), *config.prover_layout_name(), proving_metadata.n_steps, bucket)
             .await
             .inspect_err(|e| {
                 tracing::error!(job_id = %job.internal_id, error = %e, "Failed to submit task to prover client");
@@ -130,8 +139,10 @@ impl JobHandlerTrait for ProvingJobHandler {
                 Ok(JobVerificationStatus::Pending)
             }
             TaskStatus::Succeeded => {
-                // If proof download path is specified, store the proof
-                if let Some(download_path) = &proving_metadata.download_proof {
+                // The proofs of the aggregated jobs are only available through their aggregation
+                let verify_locally =
+                    config.service_config().verify_proofs_locally && proving_metadata.bucket_id.is_none();
+                if verify_locally || proving_metadata.download_proof.is_some() {
                     let fetched_proof = config.prover_client().get_proof(&task_id).await.inspect_err(|e| {
                         tracing::error!(
                             job_id = %job.internal_id,
@@ -139,12 +150,38 @@ impl JobHandlerTrait for ProvingJobHandler {
                             "Failed to get task status from prover client"
                         );
                     })?;
-                    tracing::debug!(
-                        job_id = %job.internal_id,
-                        "Downloading and storing proof to path: {}",
-                        download_path
-                    );
-                    config.storage().put_data(bytes::Bytes::from(fetched_proof.into_bytes()), download_path).await?;
+
+                    if verify_locally {
+                        tracing::debug!(job_id = %job.internal_id, "Verifying proof locally");
+                        if let Err(e) = verify_proof_locally(&config, &proving_metadata, fetched_proof.clone()).await? {
+                            tracing::info!(
+                                log_type = "failed",
+                                category = "proving",
+                                function_type = "verify_job",
+                                job_id = ?job.id,
+                                block_no = %internal_id,
+                                error = %e,
+                                "Proving job verification failed."
+                            );
+                            return Ok(JobVerificationStatus::Rejected(format!(
+                                "Prover job #{} proof verification failed: {}",
+                                job.internal_id, e
+                            )));
+                        }
+                    }
+
+                    // If proof download path is specified, store the proof
+                    if let Some(download_path) = &proving_metadata.download_proof {
+                        tracing::debug!(
+                            job_id = %job.internal_id,
+                            "Downloading and storing proof to path: {}",
+                            download_path
+                        );
+                        config
+                            .storage()
+                            .put_data(bytes::Bytes::from(fetched_proof.into_bytes()), download_path)
+                            .await?;
+                    }
                 }
                 tracing::info!(
                     log_type = "completed",
@@ -185,3 +222,43 @@ impl JobHandlerTrait for ProvingJobHandler {
         30
     }
 }
+
+/// Verifies the proof of a task with the Rust Cairo verifier, and checks that it proves the execution of the Cairo
+/// PIE of the job, on its own or run by the bootloader.
+///
+/// The outer error is a failure to run the verification, the inner one a rejection of the proof.
+async fn verify_proof_locally(
+    config: &Config,
+    proving_metadata: &ProvingMetadata,
+    proof: String,
+) -> Result<Result<(), ProverClientError>, JobError> {
+    let input_path = match &proving_metadata.input_path {
+        Some(ProvingInputType::CairoPie(path)) => path,
+        _ => return Err(JobError::Other(OtherError(eyre!("Cairo PIE input path not found in job metadata")))),
+    };
+    let cairo_pie_file = config
+        .storage()
+        .get_data(input_path)
+        .await
+        .map_err(|e| ProvingError::CairoPIEFileFetchFailed(e.to_string()))?;
+    let cairo_pie = CairoPie::from_bytes(cairo_pie_file.to_vec().as_slice())
+        .map_err(|e| ProvingError::CairoPIENotReadable(e.to_string()))?;
+
+    let program_hash = compute_program_hash_chain(&cairo_pie.metadata.program, BOOTLOADER_VERSION)
+        .map_err(|e| JobError::Other(OtherError(eyre!("Failed to compute program hash: {}", e))))?;
+    let program_hash = Felt::from_bytes_be(&program_hash.to_bytes_be());
+    let program_output: Vec<Felt> = get_program_output(&cairo_pie)?
+        .iter()
+        .map(|felt| Felt::from_bytes_be(&felt.to_bytes_be()))
+        .collect();
+
+    // Verifying a proof is CPU bound and takes a few seconds
+    let layout = *config.prover_layout_name();
+    let result = tokio::task::spawn_blocking(move || {
+        verify_proof(&proof, layout)?.ensure_proves(program_hash, &program_output)
+    })
+    .await
+    .map_err(|e| JobError::Other(OtherError(eyre!("Proof verification task failed: {}", e))))?;
+
+    Ok(result.map_err(ProverClientError::from))
+}
@@ -4,17 +4,19 @@ use crate::error::job::JobError;
 use crate::error::other::OtherError;
 use crate::types::constant::{ON_CHAIN_DATA_FILE_NAME, PROOF_FILE_NAME, PROOF_PART2_FILE_NAME};
 use crate::types::jobs::job_item::JobItem;
-use crate::types::jobs::metadata::{JobMetadata, JobSpecificMetadata, StateUpdateMetadata};
+use crate::types::jobs::metadata::{InFlightSettlementTx, JobMetadata, JobSpecificMetadata, StateUpdateMetadata};
 use crate::types::jobs::status::JobVerificationStatus;
 use crate::types::jobs::types::{JobStatus, JobType};
+use crate::worker::event_handler::jobs::da::BLOB_LEN;
 use crate::worker::event_handler::jobs::JobHandlerTrait;
 use crate::worker::utils::fact_info::OnChainData;
 use crate::worker::utils::{fetch_blob_data_for_block, fetch_program_output_for_block, fetch_snos_for_block};
 use async_trait::async_trait;
 use cairo_vm::Felt252;
 use color_eyre::eyre::eyre;
-use orchestrator_settlement_client_interface::SettlementVerificationStatus;
+use orchestrator_settlement_client_interface::{SettlementTx, SettlementVerificationStatus, TxReplacement};
 use orchestrator_utils::collections::{has_dup, is_sorted};
+use orchestrator_utils::layer::Layer;
 use starknet_core::types::Felt;
 use starknet_os::io::output::StarknetOsOutput;
 use std::sync::Arc;
@@ -36,8 +38,8 @@ impl JobHandlerTrait for StateUpdateJobHandler {
         // Extract state transition metadata
         let state_metadata: StateUpdateMetadata = metadata.specific.clone().try_into()?;
 
-        // Validate required paths
-        if state_metadata.snos_output_paths.is_empty()
+        // Validate required paths, the SNOS outputs are not needed to settle a batch
+        if (state_metadata.batch_num.is_none() && state_metadata.snos_output_paths.is_empty())
             || state_metadata.program_output_paths.is_empty()
             || state_metadata.blob_data_paths.is_empty()
         {
@@ -76,6 +78,10 @@ impl JobHandlerTrait for StateUpdateJobHandler {
 
         tracing::debug!(job_id = %job.internal_id, blocks = ?state_metadata.blocks_to_settle, "Validated block numbers");
 
+        if let Some(batch_num) = state_metadata.batch_num {
+            return self.settle_batch(config, job, state_metadata, batch_num).await;
+        }
+
         // Filter block numbers if there was a previous failure
         let last_failed_block = state_metadata.last_failed_block_no.unwrap_or(0);
         let filtered_indices: Vec<usize> = state_metadata
@@ -93,6 +99,7 @@ impl JobHandlerTrait for StateUpdateJobHandler {
         let mut nonce = config.settlement_client().get_nonce().await.map_err(|e| JobError::Other(OtherError(e)))?;
 
         let mut sent_tx_hashes: Vec<String> = Vec::with_capacity(filtered_indices.len());
+        let mut in_flight_txs: Vec<InFlightSettlementTx> = Vec::with_capacity(filtered_indices.len());
 
         for &i in &filtered_indices {
             let block_no = state_metadata.blocks_to_settle[i];
@@ -100,15 +107,19 @@ impl JobHandlerTrait for StateUpdateJobHandler {
             let snos = fetch_snos_for_block(internal_id.clone(), i, config.clone(), &snos_output_paths).await?;
             let program_output = fetch_program_output_for_block(i, config.clone(), &program_output_paths).await?;
             let blob_data = fetch_blob_data_for_block(i, config.clone(), &blob_data_paths).await?;
-            let txn_hash = match self
+            // The transactions sent with blobs are built again from their inputs when they are replaced
+            let tx_inputs_paths = (snos.use_kzg_da == Felt252::ONE)
+                .then(|| (program_output_paths[i].clone(), blob_data_paths[i].clone()));
+            let tx = match self
                 .update_state_for_block(config.clone(), block_no, snos, nonce, program_output, blob_data)
                 .await
             {
-                Ok(hash) => hash,
+                Ok(tx) => tx,
                 Err(e) => {
                     tracing::error!(job_id = %job.internal_id, block_no = %block_no, error = %e, "Error updating state for block");
                     state_metadata.last_failed_block_no = Some(block_no);
                     state_metadata.tx_hashes = sent_tx_hashes.clone();
+                    state_metadata.in_flight_txs = in_flight_txs.clone();
                     job.metadata.specific = JobSpecificMetadata::StateUpdate(state_metadata.clone());
 
                     return Err(JobError::Other(OtherError(eyre!(
@@ -117,8 +128,10 @@ impl JobHandlerTrait for StateUpdateJobHandler {
                 }
             };
 
-            sent_tx_hashes.push(txn_hash);
+            sent_tx_hashes.push(tx.tx_hash.clone());
+            in_flight_txs.push(in_flight_settlement_tx(tx, tx_inputs_paths));
             state_metadata.tx_hashes = sent_tx_hashes.clone();
+            state_metadata.in_flight_txs = in_flight_txs.clone();
             job.metadata.specific = JobSpecificMetadata::StateUpdate(state_metadata.clone());
             nonce += 1;
         }
@@ -143,6 +156,9 @@ impl JobHandlerTrait for StateUpdateJobHandler {
     /// 1. the last settlement tx hash is successful,
     /// 2. the expected last settled block from our configuration is indeed the one found in the
     ///    provider.
+    ///
+    /// A pending settlement tx is replaced with higher fees if the settlement client supports it, and the job stays
+    /// pending until the original tx or one of its replacements is included.
     #[tracing::instrument(fields(category = "state_update"), skip(self, config), ret, err)]
     async fn verify_job(&self, config: Arc<Config>, job: &mut JobItem) -> Result<JobVerificationStatus, JobError> {
         let internal_id = job.internal_id.clone();
@@ -157,14 +173,13 @@ impl JobHandlerTrait for StateUpdateJobHandler {
 
         // Get state update metadata
         let mut state_metadata: StateUpdateMetadata = job.metadata.specific.clone().try_into()?;
-        // Get transaction hashes
-        let tx_hashes = state_metadata.tx_hashes;
 
-        let block_numbers = state_metadata.blocks_to_settle;
+        let block_numbers = state_metadata.blocks_to_settle.clone();
         tracing::debug!(job_id = %job.internal_id, "Retrieved block numbers from metadata");
         let settlement_client = config.settlement_client();
 
-        for (tx_hash, block_no) in tx_hashes.iter().zip(block_numbers.iter()) {
+        for (i, block_no) in block_numbers.iter().enumerate().take(state_metadata.tx_hashes.len()) {
+            let tx_hash = state_metadata.tx_hashes[i].clone();
             tracing::trace!(
                 job_id = %job.internal_id,
                 tx_hash = %tx_hash,
@@ -172,8 +187,10 @@ impl JobHandlerTrait for StateUpdateJobHandler {
                 "Verifying transaction inclusion"
             );
 
-            let tx_inclusion_status =
-                settlement_client.verify_tx_inclusion(tx_hash).await.map_err(|e| JobError::Other(OtherError(e)))?;
+            let (tx_hash, tx_inclusion_status) =
+                self.reconcile_settlement_txs(config.clone(), &tx_hash, state_metadata.in_flight_txs.get(i)).await?;
+            state_metadata.tx_hashes[i] = tx_hash.clone();
+            job.metadata.specific = JobSpecificMetadata::StateUpdate(state_metadata.clone());
 
             match tx_inclusion_status {
                 SettlementVerificationStatus::Rejected(_) => {
@@ -186,22 +203,61 @@ impl JobHandlerTrait for StateUpdateJobHandler {
                     state_metadata.last_failed_block_no = Some(*block_no);
                     return Ok(tx_inclusion_status.into());
                 }
-                // If the tx is still pending, we wait for it to be finalized and check again the status.
+                // If the tx is still pending, it may be stuck: once it has been pending for long enough, it is replaced
+                // with higher fees, and it is checked again at the next verification. If it can't be replaced, we wait
+                // for it to be finalized and check again the status.
                 SettlementVerificationStatus::Pending => {
+                    let is_batch = state_metadata.batch_num.is_some();
+                    let replacement = self
+                        .replace_pending_settlement_tx(
+                            config.clone(),
+                            &tx_hash,
+                            state_metadata.in_flight_txs.get(i),
+                            is_batch,
+                        )
+                        .await?;
+                    match replacement {
+                        TxReplacement::Replaced(replacement) => {
+                            tracing::info!(
+                                job_id = %job.internal_id,
+                                tx_hash = %tx_hash,
+                                replacement_tx_hash = %replacement.tx_hash,
+                                block_no = %block_no,
+                                "Pending transaction replaced"
+                            );
+                            state_metadata.tx_hashes[i] = replacement.tx_hash.clone();
+                            if let Some(in_flight_tx) = state_metadata.in_flight_txs.get_mut(i) {
+                                record_replacement(in_flight_tx, replacement);
+                            }
+                            job.metadata.specific = JobSpecificMetadata::StateUpdate(state_metadata);
+                            return Ok(JobVerificationStatus::Pending);
+                        }
+                        TxReplacement::TooRecent => {
+                            tracing::debug!(
+                                job_id = %job.internal_id,
+                                tx_hash = %tx_hash,
+                                "Transaction pending, checking it again at the next verification"
+                            );
+                            return Ok(JobVerificationStatus::Pending);
+                        }
+                        TxReplacement::Unavailable => {}
+                    }
+
                     tracing::debug!(
                         job_id = %job.internal_id,
                         tx_hash = %tx_hash,
                         "Transaction pending, waiting for finality"
                     );
                     settlement_client
-                        .wait_for_tx_finality(tx_hash)
+                        .wait_for_tx_finality(&tx_hash)
                         .await
                         .map_err(|e| JobError::Other(OtherError(e)))?;
 
-                    let new_status = settlement_client
-                        .verify_tx_inclusion(tx_hash)
-                        .await
-                        .map_err(|e| JobError::Other(OtherError(e)))?;
+                    let (tx_hash, new_status) = self
+                        .reconcile_settlement_txs(config.clone(), &tx_hash, state_metadata.in_flight_txs.get(i))
+                        .await?;
+                    state_metadata.tx_hashes[i] = tx_hash.clone();
+                    job.metadata.specific = JobSpecificMetadata::StateUpdate(state_metadata.clone());
 
                     match new_status {
                         SettlementVerificationStatus::Rejected(_) => {
@@ -308,6 +364,156 @@ impl StateUpdateJobHandler {
         Ok(())
     }
 
+    /// Settles all the blocks of the batch with a single transaction, using the aggregated program output and the blob
+    /// data of the batch.
+    async fn settle_batch(
+        &self,
+        config: Arc<Config>,
+        job: &mut JobItem,
+        mut state_metadata: StateUpdateMetadata,
+        batch_num: u64,
+    ) -> Result<String, JobError> {
+        // The OS data availability commitment is not computed for aggregated batches, which can only be settled with
+        // blobs on an L1
+        if config.layer() == &Layer::L3 {
+            Err(JobError::Other(OtherError(eyre!(
+                "Batch #{batch_num} - Aggregated batches can't be settled on an L2"
+            ))))?;
+        }
+        let program_output =
+            fetch_program_output_for_block(0, config.clone(), &state_metadata.program_output_paths).await?;
+        let blobs = batch_blobs(fetch_blob_data_for_block(0, config.clone(), &state_metadata.blob_data_paths).await?);
+
+        let settlement_client = config.settlement_client();
+        let nonce = settlement_client.get_nonce().await.map_err(|e| JobError::Other(OtherError(e)))?;
+        let tx = settlement_client.update_state_with_blobs(program_output, blobs, nonce).await.map_err(|e| {
+            tracing::error!(job_id = %job.internal_id, batch_num = %batch_num, error = %e, "Error settling batch");
+            JobError::Other(OtherError(eyre!("Batch #{batch_num} - Error occurred during the state update: {e}")))
+        })?;
+
+        let tx_inputs_paths =
+            (state_metadata.program_output_paths[0].clone(), state_metadata.blob_data_paths[0].clone());
+        state_metadata.tx_hashes = vec![tx.tx_hash.clone()];
+        state_metadata.in_flight_txs = vec![in_flight_settlement_tx(tx, Some(tx_inputs_paths))];
+        job.metadata.specific = JobSpecificMetadata::StateUpdate(state_metadata.clone());
+
+        let last_block =
+            state_metadata.blocks_to_settle.last().ok_or_else(|| StateUpdateError::LastNumberReturnedError)?;
+        tracing::info!(
+            log_type = "completed",
+            category = "state_update",
+            function_type = "process_job",
+            job_id = %job.id,
+            batch_num = %batch_num,
+            last_settled_block = %last_block,
+            "Batch settled successfully."
+        );
+        Ok(last_block.to_string())
+    }
+
+    /// Finds which of the transactions sent with the nonce of `tx_hash` was included: the original one or one of its
+    /// replacements. Returns the latest transaction `tx_hash` when none of them is included yet.
+    async fn reconcile_settlement_txs(
+        &self,
+        config: Arc<Config>,
+        tx_hash: &str,
+        in_flight_tx: Option<&InFlightSettlementTx>,
+    ) -> Result<(String, SettlementVerificationStatus), JobError> {
+        let sent_tx_hashes = match in_flight_tx {
+            Some(in_flight_tx) if in_flight_tx.tx_hashes.iter().any(|hash| hash == tx_hash) => {
+                in_flight_tx.tx_hashes.clone()
+            }
+            _ => vec![tx_hash.to_string()],
+        };
+        for sent_tx_hash in sent_tx_hashes.iter().rev() {
+            let status = config
+                .settlement_client()
+                .verify_tx_inclusion(sent_tx_hash)
+                .await
+                .map_err(|e| JobError::Other(OtherError(e)))?;
+            if status != SettlementVerificationStatus::Pending {
+                return Ok((sent_tx_hash.clone(), status));
+            }
+        }
+        Ok((tx_hash.to_string(), SettlementVerificationStatus::Pending))
+    }
+
+    /// Replaces the pending settlement tx `tx_hash` with higher fees. The replacement is built again from the program
+    /// output and the blob data stored for the tx.
+    async fn replace_pending_settlement_tx(
+        &self,
+        config: Arc<Config>,
+        tx_hash: &str,
+        in_flight_tx: Option<&InFlightSettlementTx>,
+        is_batch: bool,
+    ) -> Result<TxReplacement, JobError> {
+        let Some(in_flight_tx) =
+            in_flight_tx.filter(|in_flight_tx| in_flight_tx.tx_hashes.last().is_some_and(|hash| hash == tx_hash))
+        else {
+            return Ok(TxReplacement::Unavailable);
+        };
+        let (Some(program_output_path), Some(blob_data_path)) =
+            (&in_flight_tx.program_output_path, &in_flight_tx.blob_data_path)
+        else {
+            return Ok(TxReplacement::Unavailable);
+        };
+
+        let program_output =
+            fetch_program_output_for_block(0, config.clone(), std::slice::from_ref(program_output_path)).await?;
+        let mut blob_data = fetch_blob_data_for_block(0, config.clone(), std::slice::from_ref(blob_data_path)).await?;
+        if is_batch {
+            blob_data = batch_blobs(blob_data);
+        }
+
+        let replacements = in_flight_tx.tx_hashes.len().saturating_sub(1) as u64;
+        config
+            .settlement_client()
+            .replace_pending_tx(&latest_settlement_tx(in_flight_tx), replacements, program_output, blob_data)
+            .await
+            .map_err(|e| JobError::Other(OtherError(e)))
+    }
+
+    /// Retrieves the outputs of the SNOS proof and of the layout bridge proof of the block.
+    async fn fetch_proof_outputs(
+        &self,
+        block_no: u64,
+        config: Arc<Config>,
+    ) -> Result<(Vec<[u8; 32]>, Vec<[u8; 32]>), JobError> {
+        let proof_key = format!("{block_no}/{PROOF_FILE_NAME}");
+        tracing::debug!(%proof_key, "Fetching snos proof file");
+
+        let proof_file = config.storage().get_data(&proof_key).await?;
+
+        let snos_proof = String::from_utf8(proof_file.to_vec()).map_err(|e| {
+            tracing::error!(error = %e, "Failed to parse proof file as UTF-8");
+            JobError::Other(OtherError(eyre!("{}", e)))
+        })?;
+
+        let parsed_snos_proof: StarkProof = parse(snos_proof.clone()).map_err(|e| {
+            tracing::error!(error = %e, "Failed to parse proof file as UTF-8");
+            JobError::Other(OtherError(eyre!("{}", e)))
+        })?;
+
+        let proof_key = format!("{block_no}/{PROOF_PART2_FILE_NAME}");
+        tracing::debug!(%proof_key, "Fetching 2nd proof file");
+
+        let proof_file = config.storage().get_data(&proof_key).await?;
+
+        let second_proof = String::from_utf8(proof_file.to_vec()).map_err(|e| {
+            tracing::error!(error = %e, "Failed to parse proof file as UTF-8");
+            JobError::Other(OtherError(eyre!("{}", e)))
+        })?;
+
+        let parsed_bridge_proof: StarkProof = parse(second_proof.clone()).map_err(|e| {
+            tracing::error!(error = %e, "Failed to parse proof file as UTF-8");
+            JobError::Other(OtherError(eyre!("{}", e)))
+        })?;
+
+        let snos_output = vec_felt_to_vec_bytes32(calculate_output(parsed_snos_proof));
+        let program_output = vec_felt_to_vec_bytes32(calculate_output(parsed_bridge_proof));
+        Ok((snos_output, program_output))
+    }
+
     /// Retrieves the OnChain data for the corresponding block.
     async fn fetch_onchain_data_for_block(&self, block_number: u64, config: Arc<Config>) -> OnChainData {
         let storage_client = config.storage();
@@ -327,44 +533,15 @@ impl StateUpdateJobHandler {
         nonce: u64,
         program_output: Vec<[u8; 32]>,
         blob_data: Vec<Vec<u8>>,
-    ) -> Result<String, JobError> {
+    ) -> Result<SettlementTx, JobError> {
         let settlement_client = config.settlement_client();
-        let last_tx_hash_executed = if snos.use_kzg_da == Felt252::ZERO {
-            let proof_key = format!("{block_no}/{PROOF_FILE_NAME}");
-            tracing::debug!(%proof_key, "Fetching snos proof file");
-
-            let proof_file = config.storage().get_data(&proof_key).await?;
-
-            let snos_proof = String::from_utf8(proof_file.to_vec()).map_err(|e| {
-                tracing::error!(error = %e, "Failed to parse proof file as UTF-8");
-                JobError::Other(OtherError(eyre!("{}", e)))
-            })?;
-
-            let parsed_snos_proof: StarkProof = parse(snos_proof.clone()).map_err(|e| {
-                tracing::error!(error = %e, "Failed to parse proof file as UTF-8");
-                JobError::Other(OtherError(eyre!("{}", e)))
-            })?;
-
-            let proof_key = format!("{block_no}/{PROOF_PART2_FILE_NAME}");
-            tracing::debug!(%proof_key, "Fetching 2nd proof file");
-
-            let proof_file = config.storage().get_data(&proof_key).await?;
-
-            let second_proof = String::from_utf8(proof_file.to_vec()).map_err(|e| {
-                tracing::error!(error = %e, "Failed to parse proof file as UTF-8");
-                JobError::Other(OtherError(eyre!("{}", e)))
-            })?;
-
-            let parsed_bridge_proof: StarkProof = parse(second_proof.clone()).map_err(|e| {
-                tracing::error!(error = %e, "Failed to parse proof file as UTF-8");
-                JobError::Other(OtherError(eyre!("{}", e)))
-            })?;
-
-            let snos_output = vec_felt_to_vec_bytes32(calculate_output(parsed_snos_proof));
-            let program_output = vec_felt_to_vec_bytes32(calculate_output(parsed_bridge_proof));
-
+        // Starknet has no blobs, the L3s settle with calldata whatever the DA mode
+        let use_calldata =
+            snos.use_kzg_da == Felt252::ZERO || (snos.use_kzg_da == Felt252::ONE && config.layer() == &Layer::L3);
+        let last_tx_executed = if use_calldata {
+            let (snos_output, program_output) = self.fetch_proof_outputs(block_no, config.clone()).await?;
             let onchain_data = self.fetch_onchain_data_for_block(block_no, config.clone()).await;
-            settlement_client
+            let tx_hash = settlement_client
                 .update_state_calldata(
                     snos_output,
                     program_output,
@@ -372,7 +549,8 @@ impl StateUpdateJobHandler {
                     usize_to_bytes(onchain_data.on_chain_data_size),
                 )
                 .await
-                .map_err(|e| JobError::Other(OtherError(e)))?
+                .map_err(|e| JobError::Other(OtherError(e)))?;
+            SettlementTx { tx_hash, nonce, ..Default::default() }
         } else if snos.use_kzg_da == Felt252::ONE {
             settlement_client
                 .update_state_with_blobs(program_output, blob_data, nonce)
@@ -381,10 +559,54 @@ impl StateUpdateJobHandler {
         } else {
             Err(StateUpdateError::UseKZGDaError { block_no })?
         };
-        Ok(last_tx_hash_executed)
+        Ok(last_tx_executed)
     }
 }
 
+/// Tracks a settlement tx which was just sent. The paths of its program output and blob data are only given for the
+/// txs sent with blobs, which can be replaced.
+fn in_flight_settlement_tx(tx: SettlementTx, tx_inputs_paths: Option<(String, String)>) -> InFlightSettlementTx {
+    let (program_output_path, blob_data_path) = tx_inputs_paths.unzip();
+    InFlightSettlementTx {
+        nonce: tx.nonce,
+        tx_hashes: vec![tx.tx_hash],
+        gas_limit: tx.gas_limit,
+        max_fee_per_gas: tx.max_fee_per_gas,
+        max_priority_fee_per_gas: tx.max_priority_fee_per_gas,
+        max_fee_per_blob_gas: tx.max_fee_per_blob_gas,
+        sent_at_block: tx.sent_at_block,
+        program_output_path,
+        blob_data_path,
+    }
+}
+
+/// Latest settlement tx sent for the nonce of `in_flight_tx`.
+fn latest_settlement_tx(in_flight_tx: &InFlightSettlementTx) -> SettlementTx {
+    SettlementTx {
+        tx_hash: in_flight_tx.tx_hashes.last().cloned().unwrap_or_default(),
+        nonce: in_flight_tx.nonce,
+        gas_limit: in_flight_tx.gas_limit,
+        max_fee_per_gas: in_flight_tx.max_fee_per_gas,
+        max_priority_fee_per_gas: in_flight_tx.max_priority_fee_per_gas,
+        max_fee_per_blob_gas: in_flight_tx.max_fee_per_blob_gas,
+        sent_at_block: in_flight_tx.sent_at_block,
+    }
+}
+
+fn record_replacement(in_flight_tx: &mut InFlightSettlementTx, replacement: SettlementTx) {
+    in_flight_tx.tx_hashes.push(replacement.tx_hash);
+    in_flight_tx.gas_limit = replacement.gas_limit;
+    in_flight_tx.max_fee_per_gas = replacement.max_fee_per_gas;
+    in_flight_tx.max_priority_fee_per_gas = replacement.max_priority_fee_per_gas;
+    in_flight_tx.max_fee_per_blob_gas = replacement.max_fee_per_blob_gas;
+    in_flight_tx.sent_at_block = replacement.sent_at_block;
+}
+
+/// Splits the blob data of a batch, which holds several blobs.
+fn batch_blobs(blob_data: Vec<Vec<u8>>) -> Vec<Vec<u8>> {
+    blob_data.concat().chunks(*BLOB_LEN * 32).map(|blob| blob.to_vec()).collect()
+}
+
 pub fn calculate_output(proof: StarkProof) -> Vec<Felt> {
     let output_segment = proof.public_input.segments[2].clone();
     let output_len = output_segment.stop_ptr - output_segment.begin_addr;
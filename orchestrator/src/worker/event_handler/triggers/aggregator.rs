@@ -0,0 +1,104 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use opentelemetry::KeyValue;
+
+use crate::core::config::Config;
+use crate::types::constant::{BLOB_DATA_FILE_NAME, PROGRAM_OUTPUT_FILE_NAME, PROOF_FILE_NAME, STORAGE_BATCH_DIR};
+use crate::types::jobs::metadata::{AggregatorMetadata, CommonMetadata, JobMetadata, JobSpecificMetadata};
+use crate::types::jobs::types::{JobStatus, JobType};
+use crate::utils::metrics::ORCHESTRATOR_METRICS;
+use crate::worker::event_handler::service::JobHandlerService;
+use crate::worker::event_handler::triggers::JobTrigger;
+
+pub struct AggregatorJobTrigger;
+
+#[async_trait]
+impl JobTrigger for AggregatorJobTrigger {
+    /// 1. Fetch the batches following the batch of the latest aggregator job
+    /// 2. Create an aggregator job for each closed batch whose blocks are all proven
+    async fn run_worker(&self, config: Arc<Config>) -> color_eyre::Result<()> {
+        if !config.service_config().aggregate_batch_proofs {
+            return Ok(());
+        }
+        tracing::info!(log_type = "starting", category = "AggregatorWorker", "AggregatorWorker started.");
+
+        // Self-healing: recover any orphaned Aggregator jobs before creating new ones
+        if let Err(e) = self.heal_orphaned_jobs(config.clone(), JobType::Aggregator).await {
+            tracing::error!(error = %e, "Failed to heal orphaned Aggregator jobs, continuing with normal processing");
+        }
+
+        let mut batch_index = match config.database().get_latest_job_by_type(JobType::Aggregator).await? {
+            Some(job) => {
+                let metadata: AggregatorMetadata = job.metadata.specific.try_into()?;
+                metadata.batch_num + 1
+            }
+            None => 1,
+        };
+
+        // The batches are aggregated in order, we stop at the first batch that isn't ready
+        while let Some(batch) = config.database().get_batch_by_index(batch_index).await? {
+            if !batch.is_batch_ready {
+                tracing::debug!(batch_index = %batch.index, "Batch is still open, skipping aggregation");
+                break;
+            }
+            let Some(bucket_id) = batch.bucket_id.clone() else {
+                tracing::error!(batch_index = %batch.index, "Batch has no bucket, its proofs can't be aggregated");
+                break;
+            };
+
+            let mut all_blocks_proven = true;
+            for block_number in batch.start_block..=batch.end_block {
+                let proving_job = config
+                    .database()
+                    .get_job_by_internal_id_and_type(&block_number.to_string(), &JobType::ProofCreation)
+                    .await?;
+                if proving_job.is_none_or(|job| job.status != JobStatus::Completed) {
+                    all_blocks_proven = false;
+                    break;
+                }
+            }
+            if !all_blocks_proven {
+                tracing::debug!(batch_index = %batch.index, "Proofs of the batch are not completed yet");
+                break;
+            }
+
+            let aggregator_metadata = JobMetadata {
+                common: CommonMetadata::default(),
+                specific: JobSpecificMetadata::Aggregator(AggregatorMetadata {
+                    batch_num: batch.index,
+                    bucket_id,
+                    squashed_state_update_path: batch.squashed_state_updates_path.clone(),
+                    download_proof: Some(format!("{}/{}/{}", STORAGE_BATCH_DIR, batch.index, PROOF_FILE_NAME)),
+                    program_output_path: format!("{}/{}/{}", STORAGE_BATCH_DIR, batch.index, PROGRAM_OUTPUT_FILE_NAME),
+                    blob_data_path: format!("{}/{}/{}", STORAGE_BATCH_DIR, batch.index, BLOB_DATA_FILE_NAME),
+                }),
+            };
+
+            tracing::debug!(batch_index = %batch.index, "Creating aggregator job for batch");
+            match JobHandlerService::create_job(
+                JobType::Aggregator,
+                batch.index.to_string(),
+                aggregator_metadata,
+                config.clone(),
+            )
+            .await
+            {
+                Ok(_) => tracing::info!(batch_index = %batch.index, "Successfully created new aggregator job"),
+                Err(e) => {
+                    tracing::warn!(batch_index = %batch.index, error = %e, "Failed to create new aggregator job");
+                    let attributes = [
+                        KeyValue::new("operation_job_type", format!("{:?}", JobType::Aggregator)),
+                        KeyValue::new("operation_type", format!("{:?}", "create_job")),
+                    ];
+                    ORCHESTRATOR_METRICS.failed_job_operations.add(1.0, &attributes);
+                    break;
+                }
+            }
+            batch_index += 1;
+        }
+
+        tracing::trace!(log_type = "completed", category = "AggregatorWorker", "AggregatorWorker completed.");
+        Ok(())
+    }
+}
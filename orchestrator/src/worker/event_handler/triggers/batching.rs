@@ -2,9 +2,11 @@ use crate::core::config::Config;
 use crate::error::job::JobError;
 use crate::error::other::OtherError;
 use crate::types::batch::{Batch, BatchUpdates};
-use crate::types::constant::{MAX_BATCH_SIZE, STORAGE_STATE_UPDATE_DIR};
+use crate::types::constant::STORAGE_STATE_UPDATE_DIR;
 use crate::worker::event_handler::triggers::JobTrigger;
+use crate::worker::utils::stateful_compression::{compress_batch_state_diff, AliasReader};
 use bytes::Bytes;
+use chrono::{Duration, Utc};
 use color_eyre::eyre::eyre;
 use starknet::core::types::{
     BlockId, ContractStorageDiffItem, DeclaredClassItem, DeployedContractItem, Felt, NonceUpdate, ReplacedClassItem,
@@ -16,6 +18,9 @@ use std::cmp::{max, min};
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
+/// Size of a felt in the blobs, in bytes.
+const BLOB_FELT_SIZE: u64 = 32;
+
 pub struct BatchingTrigger;
 
 #[async_trait::async_trait]
@@ -40,13 +45,30 @@ impl JobTrigger for BatchingTrigger {
 
         // Getting the latest batch in DB
         let latest_batch = config.database().get_latest_batch().await?;
-        let latest_block_in_db = latest_batch.map_or(0, |batch| batch.end_block);
+
+        // Closing the latest batch if it is too old, even if no block is added to it
+        if let Some(batch) = &latest_batch {
+            if !batch.is_batch_ready && self.is_batch_expired(batch, &config) {
+                tracing::info!(batch_index = batch.index, "Closing the batch as it reached its maximum age");
+                config
+                    .database()
+                    .update_batch(batch, BatchUpdates { end_block: batch.end_block, is_batch_ready: true })
+                    .await?;
+            }
+        }
 
         // Calculating the first block number to for which a batch needs to be assigned
-        let first_block_to_assign_batch = max(latest_block_in_db, config.service_config().min_block_to_process);
+        let first_block_to_assign_batch =
+            max(latest_batch.map_or(0, |batch| batch.end_block + 1), config.service_config().min_block_to_process);
 
+        // A batch is closed once its compressed state diff does not fit in a DA transaction anymore
+        let da_client = config.da_client();
+        let max_batch_felts =
+            (da_client.max_blob_per_txn().await * da_client.max_bytes_per_blob().await / BLOB_FELT_SIZE) as usize;
+
+        let mut aliases = None;
         for block_num in first_block_to_assign_batch..=last_block_to_assign_batch {
-            self.assign_batch_to_block(block_num, config.clone()).await?;
+            self.assign_batch_to_block(block_num, max_batch_felts, &mut aliases, config.clone()).await?;
         }
         tracing::info!(log_type = "completed", category = "BatchingWorker", "BatchingWorker completed.");
         Ok(())
@@ -55,7 +77,16 @@ impl JobTrigger for BatchingTrigger {
 
 impl BatchingTrigger {
     /// assign_batch_to_block assigns a batch to the block
-    async fn assign_batch_to_block(&self, block_number: u64, config: Arc<Config>) -> Result<(), JobError> {
+    /// The block is added to the latest batch if the compressed state diff of the batch still fits in a DA
+    /// transaction, otherwise the latest batch is closed and a new batch is started. The latest batch is also closed
+    /// when it already has the maximum number of blocks or reached its maximum age.
+    async fn assign_batch_to_block(
+        &self,
+        block_number: u64,
+        max_batch_felts: usize,
+        aliases: &mut Option<AliasReader>,
+        config: Arc<Config>,
+    ) -> Result<(), JobError> {
         // Get the provider
         let provider = config.madara_client();
 
@@ -71,85 +102,114 @@ impl BatchingTrigger {
             .await
             .map_err(|e| JobError::ProviderError(e.to_string()))?;
 
-        match state_update {
-            Update(state_update) => {
-                // FIXME: Update this flow
-                // For now, adding a hardcoded limit on the number of blocks each batch can have
-                // The Correct implementation:
-                // 1. Squash all state updates to get a single state update
-                // 2. Perform stateful compression
-                // 3. Perform stateless compression
-                // 4. Create an array of felts
-                // 5. Check if its length is less than 6 * 4096
-                //    If yes, it can be added in the same batch
-                //    Else, start a new batch
-                tracing::info!("Starting batching for block {}", block_number);
-                let latest_batch = database.get_latest_batch().await?;
-                let mut batch_index = 1;
-                if let Some(batch) = latest_batch {
-                    // A batch exists
-                    // Check if we can add a new block in the same batch
-                    if batch.size < MAX_BATCH_SIZE {
-                        // Can add in the same batch
-
-                        // Fetch existing state update
-                        let current_state_update_bytes = storage.get_data(&batch.squashed_state_updates_path).await?;
-                        let current_state_update: StateUpdate = serde_json::from_slice(&current_state_update_bytes)?;
-                        // Merge the current block's state update with the batch's state update
-                        let new_state_update = self.squash_state_updates(vec![current_state_update, state_update])?;
-                        // Update state update for the batch in storage
-                        storage
-                            .put_data(
-                                Bytes::from(serde_json::to_string(&new_state_update)?),
-                                &self.get_state_update_file_name(batch.index),
-                            )
-                            .await?;
-                        // Update batch status in the database
-                        database
-                            .update_batch(&batch, BatchUpdates { end_block: block_number, is_batch_ready: false })
-                            .await?;
-                        batch_index = batch.index;
-                    } else {
-                        // Start a new batch
-
-                        // Update the status of the previous batch
-                        database
-                            .update_batch(&batch, BatchUpdates { end_block: batch.end_block, is_batch_ready: true })
-                            .await?;
-                        let squashed_state_updates_path = self.get_state_update_file_name(batch_index + 1);
-                        // Put the state update in storage
-                        storage
-                            .put_data(Bytes::from(serde_json::to_string(&state_update)?), &squashed_state_updates_path)
-                            .await?;
-                        // Add the new batch info in the database
-                        database
-                            .create_batch(Batch::create(batch.index + 1, block_number, squashed_state_updates_path))
-                            .await?;
-                        batch_index = batch.index + 1;
-                    }
-                } else {
-                    // No batch exists in the DB yet
-                    // Create a fresh batch
+        let state_update = match state_update {
+            Update(state_update) => state_update,
+            PendingUpdate(_) => {
+                tracing::info!("Skipping batching for block {} as it is still pending", block_number);
+                return Ok(());
+            }
+        };
 
-                    let squashed_state_updates_path = self.get_state_update_file_name(batch_index + 1);
-                    // Put the state update in storage
+        tracing::info!("Starting batching for block {}", block_number);
+        let batch_index = match database.get_latest_batch().await? {
+            Some(batch) if !batch.is_batch_ready && self.is_batch_full(&batch, &config) => {
+                tracing::info!(batch_index = batch.index, "Closing the batch as it reached its maximum size or age");
+                database
+                    .update_batch(&batch, BatchUpdates { end_block: batch.end_block, is_batch_ready: true })
+                    .await?;
+                self.start_batch(batch.index + 1, block_number, &state_update, &config).await?
+            }
+            Some(batch) if !batch.is_batch_ready => {
+                // Fetch existing state update
+                let current_state_update_bytes = storage.get_data(&batch.squashed_state_updates_path).await?;
+                let current_state_update: StateUpdate = serde_json::from_slice(&current_state_update_bytes)?;
+                // Merge the current block's state update with the batch's state update
+                let new_state_update = self.squash_state_updates(vec![current_state_update, state_update.clone()])?;
+
+                // The cached aliases are only valid for the batch they were read for
+                if aliases.as_ref().is_some_and(|aliases| aliases.batch_start_block() != batch.start_block) {
+                    *aliases = None;
+                }
+                let aliases = aliases.get_or_insert_with(|| AliasReader::new(batch.start_block));
+                let compressed_size =
+                    compress_batch_state_diff(&new_state_update.state_diff, aliases, &config).await?.len();
+                tracing::debug!(
+                    batch_index = batch.index,
+                    compressed_size = compressed_size,
+                    max_batch_felts = max_batch_felts,
+                    "Computed compressed size of the batch"
+                );
+
+                if compressed_size <= max_batch_felts {
+                    // Update state update for the batch in storage
                     storage
-                        .put_data(Bytes::from(serde_json::to_string(&state_update)?), &squashed_state_updates_path)
+                        .put_data(
+                            Bytes::from(serde_json::to_string(&new_state_update)?),
+                            &batch.squashed_state_updates_path,
+                        )
+                        .await?;
+                    // Update batch status in the database
+                    database
+                        .update_batch(&batch, BatchUpdates { end_block: block_number, is_batch_ready: false })
                         .await?;
-                    // Add the new batch info in the database
-                    database.create_batch(Batch::create(1, block_number, squashed_state_updates_path)).await?;
-                    batch_index = 1;
+                    batch.index
+                } else {
+                    // Close the batch and start a new one with this block
+                    database
+                        .update_batch(&batch, BatchUpdates { end_block: batch.end_block, is_batch_ready: true })
+                        .await?;
+                    self.start_batch(batch.index + 1, block_number, &state_update, &config).await?
                 }
-                tracing::info!("Completed batching for block {}. Assigned batch {}", block_number, batch_index);
-            }
-            PendingUpdate(_) => {
-                tracing::info!("Skipping batching for block {} as it is still pending", block_number);
             }
-        }
+            Some(batch) => self.start_batch(batch.index + 1, block_number, &state_update, &config).await?,
+            // No batch exists in the DB yet
+            None => self.start_batch(1, block_number, &state_update, &config).await?,
+        };
+        tracing::info!("Completed batching for block {}. Assigned batch {}", block_number, batch_index);
 
         Ok(())
     }
 
+    /// start_batch creates a new batch starting at the block and returns its index
+    async fn start_batch(
+        &self,
+        batch_index: u64,
+        block_number: u64,
+        state_update: &StateUpdate,
+        config: &Config,
+    ) -> Result<u64, JobError> {
+        let squashed_state_updates_path = self.get_state_update_file_name(batch_index);
+        // Put the state update in storage
+        config
+            .storage()
+            .put_data(Bytes::from(serde_json::to_string(state_update)?), &squashed_state_updates_path)
+            .await?;
+        // The proofs of the blocks of the batch are submitted to the bucket of the batch to be aggregated
+        let bucket_id = match config.service_config().aggregate_batch_proofs {
+            true => Some(config.prover_client().create_bucket().await?),
+            false => None,
+        };
+        // Add the new batch info in the database
+        config
+            .database()
+            .create_batch(Batch::create(batch_index, block_number, squashed_state_updates_path, bucket_id))
+            .await?;
+        Ok(batch_index)
+    }
+
+    /// is_batch_full returns whether no block can be added to the batch anymore, because it has the maximum number
+    /// of blocks or reached its maximum age
+    fn is_batch_full(&self, batch: &Batch, config: &Config) -> bool {
+        batch.end_block - batch.start_block + 1 >= config.service_config().max_batch_size
+            || self.is_batch_expired(batch, config)
+    }
+
+    /// is_batch_expired returns whether the batch was created more than the maximum batch age ago
+    fn is_batch_expired(&self, batch: &Batch, config: &Config) -> bool {
+        let max_batch_age = Duration::seconds(config.service_config().max_batch_time_seconds as i64);
+        Utc::now() - batch.created_at >= max_batch_age
+    }
+
     fn get_state_update_file_name(&self, batch_index: u64) -> String {
         format!("{}/batch/{}.json", STORAGE_STATE_UPDATE_DIR, batch_index)
     }
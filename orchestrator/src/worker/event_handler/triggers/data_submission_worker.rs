@@ -18,6 +18,11 @@ impl JobTrigger for DataSubmissionJobTrigger {
     // 1. Fetch the latest completed Proving jobs without Data Submission jobs as successor jobs
     // 2. Create jobs.
     async fn run_worker(&self, config: Arc<Config>) -> color_eyre::Result<()> {
+        // The data of the blocks is published once per batch by the state transition job, along with the aggregated
+        // proof of the batch
+        if config.service_config().aggregate_batch_proofs {
+            return Ok(());
+        }
         tracing::trace!(log_type = "starting", category = "DataSubmissionWorker", "DataSubmissionWorker started.");
 
         // Self-healing: recover any orphaned DataSubmission jobs before creating new ones
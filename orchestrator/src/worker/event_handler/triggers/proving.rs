@@ -11,7 +11,6 @@ use crate::worker::event_handler::service::JobHandlerService;
 use crate::worker::event_handler::triggers::JobTrigger;
 use async_trait::async_trait;
 use opentelemetry::KeyValue;
-use orchestrator_utils::layer::Layer;
 
 pub struct ProvingJobTrigger;
 
@@ -50,11 +49,23 @@ impl JobTrigger for ProvingJobTrigger {
                 }
             };
 
-            let download_proof = match config.layer() {
-                Layer::L2 => None,
-                Layer::L3 => Some(format!("{}/{}", snos_job.internal_id, PROOF_FILE_NAME)),
+            // The proofs of the blocks of a batch are aggregated in the bucket of the batch, the proof of a block
+            // is then never registered on its own
+            let (bucket_id, bucket_job_index, ensure_on_chain_registration) = if config
+                .service_config()
+                .aggregate_batch_proofs
+            {
+                let Some(batch) = config.database().get_batch_for_block(snos_metadata.block_number).await? else {
+                    tracing::debug!(job_id = %snos_job.internal_id, "Block is not batched yet, skipping proving job");
+                    continue;
+                };
+                (batch.bucket_id, Some(snos_metadata.block_number - batch.start_block), None)
+            } else {
+                (None, None, Some(snos_fact))
             };
 
+            let download_proof = config.store_proofs().then(|| format!("{}/{}", snos_job.internal_id, PROOF_FILE_NAME));
+
             // Create proving job metadata
             let proving_metadata = JobMetadata {
                 common: CommonMetadata::default(),
@@ -65,8 +76,10 @@ impl JobTrigger for ProvingJobTrigger {
                     // Set a download path if needed
                     download_proof,
                     // Set SNOS fact for on-chain verification
-                    ensure_on_chain_registration: Some(snos_fact),
+                    ensure_on_chain_registration,
                     n_steps: snos_metadata.snos_n_steps,
+                    bucket_id,
+                    bucket_job_index,
                 }),
             };
 
@@ -7,7 +7,7 @@ use orchestrator_utils::layer::Layer;
 
 use crate::core::config::Config;
 use crate::types::jobs::metadata::{
-    CommonMetadata, DaMetadata, JobMetadata, JobSpecificMetadata, SnosMetadata, StateUpdateMetadata,
+    AggregatorMetadata, CommonMetadata, DaMetadata, JobMetadata, JobSpecificMetadata, SnosMetadata, StateUpdateMetadata,
 };
 use crate::types::jobs::types::{JobStatus, JobType};
 use crate::utils::metrics::ORCHESTRATOR_METRICS;
@@ -26,6 +26,10 @@ impl JobTrigger for UpdateStateJobTrigger {
             tracing::error!(error = %e, "Failed to heal orphaned StateTransition jobs, continuing with normal processing");
         }
 
+        if config.service_config().aggregate_batch_proofs {
+            return self.create_batch_state_transition_job(config).await;
+        }
+
         let latest_job = config.database().get_latest_job_by_type(JobType::StateTransition).await?;
         let (completed_da_jobs, last_block_processed_in_last_job) = match latest_job {
             Some(job) => {
@@ -128,8 +132,10 @@ impl JobTrigger for UpdateStateJobTrigger {
             snos_output_paths: Vec::new(),
             program_output_paths: Vec::new(),
             blob_data_paths: Vec::new(),
+            batch_num: None,
             last_failed_block_no: None,
             tx_hashes: Vec::new(),
+            in_flight_txs: Vec::new(),
         };
 
         // Collect paths from SNOS and DA jobs
@@ -175,10 +181,84 @@ impl JobTrigger for UpdateStateJobTrigger {
         };
 
         // Create the state transition job
-        let new_job_id = blocks_to_process[0].to_string();
-        match JobHandlerService::create_job(JobType::StateTransition, new_job_id.clone(), metadata, config.clone())
-            .await
-        {
+        self.create_state_transition_job(blocks_to_process[0].to_string(), metadata, config).await?;
+
+        tracing::trace!(log_type = "completed", category = "UpdateStateWorker", "UpdateStateWorker completed.");
+        Ok(())
+    }
+}
+
+impl UpdateStateJobTrigger {
+    /// Creates a state transition job settling the batch following the last settled block, once the proofs of the
+    /// batch are aggregated
+    async fn create_batch_state_transition_job(&self, config: Arc<Config>) -> color_eyre::Result<()> {
+        let first_block_to_settle = match config.database().get_latest_job_by_type(JobType::StateTransition).await? {
+            Some(job) => {
+                if job.status != JobStatus::Completed {
+                    tracing::warn!("There's already a pending update state job. Returning safely...");
+                    return Ok(());
+                }
+                let state_metadata: StateUpdateMetadata = job.metadata.specific.try_into()?;
+                let last_block_processed = state_metadata
+                    .blocks_to_settle
+                    .iter()
+                    .max()
+                    .ok_or_else(|| eyre!("No blocks found in previous state transition job"))?;
+                last_block_processed + 1
+            }
+            None => config.service_config().min_block_to_process,
+        };
+
+        let Some(batch) = config.database().get_batch_for_block(first_block_to_settle).await? else {
+            tracing::warn!(block_no = %first_block_to_settle, "Block to settle is not batched yet. Returning safely...");
+            return Ok(());
+        };
+        if batch.start_block != first_block_to_settle {
+            return Err(eyre!(
+                "Block {} to settle is not the first block of batch {}, which starts at block {}",
+                first_block_to_settle,
+                batch.index,
+                batch.start_block
+            ));
+        }
+
+        let aggregator_job =
+            config.database().get_job_by_internal_id_and_type(&batch.index.to_string(), &JobType::Aggregator).await?;
+        let aggregator_metadata: AggregatorMetadata = match aggregator_job {
+            Some(job) if job.status == JobStatus::Completed => job.metadata.specific.try_into()?,
+            _ => {
+                tracing::warn!(batch_index = %batch.index, "Aggregator job of the batch is not completed yet. Returning safely...");
+                return Ok(());
+            }
+        };
+
+        let state_metadata = StateUpdateMetadata {
+            blocks_to_settle: (batch.start_block..=batch.end_block).collect(),
+            snos_output_paths: Vec::new(),
+            program_output_paths: vec![aggregator_metadata.program_output_path],
+            blob_data_paths: vec![aggregator_metadata.blob_data_path],
+            batch_num: Some(batch.index),
+            last_failed_block_no: None,
+            tx_hashes: Vec::new(),
+            in_flight_txs: Vec::new(),
+        };
+        let metadata = JobMetadata {
+            common: CommonMetadata::default(),
+            specific: JobSpecificMetadata::StateUpdate(state_metadata),
+        };
+        self.create_state_transition_job(batch.start_block.to_string(), metadata, config).await?;
+
+        tracing::trace!(log_type = "completed", category = "UpdateStateWorker", "UpdateStateWorker completed.");
+        Ok(())
+    }
+
+    async fn create_state_transition_job(
+        &self,
+        new_job_id: String,
+        metadata: JobMetadata,
+        config: Arc<Config>,
+    ) -> color_eyre::Result<()> {
+        match JobHandlerService::create_job(JobType::StateTransition, new_job_id.clone(), metadata, config).await {
             Ok(_) => tracing::info!(block_id = %new_job_id, "Successfully created new state transition job"),
             Err(e) => {
                 tracing::error!(job_id = %new_job_id, error = %e, "Failed to create new state transition job");
@@ -190,8 +270,6 @@ impl JobTrigger for UpdateStateJobTrigger {
                 return Err(e.into());
             }
         }
-
-        tracing::trace!(log_type = "completed", category = "UpdateStateWorker", "UpdateStateWorker completed.");
         Ok(())
     }
 }
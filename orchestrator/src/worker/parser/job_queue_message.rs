@@ -1,7 +1,7 @@
+use crate::core::client::queue::Delivery;
 use crate::error::event::EventSystemResult;
 use crate::error::ConsumptionError;
 use crate::worker::traits::message::MessageParser;
-use omniqueue::Delivery;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
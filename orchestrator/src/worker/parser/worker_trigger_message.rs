@@ -1,10 +1,10 @@
+use crate::core::client::queue::Delivery;
 use crate::error::event::EventSystemResult;
 use crate::error::other::OtherError;
 use crate::error::ConsumptionError;
 use crate::types::jobs::WorkerTriggerType;
 use crate::worker::traits::message::MessageParser;
 use color_eyre::eyre::Context;
-use omniqueue::Delivery;
 use serde::Serialize;
 use std::str::FromStr;
 
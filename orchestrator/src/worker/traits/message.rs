@@ -1,7 +1,7 @@
+use crate::core::client::queue::Delivery;
 use crate::error::event::EventSystemResult;
 use crate::worker::parser::job_queue_message::JobQueueMessage;
 use crate::worker::parser::worker_trigger_message::WorkerTriggerMessage;
-use omniqueue::Delivery;
 
 #[derive(Debug, Clone)]
 pub enum ParsedMessage {
@@ -16,6 +16,8 @@ pub mod fact_node;
 pub mod fact_topology;
 
 pub mod conversion;
+pub mod stateful_compression;
+pub mod stateless_compression;
 
 /// biguint_vec_to_u8_vec - Converts a vector of BigUint numbers to a vector of u8 bytes.
 ///
@@ -0,0 +1,427 @@
+//! Stateful compression of the state diffs, as done by the Starknet OS since v0.13.4.
+//!
+//! The contract addresses and the storage keys are replaced by short aliases, allocated once for all in the storage of
+//! the alias contract: the alias of a key is stored at this key, and the next available alias at
+//! [`ALIAS_COUNTER_STORAGE_KEY`]. Keys that are already small are not aliased, and neither are the storage keys of the
+//! system contracts.
+//!
+//! The sequencer allocates the aliases when closing a block, in which case they are part of the state diffs. The
+//! allocation is replayed here for the keys without an alias, so that the size of the compressed data is the same for
+//! chains that do not allocate aliases yet.
+
+use crate::core::config::Config;
+use crate::error::job::JobError;
+use crate::error::other::OtherError;
+use crate::worker::event_handler::jobs::da::DAJobHandler;
+use crate::worker::utils::stateless_compression;
+use color_eyre::eyre::eyre;
+use futures::{stream, StreamExt, TryStreamExt};
+use starknet::core::types::{
+    BlockId, ContractStorageDiffItem, DeployedContractItem, Felt, NonceUpdate, ReplacedClassItem, StarknetError,
+    StateDiff, StorageEntry,
+};
+use starknet::providers::{Provider, ProviderError};
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+pub const ALIAS_CONTRACT_ADDRESS: Felt = Felt::TWO;
+pub const ALIAS_COUNTER_STORAGE_KEY: Felt = Felt::ZERO;
+/// The first allocated alias.
+pub const INITIAL_AVAILABLE_ALIAS: Felt = Felt::from_hex_unchecked("0x80");
+/// Keys smaller than this value are not aliased.
+pub const MIN_VALUE_FOR_ALIAS_ALLOC: Felt = Felt::from_hex_unchecked("0x80");
+/// The storage keys of the contracts up to this address are not aliased.
+pub const MAX_NON_COMPRESSED_CONTRACT_ADDRESS: Felt = Felt::from_hex_unchecked("0xf");
+
+/// Maximum number of concurrent requests when reading the aliases.
+const MAX_CONCURRENT_ALIAS_READS: usize = 32;
+
+/// keys_to_alias - Returns the contract addresses and storage keys of the state diff that need an alias, in the order
+/// the aliases are allocated by blockifier: for each contract address, sorted, its sorted storage keys followed by the
+/// address itself.
+pub fn keys_to_alias(state_diff: &StateDiff) -> Vec<Felt> {
+    let mut storage_keys: BTreeMap<Felt, BTreeSet<Felt>> = BTreeMap::new();
+    for ContractStorageDiffItem { address, storage_entries } in &state_diff.storage_diffs {
+        let keys = storage_keys.entry(*address).or_default();
+        if *address > MAX_NON_COMPRESSED_CONTRACT_ADDRESS {
+            keys.extend(storage_entries.iter().map(|entry| entry.key));
+        }
+    }
+    let addresses = state_diff
+        .nonces
+        .iter()
+        .map(|nonce| nonce.contract_address)
+        .chain(state_diff.deployed_contracts.iter().map(|item| item.address))
+        .chain(state_diff.replaced_classes.iter().map(|item| item.contract_address));
+    for address in addresses {
+        storage_keys.entry(address).or_default();
+    }
+
+    // A key is allocated an alias once, at its first occurrence
+    let mut seen = HashSet::new();
+    storage_keys
+        .into_iter()
+        .flat_map(|(address, keys)| keys.into_iter().chain(std::iter::once(address)))
+        .filter(|key| *key >= MIN_VALUE_FOR_ALIAS_ALLOC && seen.insert(*key))
+        .collect()
+}
+
+/// Aliases used to compress a state diff.
+struct Aliases(HashMap<Felt, Felt>);
+
+impl Aliases {
+    fn compress(&self, key: Felt) -> Result<Felt, JobError> {
+        if key < MIN_VALUE_FOR_ALIAS_ALLOC {
+            return Ok(key);
+        }
+        self.0.get(&key).copied().ok_or_else(|| JobError::Other(OtherError(eyre!("Missing alias for key {:#x}", key))))
+    }
+
+    fn compress_storage_key(&self, address: Felt, key: Felt) -> Result<Felt, JobError> {
+        if address <= MAX_NON_COMPRESSED_CONTRACT_ADDRESS {
+            return Ok(key);
+        }
+        self.compress(key)
+    }
+}
+
+/// compress_state_diff - Replaces the contract addresses and the storage keys of the state diff by their aliases
+///
+/// # Arguments
+/// * `state_diff` - The squashed state diff to compress.
+/// * `existing_aliases` - The aliases allocated before the state diff, for the keys returned by [`keys_to_alias`].
+///   Keys without an alias can be missing.
+/// * `alias_counter` - The next available alias before the state diff, `None` if no alias has been allocated yet.
+///
+/// # Returns
+/// The compressed state diff, including the storage updates of the alias contract.
+pub fn compress_state_diff(
+    state_diff: &StateDiff,
+    existing_aliases: &HashMap<Felt, Felt>,
+    alias_counter: Option<Felt>,
+) -> Result<StateDiff, JobError> {
+    // Aliases allocated by the sequencer in the state diff
+    let mut alias_storage: BTreeMap<Felt, Felt> = state_diff
+        .storage_diffs
+        .iter()
+        .filter(|diff| diff.address == ALIAS_CONTRACT_ADDRESS)
+        .flat_map(|diff| diff.storage_entries.iter().map(|entry| (entry.key, entry.value)))
+        .collect();
+    let alias_counter = alias_storage.get(&ALIAS_COUNTER_STORAGE_KEY).copied().or(alias_counter);
+
+    let mut aliases = existing_aliases.clone();
+    aliases.extend(alias_storage.iter().filter(|(key, _)| **key != ALIAS_COUNTER_STORAGE_KEY).map(|(k, v)| (*k, *v)));
+
+    // Allocate the aliases missing from the state diff
+    let mut next_alias = alias_counter.unwrap_or(INITIAL_AVAILABLE_ALIAS);
+    for key in keys_to_alias(state_diff) {
+        if let Entry::Vacant(entry) = aliases.entry(key) {
+            entry.insert(next_alias);
+            alias_storage.insert(key, next_alias);
+            next_alias += Felt::ONE;
+        }
+    }
+    // The counter is initialized with the first allocation
+    if alias_counter != Some(next_alias) {
+        alias_storage.insert(ALIAS_COUNTER_STORAGE_KEY, next_alias);
+    }
+    let aliases = Aliases(aliases);
+
+    let mut storage_diffs = Vec::with_capacity(state_diff.storage_diffs.len() + 1);
+    for ContractStorageDiffItem { address, storage_entries } in &state_diff.storage_diffs {
+        if *address == ALIAS_CONTRACT_ADDRESS {
+            continue;
+        }
+        let storage_entries = storage_entries
+            .iter()
+            .map(|entry| {
+                Ok(StorageEntry { key: aliases.compress_storage_key(*address, entry.key)?, value: entry.value })
+            })
+            .collect::<Result<_, JobError>>()?;
+        storage_diffs.push(ContractStorageDiffItem { address: aliases.compress(*address)?, storage_entries });
+    }
+    storage_diffs.push(ContractStorageDiffItem {
+        address: ALIAS_CONTRACT_ADDRESS,
+        storage_entries: alias_storage.into_iter().map(|(key, value)| StorageEntry { key, value }).collect(),
+    });
+
+    Ok(StateDiff {
+        storage_diffs,
+        deprecated_declared_classes: state_diff.deprecated_declared_classes.clone(),
+        declared_classes: state_diff.declared_classes.clone(),
+        deployed_contracts: state_diff
+            .deployed_contracts
+            .iter()
+            .map(|item| {
+                Ok(DeployedContractItem { address: aliases.compress(item.address)?, class_hash: item.class_hash })
+            })
+            .collect::<Result<_, JobError>>()?,
+        replaced_classes: state_diff
+            .replaced_classes
+            .iter()
+            .map(|item| {
+                Ok(ReplacedClassItem {
+                    contract_address: aliases.compress(item.contract_address)?,
+                    class_hash: item.class_hash,
+                })
+            })
+            .collect::<Result<_, JobError>>()?,
+        nonces: state_diff
+            .nonces
+            .iter()
+            .map(|item| {
+                Ok(NonceUpdate { contract_address: aliases.compress(item.contract_address)?, nonce: item.nonce })
+            })
+            .collect::<Result<_, JobError>>()?,
+    })
+}
+
+/// state_diff_to_felts - Encodes the state diff as published to the DA layer by the Starknet OS
+/// `[n_contracts, ..(address, da_word, class_hash?, ..(key, value)), n_classes, ..(class_hash, compiled_class_hash)]`
+pub fn state_diff_to_felts(state_diff: &StateDiff) -> Result<Vec<Felt>, JobError> {
+    let class_hashes: HashMap<Felt, Felt> = state_diff
+        .deployed_contracts
+        .iter()
+        .map(|item| (item.address, item.class_hash))
+        .chain(state_diff.replaced_classes.iter().map(|item| (item.contract_address, item.class_hash)))
+        .collect();
+    let nonces: HashMap<Felt, Felt> =
+        state_diff.nonces.iter().map(|item| (item.contract_address, item.nonce)).collect();
+    let mut storage_diffs: BTreeMap<Felt, Vec<&StorageEntry>> = BTreeMap::new();
+    for diff in &state_diff.storage_diffs {
+        storage_diffs.entry(diff.address).or_default().extend(&diff.storage_entries);
+    }
+    for address in class_hashes.keys().chain(nonces.keys()) {
+        storage_diffs.entry(*address).or_default();
+    }
+
+    let mut felts = vec![Felt::from(storage_diffs.len())];
+    for (address, mut storage_entries) in storage_diffs {
+        let class_hash = class_hashes.get(&address);
+        felts.push(address);
+        felts.push(DAJobHandler::da_word(
+            class_hash.is_some(),
+            nonces.get(&address).copied(),
+            storage_entries.len() as u64,
+        )?);
+        felts.extend(class_hash);
+        storage_entries.sort_by_key(|entry| entry.key);
+        felts.extend(storage_entries.into_iter().flat_map(|entry| [entry.key, entry.value]));
+    }
+
+    felts.push(Felt::from(state_diff.declared_classes.len()));
+    let mut declared_classes = state_diff.declared_classes.clone();
+    declared_classes.sort_by_key(|class| class.class_hash);
+    felts.extend(declared_classes.into_iter().flat_map(|class| [class.class_hash, class.compiled_class_hash]));
+    Ok(felts)
+}
+
+/// AliasReader - Reads the aliases allocated before a batch from the alias contract
+/// The aliases are cached, they are read at the same block while the batch is being built.
+pub struct AliasReader {
+    batch_start_block: u64,
+    /// Storage of the alias contract, zero if the key has no alias.
+    storage: HashMap<Felt, Felt>,
+}
+
+impl AliasReader {
+    pub fn new(batch_start_block: u64) -> Self {
+        Self { batch_start_block, storage: HashMap::new() }
+    }
+
+    /// read - Reads the storage of the alias contract for the keys not read yet
+    pub async fn read(&mut self, keys: impl IntoIterator<Item = Felt>, config: &Config) -> Result<(), JobError> {
+        let keys: HashSet<Felt> = keys.into_iter().filter(|key| !self.storage.contains_key(key)).collect();
+        // There is no alias before the genesis block
+        let Some(block_number) = self.batch_start_block.checked_sub(1) else {
+            self.storage.extend(keys.into_iter().map(|key| (key, Felt::ZERO)));
+            return Ok(());
+        };
+
+        let values: Vec<(Felt, Felt)> = stream::iter(keys)
+            .map(|key| async move {
+                match config
+                    .madara_client()
+                    .get_storage_at(ALIAS_CONTRACT_ADDRESS, key, BlockId::Number(block_number))
+                    .await
+                {
+                    Ok(value) => Ok((key, value)),
+                    // The alias contract is created with the first allocated alias
+                    Err(ProviderError::StarknetError(StarknetError::ContractNotFound)) => Ok((key, Felt::ZERO)),
+                    Err(e) => Err(JobError::ProviderError(format!("Failed to read alias: {}", e))),
+                }
+            })
+            .buffer_unordered(MAX_CONCURRENT_ALIAS_READS)
+            .try_collect()
+            .await?;
+        self.storage.extend(values);
+        Ok(())
+    }
+
+    pub fn batch_start_block(&self) -> u64 {
+        self.batch_start_block
+    }
+
+    pub fn aliases(&self) -> HashMap<Felt, Felt> {
+        self.storage
+            .iter()
+            .filter(|(key, value)| **key != ALIAS_COUNTER_STORAGE_KEY && **value != Felt::ZERO)
+            .map(|(key, value)| (*key, *value))
+            .collect()
+    }
+
+    pub fn counter(&self) -> Option<Felt> {
+        self.storage.get(&ALIAS_COUNTER_STORAGE_KEY).copied().filter(|counter| *counter != Felt::ZERO)
+    }
+}
+
+/// compress_batch_state_diff - Compresses the squashed state diff of a batch like the Starknet OS does: stateful
+/// compression with the aliases allocated before the batch, encoding as felts and stateless compression
+pub async fn compress_batch_state_diff(
+    state_diff: &StateDiff,
+    aliases: &mut AliasReader,
+    config: &Config,
+) -> Result<Vec<Felt>, JobError> {
+    aliases.read(keys_to_alias(state_diff).into_iter().chain([ALIAS_COUNTER_STORAGE_KEY]), config).await?;
+    let compressed_state_diff = compress_state_diff(state_diff, &aliases.aliases(), aliases.counter())?;
+    Ok(stateless_compression::compress(&state_diff_to_felts(&compressed_state_diff)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn storage_diff(address: u64, keys: &[u64]) -> ContractStorageDiffItem {
+        ContractStorageDiffItem {
+            address: Felt::from(address),
+            storage_entries: keys.iter().map(|key| StorageEntry { key: Felt::from(*key), value: Felt::ONE }).collect(),
+        }
+    }
+
+    fn state_diff(storage_diffs: Vec<ContractStorageDiffItem>) -> StateDiff {
+        StateDiff {
+            storage_diffs,
+            deprecated_declared_classes: vec![],
+            declared_classes: vec![],
+            deployed_contracts: vec![],
+            replaced_classes: vec![],
+            nonces: vec![],
+        }
+    }
+
+    #[test]
+    fn test_keys_to_alias() {
+        let diff = StateDiff {
+            nonces: vec![NonceUpdate { contract_address: Felt::from(0x500), nonce: Felt::ONE }],
+            deployed_contracts: [0x600, 0x40]
+                .map(|address| DeployedContractItem { address: Felt::from(address), class_hash: Felt::from(0x1234) })
+                .to_vec(),
+            ..state_diff(vec![
+                storage_diff(0x300, &[0x1000, 0x10, 0x200]),
+                storage_diff(0x1, &[0x1000]),
+                storage_diff(0x250, &[0x400, 0x1000]),
+            ])
+        };
+        // Small keys and the storage keys of the system contracts are not aliased
+        assert_eq!(keys_to_alias(&diff), [0x400, 0x1000, 0x250, 0x200, 0x300, 0x500, 0x600].map(Felt::from).to_vec());
+
+        // Storage of the alias contract written by `allocate_aliases_in_storage` of blockifier 0.15.0-rc.1 for the
+        // same state diff, without any alias and with 0x400 already aliased
+        let alias_storage = |existing_aliases: HashMap<Felt, Felt>, alias_counter: Option<Felt>| -> Vec<(Felt, Felt)> {
+            let compressed = compress_state_diff(&diff, &existing_aliases, alias_counter).unwrap();
+            compressed
+                .storage_diffs
+                .last()
+                .unwrap()
+                .storage_entries
+                .iter()
+                .map(|entry| (entry.key, entry.value))
+                .collect()
+        };
+        let blockifier_aliases = |pairs: &[(u64, u64)]| -> Vec<(Felt, Felt)> {
+            pairs.iter().map(|(key, alias)| (Felt::from(*key), Felt::from(*alias))).collect()
+        };
+        assert_eq!(
+            alias_storage(HashMap::new(), None),
+            blockifier_aliases(&[
+                (0x0, 0x87),
+                (0x200, 0x83),
+                (0x250, 0x82),
+                (0x300, 0x84),
+                (0x400, 0x80),
+                (0x500, 0x85),
+                (0x600, 0x86),
+                (0x1000, 0x81)
+            ])
+        );
+        assert_eq!(
+            alias_storage(HashMap::from([(Felt::from(0x400), Felt::from(0x80))]), Some(Felt::from(0x81))),
+            blockifier_aliases(&[
+                (0x0, 0x87),
+                (0x200, 0x83),
+                (0x250, 0x82),
+                (0x300, 0x84),
+                (0x500, 0x85),
+                (0x600, 0x86),
+                (0x1000, 0x81)
+            ])
+        );
+    }
+
+    #[test]
+    fn test_compress_state_diff_allocates_aliases() {
+        let diff = state_diff(vec![storage_diff(0x300, &[0x1000, 0x10])]);
+        let existing_aliases = HashMap::from([(Felt::from(0x1000), Felt::from(0x80))]);
+
+        let compressed = compress_state_diff(&diff, &existing_aliases, Some(Felt::from(0x81))).unwrap();
+
+        assert_eq!(
+            compressed.storage_diffs,
+            vec![
+                ContractStorageDiffItem {
+                    address: Felt::from(0x81),
+                    storage_entries: vec![
+                        StorageEntry { key: Felt::from(0x80), value: Felt::ONE },
+                        StorageEntry { key: Felt::from(0x10), value: Felt::ONE },
+                    ],
+                },
+                ContractStorageDiffItem {
+                    address: ALIAS_CONTRACT_ADDRESS,
+                    storage_entries: vec![
+                        StorageEntry { key: ALIAS_COUNTER_STORAGE_KEY, value: Felt::from(0x82) },
+                        StorageEntry { key: Felt::from(0x300), value: Felt::from(0x81) },
+                    ],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_compress_state_diff_uses_sequencer_aliases() {
+        let mut diff = state_diff(vec![storage_diff(0x300, &[0x1000])]);
+        diff.storage_diffs.push(ContractStorageDiffItem {
+            address: ALIAS_CONTRACT_ADDRESS,
+            storage_entries: vec![
+                StorageEntry { key: ALIAS_COUNTER_STORAGE_KEY, value: Felt::from(0x82) },
+                StorageEntry { key: Felt::from(0x300), value: Felt::from(0x80) },
+                StorageEntry { key: Felt::from(0x1000), value: Felt::from(0x81) },
+            ],
+        });
+
+        let compressed = compress_state_diff(&diff, &HashMap::new(), None).unwrap();
+
+        assert_eq!(compressed.storage_diffs[0], storage_diff(0x80, &[0x81]));
+        // No alias is allocated on top of the ones of the sequencer
+        assert_eq!(compressed.storage_diffs[1], diff.storage_diffs[1]);
+    }
+
+    #[test]
+    fn test_state_diff_to_felts() {
+        let diff = state_diff(vec![storage_diff(0x300, &[0x1000, 0x10])]);
+        let felts = state_diff_to_felts(&diff).unwrap();
+        // n_contracts, address, da_word, 2 storage entries, n_classes
+        assert_eq!(felts.len(), 8);
+        assert_eq!(felts[0], Felt::ONE);
+        assert_eq!(felts[3], Felt::from(0x10));
+    }
+}
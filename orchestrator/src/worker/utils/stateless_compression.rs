@@ -0,0 +1,144 @@
+//! Stateless compression of the data availability felts, as done by the Starknet OS since v0.13.4.
+//!
+//! The unique values are sorted in buckets by bit length and packed together, repeated values are replaced by a
+//! pointer to their first occurrence. The output is:
+//! `[header, ..unique values, ..repeating value pointers, ..bucket index of each element]`.
+
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+use starknet::core::types::Felt;
+use std::collections::HashMap;
+
+const COMPRESSION_VERSION: usize = 0;
+const HEADER_ELM_BOUND: usize = 1 << 20;
+const MAX_N_BITS: u64 = 251;
+/// Bit length of the values of each bucket of unique values.
+const N_BITS_PER_BUCKET: [u64; 6] = [252, 125, 83, 62, 31, 15];
+/// Bucket index of the repeated values.
+const REPEATING_BUCKET_INDEX: usize = N_BITS_PER_BUCKET.len();
+const TOTAL_N_BUCKETS: usize = N_BITS_PER_BUCKET.len() + 1;
+
+/// Number of elements bounded by `elm_bound` packed in a felt.
+fn n_elms_per_felt(elm_bound: &BigUint) -> usize {
+    if *elm_bound <= BigUint::one() {
+        return MAX_N_BITS as usize;
+    }
+    if *elm_bound > BigUint::one() << (MAX_N_BITS / 2) {
+        return 1;
+    }
+    // Number of bits needed to represent `elm_bound - 1`
+    let elm_n_bits = (elm_bound - BigUint::one()).bits();
+    (MAX_N_BITS / elm_n_bits) as usize
+}
+
+/// Packs the elements in felts, each felt holding as many elements as fit in [`MAX_N_BITS`] bits.
+fn pack_in_felts(elms: &[BigUint], elm_bound: &BigUint) -> Vec<Felt> {
+    elms.chunks(n_elms_per_felt(elm_bound))
+        .map(|chunk| {
+            let packed = chunk.iter().rev().fold(BigUint::zero(), |packed, elm| packed * elm_bound + elm);
+            Felt::from_bytes_be_slice(&packed.to_bytes_be())
+        })
+        .collect()
+}
+
+fn pack_usize_in_felts(elms: &[usize], elm_bound: usize) -> Vec<Felt> {
+    let elms: Vec<BigUint> = elms.iter().map(|elm| BigUint::from(*elm)).collect();
+    pack_in_felts(&elms, &BigUint::from(elm_bound))
+}
+
+/// compress - Compresses the data with the stateless compression of the Starknet OS
+pub fn compress(data: &[Felt]) -> Vec<Felt> {
+    let mut buckets: Vec<Vec<BigUint>> = vec![Vec::new(); N_BITS_PER_BUCKET.len()];
+    let mut value_locations: HashMap<Felt, (usize, usize)> = HashMap::new();
+    let mut repeating_value_locations = Vec::new();
+    let mut bucket_index_per_elm = Vec::with_capacity(data.len());
+
+    for value in data {
+        if let Some(location) = value_locations.get(value) {
+            repeating_value_locations.push(*location);
+            bucket_index_per_elm.push(REPEATING_BUCKET_INDEX);
+            continue;
+        }
+        // The smallest bucket fitting the value, the buckets are sorted by decreasing bit length
+        let value_n_bits = BigUint::from_bytes_be(&value.to_bytes_be()).bits();
+        let bucket_index = N_BITS_PER_BUCKET
+            .iter()
+            .rposition(|n_bits| value_n_bits <= *n_bits)
+            .expect("A felt fits in the largest bucket");
+        value_locations.insert(*value, (bucket_index, buckets[bucket_index].len()));
+        buckets[bucket_index].push(BigUint::from_bytes_be(&value.to_bytes_be()));
+        bucket_index_per_elm.push(bucket_index);
+    }
+
+    let bucket_lengths: Vec<usize> = buckets.iter().map(Vec::len).collect();
+    let bucket_offsets: Vec<usize> = bucket_lengths
+        .iter()
+        .scan(0, |offset, len| {
+            let bucket_offset = *offset;
+            *offset += len;
+            Some(bucket_offset)
+        })
+        .collect();
+    let n_unique_values: usize = bucket_lengths.iter().sum();
+    let repeating_value_pointers: Vec<usize> = repeating_value_locations
+        .iter()
+        .map(|(bucket_index, index_in_bucket)| bucket_offsets[*bucket_index] + index_in_bucket)
+        .collect();
+
+    let mut header = vec![COMPRESSION_VERSION, data.len()];
+    header.extend(&bucket_lengths);
+    header.push(repeating_value_pointers.len());
+
+    let mut compressed = pack_usize_in_felts(&header, HEADER_ELM_BOUND);
+    for (bucket, n_bits) in buckets.iter().zip(N_BITS_PER_BUCKET) {
+        compressed.extend(pack_in_felts(bucket, &(BigUint::one() << n_bits)));
+    }
+    compressed.extend(pack_usize_in_felts(&repeating_value_pointers, n_unique_values));
+    compressed.extend(pack_usize_in_felts(&bucket_index_per_elm, TOTAL_N_BUCKETS));
+    compressed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(1, 251)]
+    #[case(7, 83)]
+    #[case(1 << 20, 12)]
+    fn test_n_elms_per_felt(#[case] elm_bound: u64, #[case] expected: usize) {
+        assert_eq!(n_elms_per_felt(&BigUint::from(elm_bound)), expected);
+    }
+
+    #[rstest]
+    #[case(252, 1)]
+    #[case(125, 2)]
+    #[case(83, 3)]
+    #[case(62, 4)]
+    #[case(31, 8)]
+    #[case(15, 16)]
+    fn test_n_elms_per_felt_buckets(#[case] n_bits: u64, #[case] expected: usize) {
+        assert_eq!(n_elms_per_felt(&(BigUint::one() << n_bits)), expected);
+    }
+
+    #[test]
+    fn test_compress_small_values() {
+        // 32 distinct small values and the same values repeated
+        let data: Vec<Felt> = (0..32u64).chain(0..32u64).map(Felt::from).collect();
+        let compressed = compress(&data);
+        // header + 2 felts of 15 bits values + 1 felt of pointers + 1 felt of bucket indices
+        assert_eq!(compressed.len(), 5);
+        assert!(compressed.len() < data.len());
+    }
+
+    #[test]
+    fn test_compress_header() {
+        let data = vec![Felt::MAX, Felt::from(1u64), Felt::MAX];
+        let compressed = compress(&data);
+        // version 0, 3 elements, 1 value of 252 bits, 1 value of 15 bits and 1 repeated value
+        let header = [0usize, 3, 1, 0, 0, 0, 0, 1, 1];
+        assert_eq!(compressed[0], pack_usize_in_felts(&header, HEADER_ELM_BOUND)[0]);
+        assert_eq!(compressed[1], Felt::MAX);
+    }
+}